@@ -0,0 +1,200 @@
+//! Address Validation API error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the Google Maps Address Validation API
+/// client.
+
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+#[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::address_validation::error), url(docsrs)))]
+pub enum Error {
+    /// The address has no address lines. At least one address line is
+    /// required.
+    AddressLinesRequired,
+
+    /// The address is too long. Google does not accept addresses whose fields
+    /// add up to more than 280 characters. Contains the length of the address.
+    AddressTooLong(usize),
+
+    /// The request body must be built before the request may be sent to the
+    /// Google Maps Address Validation API server.
+    BodyNotBuilt,
+
+    /// The HTTP request was unsuccessful. Contains the HTTP status code, the
+    /// headers that help explain the failure (such as `Retry-After` and
+    /// Google's `X-Goog-*` headers), and the truncated response body. The
+    /// body usually contains Google's explanation of the error.
+    #[cfg(feature = "reqwest")]
+    HttpUnsuccessfulResponse {
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String,
+    },
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// confirmation level code. See
+    /// `google_maps\src\address_validation\response\confirmation_level.rs` for
+    /// more information.
+    InvalidConfirmationLevelCode(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// granularity code. See
+    /// `google_maps\src\address_validation\response\granularity.rs` for more
+    /// information.
+    InvalidGranularityCode(String),
+
+    /// API client library attempted to parse a string that contained an invalid
+    /// possible next action code. See
+    /// `google_maps\src\address_validation\response\possible_next_action.rs`
+    /// for more information.
+    InvalidPossibleNextActionCode(String),
+
+    /// The dependency library Reqwest generated an error.
+    #[cfg(feature = "reqwest")]
+    Reqwest(crate::ReqError),
+
+    /// The dependency library Reqwest generated an error. The error could
+    /// not be passed normally so a `String` representation is passed instead.
+    #[cfg(feature = "reqwest")]
+    ReqwestMessage(String),
+
+    /// The dependency library Serde JSON generated an error.
+    SimdJson(simd_json::Error),
+
+    /// The dependency library Serde JSON could not parse the response from the
+    /// server. The response body is included to help with debugging. It is
+    /// truncated to the length set by `GoogleMapsClient::with_error_body_length`.
+    SimdJsonWithBody(simd_json::Error, String),
+    /// The service responded successfully, but not with JSON, for example
+    /// with an HTML error page from a proxy or a sign-in page. Contains the
+    /// response's `Content-Type` header, and the response body truncated to the
+    /// length set by `GoogleMapsClient::with_error_body_length`.
+    #[cfg(feature = "reqwest")]
+    UnexpectedContentType {
+        content_type: String,
+        body_snippet: String,
+    },
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AddressLinesRequired => write!(f, "Google Maps Address Validation API client: \
+                The address must have at least one address line."),
+            Self::AddressTooLong(length) => write!(f, "Google Maps Address Validation API client: \
+                The address is {length} characters long. \
+                Google accepts addresses of up to 280 characters."),
+            Self::BodyNotBuilt => write!(f, "Google Maps Address Validation API client library: \
+                The request body must be built before the request may be sent to the Google Cloud Maps Platform. \
+                Ensure the build() method is called before post()."),
+            #[cfg(feature = "reqwest")]
+            Self::HttpUnsuccessfulResponse { status, body, .. } => write!(f,
+                "Google Maps Address Validation API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status: `{body}`"),
+            Self::InvalidConfirmationLevelCode(code) => write!(f, "Google Maps Address Validation API client: \
+                `{code}` is not a valid confirmation level code. \
+                Valid codes are `CONFIRMATION_LEVEL_UNSPECIFIED`, `CONFIRMED`, \
+                `UNCONFIRMED_BUT_PLAUSIBLE`, and `UNCONFIRMED_AND_SUSPICIOUS`."),
+            Self::InvalidGranularityCode(code) => write!(f, "Google Maps Address Validation API client: \
+                `{code}` is not a valid granularity code. \
+                Valid codes are `GRANULARITY_UNSPECIFIED`, `SUB_PREMISE`, \
+                `PREMISE`, `PREMISE_PROXIMITY`, `BLOCK`, `ROUTE`, and `OTHER`."),
+            Self::InvalidPossibleNextActionCode(code) => write!(f, "Google Maps Address Validation API client: \
+                `{code}` is not a valid possible next action code. \
+                Valid codes are `POSSIBLE_NEXT_ACTION_UNSPECIFIED`, `FIX`, \
+                `CONFIRM_ADD_SUBPREMISES`, `CONFIRM`, and `ACCEPT`."),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(error) => write!(f, "Google Maps Address Validation API client in the Reqwest library: {error}"),
+            #[cfg(feature = "reqwest")]
+            Self::ReqwestMessage(error) => write!(f, "Google Maps Address Validation API client in the Reqwest library: {error}"),
+            Self::SimdJson(error) => write!(f, "Google Maps Address Validation API client in the Serde JSON library: {error}"),
+            Self::SimdJsonWithBody(error, body) => write!(f, "Google Maps Address Validation API client in the Serde JSON library: {error}. \
+                The response body was: `{body}`"),
+            #[cfg(feature = "reqwest")]
+            Self::UnexpectedContentType { content_type, body_snippet } => write!(f,
+                "Google Maps Address Validation API client: \
+                The service responded with `{content_type}` content instead of JSON. \
+                This is usually an error page. The response body was: `{body_snippet}`"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for Error {
+    /// This trait converts from an Reqwest error type (`reqwest::Error`) into a
+    /// Google Maps Address Validation API error type
+    /// (`google_maps::address_validation::error::Error`) by wrapping it inside.
+    /// This function is required to use the `?` operator.
+    fn from(error: reqwest::Error) -> Self {
+        Self::Reqwest(crate::ReqError::from(error))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl From<simd_json::Error> for Error {
+    /// This trait converts from an Serde JSON (`simd_json::Error`) error type
+    /// into a Google Maps Address Validation API error type
+    /// (`google_maps::address_validation::error::Error`) by wrapping it inside.
+    /// This function is required to use the `?` operator.
+    fn from(error: simd_json::Error) -> Self {
+        Self::SimdJson(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl crate::client::HttpError for Error {
+    /// Reports the HTTP-level failures of the retry loop as Google Maps
+    /// Address Validation API errors.
+    fn unexpected_content_type(content_type: String, body_snippet: String) -> Self {
+        Self::UnexpectedContentType {
+            content_type,
+            body_snippet,
+        } // UnexpectedContentType
+    } // fn
+
+    fn http_unsuccessful_response(
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String
+    ) -> Self {
+        Self::HttpUnsuccessfulResponse {
+            status,
+            headers,
+            body,
+        } // HttpUnsuccessfulResponse
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(message: String) -> Self {
+        Self::ReqwestMessage(message)
+    } // fn
+} // impl
+
+#[cfg(feature = "reqwest")]
+impl crate::client::JsonError for Error {
+    /// Reports a response body that could not be parsed as a Google Maps
+    /// Address Validation API error.
+    fn simd_json_with_body(error: simd_json::Error, body: String) -> Self {
+        Self::SimdJsonWithBody(error, body)
+    } // fn
+} // impl
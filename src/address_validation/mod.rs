@@ -0,0 +1,62 @@
+//! The **Address Validation API** is a service that accepts an address. It
+//! identifies address components and validates them. It also standardizes the
+//! address for mailing and finds the best known latitude/longitude coordinates
+//! for it. Optionally, for addresses in the United States and Puerto Rico, it
+//! can enable the Coding Accuracy Support System (CASS™).
+//!
+//! # [Get Started](https://developers.google.com/maps/documentation/address-validation/overview)
+//!
+//! The Address Validation API helps to determine whether an address is
+//! deliverable, for example during a checkout flow. The response contains:
+//!
+//! * A `Verdict` that summarizes how well the address could be validated, and
+//!   Google's suggestion of what to do next.
+//!
+//! * The standardized, post-processed `Address`, with the confirmation level
+//!   of each of its components.
+//!
+//! * The `Geocode` of the address, and `AddressMetadata` such as whether the
+//!   address is a residence or a business.
+//!
+//! # [Send a request](https://developers.google.com/maps/documentation/address-validation/requests-validate-address)
+//!
+//! Unlike most Google Maps APIs, the Address Validation API is sent as an
+//! HTTP `POST` request with a JSON body, rather than as a `GET` request with a
+//! query string. It still observes the client's rate limiting and retry
+//! policy.
+//!
+//! # [Usage and billing](https://developers.google.com/maps/documentation/address-validation/usage-and-billing)
+//!
+//! If an address needs to be re-validated, for example after the customer has
+//! corrected it, pass the `response_id` of the first response to
+//! `with_previous_response_id()` so that the requests are treated as one
+//! validation.
+
+pub mod error;
+pub mod postal_address;
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://addressvalidation.googleapis.com/v1:validateAddress";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::address_validation::{
+    error::Error as AddressValidationError,
+    postal_address::PostalAddress,
+    request::Request as AddressValidationRequest,
+    response::{
+        address::Address as ValidatedAddress,
+        address_component::{AddressComponent as ValidatedAddressComponent, ComponentName},
+        address_metadata::AddressMetadata,
+        confirmation_level::ConfirmationLevel,
+        geocode::Geocode,
+        granularity::Granularity,
+        possible_next_action::PossibleNextAction,
+        validation_result::ValidationResult,
+        verdict::Verdict,
+        Response as AddressValidationResponse,
+    }, // response
+}; // crate::address_validation
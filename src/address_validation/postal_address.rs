@@ -0,0 +1,123 @@
+//! Contains the `PostalAddress` struct and its associated traits. It is used
+//! both to send an address to the Address Validation API, and to return the
+//! standardized address.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents a [postal
+/// address](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#postaladdress),
+/// for example for postal delivery or payments addresses.
+///
+/// Given a postal address, a postal service can deliver items to a premise,
+/// P.O. Box, or similar. It is not intended to model geographical locations
+/// such as roads, towns, or mountains.
+///
+/// The fields are serialized in Google's `camelCase` format, since this struct
+/// is sent to Google as-is.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostalAddress {
+    /// The [CLDR region code](https://cldr.unicode.org/) of the country or
+    /// region of the address, for example `US` or `CH`. Note that this is not
+    /// always the same as the country-code top-level domain used by `Region`,
+    /// for example the United Kingdom is `GB` rather than `UK`.
+    #[serde(default)]
+    pub region_code: String,
+
+    /// The BCP-47 language code of the contents of this address, if known.
+    /// The Address Validation API ignores this field when validating.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+
+    /// The postal code of the address. Not all countries use or require postal
+    /// codes to be present, but where they are used, they may trigger
+    /// additional validation with other parts of the address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
+
+    /// An additional, country-specific, sorting code. This is not used in most
+    /// regions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sorting_code: Option<String>,
+
+    /// The highest administrative subdivision which is used for postal
+    /// addresses of a country or region. For example, this can be a state, a
+    /// province, an oblast, or a prefecture.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub administrative_area: Option<String>,
+
+    /// Generally refers to the city or town portion of the address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locality: Option<String>,
+
+    /// The sublocality of the address. For example, this can be a
+    /// neighborhood, borough, or district.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sublocality: Option<String>,
+
+    /// Unstructured address lines describing the lower levels of an address.
+    /// An entire address may also be passed as address lines, with the other
+    /// fields left empty, in which case Google will parse it.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub address_lines: Vec<String>,
+
+    /// The recipient at the address. Google does not validate this field.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recipients: Vec<String>,
+
+    /// The name of the organization at the address. Google does not validate
+    /// this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl PostalAddress {
+    /// Creates a `PostalAddress` from a CLDR region code, such as `US`, and
+    /// one or more address lines.
+    ///
+    /// ## Arguments
+    ///
+    /// * `region_code` ‧ The CLDR region code of the address's country or
+    ///   region.
+    ///
+    /// * `address_lines` ‧ The lines of the address. For example,
+    ///   `["1600 Amphitheatre Pkwy", "Mountain View, CA 94043"]`.
+    pub fn new<C, S>(region_code: impl Into<String>, address_lines: C) -> Self
+    where
+        C: IntoIterator<Item = S>,
+        S: Into<String> {
+        Self {
+            region_code: region_code.into(),
+            address_lines: address_lines.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        } // struct
+    } // fn
+
+    /// Returns the total number of characters in the address's fields. Google
+    /// rejects addresses whose total length exceeds 280 characters.
+    #[must_use]
+    pub fn character_count(&self) -> usize {
+        [
+            self.region_code.as_str(),
+            self.language_code.as_deref().unwrap_or_default(),
+            self.postal_code.as_deref().unwrap_or_default(),
+            self.sorting_code.as_deref().unwrap_or_default(),
+            self.administrative_area.as_deref().unwrap_or_default(),
+            self.locality.as_deref().unwrap_or_default(),
+            self.sublocality.as_deref().unwrap_or_default(),
+            self.organization.as_deref().unwrap_or_default(),
+        ]
+        .into_iter()
+        .chain(self.address_lines.iter().map(String::as_str))
+        .chain(self.recipients.iter().map(String::as_str))
+        .map(|field| field.chars().count())
+        .sum()
+    } // fn
+} // impl
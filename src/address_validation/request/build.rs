@@ -0,0 +1,70 @@
+use crate::address_validation::{
+    error::Error as AddressValidationError, postal_address::PostalAddress, request::Request,
+}; // crate::address_validation
+use serde::Serialize;
+
+// -----------------------------------------------------------------------------
+
+/// The maximum total length of an address's fields accepted by Google.
+const MAX_ADDRESS_LENGTH: usize = 280;
+
+// -----------------------------------------------------------------------------
+//
+/// The JSON body of an Address Validation request.
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Body<'b> {
+    address: &'b PostalAddress,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_response_id: Option<&'b str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_usps_cass: Option<bool>,
+} // struct
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the JSON request body for the Google Maps Address Validation
+    /// request based on the input provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an `AddressLinesRequired` error if the address has no address
+    ///   lines.
+    ///
+    /// * Returns an `AddressTooLong` error if the address's fields add up to
+    ///   more than 280 characters.
+
+    pub fn build(&mut self) -> Result<&'a mut Request<'_>, AddressValidationError> {
+        // Google requires at least one address line:
+        if self.address.address_lines.iter().all(|line| line.trim().is_empty()) {
+            return Err(AddressValidationError::AddressLinesRequired);
+        } // if
+
+        // Google rejects long addresses, so they are caught before they are
+        // sent:
+        let length = self.address.character_count();
+        if length > MAX_ADDRESS_LENGTH {
+            return Err(AddressValidationError::AddressTooLong(length));
+        } // if
+
+        let body = Body {
+            address: &self.address,
+            previous_response_id: self.previous_response_id.as_deref(),
+            enable_usps_cass: self.enable_usps_cass,
+        }; // Body
+
+        // Set request body in Request struct.
+        self.body = Some(simd_json::serde::to_string(&body)?);
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,27 @@
+use crate::address_validation::{
+    request::Request as AddressValidationRequest,
+    response::Response as AddressValidationResponse,
+}; // crate::address_validation
+use crate::error::Error as GoogleMapsError;
+
+// =============================================================================
+
+impl<'a> AddressValidationRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the request you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.build()?.post()?` chain needed at the end of the builder
+    /// pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<AddressValidationResponse, GoogleMapsError> {
+        self.build()?.post().await
+    } // fn
+} // impl
@@ -0,0 +1,92 @@
+//! **Look in this module for documentation on building your _Address
+//! Validation_ request**. In particular, look at the _Request_ struct for
+//! examples of the builder pattern. This module contains the tools (enums,
+//! structs, methods) for building your Google Maps Platform request.
+
+// -----------------------------------------------------------------------------
+
+mod build;
+#[cfg(feature = "reqwest")]
+mod execute;
+mod new;
+#[cfg(feature = "reqwest")]
+mod post;
+#[cfg(feature = "reqwest")]
+mod post_raw;
+mod with_administrative_area;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
+mod with_enable_usps_cass;
+mod with_locality;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
+mod with_postal_address;
+mod with_postal_code;
+mod with_previous_response_id;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
+
+// -----------------------------------------------------------------------------
+
+use crate::address_validation::postal_address::PostalAddress;
+use crate::client::GoogleMapsClient;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Address Validation_ request**. The methods implemented for this struct are
+/// what's used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The address being validated. Unformatted addresses should be submitted
+    /// as address lines.
+    address: PostalAddress,
+
+    // Optional parameters:
+    // --------------------
+    /// The `response_id` of the first response, when an address is being
+    /// re-validated. See method `with_previous_response_id()` for more
+    /// information.
+    previous_response_id: Option<String>,
+
+    /// Enables USPS CASS compatible mode. See method
+    /// `with_enable_usps_cass()` for more information.
+    enable_usps_cass: Option<bool>,
+
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+
+    // Internal use only:
+    // ------------------
+    /// JSON request body that is to be submitted to the Google Cloud Maps
+    /// Platform.
+    body: Option<String>,
+} // struct
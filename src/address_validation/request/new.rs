@@ -0,0 +1,42 @@
+use crate::address_validation::{postal_address::PostalAddress, request::Request};
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for an Address Validation request with
+    /// the address to be validated.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `address` ‧ The postal address to validate. It must have at least one
+    ///   address line.
+
+    #[must_use]
+    pub const fn new(client: &GoogleMapsClient, address: PostalAddress) -> Request<'_> {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            address,
+            // Optional parameters:
+            previous_response_id: None,
+            enable_usps_cass: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
+            // Internal use only:
+            body: None,
+        } // struct
+    } // fn
+} // impl
@@ -0,0 +1,66 @@
+use crate::address_validation::{
+    error::Error as AddressValidationError, request::Request as AddressValidationRequest,
+    response::Response as AddressValidationResponse, SERVICE_URL,
+}; // crate::address_validation
+use crate::client::{Method, RetrySettings};
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+
+// -----------------------------------------------------------------------------
+
+impl AddressValidationRequest<'_> {
+    /// Performs the HTTP post request and returns the response to the caller.
+    ///
+    /// The JSON body that was built by `build()` is sent to Google. The
+    /// request observes the same rate limiting and retry policy as the `get()`
+    /// methods of the other APIs.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(api = %Api::AddressValidation, attempts, duration_ms, status)
+    )]
+    pub async fn post(&self) -> Result<AddressValidationResponse, GoogleMapsError> {
+        // Build the URL for the HTTP post request. The API key is the only
+        // query string parameter, everything else is sent in the body:
+        let url = format!("{SERVICE_URL}?key={key}", key = self.client.key);
+
+        // If the request body has not been built, return an error:
+        let Some(body) = &self.body else {
+            return Err(AddressValidationError::BodyNotBuilt)?;
+        }; // let
+
+        tracing::info!("making HTTP POST request to Google Maps Address Validation API");
+
+        // Observe any rate limiting before executing the request, and retry
+        // it according to its retry settings:
+        let response = self
+            .client
+            .execute_with_retry(
+                Method::Post(body),
+                &url,
+                RetrySettings {
+                    api: Api::AddressValidation,
+                    backoff: self.backoff.as_ref(),
+                    max_retries: self.max_retries,
+                    timeout: self.timeout,
+                    deadline: self.deadline,
+                }, // RetrySettings
+                "application/json",
+                |body| -> Result<AddressValidationResponse, backoff::Error<AddressValidationError>> {
+                    // The Address Validation API reports errors with an HTTP
+                    // status code rather than in the body, so a response that
+                    // was parsed is returned to the caller as-is:
+                    self.client.parse_json(&body)
+                }, // classify
+            )
+            .await?;
+
+        // Return response to caller:
+        Ok(response)
+    } // fn
+} // impl
@@ -0,0 +1,106 @@
+use crate::address_validation::{
+    error::Error as AddressValidationError, request::Request as AddressValidationRequest,
+    SERVICE_URL,
+}; // crate::address_validation
+use crate::client::{Method, RetrySettings};
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+
+// -----------------------------------------------------------------------------
+
+impl AddressValidationRequest<'_> {
+    /// Performs the HTTP post request and returns the raw response body to the
+    /// caller, without deserializing it.
+    ///
+    /// This is an escape hatch for reading fields that this crate does not
+    /// model yet. It is the counterpart of the other APIs' `get_raw()`
+    /// methods, named after `post()`. The request is sent and retried like
+    /// `post()`. The Address Validation API reports errors with an HTTP status
+    /// code, so an unsuccessful response is still returned as an error, but
+    /// the body of a successful response is not interpreted.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(api = %Api::AddressValidation, attempts, duration_ms, status)
+    )]
+    pub async fn post_raw(&self) -> Result<String, GoogleMapsError> {
+        // Build the URL for the HTTP post request. The API key is the only
+        // query string parameter, everything else is sent in the body:
+        let url = format!("{SERVICE_URL}?key={key}", key = self.client.key);
+
+        // If the request body has not been built, return an error:
+        let Some(body) = &self.body else {
+            return Err(AddressValidationError::BodyNotBuilt)?;
+        }; // let
+
+        tracing::info!("making HTTP POST request to Google Maps Address Validation API");
+
+        // Observe any rate limiting before executing the request, and retry
+        // it according to its retry settings:
+        let response = self
+            .client
+            .execute_with_retry(
+                Method::Post(body),
+                &url,
+                RetrySettings {
+                    api: Api::AddressValidation,
+                    backoff: self.backoff.as_ref(),
+                    max_retries: self.max_retries,
+                    timeout: self.timeout,
+                    deadline: self.deadline,
+                }, // RetrySettings
+                "application/json",
+                |body| -> Result<String, backoff::Error<AddressValidationError>> {
+                    // The body is returned to the caller without deserializing
+                    // it:
+                    Ok(body.text())
+                }, // classify
+            )
+            .await?;
+
+        // Return response to caller:
+        Ok(response)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::client::{no_delay_backoff, TestServer};
+    use crate::error::Error as GoogleMapsError;
+    use crate::GoogleMapsClient;
+
+    /// A response with a field that this crate does not model.
+    const RESPONSE: &str = r#"{
+        "result": {
+            "verdict": { "inputGranularity": "PREMISE" },
+            "unmodeledField": true
+        },
+        "responseId": "test"
+    }"#;
+
+    #[tokio::test]
+    async fn returns_the_body_as_is() -> Result<(), GoogleMapsError> {
+        let server = TestServer::start(vec![(200, RESPONSE)]);
+        let client = GoogleMapsClient::builder("AIzaTest")
+            .with_base_url(server.base_url.clone())
+            .with_backoff(no_delay_backoff())
+            .build()?;
+
+        let body = client
+            .address_validation("US", ["1600 Amphitheatre Parkway"])
+            .build()?
+            .post_raw()
+            .await?;
+
+        assert_eq!(server.requests(), 1);
+        assert_eq!(body, RESPONSE);
+        Ok(())
+    } // fn
+} // mod
@@ -0,0 +1,32 @@
+use crate::address_validation::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the highest administrative subdivision of the address, such
+    /// as a state, province, or prefecture. Use this method when the address
+    /// is held in separate fields.
+    ///
+    /// ## Arguments
+    ///
+    /// * `administrative_area` ‧ The state, province, or prefecture of the
+    ///   address. For example, `CA` for California.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_administrative_area("CA")
+    /// ```
+
+    pub fn with_administrative_area(
+        &'a mut self,
+        administrative_area: impl Into<String>
+    ) -> &'a mut Self {
+        // Set administrative area in Request struct.
+        self.address.administrative_area = Some(administrative_area.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,41 @@
+use crate::address_validation::request::Request;
+use backoff::ExponentialBackoff;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the retry policy to use for this request.
+    ///
+    /// ## Arguments
+    ///
+    /// * `backoff` ‧ The [ExponentialBackoff](https://docs.rs/backoff/latest/backoff/exponential/struct.ExponentialBackoff.html)
+    ///   policy used when an HTTP request fails with an error that is eligible
+    ///   for retries. Its `initial_interval` field sets the delay before the
+    ///   first retry and its `max_elapsed_time` field sets how long the client
+    ///   will keep retrying before giving up. If this method is not called,
+    ///   the client's default retry policy is used, see
+    ///   `GoogleMapsClient::with_backoff()`.
+    ///
+    /// ## Example
+    ///
+    /// * Retry quickly, and give up after 5 seconds:
+    /// ```rust
+    /// .with_backoff(ExponentialBackoff {
+    ///     initial_interval: std::time::Duration::from_millis(100),
+    ///     max_elapsed_time: Some(std::time::Duration::from_secs(5)),
+    ///     ..ExponentialBackoff::default()
+    /// })
+    /// ```
+
+    pub fn with_backoff(
+        &'a mut self,
+        backoff: ExponentialBackoff
+    ) -> &'a mut Self {
+        // Set retry policy in Request struct.
+        self.backoff = Some(backoff);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,39 @@
+use crate::address_validation::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Enables USPS CASS compatible mode.
+    ///
+    /// ## Description
+    ///
+    /// This affects only the `usps_data` field of the validation result, which
+    /// is not modelled by this crate yet. It is only supported for addresses
+    /// in the United States and Puerto Rico, and the address's region code
+    /// should be `US` or `PR`. Google recommends using a componentized address
+    /// when this mode is enabled, or specifying at least two address lines,
+    /// where the first line contains the street number and name, and the
+    /// second line contains the city, state, and zip code.
+    ///
+    /// ## Arguments
+    ///
+    /// * `enable_usps_cass` ‧ Whether USPS CASS processing is enabled.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_enable_usps_cass(true)
+    /// ```
+
+    pub fn with_enable_usps_cass(
+        &'a mut self,
+        enable_usps_cass: impl Into<bool>
+    ) -> &'a mut Self {
+        // Set USPS CASS mode in Request struct.
+        self.enable_usps_cass = Some(enable_usps_cass.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
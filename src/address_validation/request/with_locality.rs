@@ -0,0 +1,32 @@
+use crate::address_validation::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the city or town of the address. Use this method when the
+    /// address is held in separate fields, rather than passing the city or
+    /// town as part of the address lines.
+    ///
+    /// ## Arguments
+    ///
+    /// * `locality` ‧ The city or town portion of the address. For example,
+    ///   `Mountain View`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_locality("Mountain View")
+    /// ```
+
+    pub fn with_locality(
+        &'a mut self,
+        locality: impl Into<String>
+    ) -> &'a mut Self {
+        // Set locality in Request struct.
+        self.address.locality = Some(locality.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
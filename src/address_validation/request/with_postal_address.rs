@@ -0,0 +1,41 @@
+use crate::address_validation::{postal_address::PostalAddress, request::Request};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces the address to be validated.
+    ///
+    /// Most addresses can be given to `GoogleMapsClient::address_validation()`
+    /// as a region code and address lines. This method allows every field of
+    /// a `PostalAddress` to be set at once, for example an address that is
+    /// already held in a structured form.
+    ///
+    /// ## Arguments
+    ///
+    /// * `postal_address` ‧ The address to validate. It must have at least one
+    ///   address line, and its fields may add up to no more than 280
+    ///   characters.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_postal_address(PostalAddress {
+    ///     region_code: "US".to_string(),
+    ///     locality: Some("Mountain View".to_string()),
+    ///     address_lines: vec!["1600 Amphitheatre Pkwy".to_string()],
+    ///     ..PostalAddress::default()
+    /// })
+    /// ```
+
+    pub fn with_postal_address(
+        &'a mut self,
+        postal_address: impl Into<PostalAddress>
+    ) -> &'a mut Self {
+        // Set address in Request struct.
+        self.address = postal_address.into();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,32 @@
+use crate::address_validation::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the postal code of the address. Where postal codes are used,
+    /// they may trigger additional validation with other parts of the
+    /// address.
+    ///
+    /// ## Arguments
+    ///
+    /// * `postal_code` ‧ The postal code of the address. For example,
+    ///   `94043`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_postal_code("94043")
+    /// ```
+
+    pub fn with_postal_code(
+        &'a mut self,
+        postal_code: impl Into<String>
+    ) -> &'a mut Self {
+        // Set postal code in Request struct.
+        self.address.postal_code = Some(postal_code.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,39 @@
+use crate::address_validation::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Links this request to an earlier validation of the same address.
+    ///
+    /// ## Description
+    ///
+    /// This field must be empty for the first address validation request. If
+    /// more requests are necessary to fully validate a single address, for
+    /// example if the changes the customer makes after the initial validation
+    /// need to be re-validated, then each follow-up request must set this to
+    /// the `response_id` from the very first response in the validation
+    /// sequence.
+    ///
+    /// ## Arguments
+    ///
+    /// * `previous_response_id` ‧ The `response_id` of the first
+    ///   `AddressValidationResponse` for this address.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_previous_response_id(&first_response.response_id)
+    /// ```
+
+    pub fn with_previous_response_id(
+        &'a mut self,
+        previous_response_id: impl Into<String>
+    ) -> &'a mut Self {
+        // Set previous response ID in Request struct.
+        self.previous_response_id = Some(previous_response_id.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
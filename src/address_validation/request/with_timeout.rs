@@ -0,0 +1,43 @@
+use crate::address_validation::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies how long each HTTP request attempt may take before it is
+    /// abandoned.
+    ///
+    /// The timeout covers the whole attempt: from connecting to the server
+    /// until the response body has been read. A timed-out attempt is eligible
+    /// for retries, and every retry is given its own fresh timeout. The retry
+    /// policy's `max_elapsed_time` continues to limit the total time spent
+    /// retrying, see `with_backoff()` for more information.
+    ///
+    /// To limit only the time spent connecting to the server, configure a
+    /// `reqwest` client with `reqwest::ClientBuilder::connect_timeout()`, and
+    /// pass it to `GoogleMapsClient::try_from_reqwest_client()`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `timeout` ‧ The maximum duration of a single HTTP request attempt. If
+    ///   this method is not called, the `reqwest` client's own timeout, if
+    ///   any, applies.
+    ///
+    /// ## Example
+    ///
+    /// * Abandon any attempt that takes longer than 10 seconds:
+    /// ```rust
+    /// .with_timeout(std::time::Duration::from_secs(10))
+    /// ```
+
+    pub fn with_timeout(
+        &'a mut self,
+        timeout: std::time::Duration
+    ) -> &'a mut Self {
+        // Set timeout in Request struct.
+        self.timeout = Some(timeout);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
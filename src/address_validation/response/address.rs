@@ -0,0 +1,75 @@
+//! Contains the `Address` struct. It holds the standardized, post-processed
+//! address, and the details of each of its components.
+
+use crate::address_validation::{
+    postal_address::PostalAddress, response::address_component::AddressComponent,
+}; // crate::address_validation
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Details of the post-processed address. Post-processing includes correcting
+/// misspelled parts of the address, replacing incorrect parts, and inferring
+/// missing parts. See
+/// [Address](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#address).
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Address {
+    /// The post-processed address, formatted as a single-line address
+    /// following the address formatting rules of the region where the address
+    /// is located.
+    #[serde(alias = "formattedAddress")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub formatted_address: Option<String>,
+
+    /// The post-processed address, represented as a postal address.
+    #[serde(alias = "postalAddress")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub postal_address: Option<PostalAddress>,
+
+    /// The individual address components of the formatted and corrected
+    /// address, along with validation information. This provides information
+    /// on the validation status of the individual components.
+    #[serde(alias = "addressComponents")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub address_components: Vec<AddressComponent>,
+
+    /// The types of components that were expected to be present in a
+    /// correctly formatted mailing address but were not found in the input
+    /// and could not be inferred. For example, `street_number`.
+    #[serde(alias = "missingComponentTypes")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub missing_component_types: Vec<String>,
+
+    /// The types of the components that are present in the
+    /// `address_components` but could not be confirmed to be correct.
+    #[serde(alias = "unconfirmedComponentTypes")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unconfirmed_component_types: Vec<String>,
+
+    /// Any tokens in the input that could not be resolved. This might be an
+    /// input that was not recognized as a valid part of an address.
+    #[serde(alias = "unresolvedTokens")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unresolved_tokens: Vec<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Address {
+    /// Returns the address components that Google could not confirm, or that
+    /// it inferred, replaced, or spell-corrected. These are the components
+    /// that a customer may want to review.
+    pub fn components_to_review(&self) -> impl Iterator<Item = &AddressComponent> {
+        self.address_components.iter().filter(|component| {
+            !component.is_confirmed()
+                || component.inferred
+                || component.replaced
+                || component.spell_corrected
+        }) // filter
+    } // fn
+} // impl
@@ -0,0 +1,87 @@
+//! Contains the `AddressComponent` struct. It describes a single component of
+//! the validated address, and how confident Google is that it is correct.
+
+use crate::address_validation::response::confirmation_level::ConfirmationLevel;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Represents an address component, such as a street, city, or state. See
+/// [AddressComponent](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#addresscomponent).
+
+// The flags mirror Google's response, so they are kept as `bool`s:
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AddressComponent {
+    /// The name for this component.
+    #[serde(alias = "componentName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_name: Option<ComponentName>,
+
+    /// The type of the address component, for example `route` or
+    /// `postal_code`. See [Table 2: Additional types returned by the Places
+    /// service](https://developers.google.com/places/web-service/supported_types#table2)
+    /// for a list of possible types.
+    #[serde(alias = "componentType")]
+    #[serde(default)]
+    pub component_type: String,
+
+    /// Indicates the level of certainty that the component is correct.
+    #[serde(alias = "confirmationLevel")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmation_level: Option<ConfirmationLevel>,
+
+    /// Indicates that the component was not part of the input, but was
+    /// inferred for the address location. Google recommends reviewing inferred
+    /// components.
+    #[serde(default)]
+    pub inferred: bool,
+
+    /// Indicates a correction to a misspelling in the component name. The API
+    /// does not always flag changes from one spelling variant to another, such
+    /// as when changing "centre" to "center".
+    #[serde(alias = "spellCorrected")]
+    #[serde(default)]
+    pub spell_corrected: bool,
+
+    /// Indicates the name of the component was replaced with a completely
+    /// different one, for example a wrong postal code being replaced with one
+    /// that is correct for the address.
+    #[serde(default)]
+    pub replaced: bool,
+
+    /// Indicates an address component that is not expected to be present in a
+    /// postal address for the given region. Google has retained it only
+    /// because it was part of the input.
+    #[serde(default)]
+    pub unexpected: bool,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl AddressComponent {
+    /// Returns `true` if Google confirmed that this component exists and makes
+    /// sense in the context of the rest of the address.
+    #[must_use]
+    pub fn is_confirmed(&self) -> bool {
+        self.confirmation_level == Some(ConfirmationLevel::Confirmed)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A wrapper for the name of an address component.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ComponentName {
+    /// The name text. For example, "5th Avenue" for a street name or "1253"
+    /// for a street number.
+    #[serde(default)]
+    pub text: String,
+
+    /// The BCP-47 language code. This will not be present if the component
+    /// name is not associated with a language, such as a street number.
+    #[serde(alias = "languageCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_code: Option<String>,
+} // struct
@@ -0,0 +1,29 @@
+//! Contains the `AddressMetadata` struct. It describes what kind of place the
+//! validated address is.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The metadata for the address. Metadata is not guaranteed to be fully
+/// populated for every address sent to the Address Validation API. See
+/// [AddressMetadata](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#addressmetadata).
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct AddressMetadata {
+    /// Indicates that this is the address of a business. If unset, indicates
+    /// that the value is unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business: Option<bool>,
+
+    /// Indicates that the address of a PO box. If unset, indicates that the
+    /// value is unknown.
+    #[serde(alias = "poBox")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub po_box: Option<bool>,
+
+    /// Indicates that this is the address of a residence. If unset, indicates
+    /// that the value is unknown.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub residential: Option<bool>,
+} // struct
@@ -0,0 +1,140 @@
+//! Contains the `ConfirmationLevel` enum and its associated traits. It
+//! indicates how confident Google is that an address component is correct.
+
+use crate::address_validation::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The different possible values for [confirmation
+/// levels](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#confirmationlevel)
+/// of an address component.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum ConfirmationLevel {
+    /// Default value. This value is unused.
+    #[serde(rename = "CONFIRMATION_LEVEL_UNSPECIFIED")]
+    Unspecified,
+
+    /// We were able to verify that this component exists and makes sense in the
+    /// context of the rest of the address.
+    #[serde(rename = "CONFIRMED")]
+    Confirmed,
+
+    /// This component could not be confirmed, but it is plausible that it
+    /// exists. For example, a street number within a known valid range of
+    /// numbers on a street where specific house numbers are not known.
+    #[serde(rename = "UNCONFIRMED_BUT_PLAUSIBLE")]
+    UnconfirmedButPlausible,
+
+    /// This component was not confirmed and is likely to be wrong. For example,
+    /// a neighborhood that does not fit the rest of the address.
+    #[serde(rename = "UNCONFIRMED_AND_SUSPICIOUS")]
+    UnconfirmedAndSuspicious,
+
+    /// If the confirmation level is not recognized by
+    /// [serde](https://crates.io/crates/serde) when reading data from
+    /// Google it will be assigned to this `Other` variant.
+    ///
+    /// As new confirmation levels are added to Google Maps, they must also be
+    /// added to this crate. However, in the meantime, the `Other` catch-all
+    /// variant allows `serde` to read data from Google without producing an
+    /// error until the new variant added to this `enum`.
+    #[serde(untagged)]
+    Other(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for ConfirmationLevel {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    /// Unrecognized values are deserialized into the `Other` variant.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        // A value that this crate does not recognize yet is kept in the
+        // `Other` variant, rather than failing the entire response:
+        Ok(Self::try_from(string.as_str()).unwrap_or(Self::Other(string)))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&ConfirmationLevel> for String {
+    /// Converts a `ConfirmationLevel` enum to a `String` that contains a
+    /// [confirmation level](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#confirmationlevel) code.
+    fn from(confirmation_level: &ConfirmationLevel) -> Self {
+        match confirmation_level {
+
+            ConfirmationLevel::Unspecified => Self::from("CONFIRMATION_LEVEL_UNSPECIFIED"),
+            ConfirmationLevel::Confirmed => Self::from("CONFIRMED"),
+            ConfirmationLevel::UnconfirmedButPlausible => Self::from("UNCONFIRMED_BUT_PLAUSIBLE"),
+            ConfirmationLevel::UnconfirmedAndSuspicious => Self::from("UNCONFIRMED_AND_SUSPICIOUS"),
+            ConfirmationLevel::Other(code) => code.clone(),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static CONFIRMATION_LEVELS_BY_CODE: phf::Map<&'static str, ConfirmationLevel> = phf_map! {
+    "CONFIRMATION_LEVEL_UNSPECIFIED" => ConfirmationLevel::Unspecified,
+    "CONFIRMED" => ConfirmationLevel::Confirmed,
+    "UNCONFIRMED_BUT_PLAUSIBLE" => ConfirmationLevel::UnconfirmedButPlausible,
+    "UNCONFIRMED_AND_SUSPICIOUS" => ConfirmationLevel::UnconfirmedAndSuspicious,
+};
+
+impl std::convert::TryFrom<&str> for ConfirmationLevel {
+    // Error definitions are contained in the
+    // `google_maps\src\address_validation\error.rs` module.
+    type Error = crate::address_validation::error::Error;
+    /// Gets a `ConfirmationLevel` enum from a `String` that contains a valid
+    /// [confirmation level](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#confirmationlevel) code.
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        CONFIRMATION_LEVELS_BY_CODE
+            .get(code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidConfirmationLevelCode(code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for ConfirmationLevel {
+    // Error definitions are contained in the
+    // `google_maps\src\address_validation\error.rs` module.
+    type Err = crate::address_validation::error::Error;
+    /// Gets a `ConfirmationLevel` enum from a `String` that contains a valid
+    /// [confirmation level](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#confirmationlevel) code.
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        CONFIRMATION_LEVELS_BY_CODE
+            .get(code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidConfirmationLevelCode(code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for ConfirmationLevel {
+    /// Returns a reasonable default variant for the `ConfirmationLevel` enum
+    /// type.
+    fn default() -> Self {
+        Self::Unspecified
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for ConfirmationLevel {
+    /// Formats a `ConfirmationLevel` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Unspecified => write!(f, "Unspecified"),
+            Self::Confirmed => write!(f, "Confirmed"),
+            Self::UnconfirmedButPlausible => write!(f, "Unconfirmed but Plausible"),
+            Self::UnconfirmedAndSuspicious => write!(f, "Unconfirmed and Suspicious"),
+            Self::Other(code) => write!(f, "{code}"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,39 @@
+//! Contains the `Geocode` struct. It holds the location that the validated
+//! address was geocoded to.
+
+use crate::types::LatLng;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Contains information about the place the input was geocoded to. See
+/// [Geocode](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#geocode).
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Geocode {
+    /// The geocoded location of the input.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<LatLng>,
+
+    /// The size of the geocoded place, in meters. This is another measure of
+    /// the coarseness of the geocoded location, but in physical size rather
+    /// than in semantic meaning.
+    #[serde(alias = "featureSizeMeters")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature_size_meters: Option<Decimal>,
+
+    /// The place ID of the place this input geocodes to. Using place IDs is
+    /// preferred over using addresses or latitude/longitude coordinates with
+    /// other Google Maps APIs.
+    #[serde(alias = "placeId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place_id: Option<String>,
+
+    /// The type(s) of place that the input geocoded to. For example,
+    /// `["locality", "political"]`.
+    #[serde(alias = "placeTypes")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub place_types: Vec<String>,
+} // struct
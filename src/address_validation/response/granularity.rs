@@ -0,0 +1,151 @@
+//! Contains the `Granularity` enum and its associated traits. It describes how
+//! precisely an address, or its geocode, could be located.
+
+use crate::address_validation::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The various
+/// [granularities](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#granularity)
+/// that an address or a geocode can have. When used to indicate granularity for
+/// an address, these values indicate with how fine a granularity the address
+/// identifies a mailing destination. For example, an address such as "123 Main
+/// Street, Redwood City, CA, 94061" identifies a `Premise` while something like
+/// "Redwood City, CA, 94061" only identifies `Other`.
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum Granularity {
+    /// Default value. This value is unused.
+    #[serde(rename = "GRANULARITY_UNSPECIFIED")]
+    Unspecified,
+
+    /// Below-building level result, such as an apartment.
+    #[serde(rename = "SUB_PREMISE")]
+    SubPremise,
+
+    /// Building-level result.
+    #[serde(rename = "PREMISE")]
+    Premise,
+
+    /// A geocode that approximates the building-level location of the address.
+    #[serde(rename = "PREMISE_PROXIMITY")]
+    PremiseProximity,
+
+    /// The address or geocode indicates a block. Only used in regions which
+    /// have block-level addressing, such as Japan.
+    #[serde(rename = "BLOCK")]
+    Block,
+
+    /// The geocode or address is granular to route, such as a street, road, or
+    /// highway.
+    #[serde(rename = "ROUTE")]
+    Route,
+
+    /// All other granularities, which are bucketed together since they are not
+    /// deliverable. Granularities that are not recognized by this crate are
+    /// also assigned to this variant.
+    #[serde(rename = "OTHER")]
+    Other,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Granularity {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    /// Unrecognized values are deserialized into the `Other` variant.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        // A value that this crate does not recognize yet is bucketed into the
+        // `Other` variant, rather than failing the entire response:
+        Ok(Self::try_from(string.as_str()).unwrap_or(Self::Other))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Granularity> for String {
+    /// Converts a `Granularity` enum to a `String` that contains a
+    /// [granularity](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#granularity) code.
+    fn from(granularity: &Granularity) -> Self {
+        match granularity {
+
+            Granularity::Unspecified => Self::from("GRANULARITY_UNSPECIFIED"),
+            Granularity::SubPremise => Self::from("SUB_PREMISE"),
+            Granularity::Premise => Self::from("PREMISE"),
+            Granularity::PremiseProximity => Self::from("PREMISE_PROXIMITY"),
+            Granularity::Block => Self::from("BLOCK"),
+            Granularity::Route => Self::from("ROUTE"),
+            Granularity::Other => Self::from("OTHER"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static GRANULARITYS_BY_CODE: phf::Map<&'static str, Granularity> = phf_map! {
+    "GRANULARITY_UNSPECIFIED" => Granularity::Unspecified,
+    "SUB_PREMISE" => Granularity::SubPremise,
+    "PREMISE" => Granularity::Premise,
+    "PREMISE_PROXIMITY" => Granularity::PremiseProximity,
+    "BLOCK" => Granularity::Block,
+    "ROUTE" => Granularity::Route,
+    "OTHER" => Granularity::Other,
+};
+
+impl std::convert::TryFrom<&str> for Granularity {
+    // Error definitions are contained in the
+    // `google_maps\src\address_validation\error.rs` module.
+    type Error = crate::address_validation::error::Error;
+    /// Gets a `Granularity` enum from a `String` that contains a valid
+    /// [granularity](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#granularity) code.
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        GRANULARITYS_BY_CODE
+            .get(code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidGranularityCode(code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for Granularity {
+    // Error definitions are contained in the
+    // `google_maps\src\address_validation\error.rs` module.
+    type Err = crate::address_validation::error::Error;
+    /// Gets a `Granularity` enum from a `String` that contains a valid
+    /// [granularity](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#granularity) code.
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        GRANULARITYS_BY_CODE
+            .get(code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidGranularityCode(code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for Granularity {
+    /// Returns a reasonable default variant for the `Granularity` enum type.
+    fn default() -> Self {
+        Self::Unspecified
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Granularity {
+    /// Formats a `Granularity` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Unspecified => write!(f, "Unspecified"),
+            Self::SubPremise => write!(f, "Sub-premise"),
+            Self::Premise => write!(f, "Premise"),
+            Self::PremiseProximity => write!(f, "Premise Proximity"),
+            Self::Block => write!(f, "Block"),
+            Self::Route => write!(f, "Route"),
+            Self::Other => write!(f, "Other"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,56 @@
+//! Resources (enums, structs) for processing the _Address Validation API_
+//! response from the Google Maps Platform. Look in here for more information
+//! about the data returned from Google's server and how to parse it with your
+//! program.
+
+pub mod address;
+pub mod address_component;
+pub mod address_metadata;
+pub mod confirmation_level;
+pub mod geocode;
+pub mod granularity;
+pub mod possible_next_action;
+pub mod validation_result;
+pub mod verdict;
+
+// -----------------------------------------------------------------------------
+
+use crate::address_validation::response::validation_result::ValidationResult;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps _Address Validation_ request will be
+/// stored in this structure. See [Response
+/// body](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#response-body).
+///
+/// Unlike most Google Maps APIs, the Address Validation API does not return a
+/// `status` field. Errors are reported with an unsuccessful HTTP status code
+/// instead, and are returned by this crate as an
+/// `Error::HttpUnsuccessfulResponse` error.
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+    /// The result of the address validation.
+    #[serde(default)]
+    pub result: ValidationResult,
+
+    /// The UUID that identifies this response. If the address needs to be
+    /// re-validated, this UUID must accompany the new request. See the
+    /// `with_previous_response_id()` method for more information.
+    #[serde(alias = "responseId")]
+    #[serde(default)]
+    pub response_id: String,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = simd_json::Error;
+    /// Parse a Google Maps _Address Validation_ JSON `String` response into a
+    /// usable `Response` struct.
+    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
+        let mut bytes = s.to_string().into_bytes();
+        simd_json::serde::from_slice(&mut bytes)
+    } // fn
+} // impl
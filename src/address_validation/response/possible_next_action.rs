@@ -0,0 +1,158 @@
+//! Contains the `PossibleNextAction` enum and its associated traits. It is
+//! Google's interpretation of the validation response, and suggests what to do
+//! with the address.
+
+use crate::address_validation::error::Error;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Offers an interpretive summary of the API response, intended to assist in
+/// determining a potential subsequent action to take. This field is derived
+/// from other fields in the API response and should not be considered as a
+/// guarantee of address accuracy or deliverability. See [Possible Next
+/// Action](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#possiblenextaction).
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub enum PossibleNextAction {
+    /// Default value. This value is unused.
+    #[serde(rename = "POSSIBLE_NEXT_ACTION_UNSPECIFIED")]
+    Unspecified,
+
+    /// One or more fields of the API response indicate a potential issue with
+    /// the post-processed address, for example the `validation_granularity` is
+    /// `Other`. Prompting the customer to edit the address could help improve
+    /// the quality of the address.
+    #[serde(rename = "FIX")]
+    Fix,
+
+    /// The API response indicates that the post-processed address might be
+    /// missing a subpremises. Prompting the customer to review the address and
+    /// consider adding a unit number could help improve the quality of the
+    /// address.
+    #[serde(rename = "CONFIRM_ADD_SUBPREMISES")]
+    ConfirmAddSubpremises,
+
+    /// One or more fields of the API response indicate potential minor issues
+    /// with the post-processed address, for example the `postal_code` address
+    /// component was `replaced`. Prompting the customer to review the address
+    /// could help improve the quality of the address.
+    #[serde(rename = "CONFIRM")]
+    Confirm,
+
+    /// The API response does not contain signals that warrant one of the other
+    /// next actions. You might consider using the post-processed address
+    /// without further prompting your customer, though this does not guarantee
+    /// the address is valid, and the address might still contain corrections.
+    #[serde(rename = "ACCEPT")]
+    Accept,
+
+    /// If the possible next action is not recognized by
+    /// [serde](https://crates.io/crates/serde) when reading data from
+    /// Google it will be assigned to this `Other` variant.
+    ///
+    /// As new possible next actions are added to Google Maps, they must also be
+    /// added to this crate. However, in the meantime, the `Other` catch-all
+    /// variant allows `serde` to read data from Google without producing an
+    /// error until the new variant added to this `enum`.
+    #[serde(untagged)]
+    Other(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for PossibleNextAction {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    /// Unrecognized values are deserialized into the `Other` variant.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        // A value that this crate does not recognize yet is kept in the
+        // `Other` variant, rather than failing the entire response:
+        Ok(Self::try_from(string.as_str()).unwrap_or(Self::Other(string)))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&PossibleNextAction> for String {
+    /// Converts a `PossibleNextAction` enum to a `String` that contains a
+    /// [possible next action](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#possiblenextaction) code.
+    fn from(possible_next_action: &PossibleNextAction) -> Self {
+        match possible_next_action {
+
+            PossibleNextAction::Unspecified => Self::from("POSSIBLE_NEXT_ACTION_UNSPECIFIED"),
+            PossibleNextAction::Fix => Self::from("FIX"),
+            PossibleNextAction::ConfirmAddSubpremises => Self::from("CONFIRM_ADD_SUBPREMISES"),
+            PossibleNextAction::Confirm => Self::from("CONFIRM"),
+            PossibleNextAction::Accept => Self::from("ACCEPT"),
+            PossibleNextAction::Other(code) => code.clone(),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static POSSIBLE_NEXT_ACTIONS_BY_CODE: phf::Map<&'static str, PossibleNextAction> = phf_map! {
+    "POSSIBLE_NEXT_ACTION_UNSPECIFIED" => PossibleNextAction::Unspecified,
+    "FIX" => PossibleNextAction::Fix,
+    "CONFIRM_ADD_SUBPREMISES" => PossibleNextAction::ConfirmAddSubpremises,
+    "CONFIRM" => PossibleNextAction::Confirm,
+    "ACCEPT" => PossibleNextAction::Accept,
+};
+
+impl std::convert::TryFrom<&str> for PossibleNextAction {
+    // Error definitions are contained in the
+    // `google_maps\src\address_validation\error.rs` module.
+    type Error = crate::address_validation::error::Error;
+    /// Gets a `PossibleNextAction` enum from a `String` that contains a valid
+    /// [possible next action](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#possiblenextaction) code.
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        POSSIBLE_NEXT_ACTIONS_BY_CODE
+            .get(code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidPossibleNextActionCode(code.to_string()))
+    } // fn
+} // impl
+
+impl std::str::FromStr for PossibleNextAction {
+    // Error definitions are contained in the
+    // `google_maps\src\address_validation\error.rs` module.
+    type Err = crate::address_validation::error::Error;
+    /// Gets a `PossibleNextAction` enum from a `String` that contains a valid
+    /// [possible next action](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#possiblenextaction) code.
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        POSSIBLE_NEXT_ACTIONS_BY_CODE
+            .get(code)
+            .cloned()
+            .ok_or_else(|| Error::InvalidPossibleNextActionCode(code.to_string()))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for PossibleNextAction {
+    /// Returns a reasonable default variant for the `PossibleNextAction` enum
+    /// type.
+    fn default() -> Self {
+        Self::Unspecified
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for PossibleNextAction {
+    /// Formats a `PossibleNextAction` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Unspecified => write!(f, "Unspecified"),
+            Self::Fix => write!(f, "Fix"),
+            Self::ConfirmAddSubpremises => write!(f, "Confirm and Add Subpremises"),
+            Self::Confirm => write!(f, "Confirm"),
+            Self::Accept => write!(f, "Accept"),
+            Self::Other(code) => write!(f, "{code}"),
+        } // match
+    } // fn
+} // impl
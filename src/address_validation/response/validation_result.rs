@@ -0,0 +1,32 @@
+//! Contains the `ValidationResult` struct. It holds the result of validating
+//! an address.
+
+use crate::address_validation::response::{
+    address::Address, address_metadata::AddressMetadata, geocode::Geocode, verdict::Verdict,
+}; // crate::address_validation::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The result of validating an address. See
+/// [ValidationResult](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#validationresult).
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct ValidationResult {
+    /// Overall verdict flags.
+    #[serde(default)]
+    pub verdict: Verdict,
+
+    /// Information about the address itself as opposed to the geocode.
+    #[serde(default)]
+    pub address: Address,
+
+    /// Information about the location and place that the address geocoded to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geocode: Option<Geocode>,
+
+    /// Other information relevant to deliverability, such as whether the
+    /// address is a residence or a business.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<AddressMetadata>,
+} // struct
@@ -0,0 +1,72 @@
+//! Contains the `Verdict` struct. It is a high-level overview of the address
+//! validation result and geocode.
+
+use crate::address_validation::response::{
+    granularity::Granularity, possible_next_action::PossibleNextAction,
+}; // crate::address_validation::response
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// High level overview of the address validation result and geocode. See
+/// [Verdict](https://developers.google.com/maps/documentation/address-validation/reference/rest/v1/TopLevel/validateAddress#verdict).
+
+// The flags mirror Google's response, so they are kept as `bool`s:
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Verdict {
+    /// The granularity of the input address. This is the result of parsing
+    /// the input address and does not give any validation signals.
+    #[serde(alias = "inputGranularity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_granularity: Option<Granularity>,
+
+    /// The granularity level that the API can fully validate the address to.
+    /// For example, a `validation_granularity` of `Premise` indicates all
+    /// address components at the level of `Premise` or more coarse can be
+    /// validated.
+    #[serde(alias = "validationGranularity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation_granularity: Option<Granularity>,
+
+    /// Information about the granularity of the `geocode`. This can be
+    /// understood as the semantic meaning of how coarse or fine the geocoded
+    /// location is.
+    #[serde(alias = "geocodeGranularity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geocode_granularity: Option<Granularity>,
+
+    /// The address is considered complete if there are no unresolved tokens,
+    /// no unexpected address components, and no missing address components.
+    #[serde(alias = "addressComplete")]
+    #[serde(default)]
+    pub address_complete: bool,
+
+    /// At least one address component cannot be categorized or validated.
+    #[serde(alias = "hasUnconfirmedComponents")]
+    #[serde(default)]
+    pub has_unconfirmed_components: bool,
+
+    /// At least one address component was inferred (added) that wasn't in the
+    /// input.
+    #[serde(alias = "hasInferredComponents")]
+    #[serde(default)]
+    pub has_inferred_components: bool,
+
+    /// At least one address component was replaced.
+    #[serde(alias = "hasReplacedComponents")]
+    #[serde(default)]
+    pub has_replaced_components: bool,
+
+    /// At least one address component was spell-corrected.
+    #[serde(alias = "hasSpellCorrectedComponents")]
+    #[serde(default)]
+    pub has_spell_corrected_components: bool,
+
+    /// Google's interpretation of the other fields of the response, which
+    /// suggests what to do with the address. For example, whether to accept
+    /// it, or to ask the customer to fix or confirm it.
+    #[serde(alias = "possibleNextAction")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub possible_next_action: Option<PossibleNextAction>,
+} // struct
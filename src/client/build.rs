@@ -30,6 +30,25 @@ impl GoogleMapsClient {
     pub fn build(&self) -> Self {
         Self {
             key: self.key.clone(),
+            #[cfg(any(
+                feature = "autocomplete",
+                feature = "directions",
+                feature = "distance_matrix",
+                feature = "geocoding",
+                feature = "places",
+                feature = "time_zone"
+            ))]
+            language: self.language,
+            #[cfg(any(
+                feature = "autocomplete",
+                feature = "directions",
+                feature = "distance_matrix",
+                feature = "geocoding",
+                feature = "places"
+            ))]
+            region: self.region,
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            unit_system: self.unit_system.clone(),
         } // GoogleMapsClient
     } // fn
 
@@ -0,0 +1,764 @@
+//! Contains the `ClientBuilder` struct, which gathers all of the client-level
+//! settings in one place before building a `GoogleMapsClient`.
+
+use crate::client::{
+    url_signing::UrlSigner, GoogleMapsClient, RetryClassifier, RetryDecision,
+    DEFAULT_ERROR_BODY_LENGTH, DEFAULT_POOL_IDLE_TIMEOUT, DEFAULT_TIMEOUT,
+}; // use crate::client
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::{api::Api, RequestRate};
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Gathers the settings for a `GoogleMapsClient` and validates them when the
+/// client is built.
+///
+/// Unlike the `GoogleMapsClient` setters, which each take effect (or fail)
+/// immediately, the `ClientBuilder` setters only record the settings.
+/// Everything is checked at once by `build()`, so a misconfigured client is
+/// reported by a single `Result` at startup. Use `GoogleMapsClient::builder()`
+/// to create a `ClientBuilder`.
+///
+/// The language, region, and unit system set here are defaults: each request
+/// inherits them, unless it sets its own value with its `with_language()`,
+/// `with_region()`, or `with_unit_system()` method. A value set on the request
+/// always wins. If neither is set, Google's default applies.
+///
+/// ## Examples:
+///
+/// ```rust
+/// let google_maps_client = GoogleMapsClient::builder("YOUR_API_KEY_HERE")
+///     .with_timeout(std::time::Duration::from_secs(10))
+///     .with_rate_per_second(&Api::Geocoding, 10, 0)
+///     .with_language(Language::French)
+///     .with_region(Region::France)
+///     .with_unit_system(UnitSystem::Metric)
+///     .with_channel("checkout-page")
+///     .build()?;
+/// ```
+
+#[must_use]
+pub struct ClientBuilder {
+    /// Your application's API key.
+    key: String,
+
+    /// The default language in which to return results.
+    #[cfg(any(
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "geocoding",
+        feature = "places",
+        feature = "time_zone"
+    ))]
+    language: Option<crate::types::Language>,
+
+    /// The default region to bias results towards.
+    #[cfg(any(
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "geocoding",
+        feature = "places"
+    ))]
+    region: Option<crate::types::Region>,
+
+    /// The default unit system to display distances in.
+    #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+    unit_system: Option<crate::directions::UnitSystem>,
+
+    /// Rate limits for each of the Google Cloud Maps Platform APIs.
+    rate_limit: RequestRate,
+
+    /// The timeout for connecting to Google, and for each request. It is only
+    /// used if a `reqwest` client has not been provided.
+    timeout: Duration,
+
+    /// The maximum number of idle connections that are kept open to each host.
+    /// It is only used if a `reqwest` client has not been provided.
+    pool_max_idle_per_host: usize,
+
+    /// How long an idle connection is kept open for. `None` keeps idle
+    /// connections open indefinitely. It is only used if a `reqwest` client
+    /// has not been provided.
+    pool_idle_timeout: Option<Duration>,
+
+    /// Whether requests are sent using HTTP/2 without first negotiating it. It
+    /// is only used if a `reqwest` client has not been provided.
+    #[cfg(feature = "reqwest-http2")]
+    http2_prior_knowledge: bool,
+
+    /// Whether compressed responses are requested. It is only used if a
+    /// `reqwest` client has not been provided.
+    compression: bool,
+
+    /// The maximum number of characters of a response body that will be
+    /// included in an error.
+    error_body_length: usize,
+
+    /// The capacity and time-to-live of the Geocoding API response cache.
+    #[cfg(feature = "geocoding")]
+    geocoding_cache: Option<(usize, Duration)>,
+
+    /// The channel that is sent with every request.
+    channel: Option<String>,
+
+    /// The Premium Plan URL signer. A private key that could not be decoded is
+    /// kept as an error, to be returned by `build()`.
+    signing: Option<Result<UrlSigner, GoogleMapsError>>,
+
+    /// Whether the API key is left out of requests.
+    omit_key: bool,
+
+    /// The URL that replaces the scheme, host, and port of every request.
+    base_url: Option<reqwest::Url>,
+
+    /// A pre-configured `reqwest` client to use, instead of building one.
+    reqwest_client: Option<reqwest::Client>,
+
+    /// A function that overrides which errors are retried.
+    retry_classifier: Option<RetryClassifier>,
+
+    /// The default retry policy, for requests that do not set their own.
+    backoff: Option<backoff::ExponentialBackoff>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for ClientBuilder {
+    /// The API key is a secret, so only its first few characters are included
+    /// in the output, unless the `unredacted-logs` feature is enabled.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("ClientBuilder");
+        debug.field("key", &crate::redact::redact_key(&self.key));
+        #[cfg(any(
+            feature = "autocomplete",
+            feature = "directions",
+            feature = "distance_matrix",
+            feature = "geocoding",
+            feature = "places",
+            feature = "time_zone"
+        ))]
+        debug.field("language", &self.language);
+        #[cfg(any(
+            feature = "autocomplete",
+            feature = "directions",
+            feature = "distance_matrix",
+            feature = "geocoding",
+            feature = "places"
+        ))]
+        debug.field("region", &self.region);
+        #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+        debug.field("unit_system", &self.unit_system);
+        debug
+            .field("rate_limit", &self.rate_limit)
+            .field("timeout", &self.timeout)
+            .field("pool_max_idle_per_host", &self.pool_max_idle_per_host)
+            .field("pool_idle_timeout", &self.pool_idle_timeout);
+        #[cfg(feature = "reqwest-http2")]
+        debug.field("http2_prior_knowledge", &self.http2_prior_knowledge);
+        debug
+            .field("compression", &self.compression)
+            .field("error_body_length", &self.error_body_length);
+        #[cfg(feature = "geocoding")]
+        debug.field("geocoding_cache", &self.geocoding_cache);
+        debug
+            .field("channel", &self.channel)
+            .field("signing", &self.signing)
+            .field("omit_key", &self.omit_key)
+            .field("base_url", &self.base_url)
+            .field("reqwest_client", &self.reqwest_client)
+            .field("retry_classifier", &self.retry_classifier.as_ref().map(|_| ".."))
+            .field("backoff", &self.backoff)
+            .finish()
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Starts building a `GoogleMapsClient` with the specified API key. See
+    /// `ClientBuilder` for the available settings. For the simple case of an
+    /// API key and default settings, `GoogleMapsClient::try_new()` may be used
+    /// instead.
+    ///
+    /// ## Arguments
+    ///
+    /// * `key` ‧ Your application's API key. This key identifies your
+    ///   application for purposes of quota management. Learn how to [get a
+    ///   key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
+
+    pub fn builder(key: impl Into<String>) -> ClientBuilder {
+        ClientBuilder {
+            key: key.into(),
+            #[cfg(any(
+                feature = "autocomplete",
+                feature = "directions",
+                feature = "distance_matrix",
+                feature = "geocoding",
+                feature = "places",
+                feature = "time_zone"
+            ))]
+            language: None,
+            #[cfg(any(
+                feature = "autocomplete",
+                feature = "directions",
+                feature = "distance_matrix",
+                feature = "geocoding",
+                feature = "places"
+            ))]
+            region: None,
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            unit_system: None,
+            rate_limit: RequestRate::default(),
+            timeout: DEFAULT_TIMEOUT,
+            pool_max_idle_per_host: usize::MAX,
+            pool_idle_timeout: Some(DEFAULT_POOL_IDLE_TIMEOUT),
+            #[cfg(feature = "reqwest-http2")]
+            http2_prior_knowledge: false,
+            compression: true,
+            error_body_length: DEFAULT_ERROR_BODY_LENGTH,
+            #[cfg(feature = "geocoding")]
+            geocoding_cache: None,
+            channel: None,
+            signing: None,
+            omit_key: false,
+            base_url: None,
+            reqwest_client: None,
+            retry_classifier: None,
+            backoff: None,
+        } // ClientBuilder
+    } // fn
+} // impl
+
+// =============================================================================
+
+impl ClientBuilder {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the default language in which to return results. See
+    /// `GoogleMapsClient::with_language()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `language` ‧ The language in which to return results.
+
+    #[cfg(any(
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "geocoding",
+        feature = "places",
+        feature = "time_zone"
+    ))]
+    pub fn with_language(mut self, language: impl Into<crate::types::Language>) -> Self {
+        self.language = Some(language.into());
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the default region to bias results towards. See
+    /// `GoogleMapsClient::with_region()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `region` ‧ The region to bias results towards.
+
+    #[cfg(any(
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "geocoding",
+        feature = "places"
+    ))]
+    pub fn with_region(mut self, region: impl Into<crate::types::Region>) -> Self {
+        self.region = Some(region.into());
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the default unit system to display distances in. See
+    /// `GoogleMapsClient::with_unit_system()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `unit_system` ‧ The unit system to display distances in.
+
+    #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+    pub fn with_unit_system(mut self, unit_system: impl Into<crate::directions::UnitSystem>) -> Self {
+        self.unit_system = Some(unit_system.into());
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the rate limit for the specified API. See
+    /// `GoogleMapsClient::with_rate()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ Which Google Maps API are you setting the rate limit for?
+    ///
+    /// * `requests` ‧ The number of requests the client library is attempting
+    ///   to target.
+    ///
+    /// * `per_duration` ‧ The duration for the targeted request rate.
+
+    pub fn with_rate(mut self, api: &Api, requests: u16, per_duration: Duration) -> Self {
+        self.rate_limit.with_rate(api, requests, per_duration);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the rate limit for the specified API, in requests per second. See
+    /// `GoogleMapsClient::with_rate_per_second()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ Which Google Maps API are you setting the rate limit for?
+    ///
+    /// * `requests_per_second` ‧ The sustained number of requests per second.
+    ///
+    /// * `burst` ‧ The number of requests that may be sent at once.
+
+    pub fn with_rate_per_second(mut self, api: &Api, requests_per_second: u16, burst: u16) -> Self {
+        self.rate_limit.with_rate_per_second(api, requests_per_second, burst);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Turns off the client's rate limiting. See
+    /// `GoogleMapsClient::without_rate_limiting()` for more information.
+
+    pub fn without_rate_limiting(mut self) -> Self {
+        self.rate_limit.rate_map.clear();
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the timeout for connecting to Google, and for each request. The
+    /// default is 30 seconds.
+    ///
+    /// The timeout is applied to the `reqwest` client that is built by this
+    /// crate. It is ignored if a client is provided with
+    /// `with_reqwest_client()`, which should be configured with its own
+    /// timeouts. Individual requests may still override it with their
+    /// `with_timeout()` method.
+    ///
+    /// ## Arguments
+    ///
+    /// * `timeout` ‧ The maximum time to wait.
+
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of idle connections that are kept open to each
+    /// host, ready to be reused. By default, there is no limit.
+    ///
+    /// Under high concurrency, such as when geocoding a large batch of
+    /// addresses, keeping enough connections open avoids the cost of a new
+    /// TCP and TLS handshake for each request. A lower limit frees resources
+    /// when requests are infrequent.
+    ///
+    /// Like `with_timeout()`, this is ignored if a client is provided with
+    /// `with_reqwest_client()`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_idle` ‧ The maximum number of idle connections per host.
+
+    pub const fn with_pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.pool_max_idle_per_host = max_idle;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets how long an idle connection is kept open for, ready to be reused.
+    /// The default is 90 seconds.
+    ///
+    /// Like `with_timeout()`, this is ignored if a client is provided with
+    /// `with_reqwest_client()`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `idle_timeout` ‧ How long to keep an idle connection open for. `None`
+    ///   keeps idle connections open indefinitely.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::builder("YOUR_API_KEY_HERE")
+    ///     .with_pool_max_idle_per_host(32)
+    ///     .with_pool_idle_timeout(std::time::Duration::from_secs(30))
+    ///     .build()?;
+    /// ```
+
+    pub fn with_pool_idle_timeout(mut self, idle_timeout: impl Into<Option<Duration>>) -> Self {
+        self.pool_idle_timeout = idle_timeout.into();
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sends every request using HTTP/2, without first negotiating it with the
+    /// server. By default, the HTTP version is negotiated, and HTTP/2 is used
+    /// when the server supports it, which Google does.
+    ///
+    /// This is useful when requests are sent with `with_base_url()` to a proxy
+    /// or mock server that accepts HTTP/2 over plain-text connections. Requests
+    /// fail if the server does not support HTTP/2.
+    ///
+    /// Like `with_timeout()`, this is ignored if a client is provided with
+    /// `with_reqwest_client()`. It requires the `reqwest-http2` feature, which
+    /// is enabled by default.
+
+    #[cfg(feature = "reqwest-http2")]
+    pub const fn with_http2_prior_knowledge(mut self) -> Self {
+        self.http2_prior_knowledge = true;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Turns off response compression.
+    ///
+    /// By default, when the `reqwest-gzip`, `reqwest-brotli`, `reqwest-zstd`
+    /// or `reqwest-deflate` features are enabled, the client sends an
+    /// `Accept-Encoding` header for the enabled encodings, and transparently
+    /// decompresses the responses. Google's JSON responses compress well, so
+    /// this saves bandwidth, especially for large distance matrices and place
+    /// lists. Turn it off if a proxy between the client and Google mishandles
+    /// compressed bodies.
+    ///
+    /// Like `with_timeout()`, this is ignored if a client is provided with
+    /// `with_reqwest_client()`.
+
+    pub const fn without_compression(mut self) -> Self {
+        self.compression = false;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of characters of an unparsable response body
+    /// that is included in an error. See
+    /// `GoogleMapsClient::with_error_body_length()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` ‧ The maximum number of characters.
+
+    pub const fn with_error_body_length(mut self, length: usize) -> Self {
+        self.error_body_length = length;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Caches Geocoding API responses in memory. See
+    /// `GoogleMapsClient::with_geocoding_cache()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` ‧ The maximum number of responses to cache.
+    ///
+    /// * `ttl` ‧ How long a response is cached for.
+
+    #[cfg(feature = "geocoding")]
+    pub const fn with_geocoding_cache(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.geocoding_cache = Some((capacity, ttl));
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the channel that is sent with every request. The channel is
+    /// validated by `build()`. See `GoogleMapsClient::with_channel()` for more
+    /// information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `channel` ‧ The channel to report usage under.
+
+    pub fn with_channel(mut self, channel: impl Into<String>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Authenticates requests using a Premium Plan client ID and URL signing
+    /// secret. The private key is validated by `build()`, and an empty API key
+    /// is allowed. See `GoogleMapsClient::with_signing()` for more
+    /// information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client_id` ‧ Your Premium Plan client ID.
+    ///
+    /// * `private_key` ‧ Your URL signing secret.
+
+    pub fn with_signing(
+        mut self,
+        client_id: impl Into<String>,
+        private_key: &str
+    ) -> Self {
+        self.signing = Some(UrlSigner::try_new(client_id, private_key));
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Leaves the API key out of every request, for a gateway or proxy to add.
+    /// An empty API key is allowed. See `GoogleMapsClient::without_key()` for
+    /// more information.
+
+    pub const fn without_key(mut self) -> Self {
+        self.omit_key = true;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sends every request to the specified base URL, instead of to Google.
+    /// See `GoogleMapsClient::with_base_url()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `base_url` ‧ The URL to send requests to.
+
+    pub fn with_base_url(mut self, base_url: reqwest::Url) -> Self {
+        self.base_url = Some(base_url);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Overrides which errors are retried. See
+    /// `GoogleMapsClient::with_retry_classifier()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `retry_classifier` ‧ A function that is given the error of a failed
+    ///   attempt, and returns a `RetryDecision`.
+
+    pub fn with_retry_classifier(
+        mut self,
+        retry_classifier: impl Fn(&GoogleMapsError) -> RetryDecision + Send + Sync + 'static
+    ) -> Self {
+        self.retry_classifier = Some(std::sync::Arc::new(retry_classifier));
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the default retry policy, for requests that do not set their own.
+    /// See `GoogleMapsClient::with_backoff()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `backoff` ‧ The `ExponentialBackoff` policy used when an HTTP request
+    ///   fails with an error that is eligible for retries. Use
+    ///   `no_delay_backoff()` to retry immediately in tests.
+
+    pub const fn with_backoff(mut self, backoff: backoff::ExponentialBackoff) -> Self {
+        self.backoff = Some(backoff);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Uses a pre-configured `reqwest` client, for example one that is shared
+    /// with other `GoogleMapsClient`s. See
+    /// `GoogleMapsClient::try_from_reqwest_client()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `reqwest_client` ‧ A reqwest client built using the
+    ///   `reqwest::Client::builder()` function.
+
+    pub fn with_reqwest_client(mut self, reqwest_client: reqwest::Client) -> Self {
+        self.reqwest_client = Some(reqwest_client);
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the `reqwest` client that is used when one has not been
+    /// provided, with the builder's timeout and connection settings.
+
+    fn default_reqwest_client(&self) -> Result<reqwest::Client, reqwest::Error> {
+        let reqwest_client = GoogleMapsClient::default_reqwest_client_builder(self.timeout)
+            .pool_max_idle_per_host(self.pool_max_idle_per_host)
+            .pool_idle_timeout(self.pool_idle_timeout);
+
+        #[cfg(feature = "reqwest-http2")]
+        let reqwest_client = if self.http2_prior_knowledge {
+            reqwest_client.http2_prior_knowledge()
+        } else {
+            reqwest_client
+        }; // if
+
+        let reqwest_client = if self.compression {
+            reqwest_client
+        } else {
+            reqwest_client.no_gzip().no_brotli().no_zstd().no_deflate()
+        }; // if
+
+        reqwest_client.build()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Validates the settings and builds the `GoogleMapsClient`.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the API key is empty, unless a Premium Plan
+    ///   client ID has been set with `with_signing()`, or the key is omitted
+    ///   with `without_key()`. A warning is logged through `tracing` if the
+    ///   key does not look like a Google API key.
+    ///
+    /// * Returns an error if the channel is not valid.
+    ///
+    /// * Returns an error if the URL signing private key is not valid base64.
+    ///
+    /// * Returns an error if the `reqwest` client could not be built.
+
+    pub fn build(mut self) -> Result<GoogleMapsClient, GoogleMapsError> {
+        // An API key is required, unless requests are signed with a Premium
+        // Plan client ID instead, or the key is added by a proxy:
+        if self.signing.is_none() && !self.omit_key {
+            GoogleMapsClient::check_key(&self.key)?;
+        } // if
+
+        let reqwest_client = match self.reqwest_client.take() {
+            Some(reqwest_client) => reqwest_client,
+            None => self.default_reqwest_client()?,
+        }; // match
+
+        let mut client = GoogleMapsClient::from_parts(self.key, reqwest_client);
+        #[cfg(any(
+            feature = "autocomplete",
+            feature = "directions",
+            feature = "distance_matrix",
+            feature = "geocoding",
+            feature = "places",
+            feature = "time_zone"
+        ))]
+        {
+            client.language = self.language;
+        }
+        #[cfg(any(
+            feature = "autocomplete",
+            feature = "directions",
+            feature = "distance_matrix",
+            feature = "geocoding",
+            feature = "places"
+        ))]
+        {
+            client.region = self.region;
+        }
+        #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+        {
+            client.unit_system = self.unit_system;
+        }
+        client.rate_limit = self.rate_limit;
+        client.error_body_length = self.error_body_length;
+        client.base_url = self.base_url;
+        client.retry_classifier = self.retry_classifier;
+        client.backoff = self.backoff;
+        client.omit_key = self.omit_key;
+
+        #[cfg(feature = "geocoding")]
+        if let Some((capacity, ttl)) = self.geocoding_cache {
+            client.with_geocoding_cache(capacity, ttl);
+        } // if
+
+        if let Some(channel) = self.channel {
+            client.with_channel(channel)?;
+        } // if
+
+        if let Some(url_signer) = self.signing {
+            client.url_signer = Some(url_signer?);
+        } // if
+
+        Ok(client)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "distance_matrix"))]
+mod tests {
+    use crate::client::{no_delay_backoff, TestServer};
+    use crate::directions::request::waypoint::Waypoint;
+    use crate::error::Error as GoogleMapsError;
+    use crate::GoogleMapsClient;
+
+    /// A Distance Matrix API response for a 10 × 10 matrix of Canadian cities.
+    const MATRIX: &str = include_str!("../../tests/fixtures/distance_matrix_10x10.json");
+
+    fn cities() -> Vec<Waypoint> {
+        [
+            "Toronto, ON", "Montreal, QC", "Ottawa, ON", "Quebec City, QC", "Kingston, ON",
+            "London, ON", "Windsor, ON", "Hamilton, ON", "Sudbury, ON", "Sherbrooke, QC",
+        ]
+        .into_iter()
+        .map(Waypoint::from_address)
+        .collect()
+    } // fn
+
+    #[tokio::test]
+    async fn decodes_compressed_and_uncompressed_responses() -> Result<(), GoogleMapsError> {
+        let server = TestServer::start(vec![(200, MATRIX), (200, MATRIX)]);
+
+        let compressed = GoogleMapsClient::builder("AIzaTest")
+            .with_base_url(server.base_url.clone())
+            .with_backoff(no_delay_backoff())
+            .build()?
+            .distance_matrix(cities(), cities())
+            .execute()
+            .await?;
+        let compressed_bytes = server.body_bytes();
+
+        let uncompressed = GoogleMapsClient::builder("AIzaTest")
+            .with_base_url(server.base_url.clone())
+            .with_backoff(no_delay_backoff())
+            .without_compression()
+            .build()?
+            .distance_matrix(cities(), cities())
+            .execute()
+            .await?;
+        let uncompressed_bytes = server.body_bytes() - compressed_bytes;
+
+        assert_eq!(server.requests(), 2);
+        assert_eq!(compressed.rows.len(), 10);
+        assert_eq!(compressed, uncompressed);
+        assert_eq!(uncompressed_bytes, MATRIX.len());
+
+        // The response is about 31 kB, and about 2 kB with gzip:
+        if cfg!(feature = "reqwest-gzip") {
+            println!("{uncompressed_bytes} bytes, {compressed_bytes} bytes with gzip");
+            assert!(compressed_bytes * 10 < uncompressed_bytes);
+        } // if
+        Ok(())
+    } // fn
+} // mod
@@ -0,0 +1,36 @@
+//! Checks the `Content-Type` HTTP header of a successful response. Google, or
+//! a proxy between the client and Google, occasionally responds with an HTML
+//! error page instead of the JSON or image that was requested.
+
+use reqwest::{header::CONTENT_TYPE, Response};
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the `Content-Type` of a successful response if it is not the
+/// expected media type, so that an error page can be reported as such instead
+/// of as a JSON parsing error.
+///
+/// Unsuccessful responses, and responses without a `Content-Type` header, are
+/// not checked, and `None` is returned for them.
+///
+/// ## Arguments
+///
+/// * `response` ‧ The response from the server.
+///
+/// * `expected` ‧ The start of the expected media type, for example
+///   `application/json` or `image/`. Case is ignored, and any parameters such
+///   as `; charset=UTF-8` are allowed.
+
+pub fn unexpected_content_type(response: &Response, expected: &str) -> Option<String> {
+    if !response.status().is_success() {
+        return None;
+    } // if
+
+    let content_type = response.headers().get(CONTENT_TYPE)?.to_str().ok()?.trim();
+
+    if content_type.to_ascii_lowercase().starts_with(expected) {
+        None
+    } else {
+        Some(content_type.to_string())
+    } // if
+} // fn
@@ -0,0 +1,103 @@
+//! Enforces a request's deadline: the maximum total time that a request may
+//! take, including every retry and the delays between them.
+
+use crate::error::Error as GoogleMapsError;
+use std::future::Future;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// Awaits a request's retry loop, abandoning it if it has not completed within
+/// the deadline. Any attempt or retry delay that is in progress when the
+/// deadline is reached is cancelled.
+///
+/// ## Arguments
+///
+/// * `deadline` ‧ The maximum duration of the request. If `None`, the retry
+///   loop is awaited until it completes.
+///
+/// * `future` ‧ The request's retry loop.
+///
+/// # Errors
+///
+/// * Returns a `DeadlineExceeded` error if the deadline was reached, or the
+///   retry loop's own error.
+
+pub async fn within_deadline<T>(
+    deadline: Option<Duration>,
+    future: impl Future<Output = Result<T, GoogleMapsError>>
+) -> Result<T, GoogleMapsError> {
+    match deadline {
+        Some(deadline) => crate::client::timeout(deadline, future)
+            .await
+            .unwrap_or_else(|_elapsed| {
+                tracing::error!("request did not complete within its {deadline:?} deadline");
+                Err(GoogleMapsError::DeadlineExceeded(deadline))
+            }), // Some
+        None => future.await,
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns what remains of a deadline that applies to several requests, such
+/// as the chunks of a request that was split because it was too large.
+///
+/// ## Arguments
+///
+/// * `deadline` ‧ The maximum duration of all of the requests. If `None`,
+///   `None` is returned.
+///
+/// * `started` ‧ When the first of the requests was started.
+///
+/// # Errors
+///
+/// * Returns a `DeadlineExceeded` error with the whole deadline, rather than
+///   what remains of it, if none of it remains.
+
+#[cfg(any(feature = "distance_matrix", feature = "elevation", feature = "roads"))]
+pub fn remaining_deadline(
+    deadline: Option<Duration>,
+    started: std::time::Instant
+) -> Result<Option<Duration>, GoogleMapsError> {
+    let Some(deadline) = deadline else {
+        return Ok(None);
+    }; // let
+
+    match deadline.checked_sub(started.elapsed()) {
+        Some(remaining) if !remaining.is_zero() => Ok(Some(remaining)),
+        _ => {
+            tracing::error!("request did not complete within its {deadline:?} deadline");
+            Err(GoogleMapsError::DeadlineExceeded(deadline))
+        } // case
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(
+    test,
+    any(feature = "distance_matrix", feature = "elevation", feature = "roads")
+))]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn remaining_deadline_is_what_is_left() {
+        let remaining = remaining_deadline(Some(Duration::from_secs(60)), Instant::now());
+        assert!(remaining.is_ok_and(|remaining| remaining > Some(Duration::from_secs(59))));
+        let remaining = remaining_deadline(None, Instant::now());
+        assert!(remaining.is_ok_and(|remaining| remaining.is_none()));
+    } // fn
+
+    #[test]
+    fn exhausted_deadline_reports_the_whole_deadline() {
+        let started = Instant::now().checked_sub(Duration::from_secs(2)).unwrap();
+        let result = remaining_deadline(Some(Duration::from_secs(1)), started);
+        assert!(matches!(
+            result,
+            Err(GoogleMapsError::DeadlineExceeded(deadline)) if deadline == Duration::from_secs(1)
+        )); // assert!
+    } // fn
+} // mod
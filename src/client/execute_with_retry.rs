@@ -0,0 +1,488 @@
+//! The retry loop that is shared by the `get()`, `get_raw()`, and `post()`
+//! methods of every API. It observes the client's rate limits, sends the HTTP
+//! request, classifies the outcome of each attempt, and retries it according
+//! to the request's retry policy, maximum number of retries, and deadline.
+
+use crate::client::{retry, retry_after, unexpected_content_type, within_deadline, GoogleMapsClient};
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+use backoff::Error::{Permanent, Transient};
+use backoff::ExponentialBackoff;
+use reqwest::header::HeaderMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// How a request is sent to Google.
+
+pub enum Method<'a> {
+    /// An HTTP `GET` request. Everything is sent in the query string.
+    Get,
+    /// An HTTP `POST` request with a JSON body.
+    Post(&'a str),
+} // enum
+
+// -----------------------------------------------------------------------------
+//
+/// The settings of a request that its retry loop observes. They are set with
+/// the request's `with_backoff()`, `with_max_retries()`, `with_timeout()`, and
+/// `with_deadline()` methods.
+
+pub struct RetrySettings<'a> {
+    /// The API that the request is made to, for rate limiting and tracing.
+    pub api: Api,
+    /// The request's retry policy. If `None`, the client's retry policy is
+    /// used.
+    pub backoff: Option<&'a ExponentialBackoff>,
+    /// The maximum number of times a failed attempt is retried.
+    pub max_retries: Option<u32>,
+    /// The maximum duration of each attempt.
+    pub timeout: Option<Duration>,
+    /// The maximum total duration of the request, including retries.
+    pub deadline: Option<Duration>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The body of a successful HTTP response, which is interpreted by the
+/// request's `classify` function.
+
+pub struct ResponseBody {
+    /// The response's `Content-Type` header, if any.
+    pub content_type: Option<String>,
+    /// The final URL of the response, after any redirects were followed.
+    pub url: String,
+    /// The response body.
+    pub bytes: Vec<u8>,
+} // struct
+
+impl ResponseBody {
+    /// Returns the response body as text. Google's responses are UTF-8, and
+    /// any invalid sequences are replaced.
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.bytes).into_owned()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The `status` and `error_message` fields that are common to the responses
+/// of Google's legacy web services. `get_raw()` only parses these, so that
+/// Google's status is checked without interpreting the rest of the body.
+
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places",
+    feature = "time_zone"
+))]
+#[derive(serde::Deserialize)]
+pub struct StatusEnvelope<S> {
+    /// Google's status for the request.
+    pub status: S,
+    /// More detailed information about the reasons behind the given status.
+    /// Some APIs, such as the Time Zone API, name this field `errorMessage`.
+    #[serde(default, alias = "errorMessage")]
+    pub error_message: Option<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// Builds an API's errors for the HTTP-level failures that the retry loop
+/// handles, so that they are reported in the API's own error type.
+
+pub trait HttpError: Sized {
+    /// A successful response did not have the expected `Content-Type`.
+    fn unexpected_content_type(content_type: String, body_snippet: String) -> Self;
+
+    /// The server responded with an unsuccessful HTTP status code.
+    fn http_unsuccessful_response(
+        status: reqwest::StatusCode,
+        headers: Box<HeaderMap>,
+        body: String
+    ) -> Self;
+
+    /// The HTTP client did not get a response from the server.
+    fn reqwest(error: crate::ReqError) -> Self;
+
+    /// The HTTP client could not read the response body.
+    fn reqwest_message(message: String) -> Self;
+} // trait
+
+/// Builds an API's error for a JSON response body that could not be parsed.
+
+#[cfg(any(
+    feature = "address_validation",
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places",
+    feature = "roads",
+    feature = "time_zone"
+))]
+pub trait JsonError: HttpError {
+    /// The response body could not be parsed. The truncated body is included.
+    fn simd_json_with_body(error: simd_json::Error, body: String) -> Self;
+} // trait
+
+// -----------------------------------------------------------------------------
+//
+/// Returns an error for an unsuccessful Google status. Only Google's
+/// `UNKNOWN_ERROR` and `OVER_QUERY_LIMIT` statuses are eligible for retries,
+/// since in most cases an unsuccessful status indicates an issue with the
+/// request.
+///
+/// ## Arguments
+///
+/// * `error` ‧ The API's `GoogleMapsService` error for the status.
+///
+/// * `retryable` ‧ Whether the status is eligible for retries.
+
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places",
+    feature = "time_zone"
+))]
+pub fn status_error<E: std::fmt::Display>(error: E, retryable: bool) -> backoff::Error<E> {
+    if retryable {
+        tracing::warn!("{}", error);
+        Transient {
+            err: error,
+            retry_after: None,
+        } // Transient
+    } else {
+        tracing::error!("{}", error);
+        Permanent(error)
+    } // if
+} // fn
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Observes the client's rate limits, and sends a request to Google until
+    /// it is successful, an error ineligible for retries is returned, the
+    /// maximum number of retries has been reached, or the deadline has
+    /// passed.
+    ///
+    /// Connection errors, timeouts, HTTP `429 Too Many Requests` and server
+    /// errors are retried. Other unsuccessful HTTP responses, and successful
+    /// responses with an unexpected `Content-Type`, are returned as errors.
+    /// The body of a successful response is passed to `classify`, which
+    /// returns the response to the caller, or an error that may be retried,
+    /// for example because of Google's `status`. The client's retry
+    /// classifier, if any, may override whether an error is retried.
+    ///
+    /// The number of attempts, the duration, and the outcome of the request
+    /// are recorded on the current span.
+    ///
+    /// ## Arguments
+    ///
+    /// * `method` ‧ Whether the request is a `GET`, or a `POST` with a body.
+    ///
+    /// * `url` ‧ Google's URL for the request, including its query string.
+    ///
+    /// * `settings` ‧ The request's retry settings.
+    ///
+    /// * `expected_content_type` ‧ The start of the media type of a successful
+    ///   response, for example `application/json` or `image/`.
+    ///
+    /// * `classify` ‧ Interprets the body of a successful response. Errors
+    ///   wrapped in `Transient` are retried, and errors wrapped in `Permanent`
+    ///   are returned to the caller.
+
+    pub async fn execute_with_retry<T, E>(
+        &self,
+        method: Method<'_>,
+        url: &str,
+        settings: RetrySettings<'_>,
+        expected_content_type: &str,
+        classify: impl Fn(ResponseBody) -> Result<T, backoff::Error<E>> + Sync,
+    ) -> Result<T, GoogleMapsError>
+    where
+        T: Send,
+        E: HttpError + Into<GoogleMapsError> + Send,
+    {
+        // Observe any rate limiting before executing request:
+        self.rate_limit
+            .limit_apis(vec![&Api::All, &settings.api])
+            .await;
+
+        tracing::debug!("{}", crate::redact::redact_url(url));
+
+        // Retries the request until successful, an error ineligible for
+        // retries is returned, or we have reached the maximum retries. Note:
+        // errors wrapped in `Transient()` will retried by the `backoff` crate
+        // while errors wrapped in `Permanent()` will exit the retry loop.
+        let backoff = settings
+            .backoff
+            .cloned()
+            .or_else(|| self.backoff.clone())
+            .unwrap_or_default();
+        let max_elapsed_time = backoff.max_elapsed_time;
+        let started = Instant::now();
+        let attempts = AtomicU32::new(0);
+        let response = retry(backoff, || async {
+            // Keep count of attempts, so that the retry loop can be exited
+            // once the caller's maximum number of retries has been reached:
+            let attempt = attempts.fetch_add(1, Ordering::Relaxed) + 1;
+
+            let result = self
+                .attempt(&method, url, settings.timeout, expected_content_type, &classify)
+                .await;
+
+            // The client's retry classifier, if one has been set, may override
+            // whether the error is retried:
+            let result = self.classify_retry(result);
+
+            // If the maximum number of retries has been reached, a transient
+            // error is made permanent. This exits the retry loop and returns
+            // the last error to the caller:
+            match result {
+                Err(Transient { err, .. })
+                    if settings.max_retries.is_some_and(|max_retries| attempt > max_retries) =>
+                {
+                    tracing::error!("maximum number of retries reached");
+                    Err(Permanent(err))
+                } // Transient
+                // A delay requested by the server bypasses the retry policy, so
+                // the policy's maximum elapsed time is enforced here instead:
+                Err(Transient {
+                    err,
+                    retry_after: Some(retry_after),
+                }) if max_elapsed_time.is_some_and(|max_elapsed_time| {
+                    started.elapsed() + retry_after > max_elapsed_time
+                }) =>
+                {
+                    tracing::error!("server requested a retry after the maximum elapsed time");
+                    Err(Permanent(err))
+                } // Transient
+                result => result,
+            } // match
+        });
+
+        // Abandon the request, including any attempt or retry delay that is in
+        // progress, if it has not completed within its deadline:
+        let response = within_deadline(settings.deadline, response).await;
+
+        // Record the outcome of the request on the span, and summarize it in
+        // an event, so that latency and retries can be monitored:
+        let attempts = attempts.into_inner();
+        let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        let status = if response.is_ok() { "ok" } else { "error" };
+        tracing::Span::current()
+            .record("attempts", attempts)
+            .record("duration_ms", duration_ms)
+            .record("status", status);
+        tracing::info!(api = %settings.api, attempts, duration_ms, status, "request completed");
+
+        response
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Sends a single attempt of a request, and classifies its outcome as a
+    /// response, an error that is eligible for retries, or an error that is
+    /// not.
+
+    async fn attempt<T, E>(
+        &self,
+        method: &Method<'_>,
+        url: &str,
+        timeout: Option<Duration>,
+        expected_content_type: &str,
+        classify: &(impl Fn(ResponseBody) -> Result<T, backoff::Error<E>> + Sync),
+    ) -> Result<T, backoff::Error<E>>
+    where
+        T: Send,
+        E: HttpError + Send,
+    {
+        // Query the Google Cloud Maps Platform using using an HTTP request:
+        let response = match method {
+            Method::Get => self.get_request_with_timeout(url, timeout).await,
+            Method::Post(body) => self.post_request_with_timeout(url, body, timeout).await,
+        }; // match
+
+        // Check response from the HTTP client:
+        match response {
+            Ok(response) => {
+                // HTTP client was successful getting a response from the
+                // server. A successful response that is not of the expected
+                // type, such as an HTML error page, is reported as-is:
+                if let Some(content_type) = unexpected_content_type(&response, expected_content_type) {
+                    tracing::error!("HTTP client returned unexpected `{}` content", content_type);
+                    Err(Permanent(E::unexpected_content_type(
+                        content_type,
+                        self.error_body(response).await,
+                    )))
+                // Otherwise, check the HTTP status code:
+                } else if response.status().is_success() {
+                    // If the HTTP request was successful, note the content
+                    // type and final URL before reading the response body.
+                    // Redirects have already been followed:
+                    let content_type = response
+                        .headers()
+                        .get(reqwest::header::CONTENT_TYPE)
+                        .and_then(|content_type| content_type.to_str().ok())
+                        .map(str::to_string);
+                    let final_url = response.url().to_string();
+                    match response.bytes().await {
+                        Ok(bytes) => classify(ResponseBody {
+                            content_type,
+                            url: final_url,
+                            bytes: bytes.to_vec(),
+                        }), // Ok
+                        // A timeout while reading the response body is
+                        // eligible for retries, like any other timeout:
+                        Err(error) if error.is_timeout() => {
+                            tracing::warn!("HTTP client returned: {}", error);
+                            Err(Transient {
+                                err: E::reqwest_message(error.to_string()),
+                                retry_after: None,
+                            })
+                        } // Err
+                        Err(error) => {
+                            tracing::error!("HTTP client returned: {}", error);
+                            Err(Permanent(E::reqwest_message(error.to_string())))
+                        } // Err
+                    } // match
+                // We got a response from the server but it was not OK.
+                // Only HTTP "500 Server Errors", and HTTP "429 Too Many
+                // Requests" are eligible for retries.
+                } else if response.status().is_server_error() || response.status() == 429 {
+                    tracing::warn!("HTTP client returned: {}", response.status());
+                    let (status, headers, body) = self.unsuccessful_response(response).await;
+                    // Honour the server's `Retry-After` header, if present.
+                    // Otherwise, the retry policy determines the delay:
+                    let retry_after = retry_after(&headers);
+                    Err(Transient {
+                        err: E::http_unsuccessful_response(status, headers, body),
+                        retry_after,
+                    })
+                // Not a 500 Server Error or "429 Too Many Requests" error.
+                // The error is permanent, do not retry:
+                } else {
+                    tracing::error!("HTTP client returned: {}", response.status());
+                    let (status, headers, body) = self.unsuccessful_response(response).await;
+                    Err(Permanent(E::http_unsuccessful_response(status, headers, body)))
+                } // if
+            } // Ok
+            // HTTP client did not get a response from the server. Retry:
+            Err(error) => {
+                tracing::warn!("HTTP client returned: {}", error);
+                Err(Transient {
+                    err: E::reqwest(error),
+                    retry_after: None,
+                })
+            } // Err
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Parses a JSON response body. A body that cannot be parsed is reported
+    /// with the truncated body, to help with debugging, and is not retried.
+
+    #[cfg(any(
+        feature = "address_validation",
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "elevation",
+        feature = "geocoding",
+        feature = "places",
+        feature = "roads",
+        feature = "time_zone"
+    ))]
+    pub fn parse_json<T, E>(&self, body: &ResponseBody) -> Result<T, backoff::Error<E>>
+    where
+        T: serde::de::DeserializeOwned,
+        E: JsonError,
+    {
+        // `simd_json` parses in-place, so the body is copied to keep the
+        // original body for error reporting:
+        let mut bytes = body.bytes.clone();
+        simd_json::serde::from_slice::<T>(&mut bytes).map_err(|error| {
+            tracing::error!("JSON parsing error: {}", error);
+            Permanent(E::simd_json_with_body(
+                error,
+                self.truncate_error_body(&body.text()),
+            ))
+        }) // map_err
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "time_zone"))]
+mod tests {
+    use crate::client::{no_delay_backoff, TestServer};
+    use crate::error::Error as GoogleMapsError;
+    use crate::time_zone::{error::Error as TimeZoneError, response::status::Status};
+    use crate::{GoogleMapsClient, LatLng};
+    use chrono::{DateTime, Utc};
+
+    const OK: &str = r#"{
+        "dstOffset": 0,
+        "rawOffset": 0,
+        "status": "OK",
+        "timeZoneId": "UTC",
+        "timeZoneName": "Coordinated Universal Time"
+    }"#;
+
+    const OVER_QUERY_LIMIT: &str = r#"{ "status": "OVER_QUERY_LIMIT" }"#;
+
+    const REQUEST_DENIED: &str = r#"{
+        "errorMessage": "The provided API key is invalid.",
+        "status": "REQUEST_DENIED"
+    }"#;
+
+    fn client(server: &TestServer) -> GoogleMapsClient {
+        GoogleMapsClient::builder("AIzaTest")
+            .with_base_url(server.base_url.clone())
+            .with_backoff(no_delay_backoff())
+            .build()
+            .unwrap()
+    } // fn
+
+    async fn time_zone(client: &GoogleMapsClient) -> Result<(), GoogleMapsError> {
+        client
+            .time_zone(LatLng::try_from_f64(51.5, -0.12)?, DateTime::<Utc>::UNIX_EPOCH)
+            .execute()
+            .await
+            .map(|_| ())
+    } // fn
+
+    #[tokio::test]
+    async fn retries_over_query_limit() {
+        let server = TestServer::start(vec![(200, OVER_QUERY_LIMIT), (200, OK)]);
+        assert!(time_zone(&client(&server)).await.is_ok());
+        assert_eq!(server.requests(), 2);
+    } // fn
+
+    #[tokio::test]
+    async fn does_not_retry_request_denied() {
+        let server = TestServer::start(vec![(200, REQUEST_DENIED), (200, OK)]);
+        let result = time_zone(&client(&server)).await;
+        assert!(matches!(
+            result,
+            Err(GoogleMapsError::TimeZone(TimeZoneError::GoogleMapsService(
+                Status::RequestDenied,
+                Some(_)
+            )))
+        )); // assert!
+        assert_eq!(server.requests(), 1);
+    } // fn
+} // mod
@@ -0,0 +1,76 @@
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::geocoding::{forward::ForwardRequest, response::Response};
+use futures::StreamExt;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Geocodes many addresses at once, sending up to `concurrency` requests
+    /// at the same time.
+    ///
+    /// Every request still passes through the client's Geocoding API rate
+    /// limiter, so a high `concurrency` will not exceed the configured request
+    /// rate. A failed request does not stop the rest of the batch: each
+    /// address gets its own `Result`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `addresses` ‧ The street addresses to geocode.
+    ///
+    /// * `concurrency` ‧ The maximum number of requests that may be in flight
+    ///   at once. A value of `0` is treated as `1`.
+    ///
+    /// ## Returns
+    ///
+    /// One result for each address, in the same order as `addresses`.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let results = google_maps_client.geocode_batch(
+    ///     vec![
+    ///         String::from("10 Downing St, Westminster, London"),
+    ///         String::from("1600 Pennsylvania Avenue NW, Washington, DC"),
+    ///     ],
+    ///     4,
+    /// ).await;
+    ///
+    /// for result in results {
+    ///     match result {
+    ///         Ok(response) => println!("{:#?}", response.results.first()),
+    ///         Err(error) => println!("{error}"),
+    ///     } // match
+    /// } // for
+    /// ```
+
+    pub async fn geocode_batch(
+        &self,
+        addresses: Vec<String>,
+        concurrency: usize
+    ) -> Vec<Result<Response, GoogleMapsError>> {
+        let mut results: Vec<(usize, Result<Response, GoogleMapsError>)> =
+            futures::stream::iter(addresses.into_iter().enumerate())
+                .map(|(index, address)| async move {
+                    let result = ForwardRequest::new(self)
+                        .with_address(address)
+                        .execute()
+                        .await;
+                    (index, result)
+                }) // map
+                .buffer_unordered(concurrency.max(1))
+                .collect()
+                .await;
+
+        // Responses arrive in the order that they were completed. Restore the
+        // order of the addresses:
+        results.sort_unstable_by_key(|(index, _result)| *index);
+
+        results
+            .into_iter()
+            .map(|(_index, result)| result)
+            .collect()
+    } // fn
+} // impl
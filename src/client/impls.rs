@@ -1,4 +1,12 @@
 use crate::client::GoogleMapsClient;
+#[cfg(feature = "reqwest")]
+use crate::client::{DEFAULT_ERROR_BODY_LENGTH, DEFAULT_TIMEOUT};
+#[cfg(any(
+    feature = "geocoding",
+    feature = "places",
+    feature = "roads",
+    feature = "time_zone"
+))]
 use crate::types::LatLng;
 #[cfg(feature = "directions")]
 use crate::directions::request::location::Location;
@@ -23,24 +31,21 @@ impl GoogleMapsClient {
     /// * `key` ‧ Your application's API key. This key identifies your
     ///   application for purposes of quota management. Learn how to [get a
     ///   key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the API key is empty or only whitespace. A
+    ///   warning is logged through `tracing` if the key does not look like a
+    ///   Google API key, but it is still accepted.
+    ///
+    /// * Returns an error if the `reqwest` client could not be built.
 
     #[cfg(all(feature = "reqwest", not(feature = "reqwest-middleware")))]
     pub fn try_new(key: impl Into<String>) -> Result<Self, crate::GoogleMapsError> {
-        let reqwest_client = reqwest::Client::builder()
-            .user_agent(format!(
-                "RustGoogleMaps/{version}",
-                version = env!("CARGO_PKG_VERSION")
-            ))
-            .connect_timeout(std::time::Duration::from_secs(30))
-            .timeout(std::time::Duration::from_secs(30))
-            .read_timeout(std::time::Duration::from_secs(30))
-            .build()?;
-
-        Ok(Self {
-            key: key.into(),
-            rate_limit: RequestRate::default(),
-            reqwest_client,
-        }) // GoogleMapsClient
+        let key = key.into();
+        Self::check_key(&key)?;
+        let reqwest_client = Self::default_reqwest_client_builder(DEFAULT_TIMEOUT).build()?;
+        Ok(Self::from_parts(key, reqwest_client))
     } // fn
 
     // -------------------------------------------------------------------------
@@ -50,26 +55,195 @@ impl GoogleMapsClient {
     /// ## Arguments
     ///
     /// * `key` ‧ Your application's API key. This key identifies your
-    /// application for purposes of quota management. Learn how to [get a
-    /// key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
+    ///   application for purposes of quota management. Learn how to [get a
+    ///   key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the API key is empty or only whitespace. A
+    ///   warning is logged through `tracing` if the key does not look like a
+    ///   Google API key, but it is still accepted.
+    ///
+    /// * Returns an error if the `reqwest` client could not be built.
 
     #[cfg(all(feature = "reqwest", feature = "reqwest-middleware"))]
     pub fn try_new(key: impl Into<String>) -> Result<Self, crate::GoogleMapsError> {
-        let reqwest_client = reqwest::Client::builder()
+        let key = key.into();
+        Self::check_key(&key)?;
+        let reqwest_client = Self::default_reqwest_client_builder(DEFAULT_TIMEOUT).build()?;
+        Ok(Self::from_parts(key, reqwest_client))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Checks an API key before it is used, so that an obviously wrong key is
+    /// reported at startup instead of by a `REQUEST_DENIED` response.
+    ///
+    /// An empty key is rejected. Google's API keys are currently 39 characters
+    /// long and begin with `AIza`, but this is not guaranteed, so a key that
+    /// does not match only logs a warning. The key itself is never logged.
+
+    pub(crate) fn check_key(key: &str) -> Result<(), crate::types::Error> {
+        if key.trim().is_empty() {
+            return Err(crate::types::Error::InvalidApiKey);
+        } // if
+
+        if key.len() != 39 || !key.starts_with("AIza") {
+            tracing::warn!(
+                "the API key is {length} characters long and does not look like a Google API \
+                key, which usually begins with `AIza` and is 39 characters long",
+                length = key.len()
+            );
+        } // if
+
+        Ok(())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Configures the builder for the `reqwest` client that is used when the
+    /// caller has not provided one. The timeout applies to connecting, to
+    /// reading, and to the request as a whole. The connection pool and HTTP
+    /// version are left at `reqwest`'s defaults, for `ClientBuilder` to adjust.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn default_reqwest_client_builder(
+        timeout: std::time::Duration
+    ) -> reqwest::ClientBuilder {
+        reqwest::Client::builder()
             .user_agent(format!(
                 "RustGoogleMaps/{version}",
                 version = env!("CARGO_PKG_VERSION")
             ))
-            .connect_timeout(std::time::Duration::from_secs(30))
-            .timeout(std::time::Duration::from_secs(30))
-            .read_timeout(std::time::Duration::from_secs(30))
-            .build()?;
+            .connect_timeout(timeout)
+            .timeout(timeout)
+            .read_timeout(timeout)
+    } // fn
 
-        Ok(Self {
-            key: key.into(),
+    // -------------------------------------------------------------------------
+    //
+    /// Initialize the settings needed for a Google Cloud Maps API transaction,
+    /// using a `reqwest` client that you have configured yourself.
+    ///
+    /// Unlike `try_new`, no `reqwest` client is built by this crate, so only
+    /// the API key is checked. A `reqwest::Client` holds its connection pool
+    /// behind a reference count, so cloning one client and passing it to
+    /// several `GoogleMapsClient`s (for example, one per API key) lets them
+    /// all share the same connection pool, timeouts, proxy, and TLS settings.
+    ///
+    /// ## Arguments
+    ///
+    /// * `key` ‧ Your application's API key. This key identifies your
+    ///   application for purposes of quota management. Learn how to [get a
+    ///   key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
+    ///
+    /// * `reqwest_client` ‧ A reqwest client built using the
+    ///   `reqwest::Client::builder()` function. It is used for every request
+    ///   made by this client.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let reqwest_client = reqwest::Client::builder()
+    ///     .pool_max_idle_per_host(16)
+    ///     .timeout(std::time::Duration::from_secs(10))
+    ///     .build()?;
+    ///
+    /// let first_client = GoogleMapsClient::try_from_reqwest_client("FIRST_API_KEY", reqwest_client.clone())?;
+    /// let second_client = GoogleMapsClient::try_from_reqwest_client("SECOND_API_KEY", reqwest_client)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the API key is empty or only whitespace. A
+    ///   warning is logged through `tracing` if the key does not look like a
+    ///   Google API key, but it is still accepted.
+
+    #[cfg(feature = "reqwest")]
+    pub fn try_from_reqwest_client(
+        key: impl Into<String>,
+        reqwest_client: reqwest::Client
+    ) -> Result<Self, crate::GoogleMapsError> {
+        let key = key.into();
+        Self::check_key(&key)?;
+        Ok(Self::from_parts(key, reqwest_client))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initialize the settings needed for a Google Cloud Maps API transaction,
+    /// using a `reqwest` client that you have configured yourself. See
+    /// `try_from_reqwest_client` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// * `key` ‧ Your application's API key. This key identifies your
+    ///   application for purposes of quota management. Learn how to [get a
+    ///   key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
+    ///
+    /// * `reqwest_client` ‧ A reqwest client built using the
+    ///   `reqwest::Client::builder()` function. It is used for every request
+    ///   made by this client.
+    ///
+    /// An empty API key is not rejected, for compatibility, but a warning is
+    /// logged through `tracing`. Google will reject every request made with
+    /// it. Use `try_from_reqwest_client` to handle that case as an error
+    /// instead.
+
+    #[cfg(feature = "reqwest")]
+    #[deprecated(since = "4.0.0", note = "use `try_from_reqwest_client` instead")]
+    #[must_use]
+    pub fn from_reqwest_client(key: impl Into<String>, reqwest_client: reqwest::Client) -> Self {
+        let key = key.into();
+        if let Err(error) = Self::check_key(&key) {
+            tracing::warn!("{error}");
+        } // if
+        Self::from_parts(key, reqwest_client)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Assembles a client from an API key that has already been checked, or
+    /// that is not needed, and a `reqwest` client.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn from_parts(key: String, reqwest_client: reqwest::Client) -> Self {
+        Self {
+            key,
+            #[cfg(any(
+                feature = "autocomplete",
+                feature = "directions",
+                feature = "distance_matrix",
+                feature = "geocoding",
+                feature = "places",
+                feature = "time_zone"
+            ))]
+            language: None,
+            #[cfg(any(
+                feature = "autocomplete",
+                feature = "directions",
+                feature = "distance_matrix",
+                feature = "geocoding",
+                feature = "places"
+            ))]
+            region: None,
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            unit_system: None,
             rate_limit: RequestRate::default(),
+            error_body_length: DEFAULT_ERROR_BODY_LENGTH,
+            #[cfg(feature = "geocoding")]
+            geocoding_cache: None,
+            channel: None,
+            base_url: None,
+            url_signer: None,
+            omit_key: false,
+            retry_classifier: None,
+            backoff: None,
+            #[cfg(not(feature = "reqwest-middleware"))]
+            reqwest_client,
+            #[cfg(feature = "reqwest-middleware")]
             reqwest_client: crate::reqwest_maybe_middleware::Client::Vanilla(reqwest_client),
-        }) // GoogleMapsClient
+        } // GoogleMapsClient
     } // fn
 
     // -------------------------------------------------------------------------
@@ -87,12 +261,20 @@ impl GoogleMapsClient {
     /// * This function will panic if the `reqwest` client builder chain fails.
     ///   Realistically this shouldn't happen. However you may want to use
     ///   `try_new` to instantiate a new `GoogleMapsClient` instead.
+    ///
+    /// An empty API key is not rejected, for compatibility, but a warning is
+    /// logged through `tracing`.
 
     #[cfg(feature = "reqwest")]
     #[deprecated(since = "3.4.2", note = "use `try_new` instead")]
     #[must_use]
     pub fn new(key: impl Into<String>) -> Self {
-        Self::try_new(key).unwrap()
+        let key = key.into();
+        if let Err(error) = Self::check_key(&key) {
+            tracing::warn!("{error}");
+        } // if
+        let reqwest_client = Self::default_reqwest_client_builder(DEFAULT_TIMEOUT).build().unwrap();
+        Self::from_parts(key, reqwest_client)
     }
 
     // -------------------------------------------------------------------------
@@ -102,12 +284,115 @@ impl GoogleMapsClient {
     /// ## Arguments
     ///
     /// * `key` ‧ Your application's API key. This key identifies your
-    /// application for purposes of quota management. Learn how to [get a
-    /// key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
+    ///   application for purposes of quota management. Learn how to [get a
+    ///   key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
+    ///
+    /// An empty API key is not rejected, for compatibility, but a warning is
+    /// logged through `tracing`. Use `try_new` to handle that case as an error
+    /// instead.
 
     #[cfg(not(feature = "reqwest"))]
+    #[deprecated(since = "4.0.0", note = "use `try_new` instead")]
+    #[must_use]
     pub fn new(key: impl Into<String>) -> Self {
-        Self { key: key.into() }
+        let key = key.into();
+        if let Err(error) = Self::check_key(&key) {
+            tracing::warn!("{error}");
+        } // if
+        Self::from_parts(key)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initialize the settings needed for a Google Cloud Maps API transaction.
+    ///
+    /// ## Arguments
+    ///
+    /// * `key` ‧ Your application's API key. This key identifies your
+    ///   application for purposes of quota management. Learn how to [get a
+    ///   key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the API key is empty or only whitespace. A
+    ///   warning is logged through `tracing` if the key does not look like a
+    ///   Google API key, but it is still accepted.
+
+    #[cfg(not(feature = "reqwest"))]
+    pub fn try_new(key: impl Into<String>) -> Result<Self, crate::GoogleMapsError> {
+        let key = key.into();
+        Self::check_key(&key)?;
+        Ok(Self::from_parts(key))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Assembles a client from an API key that has already been checked.
+
+    #[cfg(not(feature = "reqwest"))]
+    const fn from_parts(key: String) -> Self {
+        Self {
+            key,
+            #[cfg(any(
+                feature = "autocomplete",
+                feature = "directions",
+                feature = "distance_matrix",
+                feature = "geocoding",
+                feature = "places",
+                feature = "time_zone"
+            ))]
+            language: None,
+            #[cfg(any(
+                feature = "autocomplete",
+                feature = "directions",
+                feature = "distance_matrix",
+                feature = "geocoding",
+                feature = "places"
+            ))]
+            region: None,
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            unit_system: None,
+        } // GoogleMapsClient
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Address Validation API is a service that accepts an address,
+    /// identifies and validates its components, standardizes it for mailing,
+    /// and finds the best known latitude/longitude coordinates for it.
+    ///
+    /// ## Arguments
+    ///
+    /// * `region_code` ‧ The [CLDR region code](https://cldr.unicode.org/) of
+    ///   the address's country or region, for example `US`. This is not
+    ///   always the same as a `Region`, for example the United Kingdom is `GB`.
+    ///
+    /// * `address_lines` ‧ The lines of the address. A whole, unformatted
+    ///   address may be passed as a single line.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// let validation = google_maps_client
+    ///     .address_validation("US", ["1600 Amphitheatre Pkwy", "Mountain View, CA 94043"])
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// println!("{:?}", validation.result.verdict.possible_next_action);
+    /// ```
+
+    #[cfg(feature = "address_validation")]
+    #[must_use]
+    pub fn address_validation<C, S>(
+        &self,
+        region_code: impl Into<String>,
+        address_lines: C
+    ) -> crate::address_validation::request::Request<'_>
+    where
+        C: IntoIterator<Item = S>,
+        S: Into<String> {
+        let address = crate::address_validation::PostalAddress::new(region_code, address_lines);
+        crate::address_validation::request::Request::new(self, address)
     } // fn
 
     // -------------------------------------------------------------------------
@@ -138,7 +423,7 @@ impl GoogleMapsClient {
         &self,
         origin: impl Into<Location>,
         destination: impl Into<Location>
-    ) -> crate::directions::request::Request {
+    ) -> crate::directions::request::Request<'_> {
         crate::directions::request::Request::new(self, origin.into(), destination.into())
     } // fn
 
@@ -186,7 +471,7 @@ impl GoogleMapsClient {
         &self,
         origins: C,
         destinations: C
-    ) -> crate::distance_matrix::request::Request
+    ) -> crate::distance_matrix::request::Request<'_>
     where
         C: IntoIterator<Item = W>,
         W: Into<Waypoint> {
@@ -208,7 +493,7 @@ impl GoogleMapsClient {
 
     #[cfg(feature = "elevation")]
     #[must_use]
-    pub const fn elevation(&self) -> crate::elevation::request::Request {
+    pub const fn elevation(&self) -> crate::elevation::request::Request<'_> {
         crate::elevation::request::Request::new(self)
     } // fn
 
@@ -227,7 +512,7 @@ impl GoogleMapsClient {
 
     #[cfg(feature = "geocoding")]
     #[must_use]
-    pub const fn geocoding(&self) -> crate::geocoding::forward::ForwardRequest {
+    pub const fn geocoding(&self) -> crate::geocoding::forward::ForwardRequest<'_> {
         crate::geocoding::forward::ForwardRequest::new(self)
     } // fn
 
@@ -254,6 +539,23 @@ impl GoogleMapsClient {
     ///     // 10 Downing St, Westminster, London
     ///     LatLng::try_from_dec(dec!(51.503_364), dec!(-0.127_625))?,
     /// )
+    /// .execute()
+    /// .await?;
+    /// ```
+    ///
+    /// ## Filtering results
+    ///
+    /// The optional `result_type` and `location_type` filters may be chained
+    /// onto the request before it is executed:
+    ///
+    /// ```rust
+    /// let address = google_maps_client.reverse_geocoding(
+    ///     LatLng::try_from_dec(dec!(51.503_364), dec!(-0.127_625))?,
+    /// )
+    /// .with_result_type(PlaceType::StreetAddress)
+    /// .with_location_type(LocationType::RoofTop)
+    /// .execute()
+    /// .await?;
     /// ```
 
     #[cfg(feature = "geocoding")]
@@ -261,10 +563,36 @@ impl GoogleMapsClient {
     pub fn reverse_geocoding(
         &self,
         location: impl Into<LatLng>
-    ) -> crate::geocoding::reverse::ReverseRequest {
+    ) -> crate::geocoding::reverse::ReverseRequest<'_> {
         crate::geocoding::reverse::ReverseRequest::new(self, location.into())
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Geocoding API can also resolve a
+    /// [Plus Code](https://maps.google.com/pluscodes/) to latitude & longitude
+    /// coordinates and the nearest human-readable address.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let location = google_maps_client.plus_code()
+    ///     .with_code("CWC8+R9")
+    ///     .with_locality("Mountain View, CA, USA")
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[cfg(feature = "geocoding")]
+    #[must_use]
+    pub const fn plus_code(&self) -> crate::geocoding::plus_codes::PlusCodeRequest<'_> {
+        crate::geocoding::plus_codes::PlusCodeRequest::new(self)
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The **Time Zone API** provides time offset data for locations on the
@@ -302,7 +630,7 @@ impl GoogleMapsClient {
         &self,
         location: impl Into<LatLng>,
         timestamp: impl Into<DateTime<Utc>>
-    ) -> crate::time_zone::request::Request {
+    ) -> crate::time_zone::request::Request<'_> {
         crate::time_zone::request::Request::new(self, location.into(), timestamp.into())
     } // fn
 
@@ -323,7 +651,7 @@ impl GoogleMapsClient {
     pub fn place_autocomplete(
         &self,
         input: impl Into<String>
-    ) -> crate::places::place_autocomplete::request::Request {
+    ) -> crate::places::place_autocomplete::request::Request<'_> {
         crate::places::place_autocomplete::request::Request::new(self, input)
     } // fn
 
@@ -363,10 +691,57 @@ impl GoogleMapsClient {
     pub fn query_autocomplete(
         &self,
         input: impl Into<String>
-    ) -> crate::places::query_autocomplete::request::Request {
+    ) -> crate::places::query_autocomplete::request::Request<'_> {
         crate::places::query_autocomplete::request::Request::new(self, input)
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API **Find Place** service takes a text input, such as a
+    /// name, address, or phone number, and returns the places that best match
+    /// it. Usually only one candidate is returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` ‧ The text string on which to search, for example:
+    ///   "restaurant" or "123 Main Street". Use `with_input_type()` to search
+    ///   by phone number instead.
+    ///
+    /// ## Additional information:
+    ///
+    /// Unlike Nearby Search and Text Search, Find Place only returns the
+    /// fields that are requested with `with_fields()`, so that you are not
+    /// billed for data that you do not need. If no fields are requested, only
+    /// the `place_id` is returned.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let find_place = google_maps_client.find_place("Museum of Contemporary Art Australia")
+    ///     .with_fields([PlaceField::FormattedAddress, PlaceField::Name, PlaceField::Rating])
+    ///     .with_location_bias(LocationBias::try_circle(
+    ///         LatLng::try_new(-33.856_8, 151.215_3)?,
+    ///         2_000,
+    ///     )?)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// println!("{:#?}", find_place.best_candidate());
+    /// ```
+
+    #[cfg(feature = "places")]
+    #[must_use]
+    pub fn find_place(
+        &self,
+        input: impl Into<String>
+    ) -> crate::places::place_search::find_place::request::Request<'_> {
+        crate::places::place_search::find_place::request::Request::new(self, input)
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Places API **Text Search** service returns information about a set
@@ -457,7 +832,7 @@ impl GoogleMapsClient {
         &self,
         query: impl Into<String>,
         radius: impl Into<u32>
-    ) -> crate::places::place_search::text_search::request::Request {
+    ) -> crate::places::place_search::text_search::request::Request<'_> {
         crate::places::place_search::text_search::request::Request::new(self, query, radius.into())
     } // fn
 
@@ -514,7 +889,7 @@ impl GoogleMapsClient {
         &self,
         location: impl Into<LatLng>,
         radius: impl Into<u32>
-    ) -> crate::places::place_search::nearby_search::request::Request {
+    ) -> crate::places::place_search::nearby_search::request::Request<'_> {
         crate::places::place_search::nearby_search::request::Request::new(
             self,
             location.into(),
@@ -522,6 +897,51 @@ impl GoogleMapsClient {
         )
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API **Nearby Search** service lets you search for places
+    /// within a specified area. This method creates a search that lists
+    /// results in ascending order of their distance from the specified
+    /// location, which is also known as `rankby=distance`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `location` ‧ The point around which to retrieve place information.
+    ///   This must be specified as `latitude,longitude`.
+    ///
+    /// Google does not accept a radius when results are ranked by distance,
+    /// and requires a keyword, a place type, or both. The request will fail to
+    /// build if neither the `with_keyword()` nor the `with_type()` method is
+    /// used.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let search_results = google_maps_client.nearby_search_by_distance(LatLng::try_from_dec(dec!(53.740_989), dec!(-113.493_768))?)
+    ///     .with_type(PlaceType::Restaurant)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// println!("{:#?}", search_results);
+    /// ```
+
+    #[cfg(feature = "places")]
+    #[must_use]
+    pub fn nearby_search_by_distance(
+        &self,
+        location: impl Into<LatLng>
+    ) -> crate::places::place_search::nearby_search::request::Request<'_> {
+        crate::places::place_search::nearby_search::request::Request::new_by_distance(
+            self,
+            location.into()
+        )
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Places API **Place Details** service returns more details about a
@@ -557,13 +977,51 @@ impl GoogleMapsClient {
     pub fn place_details(
         &self,
         place_id: impl Into<String>
-    ) -> crate::places::place_details::request::Request {
+    ) -> crate::places::place_details::request::Request<'_> {
         crate::places::place_details::request::Request::new(
             self,
             place_id.into()
         )
     } // fn
 
+    // -------------------------------------------------------------------------
+    //
+    /// The Places API **Place Photo** service gives you access to the millions
+    /// of photos stored in the Places database. When you get place information
+    /// using a Place Search or Place Details request, photo references will be
+    /// returned for relevant photographic content. The Place Photo service
+    /// lets you download the images that these references point to.
+    ///
+    /// ## Arguments
+    ///
+    /// * `photo_reference` ‧ A string identifier that uniquely identifies a
+    ///   photo. Photo references are returned from Place Search, Place
+    ///   Details, and Find Place requests.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let photo = google_maps_client.place_photo(&place_photo.photo_reference)
+    ///     .with_max_width(400)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// std::fs::write("photo.jpg", photo.bytes)?;
+    /// ```
+
+    #[cfg(feature = "places")]
+    #[must_use]
+    pub fn place_photo(
+        &self,
+        photo_reference: impl Into<String>
+    ) -> crate::places::place_photo::request::Request<'_> {
+        crate::places::place_photo::request::Request::new(self, photo_reference)
+    } // fn
+
     // -------------------------------------------------------------------------
     //
     /// The Roads API **Snap To Roads** service takes up to 100 GPS points
@@ -617,7 +1075,7 @@ impl GoogleMapsClient {
     pub fn snap_to_roads<C, L>(
         &self,
         path: C
-    ) -> crate::roads::snap_to_roads::request::Request
+    ) -> crate::roads::snap_to_roads::request::Request<'_>
     where
         C: IntoIterator<Item = L>,
         L: Into<LatLng> {
@@ -667,19 +1125,335 @@ impl GoogleMapsClient {
     pub fn nearest_roads<C, L>(
         &self,
         points: C
-    ) -> crate::roads::snap_to_roads::request::Request
+    ) -> crate::roads::nearest_roads::request::Request<'_>
     where
         C: IntoIterator<Item = L>,
         L: Into<LatLng> {
         let points: Vec<LatLng> = points.into_iter().map(Into::into).collect();
-        crate::roads::snap_to_roads::request::Request::new(self, points)
+        crate::roads::nearest_roads::request::Request::new(self, points)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The Roads API **Speed Limits** service returns the posted speed limit
+    /// for a road segment. The road segments are specified either by a path,
+    /// using the `with_path()` method, or by place IDs, using the
+    /// `with_place_ids()` method.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let speed_limits = google_maps_client.speed_limits()
+    ///     .with_place_ids(vec![
+    ///         "ChIJX12duJAwGQ0Ra0d4Oi4jOGE",
+    ///         "ChIJLQcticc0GQ0RoiNZJVa5GxU",
+    ///     ])
+    ///     .with_units(SpeedUnits::MilesPerHour)
+    ///     .execute()
+    ///     .await?;
+    /// ```
+
+    #[cfg(feature = "roads")]
+    #[must_use]
+    pub const fn speed_limits(&self) -> crate::roads::speed_limits::request::Request<'_> {
+        crate::roads::speed_limits::request::Request::new(self)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// The **Maps Static API** returns a map as an image, which can be
+    /// embedded in a web page, an email, or a PDF. The map is positioned using
+    /// the `with_center()` and `with_zoom()` methods, or fitted to the markers
+    /// and paths that are added with the `with_markers()` and `with_path()`
+    /// methods. Use `StaticMapRequest::from_route()` to draw a route returned
+    /// by the Directions API.
+    ///
+    /// ## Basic usage
+    ///
+    /// ```rust
+    /// use google_maps::prelude::*;
+    ///
+    /// let google_maps_client = GoogleMapsClient::new("YOUR_GOOGLE_API_KEY_HERE");
+    ///
+    /// let image = google_maps_client.static_map()
+    ///     .with_center(LatLng::try_from_dec(dec!(40.714728), dec!(-73.998672))?)
+    ///     .with_zoom(12)
+    ///     .with_size(400, 400)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// std::fs::write("map.png", image.bytes)?;
+    /// ```
+
+    #[cfg(feature = "static_map")]
+    #[must_use]
+    pub const fn static_map(&self) -> crate::static_map::request::Request<'_> {
+        crate::static_map::request::Request::new(self)
     } // fn
 
     #[cfg(feature = "reqwest")]
     pub async fn get_request(&self, url: &str) -> Result<Response, crate::ReqError> {
-        match self.reqwest_client.get(url).build() {
-            Ok(request) => self.reqwest_client.execute(request).await,
-            Err(error) => Err(crate::ReqError::from(error)),
-        }
+        self.get_request_with_timeout(url, None).await
     }
+
+    // -------------------------------------------------------------------------
+    //
+    /// Performs an HTTP post request with a JSON body, using the client's
+    /// `reqwest` client and base URL. This is the `POST` counterpart of
+    /// `get_request()`.
+    ///
+    /// Only some Google Maps Platform APIs, such as the Address Validation
+    /// API, accept `POST` requests. The classic web services, such as the
+    /// Directions, Elevation, and Roads APIs, only accept `GET` requests,
+    /// however long their URL is.
+    ///
+    /// ## Arguments
+    ///
+    /// * `url` ‧ The URL to post to, including its query string.
+    ///
+    /// * `body` ‧ The JSON request body.
+
+    #[cfg(feature = "reqwest")]
+    pub async fn post_request(&self, url: &str, body: &str) -> Result<Response, crate::ReqError> {
+        self.post_request_with_timeout(url, body, None).await
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Performs an HTTP get request. If a `timeout` is provided, it overrides
+    /// the `reqwest` client's timeout for this request only.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) async fn get_request_with_timeout(
+        &self,
+        url: &str,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<Response, crate::ReqError> {
+        let url = self.request_url(url);
+
+        // Google rejects URLs over its length limit. The request is still
+        // sent, so that Google's own error is returned to the caller, but the
+        // cause is logged since the error does not always make it obvious:
+        if url.len() > crate::client::MAX_URL_LENGTH {
+            tracing::warn!(
+                length = url.len(),
+                "request URL is longer than Google's limit of {} characters",
+                crate::client::MAX_URL_LENGTH
+            ); // warn!
+        } // if
+
+        match self.reqwest_client.get(url.as_ref()).build() {
+            Ok(mut request) => {
+                if let Some(timeout) = timeout {
+                    *request.timeout_mut() = Some(timeout);
+                } // if
+                self.reqwest_client
+                    .execute(request)
+                    .await
+                    .map_err(crate::redact::redact_error)
+            } // Ok
+            Err(error) => Err(crate::redact::redact_error(crate::ReqError::from(error))),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Performs an HTTP post request with a JSON body. If a `timeout` is
+    /// provided, it overrides the `reqwest` client's timeout for this request
+    /// only.
+    ///
+    /// The channel and URL signing are not applied, since they are not
+    /// supported by the `POST`-based APIs. The base URL, and the omission of
+    /// the API key, are applied as for `GET` requests.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) async fn post_request_with_timeout(
+        &self,
+        url: &str,
+        body: &str,
+        timeout: Option<std::time::Duration>,
+    ) -> Result<Response, crate::ReqError> {
+        let url = self.rebase_url(self.remove_key(std::borrow::Cow::Borrowed(url)));
+        match self
+            .reqwest_client
+            .post(url.as_ref())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.to_string())
+            .build()
+        {
+            Ok(mut request) => {
+                if let Some(timeout) = timeout {
+                    *request.timeout_mut() = Some(timeout);
+                } // if
+                self.reqwest_client
+                    .execute(request)
+                    .await
+                    .map_err(crate::redact::redact_error)
+            } // Ok
+            Err(error) => Err(crate::redact::redact_error(crate::ReqError::from(error))),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the URL that a request should be sent to. If a channel has
+    /// been set, it is added to the query string. If the API key is to be
+    /// omitted, it is removed. If URL signing has been set up, the URL is
+    /// signed. If a base URL has been set, it replaces the scheme, host, and
+    /// port of Google's URL, and its path is prepended to Google's path.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) fn request_url<'u>(&self, url: &'u str) -> std::borrow::Cow<'u, str> {
+        let url = self.channel.as_ref().map_or(
+            std::borrow::Cow::Borrowed(url),
+            |channel| std::borrow::Cow::Owned(format!("{url}&channel={channel}")),
+        ); // map_or
+
+        let url = self.remove_key(url);
+
+        // The signature covers the whole query string, including the channel,
+        // and Google's path, so the URL is signed after the channel is added
+        // and before the base URL is applied:
+        let url = match &self.url_signer {
+            Some(url_signer) => std::borrow::Cow::Owned(url_signer.sign(&url)),
+            None => url,
+        }; // match
+
+        self.rebase_url(url)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Removes the `key` parameter from a URL's query string, if the client
+    /// has been set to omit the API key with `without_key()`. The URL is
+    /// returned unchanged otherwise.
+
+    #[cfg(feature = "reqwest")]
+    fn remove_key<'u>(&self, url: std::borrow::Cow<'u, str>) -> std::borrow::Cow<'u, str> {
+        if !self.omit_key {
+            return url;
+        } // if
+
+        let (stem, query) = url.split_once('?').unwrap_or((&url, ""));
+
+        let query: Vec<&str> = query
+            .split('&')
+            .filter(|parameter| !parameter.is_empty() && !parameter.starts_with("key="))
+            .collect();
+
+        if query.is_empty() {
+            std::borrow::Cow::Owned(stem.to_string())
+        } else {
+            std::borrow::Cow::Owned(format!("{stem}?{query}", query = query.join("&")))
+        } // if
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces the scheme, host, and port of Google's URL with those of the
+    /// base URL, if one has been set. The base URL's path is prepended to
+    /// Google's path.
+
+    #[cfg(feature = "reqwest")]
+    fn rebase_url<'u>(&self, url: std::borrow::Cow<'u, str>) -> std::borrow::Cow<'u, str> {
+        let Some(base_url) = &self.base_url else {
+            return url;
+        }; // let
+
+        // A URL that can not be parsed is passed along as-is, so that the
+        // HTTP client reports the error:
+        let Ok(google_url) = reqwest::Url::parse(&url) else {
+            return url;
+        }; // let
+
+        let mut request_url = base_url.clone();
+        request_url.set_path(&format!(
+            "{}{}",
+            base_url.path().trim_end_matches('/'),
+            google_url.path()
+        ));
+        request_url.set_query(google_url.query());
+
+        std::borrow::Cow::Owned(request_url.into())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Collects the details of an unsuccessful HTTP response: the HTTP status
+    /// code, the headers that help explain the failure (`Retry-After` and
+    /// Google's `X-Goog-*` headers), and the response body truncated to the
+    /// client's `error_body_length`.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) async fn unsuccessful_response(
+        &self,
+        response: Response
+    ) -> (reqwest::StatusCode, Box<reqwest::header::HeaderMap>, String) {
+        let status = response.status();
+
+        // Only keep the headers that may explain why the request failed:
+        let headers: reqwest::header::HeaderMap = response
+            .headers()
+            .iter()
+            .filter(|(name, _value)| {
+                name == &reqwest::header::RETRY_AFTER || name.as_str().starts_with("x-goog-")
+            })
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+
+        // The body often contains Google's explanation for the failure. If
+        // it can't be read, the status code and headers are still returned:
+        let body = self.error_body(response).await;
+
+        (status, Box::new(headers), body)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Reads the body of a response that is being reported as an error,
+    /// truncated to the client's `error_body_length`. An empty string is
+    /// returned if the body can't be read.
+
+    #[cfg(feature = "reqwest")]
+    pub(crate) async fn error_body(&self, response: Response) -> String {
+        response
+            .text()
+            .await
+            .map(|body| self.truncate_error_body(&body))
+            .unwrap_or_default()
+    } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest"))]
+mod tests {
+    use crate::GoogleMapsClient;
+
+    #[test]
+    fn every_fallible_constructor_rejects_an_empty_key() {
+        for key in ["", "   "] {
+            assert!(GoogleMapsClient::try_new(key).is_err());
+            assert!(GoogleMapsClient::try_from_reqwest_client(key, reqwest::Client::new()).is_err());
+            assert!(GoogleMapsClient::builder(key).build().is_err());
+        } // for
+    } // fn
+
+    #[test]
+    fn accepts_a_key() {
+        let client = GoogleMapsClient::try_from_reqwest_client("AIzaTest", reqwest::Client::new());
+        assert!(client.is_ok());
+    } // fn
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_constructor_still_accepts_an_empty_key() {
+        let client = GoogleMapsClient::from_reqwest_client("", reqwest::Client::new());
+        assert!(client.key.is_empty());
+    } // fn
+} // mod
@@ -0,0 +1,58 @@
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::geocoding::{
+    error::Error as GeocodingError, forward::ForwardRequest, response::status::Status,
+}; // crate::geocoding
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Checks whether Google accepts the client's API key, by sending a single
+    /// minimal Geocoding API request. This is a quick preflight check that may
+    /// be run before a batch job, so that a missing, restricted, or revoked
+    /// key is caught before the first real request fails.
+    ///
+    /// The request is billed like any other Geocoding API request. Only the
+    /// Geocoding API is checked: a key may work with it, and still not be
+    /// enabled for other APIs.
+    ///
+    /// ## Returns
+    ///
+    /// * `true` if Google returned `OK` or `ZERO_RESULTS`.
+    ///
+    /// * `false` if Google returned `REQUEST_DENIED`, which means that the key
+    ///   is invalid, or is not allowed to use the Geocoding API. This status
+    ///   is permanent, so it is not retried.
+    ///
+    /// # Errors
+    ///
+    /// * Returns any other error, such as a connection failure or an
+    ///   `OVER_QUERY_LIMIT` status that persisted through every retry, since
+    ///   it does not say whether the key is valid.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// if !google_maps_client.is_key_valid().await? {
+    ///     panic!("the Google Maps API key was rejected");
+    /// } // if
+    /// ```
+
+    pub async fn is_key_valid(&self) -> Result<bool, GoogleMapsError> {
+        let result = ForwardRequest::new(self)
+            .with_address("1600 Amphitheatre Parkway, Mountain View, CA")
+            .execute()
+            .await;
+
+        match result {
+            Ok(_response) => Ok(true),
+            Err(GoogleMapsError::Geocoding(GeocodingError::GoogleMapsService(
+                Status::RequestDenied,
+                _error_message,
+            ))) => Ok(false),
+            Err(error) => Err(error),
+        } // match
+    } // fn
+} // impl
@@ -5,16 +5,184 @@
 // -----------------------------------------------------------------------------
 
 mod build;
+#[cfg(feature = "reqwest")]
+mod client_builder;
+#[cfg(feature = "reqwest")]
+mod content_type;
+#[cfg(feature = "reqwest")]
+mod deadline;
+#[cfg(feature = "reqwest")]
+mod execute_with_retry;
+#[cfg(all(feature = "geocoding", feature = "reqwest"))]
+mod geocode_batch;
 mod impls;
+#[cfg(all(feature = "geocoding", feature = "reqwest"))]
+mod is_key_valid;
+#[cfg(feature = "reqwest")]
+mod retry_after;
+#[cfg(feature = "reqwest")]
+mod retry_classifier;
+#[cfg(feature = "reqwest")]
+mod runtime;
+#[cfg(all(
+    test,
+    feature = "reqwest",
+    any(
+        feature = "address_validation",
+        feature = "distance_matrix",
+        feature = "places",
+        feature = "time_zone"
+    )
+))]
+mod test_server;
+#[cfg(feature = "reqwest")]
+mod url_signing;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+#[cfg(feature = "reqwest")]
+mod with_base_url;
+#[cfg(feature = "reqwest")]
+mod with_channel;
+#[cfg(feature = "reqwest")]
+mod with_error_body_length;
+#[cfg(all(feature = "geocoding", feature = "reqwest"))]
+mod with_geocoding_cache;
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "geocoding",
+    feature = "places",
+    feature = "time_zone"
+))]
+mod with_language;
 #[cfg(feature = "reqwest")]
 mod with_rate;
 #[cfg(feature = "reqwest")]
+mod with_rate_per_second;
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "geocoding",
+    feature = "places"
+))]
+mod with_region;
+#[cfg(feature = "reqwest")]
 mod with_reqwest_client;
+#[cfg(feature = "reqwest")]
+mod with_retry_classifier;
+#[cfg(feature = "reqwest")]
+mod with_signing;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+mod with_unit_system;
+#[cfg(feature = "reqwest")]
+mod without_key;
+#[cfg(feature = "reqwest")]
+mod without_rate_limiting;
 
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "reqwest")]
 use crate::request_rate::RequestRate;
+#[cfg(feature = "reqwest")]
+pub use crate::client::client_builder::ClientBuilder;
+#[cfg(feature = "reqwest")]
+pub use crate::client::content_type::unexpected_content_type;
+#[cfg(all(
+    feature = "reqwest",
+    any(feature = "distance_matrix", feature = "elevation", feature = "roads")
+))]
+pub use crate::client::deadline::remaining_deadline;
+#[cfg(feature = "reqwest")]
+pub use crate::client::deadline::within_deadline;
+#[cfg(all(
+    feature = "reqwest",
+    any(
+        feature = "address_validation",
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "elevation",
+        feature = "geocoding",
+        feature = "places",
+        feature = "roads",
+        feature = "static_map",
+        feature = "time_zone"
+    )
+))]
+pub use crate::client::execute_with_retry::{HttpError, Method, RetrySettings};
+#[cfg(all(
+    feature = "reqwest",
+    any(
+        feature = "address_validation",
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "elevation",
+        feature = "geocoding",
+        feature = "places",
+        feature = "roads",
+        feature = "time_zone"
+    )
+))]
+pub use crate::client::execute_with_retry::JsonError;
+#[cfg(all(
+    feature = "reqwest",
+    any(
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "elevation",
+        feature = "geocoding",
+        feature = "places",
+        feature = "time_zone"
+    )
+))]
+pub use crate::client::execute_with_retry::{status_error, StatusEnvelope};
+#[cfg(feature = "reqwest")]
+pub use crate::client::retry_after::retry_after;
+#[cfg(feature = "reqwest")]
+pub use crate::client::retry_classifier::{RetryClassifier, RetryDecision};
+#[cfg(feature = "reqwest")]
+pub use crate::client::runtime::{retry, timeout};
+#[cfg(feature = "reqwest")]
+pub use crate::client::runtime::sleep;
+#[cfg(all(
+    test,
+    feature = "reqwest",
+    any(
+        feature = "address_validation",
+        feature = "distance_matrix",
+        feature = "places",
+        feature = "time_zone"
+    )
+))]
+pub use crate::client::test_server::TestServer;
+#[cfg(feature = "reqwest")]
+pub use crate::client::with_backoff::no_delay_backoff;
+
+// -----------------------------------------------------------------------------
+
+/// The default maximum number of characters of a response body that will be
+/// included in an error, when the response could not be parsed.
+#[cfg(feature = "reqwest")]
+const DEFAULT_ERROR_BODY_LENGTH: usize = 1_024;
+
+/// The default timeout for connecting to Google, and for each request, when
+/// this crate builds the `reqwest` client.
+#[cfg(feature = "reqwest")]
+const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// The default time that an idle connection is kept open for, so that it may
+/// be reused by a later request. This is the same as `reqwest`'s default.
+#[cfg(feature = "reqwest")]
+const DEFAULT_POOL_IDLE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(90);
+
+/// The maximum length of a request URL accepted by Google's web services, in
+/// characters. Longer `GET` requests are rejected by Google.
+#[cfg(feature = "reqwest")]
+const MAX_URL_LENGTH: usize = 16_384;
 
 // -----------------------------------------------------------------------------
 //
@@ -36,17 +204,95 @@ use crate::request_rate::RequestRate;
 ///     .build();
 /// ```
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct GoogleMapsClient {
     /// Your application's API key. This key identifies your application for
     /// purposes of quota management. Learn how to [get a
     /// key](https://developers.google.com/maps/documentation/geocoding/get-api-key).
     pub key: String,
 
-    /// Rate limits for each of the Google Cloud Maps Platform APIs.
+    /// The default language in which to return results, for requests that do
+    /// not set their own language. See the `with_language()` method for more
+    /// information.
+    #[cfg(any(
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "geocoding",
+        feature = "places",
+        feature = "time_zone"
+    ))]
+    pub language: Option<crate::types::Language>,
+
+    /// The default region to bias results towards, for requests that do not
+    /// set their own region. See the `with_region()` method for more
+    /// information.
+    #[cfg(any(
+        feature = "autocomplete",
+        feature = "directions",
+        feature = "distance_matrix",
+        feature = "geocoding",
+        feature = "places"
+    ))]
+    pub region: Option<crate::types::Region>,
+
+    /// The default unit system to display distances in, for Directions and
+    /// Distance Matrix requests that do not set their own unit system. See
+    /// the `with_unit_system()` method for more information.
+    #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+    pub unit_system: Option<crate::directions::UnitSystem>,
+
+    /// Rate limits for each of the Google Cloud Maps Platform APIs. By
+    /// default, each API is limited to Google's documented quota.
+    /// See the `with_rate()` and `with_rate_per_second()` methods for more
+    /// information.
     #[cfg(feature = "reqwest")]
     pub rate_limit: RequestRate,
 
+    /// The maximum number of characters of a response body that will be
+    /// included in an error, when the response could not be parsed. See the
+    /// `with_error_body_length()` method for more information.
+    #[cfg(feature = "reqwest")]
+    pub error_body_length: usize,
+
+    /// An optional in-memory cache of Geocoding API responses. See the
+    /// `with_geocoding_cache()` method for more information.
+    #[cfg(all(feature = "geocoding", feature = "reqwest"))]
+    pub geocoding_cache: Option<std::sync::Arc<crate::geocoding::cache::Cache>>,
+
+    /// An optional channel that is sent with every request, so that usage can
+    /// be reported per channel. See the `with_channel()` method for more
+    /// information.
+    #[cfg(feature = "reqwest")]
+    pub channel: Option<String>,
+
+    /// An optional URL that replaces the scheme, host, and port of every
+    /// request sent to Google. See the `with_base_url()` method for more
+    /// information.
+    #[cfg(feature = "reqwest")]
+    pub base_url: Option<reqwest::Url>,
+
+    /// An optional Premium Plan client ID and private key, used to sign
+    /// request URLs instead of sending an API key. See the `with_signing()`
+    /// method for more information.
+    #[cfg(feature = "reqwest")]
+    url_signer: Option<crate::client::url_signing::UrlSigner>,
+
+    /// Whether the API key is left out of requests, for a gateway or proxy
+    /// to add. See the `without_key()` method for more information.
+    #[cfg(feature = "reqwest")]
+    pub omit_key: bool,
+
+    /// An optional function that overrides which errors are retried. See the
+    /// `with_retry_classifier()` method for more information.
+    #[cfg(feature = "reqwest")]
+    pub retry_classifier: Option<RetryClassifier>,
+
+    /// The default retry policy, for requests that do not set their own. See
+    /// the `with_backoff()` method for more information.
+    #[cfg(feature = "reqwest")]
+    pub backoff: Option<backoff::ExponentialBackoff>,
+
     /// Allows you to optionally provide your own pre-configured reqwest client
     /// that will be used by the Google Maps client.
     #[cfg(all(feature = "reqwest", feature = "reqwest-middleware"))]
@@ -57,3 +303,49 @@ pub struct GoogleMapsClient {
     #[cfg(all(feature = "reqwest", not(feature = "reqwest-middleware")))]
     pub reqwest_client: reqwest::Client,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for GoogleMapsClient {
+    /// The API key is a secret, so only its first few characters are included
+    /// in the output, unless the `unredacted-logs` feature is enabled.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("GoogleMapsClient");
+        debug.field("key", &crate::redact::redact_key(&self.key));
+        #[cfg(any(
+            feature = "autocomplete",
+            feature = "directions",
+            feature = "distance_matrix",
+            feature = "geocoding",
+            feature = "places",
+            feature = "time_zone"
+        ))]
+        debug.field("language", &self.language);
+        #[cfg(any(
+            feature = "autocomplete",
+            feature = "directions",
+            feature = "distance_matrix",
+            feature = "geocoding",
+            feature = "places"
+        ))]
+        debug.field("region", &self.region);
+        #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+        debug.field("unit_system", &self.unit_system);
+        #[cfg(feature = "reqwest")]
+        debug
+            .field("rate_limit", &self.rate_limit)
+            .field("error_body_length", &self.error_body_length);
+        #[cfg(all(feature = "geocoding", feature = "reqwest"))]
+        debug.field("geocoding_cache", &self.geocoding_cache);
+        #[cfg(feature = "reqwest")]
+        debug
+            .field("channel", &self.channel)
+            .field("base_url", &self.base_url)
+            .field("url_signer", &self.url_signer)
+            .field("omit_key", &self.omit_key)
+            .field("retry_classifier", &self.retry_classifier.as_ref().map(|_| ".."))
+            .field("backoff", &self.backoff)
+            .field("reqwest_client", &self.reqwest_client);
+        debug.finish()
+    } // fn
+} // impl
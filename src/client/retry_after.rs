@@ -0,0 +1,96 @@
+//! Parses the `Retry-After` HTTP header. Google may include this header in
+//! `429 Too Many Requests` and `503 Service Unavailable` responses to indicate
+//! how long the client should wait before making another request.
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the delay requested by the server's `Retry-After` header, if the
+/// header is present and valid.
+///
+/// Both forms of the header are supported:
+///
+/// * `delta-seconds`, for example `Retry-After: 120`
+///
+/// * `HTTP-date` in the preferred IMF-fixdate format, for example
+///   `Retry-After: Wed, 21 Oct 2015 07:28:00 GMT`. A date in the past results
+///   in a zero delay. The obsolete RFC 850 and asctime date formats are not
+///   supported, and will be ignored.
+
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    // The header is either a number of seconds, or a date:
+    value.parse::<u64>().map_or_else(
+        |_error| {
+            let date = parse_http_date(value)?;
+            Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+        },
+        |seconds| Some(Duration::from_secs(seconds)),
+    ) // map_or_else
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Parses an IMF-fixdate, for example `Sun, 06 Nov 1994 08:49:37 GMT`, into a
+/// `SystemTime`.
+
+fn parse_http_date(value: &str) -> Option<SystemTime> {
+    let (_day_name, date) = value.split_once(", ")?;
+    let mut parts = date.split(' ');
+
+    let day: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = match parts.next()? {
+        "Jan" => 1,
+        "Feb" => 2,
+        "Mar" => 3,
+        "Apr" => 4,
+        "May" => 5,
+        "Jun" => 6,
+        "Jul" => 7,
+        "Aug" => 8,
+        "Sep" => 9,
+        "Oct" => 10,
+        "Nov" => 11,
+        "Dec" => 12,
+        _ => return None,
+    }; // match
+    let year: i64 = parts.next()?.parse().ok()?;
+
+    let mut time = parts.next()?.split(':');
+    let hour: i64 = time.next()?.parse().ok()?;
+    let minute: i64 = time.next()?.parse().ok()?;
+    let second: i64 = time.next()?.parse().ok()?;
+
+    // The date must end with the `GMT` time zone, and nothing else:
+    if parts.next()? != "GMT" || parts.next().is_some() || time.next().is_some() {
+        return None;
+    } // if
+
+    if !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    } // if
+
+    let seconds = days_from_civil(year, month, day) * 86_400 + hour * 3_600 + minute * 60 + second;
+    let seconds = u64::try_from(seconds).ok()?;
+
+    Some(UNIX_EPOCH + Duration::from_secs(seconds))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the number of days between the Unix epoch (1970-01-01) and the
+/// given date in the proleptic Gregorian calendar. This is Howard Hinnant's
+/// `days_from_civil` algorithm.
+
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+} // fn
@@ -0,0 +1,96 @@
+//! Contains the `RetryDecision` enum, which a retry classifier uses to override
+//! whether a failed request is retried. See
+//! `GoogleMapsClient::with_retry_classifier()` for more information.
+
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use backoff::Error::{Permanent, Transient};
+use std::sync::Arc;
+
+// -----------------------------------------------------------------------------
+//
+/// A function that decides whether a failed request should be retried.
+
+pub type RetryClassifier = Arc<dyn Fn(&GoogleMapsError) -> RetryDecision + Send + Sync>;
+
+// -----------------------------------------------------------------------------
+//
+/// Whether a failed request should be retried, as decided by a retry
+/// classifier.
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum RetryDecision {
+    /// Use this crate's built-in logic: connection errors, timeouts, HTTP
+    /// `5xx` responses, Google's `UNKNOWN_ERROR` status, and `Quota` errors
+    /// for a rate limit are retried, and every other error, including `Quota`
+    /// errors for a daily quota, is returned immediately.
+    #[default]
+    Default,
+    /// Retry the request, subject to the request's retry policy and maximum
+    /// number of retries.
+    Transient,
+    /// Do not retry the request. The error is returned to the caller
+    /// immediately.
+    Permanent,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Applies the client's retry classifier, if any, to the outcome of a
+    /// request attempt. The error is converted into a `GoogleMapsError`, so
+    /// that one classifier can be used for every API, and an exceeded quota is
+    /// reported as a `Quota` error, whichever way the API reported it.
+
+    pub(crate) fn classify_retry<T, E>(
+        &self,
+        result: Result<T, backoff::Error<E>>
+    ) -> Result<T, backoff::Error<GoogleMapsError>>
+    where
+        E: Into<GoogleMapsError>,
+    {
+        // Unless the classifier decides otherwise, a rate limit is retried,
+        // and a daily quota is not, since it will not be reset soon:
+        let classify = |error: &GoogleMapsError| {
+            let decision = self
+                .retry_classifier
+                .as_ref()
+                .map_or(RetryDecision::Default, |retry_classifier| retry_classifier(error));
+            match (decision, error.quota_kind()) {
+                (RetryDecision::Default, Some(kind)) if kind.is_retryable() => RetryDecision::Transient,
+                (RetryDecision::Default, Some(_kind)) => RetryDecision::Permanent,
+                (decision, _) => decision,
+            } // match
+        }; // classify
+
+        match result {
+            Ok(value) => Ok(value),
+
+            Err(Transient { err, retry_after }) => {
+                let err = err.into().into_quota(retry_after);
+                if classify(&err) == RetryDecision::Permanent {
+                    tracing::warn!("the error was made permanent: {err}");
+                    Err(Permanent(err))
+                } else {
+                    Err(Transient { err, retry_after })
+                } // if
+            } // Transient
+
+            Err(Permanent(err)) => {
+                let err = err.into().into_quota(None);
+                if classify(&err) == RetryDecision::Transient {
+                    tracing::warn!("the error was made transient: {err}");
+                    let retry_after = match &err {
+                        GoogleMapsError::Quota { retry_after, .. } => *retry_after,
+                        _ => None,
+                    }; // match
+                    Err(Transient { err, retry_after })
+                } else {
+                    Err(Permanent(err))
+                } // if
+            } // Permanent
+        } // match
+    } // fn
+} // impl
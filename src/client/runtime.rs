@@ -0,0 +1,331 @@
+//! The timer used for retry delays, request deadlines, rate limits, and the
+//! delay before a next page of Places results may be requested.
+//!
+//! By default, Tokio's timer is used. With the `runtime-agnostic` feature, a
+//! timer that does not depend on any async runtime is used instead, so that
+//! these delays also work on other runtimes, such as `async-std` or `smol`.
+//! Every delay is then waited out by a single timer thread, which is started
+//! on first use and wakes each waiting task once its delay has elapsed.
+
+use backoff::{backoff::Backoff, future::Retry, Notify};
+use std::future::Future;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// A future that completes once a duration has elapsed. Returned by `sleep()`.
+
+#[cfg(not(feature = "runtime-agnostic"))]
+pub type Sleep = tokio::time::Sleep;
+
+/// A future that completes once a duration has elapsed. Returned by `sleep()`.
+
+#[cfg(feature = "runtime-agnostic")]
+pub struct Sleep(std::sync::Arc<std::sync::Mutex<SleepState>>);
+
+/// Whether a `Sleep` has completed, and the waker of the task that is waiting
+/// for it.
+
+#[cfg(feature = "runtime-agnostic")]
+#[derive(Default)]
+pub struct SleepState {
+    elapsed: bool,
+    waker: Option<std::task::Waker>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The timer thread's queue of pending delays, and the condition variable that
+/// wakes the thread when a delay is added.
+
+#[cfg(feature = "runtime-agnostic")]
+#[derive(Default)]
+struct Timer {
+    queue: std::sync::Mutex<std::collections::BinaryHeap<TimerEntry>>,
+    added: std::sync::Condvar,
+} // struct
+
+/// A pending delay. The entries are ordered so that the `BinaryHeap` pops the
+/// earliest deadline first. A `Sleep` that is dropped before its deadline,
+/// such as the losing side of `timeout()`, is skipped when its entry is popped.
+
+#[cfg(feature = "runtime-agnostic")]
+struct TimerEntry {
+    deadline: std::time::Instant,
+    state: std::sync::Weak<std::sync::Mutex<SleepState>>,
+} // struct
+
+#[cfg(feature = "runtime-agnostic")]
+impl PartialEq for TimerEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.deadline == other.deadline
+    } // fn
+} // impl
+
+#[cfg(feature = "runtime-agnostic")]
+impl Eq for TimerEntry {}
+
+#[cfg(feature = "runtime-agnostic")]
+impl PartialOrd for TimerEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    } // fn
+} // impl
+
+#[cfg(feature = "runtime-agnostic")]
+impl Ord for TimerEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Reversed, so that the earliest deadline is the greatest:
+        other.deadline.cmp(&self.deadline)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "runtime-agnostic")]
+impl Timer {
+    /// Returns the shared timer, starting its thread on first use. Returns
+    /// `None` if the thread could not be started.
+    fn get() -> Option<&'static Self> {
+        static TIMER: std::sync::OnceLock<Timer> = std::sync::OnceLock::new();
+        static STARTED: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+
+        let timer = TIMER.get_or_init(Self::default);
+        let started = STARTED.get_or_init(|| {
+            std::thread::Builder::new()
+                .name("google_maps-timer".to_string())
+                .spawn(|| timer.run())
+                .map_err(|error| tracing::warn!("could not start the timer thread: {error}"))
+                .is_ok()
+        }); // get_or_init
+
+        started.then_some(timer)
+    } // fn
+
+    /// Waits for the earliest deadline, wakes the tasks whose delays have
+    /// elapsed, and repeats.
+    fn run(&self) {
+        let mut queue = self.queue.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        loop {
+            let now = std::time::Instant::now();
+            let mut wakers = Vec::new();
+            while queue.peek().is_some_and(|entry| entry.deadline <= now) {
+                let Some(state) = queue.pop().and_then(|entry| entry.state.upgrade()) else {
+                    continue;
+                }; // let
+                let mut state = state.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+                state.elapsed = true;
+                wakers.extend(state.waker.take());
+            } // while
+
+            // The tasks are woken without holding the queue's lock, in case a
+            // woken task immediately starts another delay:
+            if !wakers.is_empty() {
+                drop(queue);
+                wakers.into_iter().for_each(std::task::Waker::wake);
+                queue = self.queue.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+                continue;
+            } // if
+
+            queue = match queue.peek() {
+                Some(entry) => {
+                    let wait = entry.deadline.saturating_duration_since(now);
+                    self.added
+                        .wait_timeout(queue, wait)
+                        .unwrap_or_else(std::sync::PoisonError::into_inner)
+                        .0
+                } // Some
+                None => self
+                    .added
+                    .wait(queue)
+                    .unwrap_or_else(std::sync::PoisonError::into_inner),
+            }; // match
+        } // loop
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Waits until the duration has elapsed, using Tokio's timer.
+
+#[cfg(not(feature = "runtime-agnostic"))]
+pub fn sleep(duration: Duration) -> Sleep {
+    tokio::time::sleep(duration)
+} // fn
+
+/// Waits until the duration has elapsed. The delay is added to the shared
+/// timer thread's queue, and the thread wakes the waiting task, so that no
+/// async runtime is needed.
+
+#[cfg(feature = "runtime-agnostic")]
+pub fn sleep(duration: Duration) -> Sleep {
+    let state = std::sync::Arc::new(std::sync::Mutex::new(SleepState::default()));
+
+    // A zero delay, such as with `no_delay_backoff()`, needs no timer. If the
+    // timer thread could not be started, the delay is skipped rather than
+    // leaving the request waiting forever:
+    let timer = if duration.is_zero() { None } else { Timer::get() };
+    let Some(timer) = timer else {
+        state.lock().unwrap_or_else(std::sync::PoisonError::into_inner).elapsed = true;
+        return Sleep(state);
+    }; // let
+
+    timer
+        .queue
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .push(TimerEntry {
+            deadline: std::time::Instant::now() + duration,
+            state: std::sync::Arc::downgrade(&state),
+        }); // push
+    timer.added.notify_one();
+
+    Sleep(state)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "runtime-agnostic")]
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>
+    ) -> std::task::Poll<()> {
+        let mut state = self.0.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if state.elapsed {
+            std::task::Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            std::task::Poll::Pending
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Awaits a future, unless the duration elapses first.
+///
+/// # Errors
+///
+/// * Returns an error if the duration elapsed before the future completed.
+///   The future is dropped, which cancels it.
+
+#[cfg(not(feature = "runtime-agnostic"))]
+pub async fn timeout<T>(duration: Duration, future: impl Future<Output = T>) -> Result<T, ()> {
+    tokio::time::timeout(duration, future).await.map_err(|_elapsed| ())
+} // fn
+
+/// Awaits a future, unless the duration elapses first.
+///
+/// # Errors
+///
+/// * Returns an error if the duration elapsed before the future completed.
+///   The future is dropped, which cancels it.
+
+#[cfg(feature = "runtime-agnostic")]
+pub async fn timeout<T>(duration: Duration, future: impl Future<Output = T>) -> Result<T, ()> {
+    let future = std::pin::pin!(future);
+    match futures::future::select(future, sleep(duration)).await {
+        futures::future::Either::Left((output, _sleep)) => Ok(output),
+        futures::future::Either::Right(((), _future)) => Err(()),
+    } // match
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Provides the `backoff` crate with this crate's timer, for the delays
+/// between retries.
+
+pub struct Sleeper;
+
+impl backoff::future::Sleeper for Sleeper {
+    type Sleep = Sleep;
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        sleep(duration)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Ignores failed attempts. Retries are logged by the request's retry loop
+/// instead.
+
+pub struct NoNotify;
+
+impl<E> Notify<E> for NoNotify {
+    fn notify(&mut self, _error: E, _duration: Duration) {}
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Retries an operation according to a retry policy, like
+/// `backoff::future::retry()`, but with this crate's timer for the delays
+/// between retries.
+///
+/// ## Arguments
+///
+/// * `backoff` ‧ The retry policy. It is reset before it is used.
+///
+/// * `operation` ‧ Returns a future for each attempt. Errors wrapped in
+///   `backoff::Error::Transient` are retried, and errors wrapped in
+///   `backoff::Error::Permanent` are returned.
+
+pub fn retry<I, E, B, F, Fut>(
+    mut backoff: B,
+    operation: F
+) -> Retry<Sleeper, B, NoNotify, F, Fut>
+where
+    B: Backoff,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<I, backoff::Error<E>>>,
+{
+    backoff.reset();
+    Retry::new(Sleeper, backoff, NoNotify, operation)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "runtime-agnostic"))]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::time::Instant;
+
+    #[test]
+    fn sleep_waits_for_the_duration() {
+        let started = Instant::now();
+        block_on(sleep(Duration::from_millis(50)));
+        assert!(started.elapsed() >= Duration::from_millis(50));
+    } // fn
+
+    #[test]
+    fn concurrent_sleeps_share_the_timer() {
+        // The delays are waited out together, in deadline order, and not one
+        // after another:
+        let started = Instant::now();
+        block_on(futures::future::join_all(
+            (1..=100).rev().map(|millis| sleep(Duration::from_millis(millis))),
+        )); // block_on
+        assert!(started.elapsed() >= Duration::from_millis(100));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    } // fn
+
+    #[test]
+    fn timeout_returns_the_output() {
+        assert_eq!(block_on(timeout(Duration::from_secs(10), async { 5 })), Ok(5));
+    } // fn
+
+    #[test]
+    fn timeout_expires() {
+        let started = Instant::now();
+        let result = block_on(timeout(
+            Duration::from_millis(10),
+            sleep(Duration::from_secs(10)),
+        )); // block_on
+        assert_eq!(result, Err(()));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    } // fn
+} // mod
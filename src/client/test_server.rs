@@ -0,0 +1,97 @@
+//! A minimal HTTP server for unit tests. It answers each connection with the
+//! next of a list of canned responses, and counts the requests it receives.
+//! Responses are compressed with gzip when the request accepts it.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+// -----------------------------------------------------------------------------
+
+/// A running test server. Point a client at it with `with_base_url()`.
+pub struct TestServer {
+    /// The base URL of the server, for example `http://127.0.0.1:50123`.
+    pub base_url: reqwest::Url,
+    /// The number of requests the server has received.
+    requests: Arc<AtomicUsize>,
+    /// The number of response body bytes the server has sent, after any
+    /// compression.
+    body_bytes: Arc<AtomicUsize>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl TestServer {
+    /// Starts a server that answers the first request with the first response,
+    /// the second request with the second, and so on. Requests received after
+    /// the responses run out get a `500 Internal Server Error`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `responses` ‧ The HTTP status code and JSON body of each response.
+    pub fn start(responses: Vec<(u16, &'static str)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap())
+            .parse()
+            .unwrap();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        let body_bytes = Arc::new(AtomicUsize::new(0));
+        let body_counter = Arc::clone(&body_bytes);
+
+        std::thread::spawn(move || {
+            let mut responses = responses.into_iter();
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+
+                // Read the request line and headers. Test requests are `GET`s,
+                // or small `POST`s whose body is not inspected:
+                let mut reader = BufReader::new(&stream);
+                let mut line = String::new();
+                let mut gzip = false;
+                while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+                    let header = line.to_ascii_lowercase();
+                    gzip |= header.starts_with("accept-encoding:") && header.contains("gzip");
+                    line.clear();
+                } // while
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let (status, body) = responses.next().unwrap_or((500, "{}"));
+                let (content_encoding, body) = if gzip {
+                    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                    encoder.write_all(body.as_bytes()).unwrap();
+                    ("Content-Encoding: gzip\r\n", encoder.finish().unwrap())
+                } else {
+                    ("", body.as_bytes().to_vec())
+                }; // if
+                body_counter.fetch_add(body.len(), Ordering::SeqCst);
+
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status} Test\r\n\
+                    Content-Type: application/json\r\n\
+                    {content_encoding}\
+                    Content-Length: {}\r\n\
+                    Connection: close\r\n\r\n",
+                    body.len()
+                ); // write!
+                let _ = stream.write_all(&body);
+            } // for
+        }); // spawn
+
+        Self { base_url, requests, body_bytes }
+    } // fn
+
+    /// Returns the number of requests the server has received so far.
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    } // fn
+
+    /// Returns the number of response body bytes the server has sent so far,
+    /// after any compression.
+    #[allow(dead_code)] // only used by the compression test
+    pub fn body_bytes(&self) -> usize {
+        self.body_bytes.load(Ordering::SeqCst)
+    } // fn
+} // impl
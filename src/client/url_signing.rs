@@ -0,0 +1,395 @@
+//! Signs request URLs for Google Maps Platform Premium Plan customers, who
+//! authenticate using a client ID and a cryptographic signature instead of an
+//! API key. See [Google's
+//! documentation](https://developers.google.com/maps/documentation/maps-static/digital-signature)
+//! for a description of the algorithm.
+//!
+//! The signature is an HMAC-SHA1 of the URL's path and query string, keyed by
+//! the client's private key, and encoded as URL-safe base64. The algorithms are
+//! implemented here so that signing does not need a cryptography dependency.
+
+use crate::error::Error as GoogleMapsError;
+use crate::types::Error as TypeError;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// -----------------------------------------------------------------------------
+//
+/// A Premium Plan client ID, and the private key that is used to sign URLs for
+/// it.
+
+#[derive(Clone)]
+pub struct UrlSigner {
+    /// The client ID, for example `gme-yourcompany`. It is sent in the
+    /// `client` parameter of every request.
+    client_id: String,
+
+    /// The decoded private key.
+    private_key: Vec<u8>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for UrlSigner {
+    /// The private key is a secret, so it is not included in the output.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("UrlSigner")
+            .field("client_id", &self.client_id)
+            .finish_non_exhaustive()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl UrlSigner {
+    // -------------------------------------------------------------------------
+    //
+    /// Creates a URL signer from a client ID and the URL-safe base64 private
+    /// key that Google provides in the Cloud Console.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the private key is not valid base64.
+
+    pub fn try_new(
+        client_id: impl Into<String>,
+        private_key: &str
+    ) -> Result<Self, GoogleMapsError> {
+        let private_key = base64_decode(private_key)
+            .ok_or_else(|| TypeError::InvalidSigningKey(private_key.to_string()))?;
+
+        Ok(Self {
+            client_id: client_id.into(),
+            private_key,
+        })
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Signs a request URL. The `key` parameter is removed, the `client`
+    /// parameter is added, and the `signature` parameter is appended last, as
+    /// Google requires.
+
+    pub fn sign(&self, url: &str) -> String {
+        let (stem, query) = url.split_once('?').unwrap_or((url, ""));
+
+        // Premium Plan requests are authenticated by the client ID, not an API
+        // key:
+        let mut query: Vec<&str> = query
+            .split('&')
+            .filter(|parameter| !parameter.is_empty() && !parameter.starts_with("key="))
+            .collect();
+        let client = format!("client={}", utf8_percent_encode(&self.client_id, NON_ALPHANUMERIC));
+        query.push(&client);
+        let url = format!("{stem}?{query}", query = query.join("&"));
+
+        // Only the path and query string are signed. The scheme and host are
+        // not:
+        let path_start = url
+            .find("://")
+            .and_then(|scheme_end| url[scheme_end + 3..].find('/').map(|index| index + scheme_end + 3))
+            .unwrap_or(0);
+        let signature = hmac_sha1(&self.private_key, &url.as_bytes()[path_start..]);
+
+        format!("{url}&signature={signature}", signature = base64_encode(&signature))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The URL-safe base64 alphabet. `-` and `_` take the places of `+` and `/`.
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// -----------------------------------------------------------------------------
+//
+/// Encodes bytes as URL-safe base64, with padding.
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+    for chunk in bytes.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0_u32, |group, (index, byte)| group | u32::from(*byte) << (16 - index * 8));
+
+        // Every 3 bytes become 4 characters. A partial chunk is padded with `=`:
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (group >> (18 - index * 6)) & 0x3f;
+                encoded.push(char::from(BASE64_ALPHABET[sextet as usize]));
+            } else {
+                encoded.push('=');
+            } // if
+        } // for
+    } // for
+
+    encoded
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes URL-safe base64, with or without padding. The standard alphabet's
+/// `+` and `/` are also accepted. Returns `None` if the input is not valid.
+
+fn base64_decode(encoded: &str) -> Option<Vec<u8>> {
+    let encoded = encoded.trim().trim_end_matches('=');
+    let mut decoded = Vec::with_capacity(encoded.len() * 3 / 4);
+    let mut group: u32 = 0;
+    let mut bits = 0;
+
+    for byte in encoded.bytes() {
+        let sextet = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'-' | b'+' => 62,
+            b'_' | b'/' => 63,
+            _ => return None,
+        }; // match
+
+        group = (group << 6) | u32::from(sextet);
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            decoded.push(u8::try_from((group >> bits) & 0xff).ok()?);
+        } // if
+    } // for
+
+    // A single character left over can not hold a whole byte:
+    if bits >= 6 {
+        return None;
+    } // if
+
+    Some(decoded)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Computes the HMAC-SHA1 of a message, as described in
+/// [RFC 2104](https://www.rfc-editor.org/rfc/rfc2104).
+
+fn hmac_sha1(key: &[u8], message: &[u8]) -> [u8; 20] {
+    const BLOCK_SIZE: usize = 64;
+
+    // Keys that are longer than a block are hashed first, and all keys are
+    // padded with zeros to the block size:
+    let mut block = [0_u8; BLOCK_SIZE];
+    if key.len() > BLOCK_SIZE {
+        block[..20].copy_from_slice(&sha1(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    } // if
+
+    let mut inner = block.map(|byte| byte ^ 0x36).to_vec();
+    inner.extend_from_slice(message);
+
+    let mut outer = block.map(|byte| byte ^ 0x5c).to_vec();
+    outer.extend_from_slice(&sha1(&inner));
+
+    sha1(&outer)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Computes the SHA-1 digest of a message, as described in
+/// [RFC 3174](https://www.rfc-editor.org/rfc/rfc3174). The working variables
+/// are named `a` through `e`, as they are in the RFC.
+
+#[allow(clippy::many_single_char_names)]
+fn sha1(message: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476, 0xc3d2_e1f0];
+
+    // The message is padded with a `1` bit, then zeros, then its length in
+    // bits, so that it is a multiple of 64 bytes long:
+    let bit_length = (message.len() as u64).wrapping_mul(8);
+    let mut padded = message.to_vec();
+    padded.push(0x80);
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    } // while
+    padded.extend_from_slice(&bit_length.to_be_bytes());
+
+    for block in padded.chunks_exact(64) {
+        let mut words = [0_u32; 80];
+        for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+            *word = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        } // for
+        for index in 16..80 {
+            words[index] = (words[index - 3] ^ words[index - 8] ^ words[index - 14] ^ words[index - 16])
+                .rotate_left(1);
+        } // for
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+
+        for (index, word) in words.iter().enumerate() {
+            let (f, k) = match index {
+                0..=19 => ((b & c) | (!b & d), 0x5a82_7999),
+                20..=39 => (b ^ c ^ d, 0x6ed9_eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1b_bcdc),
+                _ => (b ^ c ^ d, 0xca62_c1d6),
+            }; // match
+
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        } // for
+
+        for (value, working) in state.iter_mut().zip([a, b, c, d, e]) {
+            *value = value.wrapping_add(working);
+        } // for
+    } // for
+
+    let mut digest = [0_u8; 20];
+    for (bytes, value) in digest.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&value.to_be_bytes());
+    } // for
+
+    digest
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        use std::fmt::Write;
+        bytes.iter().fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        }) // fold
+    } // fn
+
+    /// The example from Google's documentation of URL signing.
+    #[test]
+    fn signs_google_example() {
+        let signer = UrlSigner::try_new("clientID", "vNIXE0xscrmjlyV-12Nj_BvUPaw=").unwrap();
+        assert_eq!(
+            signer.sign("https://maps.googleapis.com/maps/api/geocode/json?address=New+York"),
+            "https://maps.googleapis.com/maps/api/geocode/json?address=New+York&client=clientID\
+                &signature=chaRF2hTJKOScPr-RQCEhZbSzIE="
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn removes_key_before_signing() {
+        let signer = UrlSigner::try_new("clientID", "vNIXE0xscrmjlyV-12Nj_BvUPaw=").unwrap();
+        assert_eq!(
+            signer.sign("https://maps.googleapis.com/maps/api/geocode/json?key=AIzaSecret&address=New+York"),
+            signer.sign("https://maps.googleapis.com/maps/api/geocode/json?address=New+York")
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn rejects_invalid_private_key() {
+        assert!(UrlSigner::try_new("clientID", "not base64!").is_err());
+    } // fn
+
+    #[test]
+    fn debug_omits_private_key() {
+        let signer = UrlSigner::try_new("clientID", "vNIXE0xscrmjlyV-12Nj_BvUPaw=").unwrap();
+        let debug = format!("{signer:?}");
+        assert!(debug.contains("clientID"));
+        assert!(!debug.contains("private_key"));
+    } // fn
+
+    /// The test vectors from FIPS 180-2, appendix A.
+    #[test]
+    fn sha1_matches_fips_180_vectors() {
+        assert_eq!(hex(&sha1(b"abc")), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(hex(&sha1(b"")), "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(
+            hex(&sha1(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")),
+            "84983e441c3bd26ebaae4aa1f95129e5e54670f1"
+        ); // assert_eq!
+        assert_eq!(
+            hex(&sha1(&vec![b'a'; 1_000_000])),
+            "34aa973cd4c4daa4f61eeb2bdbad27316534016f"
+        ); // assert_eq!
+    } // fn
+
+    /// The HMAC-SHA1 test cases from RFC 2202, section 3.
+    #[test]
+    fn hmac_sha1_matches_rfc_2202_vectors() {
+        let cases: [(Vec<u8>, Vec<u8>, &str); 7] = [
+            (vec![0x0b; 20], b"Hi There".to_vec(), "b617318655057264e28bc0b6fb378c8ef146be00"),
+            (
+                b"Jefe".to_vec(),
+                b"what do ya want for nothing?".to_vec(),
+                "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79",
+            ),
+            (vec![0xaa; 20], vec![0xdd; 50], "125d7342b9ac11cd91a39af48aa17b4f63f175d3"),
+            (
+                (0x01..=0x19).collect(),
+                vec![0xcd; 50],
+                "4c9007f4026250c6bc8414f9bf50c86c2d7235da",
+            ),
+            (
+                vec![0x0c; 20],
+                b"Test With Truncation".to_vec(),
+                "4c1a03424b55e07fe7f27be1d58bb9324a9a5a04",
+            ),
+            (
+                vec![0xaa; 80],
+                b"Test Using Larger Than Block-Size Key - Hash Key First".to_vec(),
+                "aa4ae5e15272d00e95705637ce8a3b55ed402112",
+            ),
+            (
+                vec![0xaa; 80],
+                b"Test Using Larger Than Block-Size Key and Larger Than One Block-Size Data".to_vec(),
+                "e8e99d0f45237d786d6bbaa7965c7808bbff1a91",
+            ),
+        ]; // cases
+
+        for (key, data, digest) in cases {
+            assert_eq!(hex(&hmac_sha1(&key, &data)), digest);
+        } // for
+    } // fn
+
+    /// The test vectors from RFC 4648, section 10.
+    #[test]
+    fn base64_matches_rfc_4648_vectors() {
+        let cases = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ]; // cases
+
+        for (decoded, encoded) in cases {
+            assert_eq!(base64_encode(decoded.as_bytes()), encoded);
+            assert_eq!(base64_decode(encoded).unwrap(), decoded.as_bytes());
+            assert_eq!(base64_decode(encoded.trim_end_matches('=')).unwrap(), decoded.as_bytes());
+        } // for
+    } // fn
+
+    #[test]
+    fn base64_uses_url_safe_alphabet() {
+        assert_eq!(base64_encode(&[0xfb, 0xff]), "-_8=");
+        assert_eq!(base64_decode("-_8=").unwrap(), [0xfb, 0xff]);
+        // The standard alphabet is also accepted when decoding:
+        assert_eq!(base64_decode("+/8=").unwrap(), [0xfb, 0xff]);
+    } // fn
+
+    #[test]
+    fn base64_rejects_invalid_input() {
+        assert!(base64_decode("Zm9v!").is_none());
+        // A single character left over can not hold a whole byte:
+        assert!(base64_decode("Zm9vY").is_none());
+    } // fn
+} // mod
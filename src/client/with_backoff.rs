@@ -0,0 +1,172 @@
+use crate::client::GoogleMapsClient;
+use backoff::ExponentialBackoff;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the default retry policy, for requests that do not set their own
+    /// with the request's `with_backoff()` method.
+    ///
+    /// ## Arguments
+    ///
+    /// * `backoff` ‧ The [ExponentialBackoff](https://docs.rs/backoff/latest/backoff/exponential/struct.ExponentialBackoff.html)
+    ///   policy used when an HTTP request fails with an error that is eligible
+    ///   for retries. If this method is not called,
+    ///   `ExponentialBackoff::default()` is used. Use `no_delay_backoff()` to
+    ///   retry immediately in tests.
+    ///
+    /// ## Examples:
+    ///
+    /// * Retry quickly, and give up after 5 seconds:
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_API_KEY_HERE")?
+    ///     .with_backoff(ExponentialBackoff {
+    ///         initial_interval: std::time::Duration::from_millis(100),
+    ///         max_elapsed_time: Some(std::time::Duration::from_secs(5)),
+    ///         ..ExponentialBackoff::default()
+    ///     })
+    ///     .build();
+    /// ```
+
+    pub fn with_backoff(&mut self, backoff: ExponentialBackoff) -> &mut Self {
+        self.backoff = Some(backoff);
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns a retry policy that retries immediately, without any delay between
+/// attempts, and without randomization, so that the retry logic may be tested
+/// against a mock server without waiting.
+///
+/// The policy still gives up after `ExponentialBackoff::default()`'s maximum
+/// elapsed time, which would be a great many attempts against a server that
+/// always fails, so combine it with a request's `with_max_retries()` method.
+///
+/// **This is intended for tests. Retrying without a delay against Google may
+/// exceed your quota, and may be throttled.**
+///
+/// ## Examples:
+///
+/// * A test that answers `500 Internal Server Error` twice and then `200 OK`,
+///   and asserts that the request succeeds on the third attempt without a
+///   wall-clock delay:
+/// ```rust
+/// use google_maps::{no_delay_backoff, time_zone::TimeZoneStatus, GoogleMapsClient, LatLng};
+/// use std::sync::{atomic::{AtomicU32, Ordering}, Arc};
+/// use tokio::{io::{AsyncReadExt, AsyncWriteExt}, net::TcpListener};
+///
+/// #[tokio::test]
+/// async fn retries_server_errors() -> Result<(), Box<dyn std::error::Error>> {
+///     let listener = TcpListener::bind("127.0.0.1:0").await?;
+///     let base_url = format!("http://{}", listener.local_addr()?).parse()?;
+///     let attempts = Arc::new(AtomicU32::new(0));
+///
+///     let server_attempts = attempts.clone();
+///     tokio::spawn(async move {
+///         while let Ok((mut stream, _)) = listener.accept().await {
+///             let mut request = [0; 4_096];
+///             let _ = stream.read(&mut request).await;
+///             let response = if server_attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+///                 "HTTP/1.1 500 Internal Server Error\r\ncontent-length: 0\r\n\r\n".to_string()
+///             } else {
+///                 let body = r#"{"status":"OK","timeZoneId":"UTC","timeZoneName":"UTC","dstOffset":0,"rawOffset":0}"#;
+///                 format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n{body}", body.len())
+///             };
+///             let _ = stream.write_all(response.as_bytes()).await;
+///         }
+///     });
+///
+///     let google_maps_client = GoogleMapsClient::builder("AIzaTestKey")
+///         .with_base_url(base_url)
+///         .with_backoff(no_delay_backoff())
+///         .build()?;
+///
+///     let started = std::time::Instant::now();
+///     let time_zone = google_maps_client
+///         .time_zone(LatLng::try_from_f64(0.0, 0.0)?, chrono::Utc::now())
+///         .with_max_retries(5)
+///         .execute()
+///         .await?;
+///
+///     assert_eq!(time_zone.status, TimeZoneStatus::Ok);
+///     assert_eq!(attempts.load(Ordering::SeqCst), 3);
+///     assert!(started.elapsed() < std::time::Duration::from_secs(1));
+///     Ok(())
+/// }
+/// ```
+
+#[must_use]
+pub fn no_delay_backoff() -> ExponentialBackoff {
+    ExponentialBackoff {
+        current_interval: std::time::Duration::ZERO,
+        initial_interval: std::time::Duration::ZERO,
+        randomization_factor: 0.0,
+        multiplier: 1.0,
+        max_interval: std::time::Duration::ZERO,
+        ..ExponentialBackoff::default()
+    } // ExponentialBackoff
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "time_zone"))]
+mod tests {
+    use crate::client::{no_delay_backoff, TestServer};
+    use crate::error::Error as GoogleMapsError;
+    use crate::time_zone::response::status::Status;
+    use crate::{GoogleMapsClient, LatLng};
+    use chrono::{DateTime, Utc};
+    use std::time::{Duration, Instant};
+
+    const OK: &str = r#"{
+        "dstOffset": 0,
+        "rawOffset": 0,
+        "status": "OK",
+        "timeZoneId": "UTC",
+        "timeZoneName": "Coordinated Universal Time"
+    }"#;
+
+    fn client(server: &TestServer) -> GoogleMapsClient {
+        GoogleMapsClient::builder("AIzaTest")
+            .with_base_url(server.base_url.clone())
+            .with_backoff(no_delay_backoff())
+            .build()
+            .unwrap()
+    } // fn
+
+    #[tokio::test]
+    async fn retries_server_errors_without_delay() -> Result<(), GoogleMapsError> {
+        let server = TestServer::start(vec![(500, "{}"), (500, "{}"), (200, OK)]);
+
+        let started = Instant::now();
+        let time_zone = client(&server)
+            .time_zone(LatLng::try_from_f64(0.0, 0.0)?, DateTime::<Utc>::UNIX_EPOCH)
+            .with_max_retries(5)
+            .execute()
+            .await?;
+
+        assert_eq!(time_zone.status, Status::Ok);
+        assert_eq!(server.requests(), 3);
+        assert!(started.elapsed() < Duration::from_secs(1));
+        Ok(())
+    } // fn
+
+    #[tokio::test]
+    async fn gives_up_after_max_retries() -> Result<(), GoogleMapsError> {
+        let server = TestServer::start(vec![(500, "{}"); 5]);
+
+        let result = client(&server)
+            .time_zone(LatLng::try_from_f64(0.0, 0.0)?, DateTime::<Utc>::UNIX_EPOCH)
+            .with_max_retries(2)
+            .execute()
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(server.requests(), 3);
+        Ok(())
+    } // fn
+} // mod
@@ -0,0 +1,63 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sends every request to the specified base URL, instead of to Google.
+    ///
+    /// The base URL replaces the scheme, host, and port of each request's URL.
+    /// If the base URL has a path, it is prepended to Google's path. The rest
+    /// of the request, including the query string, rate limiting, and retry
+    /// handling, is unchanged.
+    ///
+    /// This is intended for testing. Pointed at a mock HTTP server, such as
+    /// [wiremock](https://crates.io/crates/wiremock) or
+    /// [httpmock](https://crates.io/crates/httpmock), the client can be fed
+    /// canned JSON responses so that code that uses this crate can be tested
+    /// without contacting Google. It may also be used to send requests through
+    /// a reverse proxy.
+    ///
+    /// ## Arguments
+    ///
+    /// * `base_url` ‧ The URL to send requests to. For example,
+    ///   `http://127.0.0.1:8080` turns a request for
+    ///   `https://maps.googleapis.com/maps/api/geocode/json?address=...` into
+    ///   `http://127.0.0.1:8080/maps/api/geocode/json?address=...`.
+    ///
+    /// ## Examples:
+    ///
+    /// * Test a geocoding lookup against a `wiremock` server:
+    /// ```rust
+    /// use wiremock::{matchers, Mock, MockServer, ResponseTemplate};
+    ///
+    /// let mock_server = MockServer::start().await;
+    ///
+    /// Mock::given(matchers::method("GET"))
+    ///     .and(matchers::path("/maps/api/geocode/json"))
+    ///     .respond_with(ResponseTemplate::new(200).set_body_string(
+    ///         r#"{ "results": [], "status": "ZERO_RESULTS" }"#
+    ///     ))
+    ///     .mount(&mock_server)
+    ///     .await;
+    ///
+    /// let google_maps_client = GoogleMapsClient::try_new("TEST_KEY")?
+    ///     .with_base_url(mock_server.uri().parse()?)
+    ///     .without_rate_limiting()
+    ///     .build();
+    ///
+    /// let response = google_maps_client
+    ///     .geocoding()
+    ///     .with_address("10 Downing St, London")
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// assert!(response.results.is_empty()); // `ZERO_RESULTS`
+    /// ```
+
+    pub fn with_base_url(&mut self, base_url: reqwest::Url) -> &mut Self {
+        self.base_url = Some(base_url);
+        self
+    } // fn
+} // impl
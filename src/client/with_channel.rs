@@ -0,0 +1,52 @@
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::types::Error as TypeError;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Tags every request with a `channel`, so that usage can be reported per
+    /// channel in the Google Cloud Console. This could be used to attribute
+    /// quota usage to different teams, applications, or customers that share
+    /// an API key or client ID.
+    ///
+    /// ## Arguments
+    ///
+    /// * `channel` ‧ The channel name. It must not be empty, and may only
+    ///   contain ASCII letters, digits, periods (`.`), underscores (`_`), and
+    ///   hyphens (`-`). Google treats channels case-insensitively, so the
+    ///   channel is sent in lowercase.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the channel is empty or contains a character that
+    ///   is not allowed.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_API_KEY_HERE")?
+    ///     .with_channel("checkout-page")?
+    ///     .build();
+    /// ```
+
+    pub fn with_channel(
+        &mut self,
+        channel: impl Into<String>
+    ) -> Result<&mut Self, GoogleMapsError> {
+        let channel = channel.into();
+
+        if channel.is_empty()
+            || !channel
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || matches!(character, '.' | '_' | '-'))
+        {
+            return Err(TypeError::InvalidChannel(channel))?;
+        } // if
+
+        self.channel = Some(channel.to_ascii_lowercase());
+        Ok(self)
+    } // fn
+} // impl
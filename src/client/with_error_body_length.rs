@@ -0,0 +1,47 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the maximum number of characters of a response body that will be
+    /// included in an error, when Google's response could not be parsed.
+    ///
+    /// The response body can be helpful for debugging malformed responses, or
+    /// responses that have been changed by Google. It is truncated to avoid
+    /// flooding your logs. Defaults to 1,024 characters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `length` ‧ The maximum number of characters of the response body to
+    ///   keep. A value of `0` will leave the response body out entirely.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_error_body_length(4_096)
+    ///     .build();
+    /// ```
+
+    pub fn with_error_body_length(&mut self, length: usize) -> &mut Self {
+        self.error_body_length = length;
+        self
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Truncates a response body to the client's `error_body_length`, so that
+    /// it may be included in an error.
+
+    pub(crate) fn truncate_error_body(&self, body: &str) -> String {
+        match body.char_indices().nth(self.error_body_length) {
+            // The body is too long. Truncate it and indicate that it has been
+            // shortened:
+            Some((index, _character)) => format!("{}…", &body[..index]),
+            // The body is short enough to be returned as-is:
+            None => body.to_string(),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,41 @@
+use crate::client::GoogleMapsClient;
+use crate::geocoding::cache::Cache;
+use std::sync::Arc;
+use std::time::Duration;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Enables an in-memory cache of Geocoding API responses.
+    ///
+    /// Forward and reverse geocoding requests are looked up in the cache
+    /// before they are sent to Google, and successful responses are added to
+    /// the cache. Error and `ZERO_RESULTS` responses are never cached. The
+    /// cache is shared by all clones of this client, so identical queries made
+    /// from different async tasks are only paid for once.
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` ‧ The maximum number of responses held by the cache. When
+    ///   the cache is full, the least recently used response is evicted.
+    ///
+    /// * `ttl` ‧ How long a cached response may be used for, before the query
+    ///   is sent to Google again. Please review Google's terms of service for
+    ///   any restrictions on caching content.
+    ///
+    /// ## Examples:
+    ///
+    /// * Cache up to 1,000 responses for a day:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_geocoding_cache(1_000, std::time::Duration::from_secs(86_400))
+    ///     .build();
+    /// ```
+
+    pub fn with_geocoding_cache(&mut self, capacity: usize, ttl: Duration) -> &mut Self {
+        self.geocoding_cache = Some(Arc::new(Cache::new(capacity, ttl)));
+        self
+    } // fn
+} // impl
@@ -0,0 +1,41 @@
+use crate::client::GoogleMapsClient;
+use crate::types::Language;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the default language in which to return results.
+    ///
+    /// The default language is sent with every request that supports the
+    /// `language` parameter, unless that request has set its own language
+    /// using its `with_language()` method. If neither is set, Google attempts
+    /// to use the preferred language as specified in the `Accept-Language`
+    /// header, or the native language of the domain from which the request is
+    /// sent.
+    ///
+    /// The `language` parameter is honoured by the Directions, Distance
+    /// Matrix, Geocoding (forward and reverse), Time Zone, and Places (Text
+    /// Search, Nearby Search, Place Details, Place Autocomplete, and Query
+    /// Autocomplete) APIs. The Elevation and Roads APIs do not return
+    /// localized text and ignore it.
+    ///
+    /// ## Arguments
+    ///
+    /// * `language` ‧ The language in which to return results. See the
+    ///   `Language` enum for the list of languages supported by Google.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_language(Language::French)
+    ///     .build();
+    /// ```
+
+    pub fn with_language(&mut self, language: impl Into<Language>) -> &mut Self {
+        self.language = Some(language.into());
+        self
+    } // fn
+} // impl
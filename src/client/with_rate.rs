@@ -8,6 +8,11 @@ impl GoogleMapsClient {
     //
     /// Sets the rate limit for the specified API.
     ///
+    /// By default, each API is limited to the per-minute quota that Google
+    /// documents for it, spread evenly over the minute. For example, Geocoding
+    /// API requests are limited to 50 requests per second. Setting a rate
+    /// replaces the default for that API.
+    ///
     /// ## Arguments
     ///
     /// * `api` ‧ Which Google Maps API are you setting the rate limit for? For
@@ -0,0 +1,50 @@
+use crate::{client::GoogleMapsClient, request_rate::api::Api};
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the rate limit for the specified API, in requests per second.
+    ///
+    /// By default, each API is limited to the per-minute quota that Google
+    /// documents for it, spread evenly over the minute. Use this method to
+    /// tune the throttling to your project's quota. See `with_rate()` to set a
+    /// rate over any duration.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ Which Google Maps API are you setting the rate limit for? For
+    ///   example, `Api::Directions`, `Api::Geocoding`, `Api::Roads`, and so
+    ///   on. The `Api::All` rate limit is applied to all Google Maps API
+    ///   requests _in addition_ to the per-API rate limits.
+    ///
+    /// * `requests_per_second` ‧ The sustained number of requests per second.
+    ///   A value of `0` removes the rate limit for the API.
+    ///
+    /// * `burst` ‧ The number of requests that may be sent at once, before
+    ///   the client starts spacing requests out to match
+    ///   `requests_per_second`. A value of `0` is treated as
+    ///   `requests_per_second`, which allows one second's worth of requests to
+    ///   be sent at once.
+    ///
+    /// ## Examples:
+    ///
+    /// * Limit Roads API requests to 100 per second, in bursts of up to 10
+    ///   requests:
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_rate_per_second(&Api::Roads, 100, 10)
+    ///     .build();
+    /// ```
+
+    pub fn with_rate_per_second(
+        &mut self,
+        api: &Api,
+        requests_per_second: u16,
+        burst: u16
+    ) -> &mut Self {
+        self.rate_limit.with_rate_per_second(api, requests_per_second, burst);
+        self
+    } // fn
+} // impl
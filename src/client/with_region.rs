@@ -0,0 +1,37 @@
+use crate::client::GoogleMapsClient;
+use crate::types::Region;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the default region to bias results towards.
+    ///
+    /// The default region is sent with every request that supports the
+    /// `region` parameter, unless that request has set its own region using
+    /// its `with_region()` method. If neither is set, Google biases results
+    /// towards the region of the domain from which the request is sent.
+    ///
+    /// The `region` parameter is honoured by the Directions, Distance Matrix,
+    /// Geocoding (forward), and Places (Text Search, Place Details, and Place
+    /// Autocomplete) APIs.
+    ///
+    /// ## Arguments
+    ///
+    /// * `region` ‧ The region to bias results towards. See the `Region` enum
+    ///   for the list of regions supported by Google.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_region(Region::Canada)
+    ///     .build();
+    /// ```
+
+    pub fn with_region(&mut self, region: impl Into<Region>) -> &mut Self {
+        self.region = Some(region.into());
+        self
+    } // fn
+} // impl
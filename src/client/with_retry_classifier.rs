@@ -0,0 +1,46 @@
+use crate::client::{retry_classifier::RetryDecision, GoogleMapsClient};
+use crate::error::Error as GoogleMapsError;
+use std::sync::Arc;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Overrides which errors are retried.
+    ///
+    /// Every failed request attempt is passed to the classifier, which may
+    /// force the error to be retried, force it to be returned immediately, or
+    /// defer to this crate's built-in logic by returning
+    /// `RetryDecision::Default`. Retried errors remain subject to the
+    /// request's retry policy and maximum number of retries.
+    ///
+    /// ## Arguments
+    ///
+    /// * `retry_classifier` ‧ A function that is given the error of a failed
+    ///   attempt, and returns a `RetryDecision`. The `http_status()` method of
+    ///   the error returns the HTTP status code of an unsuccessful response,
+    ///   for any API.
+    ///
+    /// ## Examples:
+    ///
+    /// * Retry `403 Forbidden` responses from a proxy, and never retry
+    ///   server errors:
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::try_new("YOUR_API_KEY_HERE")?
+    ///     .with_retry_classifier(|error| match error.http_status() {
+    ///         Some(status) if status == 403 => RetryDecision::Transient,
+    ///         Some(status) if status.is_server_error() => RetryDecision::Permanent,
+    ///         _ => RetryDecision::Default,
+    ///     })
+    ///     .build();
+    /// ```
+
+    pub fn with_retry_classifier(
+        &mut self,
+        retry_classifier: impl Fn(&GoogleMapsError) -> RetryDecision + Send + Sync + 'static
+    ) -> &mut Self {
+        self.retry_classifier = Some(Arc::new(retry_classifier));
+        self
+    } // fn
+} // impl
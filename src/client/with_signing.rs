@@ -0,0 +1,48 @@
+use crate::client::{url_signing::UrlSigner, GoogleMapsClient};
+use crate::error::Error as GoogleMapsError;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Authenticates requests using a Google Maps Platform Premium Plan client
+    /// ID and URL signing secret, instead of an API key.
+    ///
+    /// Once set, every request URL has its `key` parameter replaced by a
+    /// `client` parameter, and is signed with an HMAC-SHA1 `signature`
+    /// parameter as described in [Google's
+    /// documentation](https://developers.google.com/maps/documentation/maps-static/digital-signature).
+    /// The client's API key is not sent to Google. `GoogleMapsClient::try_new()`
+    /// does not accept an empty key, so use `GoogleMapsClient::builder()` to
+    /// create a client without one.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client_id` ‧ Your Premium Plan client ID, which begins with `gme-`.
+    ///
+    /// * `private_key` ‧ Your URL signing secret, exactly as it is shown in
+    ///   the Google Cloud Console. It is URL-safe base64, for example
+    ///   `vNIXE0xscrmjlyV-12Nj_BvUPaw=`.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the private key is not valid base64.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::builder("")
+    ///     .with_signing("gme-yourcompany", "vNIXE0xscrmjlyV-12Nj_BvUPaw=")
+    ///     .build()?;
+    /// ```
+
+    pub fn with_signing(
+        &mut self,
+        client_id: impl Into<String>,
+        private_key: &str
+    ) -> Result<&mut Self, GoogleMapsError> {
+        self.url_signer = Some(UrlSigner::try_new(client_id, private_key)?);
+        Ok(self)
+    } // fn
+} // impl
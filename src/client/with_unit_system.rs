@@ -0,0 +1,35 @@
+use crate::client::GoogleMapsClient;
+use crate::directions::UnitSystem;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Sets the default unit system to display distances in.
+    ///
+    /// The default unit system is sent with every Directions and Distance
+    /// Matrix request, unless that request has set its own unit system using
+    /// its `with_unit_system()` method. If neither is set, Google uses the
+    /// unit system of the origin's country or region.
+    ///
+    /// The unit system only changes the `text` of distances. Their `value` is
+    /// always expressed in meters.
+    ///
+    /// ## Arguments
+    ///
+    /// * `unit_system` ‧ The unit system to display distances in.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .with_unit_system(UnitSystem::Imperial)
+    ///     .build();
+    /// ```
+
+    pub fn with_unit_system(&mut self, unit_system: impl Into<UnitSystem>) -> &mut Self {
+        self.unit_system = Some(unit_system.into());
+        self
+    } // fn
+} // impl
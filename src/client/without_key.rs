@@ -0,0 +1,35 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Leaves the API key out of every request. The `key` parameter is not
+    /// sent at all.
+    ///
+    /// This is intended for requests that are sent, usually with
+    /// `with_base_url()`, through a gateway or proxy that adds the API key
+    /// itself, so that the key never needs to be given to this process.
+    ///
+    /// **Google rejects requests that have no key with a `REQUEST_DENIED`
+    /// status, so only use this if something between this client and Google
+    /// adds the key.**
+    ///
+    /// `GoogleMapsClient::try_new()` does not accept an empty key, so use
+    /// `GoogleMapsClient::builder()` to create a client without one.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let google_maps_client = GoogleMapsClient::builder("")
+    ///     .with_base_url("https://maps-gateway.internal.example.com".parse()?)
+    ///     .without_key()
+    ///     .build()?;
+    /// ```
+
+    pub fn without_key(&mut self) -> &mut Self {
+        self.omit_key = true;
+        self
+    } // fn
+} // impl
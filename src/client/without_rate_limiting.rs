@@ -0,0 +1,34 @@
+use crate::client::GoogleMapsClient;
+
+// =============================================================================
+
+impl GoogleMapsClient {
+    // -------------------------------------------------------------------------
+    //
+    /// Disables the client's rate limiting, including the default per-API
+    /// rate limits. Requests are sent to Google immediately.
+    ///
+    /// This is intended for tests, and for applications that already enforce
+    /// their quotas elsewhere, for example in a proxy that sits between this
+    /// client and Google.
+    ///
+    /// **Without rate limiting, nothing stops your application from exceeding
+    /// your Google Maps Platform quotas, or from running up charges. Use with
+    /// care.**
+    ///
+    /// Rate limits may be added back afterwards, one API at a time, using the
+    /// `with_rate()` or `with_rate_per_second()` methods.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let mut google_maps_client = GoogleMapsClient::new("YOUR_API_KEY_HERE")
+    ///     .without_rate_limiting()
+    ///     .build();
+    /// ```
+
+    pub fn without_rate_limiting(&mut self) -> &mut Self {
+        self.rate_limit.rate_map.clear();
+        self
+    } // fn
+} // impl
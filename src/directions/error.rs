@@ -3,6 +3,7 @@
 // -----------------------------------------------------------------------------
 
 use crate::directions::response::status::Status;
+#[cfg(feature = "diagnostics")]
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -10,32 +11,63 @@ use thiserror::Error;
 //
 /// Errors that may be produced by the Google Maps Directions API client.
 
-#[derive(Debug, Diagnostic, Error)]
-#[diagnostic(code(google_maps::directions::error), url(docsrs))]
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+#[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::directions::error), url(docsrs)))]
 pub enum Error {
     /// An arrival time may only be specified in Transit travel mode.
+    ///
+    /// No longer returned by `validate()`, which returns
+    /// `ConflictingParameters` instead.
     ArrivalTimeIsForTransitOnly(String, String),
+    /// Two parameters were set that may not be used together in the same
+    /// request. For example, an arrival time and a departure time, or a
+    /// transit-only parameter in driving mode. Contains a description of each
+    /// of the two parameters.
+    ConflictingParameters(String, String),
     /// Alternatives may not be requested when waypoints are specified.
+    ///
+    /// No longer returned by `validate()`, which returns
+    /// `ConflictingParameters` instead.
     EitherAlternativesOrWaypoints(usize),
     /// An departure time may not be specified when an arrival time is
     /// specified.
+    ///
+    /// No longer returned by `validate()`, which returns
+    /// `ConflictingParameters` instead.
     EitherDepartureTimeOrArrivalTime(String, String),
     /// Restrictions may not be specified when waypoints are specified.
+    ///
+    /// No longer returned by `validate()`. Google accepts restrictions and
+    /// waypoints in the same request.
     EitherRestrictionsOrWaypoints(usize, String),
     /// Waypoints may not be specified in Transit travel mode.
+    ///
+    /// No longer returned by `validate()`, which returns
+    /// `ConflictingParameters` instead.
     EitherWaypointsOrTransitMode(usize),
     /// Google Maps Directions API service generated an error. See the `Status`
-    /// enum for more information.
+    /// enum for more information. A `ZERO_RESULTS` status is not an error:
+    /// the response is returned with no results.
     GoogleMapsService(Status, Option<String>),
     /// The HTTP request was unsuccessful.
     HttpUnsuccessful(String),
+    /// The HTTP request was unsuccessful. Contains the HTTP status code, the
+    /// headers that help explain the failure (such as `Retry-After` and
+    /// Google's `X-Goog-*` headers), and the truncated response body.
+    #[cfg(feature = "reqwest")]
+    HttpUnsuccessfulResponse {
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String,
+    },
     /// API client library attempted to parse a string that contained an invalid
     /// avoid/restrictions code. See
     /// `google_maps\src\directions\request\avoid.rs` for more information.
     InvalidAvoidCode(String),
     /// API client library attempted to parse a string that contained an invalid
     /// currency code. See
-    /// `google_maps\src\directions\response\currency.rs` for more information.
+    /// `google_maps\src\types\currency.rs` for more information.
     InvalidCurrencyCode(String),
     /// API client library attempted to parse a string that contained an invalid
     /// geocoder status code. See
@@ -102,12 +134,36 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SimdJson(simd_json::Error),
+    /// The dependency library Serde JSON could not parse the response from the
+    /// server. The response body is included to help with debugging. It is
+    /// truncated to the length set by `GoogleMapsClient::with_error_body_length`.
+    SimdJsonWithBody(simd_json::Error, String),
+    /// The service responded successfully, but not with JSON, for example
+    /// with an HTML error page from a proxy or a sign-in page. Contains the
+    /// response's `Content-Type` header, and the response body truncated to the
+    /// length set by `GoogleMapsClient::with_error_body_length`.
+    #[cfg(feature = "reqwest")]
+    UnexpectedContentType {
+        content_type: String,
+        body_snippet: String,
+    },
     /// Too many waypoints specified.
     TooManyWaypoints(usize),
     /// Transit mode may only be specified in Transit travel mode.
+    ///
+    /// No longer returned by `validate()`, which returns
+    /// `ConflictingParameters` instead.
     TransitModeIsForTransitOnly(String, String),
     /// Transit routing preference may only be specified in Transit travel mode.
+    ///
+    /// No longer returned by `validate()`, which returns
+    /// `ConflictingParameters` instead.
     TransitRoutePreferenceIsForTransitOnly(String, String),
+    /// Waypoint optimization may not be requested in Transit travel mode.
+    ///
+    /// No longer returned by `validate()`, which returns
+    /// `ConflictingParameters` instead.
+    WaypointOptimizationIsNotForTransit,
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -122,6 +178,10 @@ impl std::fmt::Display for Error {
                 The with_arrival_time() method may only be used when with_travel_mode() is set to `TravelMode::Transit`. \
                 The travel mode is set to `{travel_mode}` and the arrival time is set to `{arrival_time}`. \
                 Try again either with a travel mode of `TravelMode::Transit` or no arrival time."),
+            Self::ConflictingParameters(first, second) => write!(f,
+                "Google Maps Directions API client: \
+                The {first} and the {second} cannot be used in the same request. \
+                Try again without one of them."),
             Self::EitherAlternativesOrWaypoints(waypoint_count) => write!(f,
                 "Google Maps Directions API client: \
                 The with_alternatives() method cannot be set to `true` if with_waypoints() has been set. \
@@ -179,12 +239,19 @@ impl std::fmt::Display for Error {
                     Status::ZeroResults => write!(f, "Google Maps Directions API service: \
                         Zero results. \
                         This may occur if the geocoder was passed a non-existent address."),
+                    Status::Other(status_code) => write!(f, "Google Maps Directions API service: \
+                        Unrecognized status `{status_code}`."),
                 } // match
             }, // match
             Self::HttpUnsuccessful(status) => write!(f,
                 "Google Maps Directions API client: \
                 Could not successfully query the Google Cloud Platform service. \
                 The service last responded with a `{status}` status."),
+            #[cfg(feature = "reqwest")]
+            Self::HttpUnsuccessfulResponse { status, body, .. } => write!(f,
+                "Google Maps Directions API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status: `{body}`"),
             Self::InvalidAvoidCode(avoid_code) => write!(f,
                 "Google Maps Directions API client: \
                 `{avoid_code}` is not a valid restrictions code. \
@@ -266,6 +333,13 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "reqwest")]
             Self::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Self::SimdJson(error) => write!(f, "Google Maps Directions API client in the Serde JSON library: {error}"),
+            Self::SimdJsonWithBody(error, body) => write!(f, "Google Maps Directions API client in the Serde JSON library: {error}. \
+                The response body was: `{body}`"),
+            #[cfg(feature = "reqwest")]
+            Self::UnexpectedContentType { content_type, body_snippet } => write!(f,
+                "Google Maps Directions API client: \
+                The service responded with `{content_type}` content instead of JSON. \
+                This is usually an error page. The response body was: `{body_snippet}`"),
             Self::TooManyWaypoints(waypoint_count) => write!(f,
                 "Google Maps Directions API client: \
                 The maximum allowed number of waypoints is 25 plus the origin and destination. \
@@ -283,6 +357,10 @@ impl std::fmt::Display for Error {
                 The with_transit_route_preference() method may only be used when with_travel_mode() is set to `TravelMode::Transit`. \
                 The travel mode is set to `{travel_mode}` and the transit route preference is set to `{transit_route_preference}`. \
                 Try again either with a travel mode of `TravelMode::Transit` or no transit route preference."),
+            Self::WaypointOptimizationIsNotForTransit => write!(f,
+                "Google Maps Directions API client: \
+                The with_waypoint_optimization() method cannot be set to `true` when with_travel_mode() is set to `TravelMode::Transit`. \
+                Try again either with a different travel mode or no waypoint optimization."),
         } // match
     } // fn
 } // impl
@@ -311,3 +389,46 @@ impl From<simd_json::Error> for Error {
         Self::SimdJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl crate::client::HttpError for Error {
+    /// Reports the HTTP-level failures of the retry loop as Google Maps
+    /// Directions API errors.
+    fn unexpected_content_type(content_type: String, body_snippet: String) -> Self {
+        Self::UnexpectedContentType {
+            content_type,
+            body_snippet,
+        } // UnexpectedContentType
+    } // fn
+
+    fn http_unsuccessful_response(
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String
+    ) -> Self {
+        Self::HttpUnsuccessfulResponse {
+            status,
+            headers,
+            body,
+        } // HttpUnsuccessfulResponse
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(message: String) -> Self {
+        Self::ReqwestMessage(message)
+    } // fn
+} // impl
+
+#[cfg(feature = "reqwest")]
+impl crate::client::JsonError for Error {
+    /// Reports a response body that could not be parsed as a Google Maps
+    /// Directions API error.
+    fn simd_json_with_body(error: simd_json::Error, body: String) -> Self {
+        Self::SimdJsonWithBody(error, body)
+    } // fn
+} // impl
@@ -103,7 +103,8 @@ pub use crate::directions::{
     }, // crate::directions::request
     response::{
         directions_distance::DirectionsDistance, directions_duration::DirectionsDuration,
-        driving_maneuver::DrivingManeuver, leg::Leg, overview_polyline::OverviewPolyline,
+        driving_maneuver::DrivingManeuver, geocoded_waypoint::GeocodedWaypoint,
+        geocoder_status::GeocoderStatus, leg::Leg, overview_polyline::OverviewPolyline,
         polyline::Polyline, route::Route, status::Status as DirectionsStatus, step::Step,
         transit_agency::TransitAgency, transit_currency::TransitCurrency,
         transit_details::TransitDetails, transit_fare::TransitFare, transit_line::TransitLine,
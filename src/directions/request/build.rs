@@ -1,4 +1,4 @@
-use crate::directions::{error::Error, request::Request};
+use crate::directions::{error::Error, request::Request, travel_mode::TravelMode};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // =============================================================================
@@ -64,10 +64,11 @@ impl<'a> Request<'a> {
             query.push_str(&String::from(departure_time));
         } // if
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // one has not been set for this request:
+        if let Some(language) = self.language.or(self.client.language) {
             query.push_str("&language=");
-            query.push_str(&String::from(language));
+            query.push_str(&String::from(&language));
         } // if
 
         // Travel mode key/value pair:
@@ -76,20 +77,26 @@ impl<'a> Request<'a> {
             query.push_str(&String::from(travel_mode).to_lowercase());
         } // if
 
-        // Region key/value pair:
-        if let Some(region) = &self.region {
+        // Region key/value pair. The client's default region is used if one
+        // has not been set for this request:
+        if let Some(region) = self.region.or(self.client.region) {
             query.push_str("&region=");
-            query.push_str(&String::from(region));
+            query.push_str(&String::from(&region));
         } // if
 
-        // Traffic model key/value pair:
-        if let Some(traffic_model) = &self.traffic_model {
+        // Traffic model key/value pair. Google ignores the traffic model unless
+        // a departure time is set, so it is only sent with a departure time:
+        if let (Some(traffic_model), Some(_departure_time)) = (&self.traffic_model, &self.departure_time) {
             query.push_str("&traffic_model=");
             query.push_str(&String::from(traffic_model));
         } // if
 
+        // Transit parameters are only used for transit directions, so they are
+        // only sent when the travel mode is transit:
+        let is_transit = self.travel_mode == Some(TravelMode::Transit);
+
         // Transit mode key/value pair:
-        if !self.transit_modes.is_empty() {
+        if is_transit && !self.transit_modes.is_empty() {
             query.push_str("&transit_mode=");
             query.push_str(
                 &utf8_percent_encode(
@@ -106,13 +113,14 @@ impl<'a> Request<'a> {
         } // if
 
         // Transit route preference key/value pair:
-        if let Some(transit_route_preference) = &self.transit_route_preference {
+        if let (true, Some(transit_route_preference)) = (is_transit, &self.transit_route_preference) {
             query.push_str("&transit_routing_preference=");
             query.push_str(&String::from(transit_route_preference));
         } // if
 
-        // Unit system key/value pair:
-        if let Some(unit_system) = &self.unit_system {
+        // Unit system key/value pair. The client's default unit system is used
+        // if one has not been set for this request:
+        if let Some(unit_system) = self.unit_system.as_ref().or(self.client.unit_system.as_ref()) {
             query.push_str("&units=");
             query.push_str(&String::from(unit_system));
         } // if
@@ -138,7 +146,7 @@ impl<'a> Request<'a> {
         } // if
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         Ok(self)
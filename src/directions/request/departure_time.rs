@@ -44,7 +44,7 @@ pub enum DepartureTime {
     /// current time (correct to the nearest second).
     #[default]
     Now = 0,
-    /// Specifies the desired time of departure.
+    /// Specifies the desired time of departure. The date & time is in UTC.
     At(NaiveDateTime) = 1,
 } // enum
 
@@ -63,6 +63,16 @@ impl std::convert::From<&DepartureTime> for String {
 
 // -----------------------------------------------------------------------------
 
+impl std::convert::From<NaiveDateTime> for DepartureTime {
+    /// Converts a `NaiveDateTime` struct to a `DepartureTime::At` enum, so that
+    /// a date & time can be passed directly to `with_departure_time()`.
+    fn from(departure_time: NaiveDateTime) -> Self {
+        Self::At(departure_time)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::fmt::Display for DepartureTime {
     /// Converts a `DepartureTime` enum to a `String` that contains a [departure
     /// time](https://developers.google.com/maps/documentation/directions/intro#optional-parameters).
@@ -0,0 +1,105 @@
+use crate::directions::{error::Error, request::Request};
+use crate::fingerprint::fingerprint;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a stable fingerprint of the query you've built, for use as a
+    /// cache key or to recognize the request in logs.
+    ///
+    /// ## Description
+    ///
+    /// The fingerprint is a 64-bit hash of the URL returned by `query_url()`.
+    /// The API key is not part of the fingerprint, and query string parameters
+    /// are sorted first, so two requests that ask Google for the same thing
+    /// have the same fingerprint, whatever order their builder methods were
+    /// called in. Restrictions and transit modes may also be added in any
+    /// order, but the order of the waypoints is kept, since it changes the
+    /// route. The hash does not depend on the platform or Rust version, so
+    /// fingerprints may be stored and compared between runs.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the query could not be built, as `query_url()`
+    ///   does.
+
+    pub fn fingerprint(&'a mut self) -> Result<u64, Error> {
+        self.query_url().map(|url| fingerprint(&url))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::directions::{
+        request::{
+            avoid::Avoid, location::Location, transit_mode::TransitMode, waypoint::Waypoint,
+            Request,
+        }, // request
+        travel_mode::TravelMode,
+    }; // crate::directions
+    use crate::GoogleMapsClient;
+
+    fn request(client: &GoogleMapsClient) -> Request<'_> {
+        Request::new(
+            client,
+            Location::from_address("Toronto, ON"),
+            Location::from_address("Montreal, QC"),
+        ) // Request
+    } // fn
+
+    #[test]
+    fn ignores_the_order_of_sets() {
+        let client = GoogleMapsClient::try_new("AIzaTest").unwrap();
+
+        let mut first = request(&client);
+        let first = first
+            .with_travel_mode(TravelMode::Transit)
+            .with_restriction(Avoid::Tolls)
+            .with_restriction(Avoid::Ferries)
+            .with_transit_mode(TransitMode::Bus)
+            .with_transit_mode(TransitMode::Rail)
+            .fingerprint()
+            .unwrap();
+
+        let mut second = request(&client);
+        let second = second
+            .with_transit_mode(TransitMode::Rail)
+            .with_transit_mode(TransitMode::Bus)
+            .with_restriction(Avoid::Ferries)
+            .with_restriction(Avoid::Tolls)
+            .with_travel_mode(TravelMode::Transit)
+            .fingerprint()
+            .unwrap();
+
+        assert_eq!(first, second);
+    } // fn
+
+    #[test]
+    fn keeps_the_order_of_waypoints() {
+        let client = GoogleMapsClient::try_new("AIzaTest").unwrap();
+
+        let mut first = request(&client);
+        let first = first
+            .with_waypoint(Waypoint::from_address("Kingston, ON"))
+            .with_waypoint(Waypoint::from_address("Ottawa, ON"))
+            .fingerprint()
+            .unwrap();
+
+        let mut second = request(&client);
+        let second = second
+            .with_waypoint(Waypoint::from_address("Ottawa, ON"))
+            .with_waypoint(Waypoint::from_address("Kingston, ON"))
+            .fingerprint()
+            .unwrap();
+
+        assert_ne!(first, second);
+    } // fn
+} // mod
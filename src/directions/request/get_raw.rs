@@ -0,0 +1,73 @@
+use crate::directions::{
+    error::Error as DirectionsError, request::Request as DirectionsRequest, OUTPUT_FORMAT,
+    SERVICE_URL,
+}; // use crate::directions
+use crate::client::{Method, RetrySettings, StatusEnvelope};
+use crate::directions::response::status::Status as DirectionsStatus;
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+
+// -----------------------------------------------------------------------------
+
+impl DirectionsRequest<'_> {
+    /// Performs the HTTP get request and returns the raw response body to the
+    /// caller, without deserializing it.
+    ///
+    /// This is an escape hatch for reading fields that this crate does not
+    /// model yet. The request is checked and retried like `get()`: only
+    /// Google's `status` and `error_message` are parsed, so that an
+    /// unsuccessful status is returned as an error, and `UNKNOWN_ERROR` and
+    /// `OVER_QUERY_LIMIT` are retried. The rest of the body is not
+    /// interpreted.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(api = %Api::Directions, attempts, duration_ms, status)
+    )]
+    pub async fn get_raw(&self) -> Result<String, GoogleMapsError> {
+        // Build the URL stem for the HTTP get request:
+        let mut url = format!("{SERVICE_URL}/{OUTPUT_FORMAT}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(DirectionsError::QueryNotBuilt)?,
+        } // match
+
+        tracing::info!("making HTTP GET request to Google Maps Directions API");
+
+        // Observe any rate limiting before executing the request, and retry
+        // it according to its retry settings:
+        let response = self
+            .client
+            .execute_with_retry(
+                Method::Get,
+                &url,
+                RetrySettings {
+                    api: Api::Directions,
+                    backoff: self.backoff.as_ref(),
+                    max_retries: self.max_retries,
+                    timeout: self.timeout,
+                    deadline: self.deadline,
+                }, // RetrySettings
+                "application/json",
+                |body| -> Result<String, backoff::Error<DirectionsError>> {
+                    // Google's `status` is checked, but the body is returned to
+                    // the caller without deserializing it:
+                    let envelope: StatusEnvelope<DirectionsStatus> = self.client.parse_json(&body)?;
+                    envelope.status.check(envelope.error_message.as_deref())?;
+                    Ok(body.text())
+                }, // classify
+            )
+            .await?;
+
+        // Return response to caller:
+        Ok(response)
+    } // fn
+} // impl
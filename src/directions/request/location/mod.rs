@@ -227,6 +227,28 @@ impl From<LatLng> for Location {
 
 // -----------------------------------------------------------------------------
 
+impl<V: TryInto<Decimal>> TryFrom<(V, V)> for Location {
+    type Error = GoogleMapsError;
+    /// Attempts to convert a `(lat, lng)` tuple into a `Location` type. If either
+    /// the latitude or longitude are out of range, an error is returned.
+    fn try_from(coordinates: (V, V)) -> Result<Self, Self::Error> {
+        Ok(Self::LatLng(LatLng::try_from(coordinates)?))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<V: TryInto<Decimal>> TryFrom<[V; 2]> for Location {
+    type Error = GoogleMapsError;
+    /// Attempts to convert a `[lat, lng]` array into a `Location` type. If either
+    /// the latitude or longitude are out of range, an error is returned.
+    fn try_from(coordinates: [V; 2]) -> Result<Self, Self::Error> {
+        Ok(Self::LatLng(LatLng::try_from(coordinates)?))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl From<&LatLng> for Location {
     /// Converts a borrowed `&LatLng` coordinates type into a `Location` type.
      fn from(latlng: &LatLng) -> Self {
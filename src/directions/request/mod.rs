@@ -8,8 +8,11 @@ mod build;
 pub mod departure_time;
 #[cfg(feature = "reqwest")]
 mod execute;
+mod fingerprint;
 #[cfg(feature = "reqwest")]
 mod get;
+#[cfg(feature = "reqwest")]
+mod get_raw;
 pub mod location;
 mod new;
 mod query_url;
@@ -17,14 +20,27 @@ pub mod traffic_model;
 pub mod transit_mode;
 pub mod transit_route_preference;
 pub mod unit_system;
+#[cfg(feature = "reqwest")]
+mod url;
 mod validate;
 pub mod waypoint;
 mod with_alternatives;
 mod with_arrival_time;
+mod with_arrival_time_utc;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
+mod with_departure_now;
 mod with_departure_time;
+mod with_departure_time_utc;
 mod with_language;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
 mod with_region;
 mod with_restrictions;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
 mod with_traffic_model;
 mod with_transit_modes;
 mod with_transit_route_preference;
@@ -44,6 +60,7 @@ use crate::directions::request::{
 use crate::directions::travel_mode::TravelMode;
 use crate::types::{Language, Region};
 use chrono::NaiveDateTime;
+use crate::redact::QueryString;
 
 // -----------------------------------------------------------------------------
 //
@@ -73,7 +90,7 @@ pub struct Request<'a> {
     /// response. See method `with_alternatives()` for more information.
     alternatives: Option<bool>,
 
-    /// Desired arrival time. See method `with_arrival_time()` for more
+    /// Desired arrival time, in UTC. See method `with_arrival_time()` for more
     /// information.
     arrival_time: Option<NaiveDateTime>,
 
@@ -122,11 +139,33 @@ pub struct Request<'a> {
     /// `waypoint.rs` and method `with_waypoints()` for more information.
     waypoints: Vec<Waypoint>,
 
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
     /// The URL-encoded query string that is passed to the Google Maps
     /// Directions API through cURL.
-    query: Option<String>,
+    query: Option<QueryString>,
 
     /// Has the request been validated?
     validated: bool,
@@ -39,6 +39,15 @@ impl<'a> Request<'a> {
             unit_system: None,
             waypoint_optimization: false,
             waypoints: Vec::new(),
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
             validated: false,
@@ -1,4 +1,8 @@
-use crate::directions::{error::Error, request::Request, travel_mode::TravelMode}; // crate::directions
+use crate::directions::{
+    error::Error,
+    request::{avoid::Avoid, Request},
+    travel_mode::TravelMode,
+}; // crate::directions
 
 impl<'a> Request<'a> {
     /// Ensures the built query is valid. This function checks the combination
@@ -8,71 +12,124 @@ impl<'a> Request<'a> {
     /// not check parameter values for validity - i.e. it will not ensure
     /// Polylines or Place ID's are valid and well-formed.
     ///
+    /// Conflicting parameters are reported with an
+    /// `Error::ConflictingParameters` error that names both parameters.
+    ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
     pub fn validate(&'a mut self) -> Result<&'a mut Self, Error> {
-        if let Some(travel_mode) = &self.travel_mode {
-            // If the travel mode has been set to TravelMode::Transit...
-            if *travel_mode == TravelMode::Transit {
-                // ...waypoints cannot be set:
-                if !self.waypoints.is_empty() {
-                    return Err(Error::EitherWaypointsOrTransitMode(self.waypoints.len()));
-                } // if
+        // If the travel mode has not been set, Google uses driving directions:
+        let (travel_mode, travel_mode_description) = self.travel_mode.as_ref().map_or_else(
+            || (&TravelMode::Driving, format!("default travel mode `{}`", TravelMode::Driving)),
+            |travel_mode| (travel_mode, format!("travel mode `{travel_mode}`")),
+        ); // map_or_else
 
-            // If the transit mode is not set to TravelMode::Transit...
-            } else {
-                // ...an arrival time cannot be set:
-                if let Some(arrival_time) = &self.arrival_time {
-                    return Err(Error::ArrivalTimeIsForTransitOnly(
-                        travel_mode.to_string(),
-                        arrival_time.format("%F %r").to_string(),
-                    )); // Err
-                } // if
+        // If the travel mode has been set to TravelMode::Transit...
+        if *travel_mode == TravelMode::Transit {
+            // ...waypoint optimization cannot be requested:
+            if self.waypoint_optimization {
+                return Err(Error::ConflictingParameters(
+                    travel_mode_description,
+                    String::from("waypoint optimization"),
+                )); // Err
+            } // if
+
+            // ...waypoints cannot be set:
+            if !self.waypoints.is_empty() {
+                return Err(Error::ConflictingParameters(
+                    travel_mode_description,
+                    format!("{} waypoint(s)", self.waypoints.len()),
+                )); // Err
+            } // if
+
+        // If the transit mode is not set to TravelMode::Transit...
+        } else {
+            // ...an arrival time cannot be set:
+            if let Some(arrival_time) = &self.arrival_time {
+                return Err(Error::ConflictingParameters(
+                    travel_mode_description,
+                    format!("arrival time `{}`", arrival_time.format("%F %r")),
+                )); // Err
+            } // if
 
-                // ...a transit mode cannot be set:
-                if !self.transit_modes.is_empty() {
-                    return Err(Error::TransitModeIsForTransitOnly(
-                        travel_mode.to_string(),
+            // ...a transit mode cannot be set:
+            if !self.transit_modes.is_empty() {
+                return Err(Error::ConflictingParameters(
+                    travel_mode_description,
+                    format!(
+                        "transit mode(s) `{}`",
                         self.transit_modes
                             .iter()
                             .map(std::string::ToString::to_string)
                             .collect::<Vec<String>>()
-                            .join("|"),
-                    )); // Err
-                } // if
+                            .join("|")
+                    ), // format!
+                )); // Err
+            } // if
 
-                // ...a transit route preference cannot be set:
-                if let Some(transit_route_preference) = &self.transit_route_preference {
-                    return Err(Error::TransitRoutePreferenceIsForTransitOnly(
-                        travel_mode.to_string(),
-                        transit_route_preference.to_string(),
-                    )); // Err
-                } // if
+            // ...a transit route preference cannot be set:
+            if let Some(transit_route_preference) = &self.transit_route_preference {
+                return Err(Error::ConflictingParameters(
+                    travel_mode_description,
+                    format!("transit route preference `{transit_route_preference}`"),
+                )); // Err
+            } // if
+        } // if
+
+        // A departure time is only used for driving and transit directions.
+        // Walking and bicycling directions do not depend on the time of day:
+        if matches!(travel_mode, TravelMode::Walking | TravelMode::Bicycling) {
+            if let Some(departure_time) = &self.departure_time {
+                return Err(Error::ConflictingParameters(
+                    travel_mode_description,
+                    format!("departure time `{departure_time}`"),
+                )); // Err
+            } // if
+        } // if
+
+        // Indoor steps are only avoided for walking and transit directions:
+        if matches!(travel_mode, TravelMode::Driving | TravelMode::Bicycling)
+            && self.restrictions.contains(&Avoid::Indoor)
+        {
+            return Err(Error::ConflictingParameters(
+                travel_mode_description,
+                format!("restriction `{}`", Avoid::Indoor),
+            )); // Err
+        } // if
+
+        // A traffic model is only used for driving directions:
+        if *travel_mode != TravelMode::Driving {
+            if let Some(traffic_model) = &self.traffic_model {
+                return Err(Error::ConflictingParameters(
+                    travel_mode_description,
+                    format!("traffic model `{traffic_model}`"),
+                )); // Err
             } // if
         } // if
 
         // If waypoints have been set...
         if !self.waypoints.is_empty() {
             // ...alternatives cannot be set to true:
-            if let Some(alternatives) = &self.alternatives {
-                if !alternatives {
-                    return Err(Error::EitherAlternativesOrWaypoints(self.waypoints.len()));
-                    // Err
-                } // if
+            if self.alternatives == Some(true) {
+                return Err(Error::ConflictingParameters(
+                    format!("{} waypoint(s)", self.waypoints.len()),
+                    String::from("alternatives"),
+                )); // Err
             } // if
 
-            // ...restrictions cannot be set:
-            if self.restrictions.is_empty() {
-                return Err(Error::EitherRestrictionsOrWaypoints(
-                    self.waypoints.len(),
-                    self.restrictions
-                        .iter()
-                        .map(std::string::ToString::to_string)
-                        .collect::<Vec<String>>()
-                        .join("|"),
-                )); // Err
+            // ...waypoint optimization cannot be requested if any waypoint is
+            // a pass-through `via:` waypoint, since all waypoints must be
+            // stopovers for their order to be optimized:
+            if self.waypoint_optimization {
+                let via_waypoints = self.waypoints.iter().filter(|waypoint| waypoint.is_via()).count();
+                if via_waypoints > 0 {
+                    return Err(Error::ConflictingParameters(
+                        format!("{via_waypoints} `via:` waypoint(s)"),
+                        String::from("waypoint optimization"),
+                    )); // Err
+                } // if
             } // if
 
             // ...ensure that the number of waypoints is equal to or less than 25:
@@ -85,9 +142,9 @@ impl<'a> Request<'a> {
         if let Some(arrival_time) = &self.arrival_time {
             // ...a departure time cannot be set:
             if let Some(departure_time) = &self.departure_time {
-                return Err(Error::EitherDepartureTimeOrArrivalTime(
-                    arrival_time.format("%F %r").to_string(),
-                    departure_time.to_string(),
+                return Err(Error::ConflictingParameters(
+                    format!("arrival time `{}`", arrival_time.format("%F %r")),
+                    format!("departure time `{departure_time}`"),
                 )); // Err
             } // if
         } // if
@@ -99,3 +156,61 @@ impl<'a> Request<'a> {
         Ok(self)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::directions::{
+        error::Error,
+        request::{avoid::Avoid, location::Location, waypoint::Waypoint, Request},
+        travel_mode::TravelMode,
+    }; // crate::directions
+    use crate::GoogleMapsClient;
+
+    fn client() -> GoogleMapsClient {
+        GoogleMapsClient::try_new("AIzaTest").unwrap()
+    } // fn
+
+    fn request(client: &GoogleMapsClient) -> Request<'_> {
+        Request::new(
+            client,
+            Location::from_address("Toronto, ON"),
+            Location::from_address("Montreal, QC"),
+        ) // Request
+    } // fn
+
+    #[test]
+    fn accepts_waypoints_with_restrictions() {
+        let client = client();
+        let mut request = request(&client);
+        let result = request
+            .with_waypoint(Waypoint::from_address("Kingston, ON"))
+            .with_restriction(Avoid::Tolls)
+            .with_restriction(Avoid::Highways)
+            .validate();
+        assert!(result.is_ok());
+    } // fn
+
+    #[test]
+    fn rejects_waypoints_with_alternatives() {
+        let client = client();
+        let mut request = request(&client);
+        let result = request
+            .with_waypoint(Waypoint::from_address("Kingston, ON"))
+            .with_alternatives(true)
+            .validate();
+        assert!(matches!(result, Err(Error::ConflictingParameters(_, _))));
+    } // fn
+
+    #[test]
+    fn rejects_transit_with_waypoint_optimization() {
+        let client = client();
+        let mut request = request(&client);
+        let result = request
+            .with_travel_mode(TravelMode::Transit)
+            .with_waypoint_optimization(true)
+            .validate();
+        assert!(matches!(result, Err(Error::ConflictingParameters(_, _))));
+    } // fn
+} // mod
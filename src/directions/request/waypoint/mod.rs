@@ -47,6 +47,16 @@ pub enum Waypoint {
     /// See also: the Google Encoded Polyline encoding & decoding crate called
     /// [polyline](https://crates.io/crates/polyline).
     Polyline(String),
+    /// A `via:` waypoint is passed through without stopping. The route is
+    /// routed through the wrapped location, but the location does not split
+    /// the route into separate legs, so no leg begins or ends at it. This is
+    /// useful for steering a route down a particular road.
+    ///
+    /// `via:` waypoints may not be combined with waypoint optimization, which
+    /// requires every waypoint to be a stopover. `validate()` returns an error
+    /// if both are requested. A `Via` inside a `Via` is the same as a single
+    /// `Via`.
+    Via(Box<Self>),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -62,6 +72,7 @@ impl std::convert::From<&Waypoint> for String {
             Waypoint::LatLng(latlng) => Self::from(latlng),
             Waypoint::PlaceId(place_id) => format!("place_id:{place_id}"),
             Waypoint::Polyline(polyline) => format!("enc:{polyline}:"),
+            Waypoint::Via(waypoint) => format!("via:{}", Self::from(waypoint.innermost())),
         } // match
     } // fn
 } // impl
@@ -120,6 +131,16 @@ pub enum Waypoint {
     /// [geo](https://crates.io/crates/geo) crate's
     /// [Point](https://docs.rs/geo/latest/geo/geometry/struct.Point.html) type.
     Point(geo_types::geometry::Point),
+    /// A `via:` waypoint is passed through without stopping. The route is
+    /// routed through the wrapped location, but the location does not split
+    /// the route into separate legs, so no leg begins or ends at it. This is
+    /// useful for steering a route down a particular road.
+    ///
+    /// `via:` waypoints may not be combined with waypoint optimization, which
+    /// requires every waypoint to be a stopover. `validate()` returns an error
+    /// if both are requested. A `Via` inside a `Via` is the same as a single
+    /// `Via`.
+    Via(Box<Self>),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -150,6 +171,8 @@ impl std::convert::From<&Waypoint> for String {
                 latitude = point.y(),
                 longitude = point.x()
             ),
+
+            Waypoint::Via(waypoint) => format!("via:{}", Self::from(waypoint.innermost())),
         } // match
     } // fn
 } // impl
@@ -201,6 +224,32 @@ impl Waypoint {
 
 // -----------------------------------------------------------------------------
 
+impl Waypoint {
+    /// Wraps a waypoint in a `via:` prefix, so that the route passes through
+    /// the location without stopping there. A `via:` waypoint does not split
+    /// the route into legs, and may not be used with waypoint optimization.
+    pub fn via(waypoint: impl Into<Self>) -> Self {
+        Self::Via(Box::new(waypoint.into()))
+    } // fn
+
+    /// Returns `true` if the route passes through this waypoint without
+    /// stopping, rather than stopping over at it.
+    #[must_use]
+    pub const fn is_via(&self) -> bool {
+        matches!(self, Self::Via(_))
+    } // fn
+
+    /// Returns the waypoint that a chain of `Via` wrappers refers to.
+    fn innermost(&self) -> &Self {
+        match self {
+            Self::Via(waypoint) => waypoint.innermost(),
+            waypoint => waypoint,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl Waypoint {
     /// Takes individual latitude & longitude `Decimal` coordinates and
     /// converts them into a `Waypoint` structure. If either the latitude
@@ -249,6 +298,28 @@ impl From<LatLng> for Waypoint {
 
 // -----------------------------------------------------------------------------
 
+impl<V: TryInto<Decimal>> TryFrom<(V, V)> for Waypoint {
+    type Error = GoogleMapsError;
+    /// Attempts to convert a `(lat, lng)` tuple into a `Waypoint` type. If either
+    /// the latitude or longitude are out of range, an error is returned.
+    fn try_from(coordinates: (V, V)) -> Result<Self, Self::Error> {
+        Ok(Self::LatLng(LatLng::try_from(coordinates)?))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<V: TryInto<Decimal>> TryFrom<[V; 2]> for Waypoint {
+    type Error = GoogleMapsError;
+    /// Attempts to convert a `[lat, lng]` array into a `Waypoint` type. If either
+    /// the latitude or longitude are out of range, an error is returned.
+    fn try_from(coordinates: [V; 2]) -> Result<Self, Self::Error> {
+        Ok(Self::LatLng(LatLng::try_from(coordinates)?))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl From<&LatLng> for Waypoint {
     /// Converts a borrowed `&LatLng` coordinates type into a `Waypoint` type.
      fn from(latlng: &LatLng) -> Self {
@@ -36,28 +36,33 @@ impl<'a> Request<'a> {
     ///
     /// * Set preferred transit mode to rail:
     /// ```rust
-    /// .with_transit_mode((vec![TransitMode::Rail]))
+    /// .with_transit_mode(TransitMode::Rail)
     /// ```
     ///
-    /// * Set preferred transit modes to bus and subway:
+    /// * Multiple modes may be stacked together. This example sets preferred
+    ///   transit modes to bus and subway:
+    ///
     /// ```rust
-    /// .with_transit_modes(vec![
-    ///     TransitMode::Bus,
-    ///     TransitMode::Subway
-    /// ])
+    /// .with_transit_mode(TransitMode::Bus)
+    /// .with_transit_mode(TransitMode::Subway)
     /// ```
 
     pub fn with_transit_mode(
         &'a mut self,
         transit_mode: impl Into<TransitMode>
     ) -> &'a mut Self {
-        // Add restiction to Request struct.
-        self.transit_modes = vec![transit_mode.into()];
+        // Add transit mode to Request struct, unless it has already been
+        // added.
+        let transit_mode = transit_mode.into();
+        if !self.transit_modes.contains(&transit_mode) {
+            self.transit_modes.push(transit_mode);
+        } // if
         // Return modified Request struct to caller.
         self
     } // fn
 
-    /// Specifies preferred modes of transit.
+    /// Specifies preferred modes of transit. The transit modes are added to
+    /// any transit modes that have already been set.
     ///
     /// # Example:
     ///
@@ -86,8 +91,14 @@ impl<'a> Request<'a> {
     where
         C: IntoIterator<Item = T>,
         T: Into<TransitMode> {
-        // Add transit_modes to Request struct.
-        self.transit_modes = transit_modes.into_iter().map(Into::into).collect();
+        // Add transit_modes to Request struct, skipping any that have already
+        // been added.
+        for transit_mode in transit_modes {
+            let transit_mode = transit_mode.into();
+            if !self.transit_modes.contains(&transit_mode) {
+                self.transit_modes.push(transit_mode);
+            } // if
+        } // for
         // Return modified Request struct to caller.
         self
     } // fn
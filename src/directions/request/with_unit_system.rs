@@ -33,6 +33,10 @@ impl<'a> Request<'a> {
     /// within `distance` fields. The `distance` fields also contain `values`
     /// which are always expressed in meters.
     ///
+    /// If this method is not called, the `units` parameter is left out of the
+    /// query and Google infers the unit system from the origin's country or
+    /// region. Durations are not affected by this setting.
+    ///
     /// ## Example
     ///
     /// * Force unit system to Metric:
@@ -23,13 +23,21 @@ impl<'a> Request<'a> {
     /// Travel time is the primary factor which is optimized, but other factors
     /// such as distance, number of turns and many more may be taken into
     /// account when deciding which route is the most efficient. All waypoints
-    /// must be stopovers for the Directions service to optimize their route.
+    /// must be stopovers for the Directions service to optimize their route,
+    /// so `validate()` will return an error if any waypoint is a
+    /// `Waypoint::Via` pass-through.
     ///
     /// If you instruct the Directions service to optimize the order of its
     /// waypoints, their order will be returned in the `waypoint_order` field
     /// within the
     /// `[routes](https://developers.google.com/maps/documentation/directions/intro#Routes)`
     /// object. The `waypoint_order` field returns values which are zero-based.
+    /// Use it to map the optimized order back to the waypoints passed to
+    /// `with_waypoints()`.
+    ///
+    /// Waypoints are not supported by the `TravelMode::Transit` travel mode,
+    /// so `validate()` will return an error if waypoint optimization is
+    /// requested for a transit route.
     ///
     /// The following example calculates a road journey from Adelaide, South
     /// Australia to each of South Australia's main wine regions using route
@@ -17,7 +17,7 @@ impl<'a> Request<'a> {
     /// walking and bicycling; not transit. You can specify waypoints using the
     /// following values:
     ///
-    /// * `Waypoint::Address`: An explicit value pair.
+    /// * `Waypoint::LatLng`: An explicit value pair.
     ///
     /// * `Waypoint::PlaceId`: The unique value specific to a location. This
     ///   value is only available only if the request includes an API key or
@@ -29,6 +29,12 @@ impl<'a> Request<'a> {
     /// * `Waypoint::Polyline`: Encoded polyline that can be specified by a set
     ///   of any of the above. (`lexeF{~wsZejrPjtye@:`)
     ///
+    /// * `Waypoint::Via`: Any of the above, prefixed with `via:`. The route
+    ///   passes through the location without stopping, and the location does
+    ///   not split the route into separate legs. `via:` waypoints cannot be
+    ///   combined with `with_waypoint_optimization(true)`, which requires all
+    ///   waypoints to be stopovers. (`via:-37.81223,144.96254`)
+    ///
     /// [Waypoints](https://developers.google.com/maps/documentation/directions/intro#Waypoints)
     /// ----------------------------------------------------------------------------------------
     ///
@@ -124,6 +130,15 @@ impl<'a> Request<'a> {
     /// ])
     /// ```
     ///
+    /// * Drive along Colonel By Drive, by passing through a point on it
+    ///   without stopping. The route still has a single leg:
+    ///
+    /// ```rust
+    /// .with_waypoints(vec![
+    ///     Waypoint::via(LatLng::try_from_dec(dec!(45.40550), dec!(-75.68510))?),
+    /// ])
+    /// ```
+    ///
     /// # Generics
     ///
     /// This method uses generics to improve ergonomics. The `C` generic is
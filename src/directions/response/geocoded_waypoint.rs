@@ -1,6 +1,19 @@
+//! Contains the `GeocodedWaypoint` struct and its associated traits. It
+//! describes how an origin, destination, or waypoint was geocoded.
+
 use crate::{directions::response::geocoder_status::GeocoderStatus, types::PlaceType}; // use
 use serde::{Deserialize, Serialize};
 
+// -----------------------------------------------------------------------------
+//
+/// Details about the geocoding of an origin, destination, or waypoint. See
+/// [Geocoded
+/// Waypoints](https://developers.google.com/maps/documentation/directions/get-directions#DirectionsGeocodedWaypoint).
+///
+/// These details can be used to find out why a route is not what was expected.
+/// For example, an address such as "Main St" that matched only in part, or
+/// that matched a street in the wrong town.
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct GeocodedWaypoint {
     /// Indicates the status code resulting from the geocoding operation.
@@ -24,3 +37,21 @@ pub struct GeocodedWaypoint {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub types: Vec<PlaceType>,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl GeocodedWaypoint {
+    /// Returns `true` if the geocoder found the location. A missing
+    /// `geocoder_status` is treated as `GeocoderStatus::Ok`.
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.geocoder_status.as_ref().map_or(true, |status| *status == GeocoderStatus::Ok)
+    } // fn
+
+    /// Returns `true` if the geocoder did not find an exact match for the
+    /// location, though it was able to match part of it.
+    #[must_use]
+    pub fn is_partial_match(&self) -> bool {
+        self.partial_match.unwrap_or(false)
+    } // fn
+} // impl
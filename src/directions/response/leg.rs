@@ -179,3 +179,72 @@ impl Leg {
             .map(|time| time.time_zone.name().to_string())
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Leg {
+    /// Decodes the polylines of every step in the leg, and joins them into a
+    /// single list of coordinates that follows the leg from its start to its
+    /// end. The point where one step ends and the next one begins is only
+    /// included once.
+    ///
+    /// An empty list is returned if any of the polylines could not be
+    /// decoded.
+    /// ```rust
+    /// let path = leg.decoded_polyline();
+    /// ```
+
+    #[must_use]
+    pub fn decoded_polyline(&self) -> Vec<LatLng> {
+        let mut path: Vec<LatLng> = Vec::new();
+
+        for step in &self.steps {
+            let Ok(points) = crate::polyline::decode(&step.polyline.points) else {
+                return Vec::new();
+            }; // let
+
+            // Skip the first point of this step if it is the same as the last
+            // point of the previous step:
+            let skip = usize::from(path.last().is_some() && path.last() == points.first());
+            path.extend(points.into_iter().skip(skip));
+        } // for
+
+        path
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Leg {
+    /// Summarizes the leg on a single line: its start and end addresses, its
+    /// distance and duration as Google formatted them, and its number of
+    /// steps. This is intended for logging and debugging.
+    /// ```rust
+    /// // "Toronto, ON, Canada → Montreal, QC, Canada: 541 km, 5 hours 22 mins, 23 steps"
+    /// println!("{}", leg.summary());
+    /// ```
+
+    #[must_use]
+    pub fn summary(&self) -> String {
+        format!(
+            "{start} → {end}: {distance}, {duration}, {steps}",
+            start = self.start_address,
+            end = self.end_address,
+            distance = self.distance.text,
+            duration = self.duration.text,
+            steps = steps_to_string(self.steps.len()),
+        ) // format!
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Formats a number of steps, for example `1 step` or `23 steps`.
+
+pub(in crate::directions::response) fn steps_to_string(steps: usize) -> String {
+    if steps == 1 {
+        String::from("1 step")
+    } else {
+        format!("{steps} steps")
+    } // if
+} // fn
@@ -9,6 +9,7 @@ pub mod geocoded_waypoint;
 pub mod geocoder_status;
 pub mod leg;
 pub mod overview_polyline;
+mod plain_text;
 pub mod polyline;
 pub mod route;
 pub mod status;
@@ -30,7 +31,7 @@ use serde::{Deserialize, Serialize};
 
 /// Directions responses contain the following root elements.
 
-#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct Response {
     /// Contains an array of available travel modes. This field is returned when
     /// a request specifies a travel `mode` and gets no results. The array
@@ -54,6 +55,13 @@ pub struct Response {
     /// Contains an array with details about the geocoding of origin,
     /// destination and waypoints. See [Geocoded
     /// Waypoints](https://developers.google.com/maps/documentation/directions/intro#GeocodedWaypoints).
+    ///
+    /// The elements are in the same order as the locations in the request: the
+    /// origin first, then each waypoint in the order it was passed to
+    /// `with_waypoints()`, and the destination last. This order is kept even if
+    /// waypoint optimization was requested. `geocoded_origin()`,
+    /// `geocoded_destination()`, and `inexact_waypoints()` help to find
+    /// locations that were not geocoded as expected.
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub geocoded_waypoints: Vec<GeocodedWaypoint>,
@@ -81,3 +89,140 @@ impl std::str::FromStr for Response {
         simd_json::serde::from_slice(&mut bytes)
     }
 }
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the geocoding details of the request's origin, which is the
+    /// first element of `geocoded_waypoints`.
+    #[must_use]
+    pub fn geocoded_origin(&self) -> Option<&GeocodedWaypoint> {
+        self.geocoded_waypoints.first()
+    } // fn
+
+    /// Returns the geocoding details of the request's destination, which is
+    /// the last element of `geocoded_waypoints`.
+    #[must_use]
+    pub fn geocoded_destination(&self) -> Option<&GeocodedWaypoint> {
+        // A response with only one element can't have both an origin and a
+        // destination:
+        if self.geocoded_waypoints.len() < 2 {
+            return None;
+        } // if
+        self.geocoded_waypoints.last()
+    } // fn
+
+    /// Returns the locations that were not found, or that only partially
+    /// matched, along with their index in `geocoded_waypoints`. Index `0` is
+    /// the origin, and the last index is the destination.
+    /// ```rust
+    /// for (index, waypoint) in directions.inexact_waypoints() {
+    ///     println!("Location #{index} was not matched exactly: {waypoint:?}");
+    /// }
+    /// ```
+    pub fn inexact_waypoints(&self) -> impl Iterator<Item = (usize, &GeocodedWaypoint)> {
+        self.geocoded_waypoints
+            .iter()
+            .enumerate()
+            .filter(|(_index, waypoint)| !waypoint.is_ok() || waypoint.is_partial_match())
+    } // fn
+
+    /// Returns the travel modes that are available between the request's
+    /// locations, when no routes were found for the requested travel mode.
+    /// For example, a transit request in an area without public transit may
+    /// suggest driving or walking instead.
+    ///
+    /// This is the `available_travel_modes` field, as an `Option`. `None` is
+    /// returned if Google did not suggest any travel modes, which is the case
+    /// for every response that has routes, and for requests that have `via:`
+    /// waypoints.
+    /// ```rust
+    /// if directions.routes.is_empty() {
+    ///     if let Some(travel_modes) = directions.suggested_travel_modes() {
+    ///         println!("No routes found. Try {travel_modes:?} instead.");
+    ///     } // if
+    /// } // if
+    /// ```
+
+    #[must_use]
+    pub fn suggested_travel_modes(&self) -> Option<&[TravelMode]> {
+        if self.available_travel_modes.is_empty() {
+            None
+        } else {
+            Some(&self.available_travel_modes)
+        } // if
+    } // fn
+
+    /// A helper function for selecting the fastest of the returned routes.
+    /// Google only returns more than one route when alternatives have been
+    /// requested using the `with_alternatives()` method. The duration of a
+    /// route is the sum of the durations of its legs, not taking traffic into
+    /// account. If no routes were returned, this function will return `None`.
+    /// ```rust
+    /// let fastest_route = directions.shortest_by_duration();
+    /// ```
+
+    #[must_use]
+    pub fn shortest_by_duration(&self) -> Option<&Route> {
+        self.routes.iter().min_by_key(|route| {
+            route
+                .legs
+                .iter()
+                .fold(chrono::Duration::zero(), |duration, leg| duration + leg.duration.value)
+        }) // min_by_key
+    } // fn
+
+    /// A helper function for selecting the best of the returned routes by any
+    /// measure. The best route is the one with the lowest key. If several
+    /// routes share the lowest key, the first of them is returned, since
+    /// Google returns its recommended route first. If no routes were
+    /// returned, this function will return `None`.
+    /// ```rust
+    /// let shortest_route = directions.best_by(|route| route.total_distance_meters());
+    /// ```
+
+    pub fn best_by<K: Ord>(&self, mut key: impl FnMut(&Route) -> K) -> Option<&Route> {
+        self.routes.iter().min_by_key(|route| key(route))
+    } // fn
+
+    /// A helper function for selecting the route with the fewest steps, which
+    /// is usually the one with the simplest directions to follow. Transit
+    /// substeps are not counted. If no routes were returned, this function
+    /// will return `None`.
+    /// ```rust
+    /// let simplest_route = directions.fewest_steps();
+    /// ```
+
+    #[must_use]
+    pub fn fewest_steps(&self) -> Option<&Route> {
+        self.best_by(|route| route.all_steps().count())
+    } // fn
+
+    /// A helper function for selecting the route with the fewest tolls.
+    ///
+    /// Google does not report the tolls of a route directly, so this is a
+    /// heuristic. Routes without a warning that mentions tolls, such as "This
+    /// route has tolls.", are preferred. Then, the route with the fewest steps
+    /// whose instructions mention a toll, such as "Toll road", is selected.
+    /// Google's warnings and instructions are in the request's language, so
+    /// the heuristic only works for English. To avoid tolls entirely, use
+    /// `with_restrictions()` on the request instead. If no routes were
+    /// returned, this function will return `None`.
+    /// ```rust
+    /// let cheapest_route = directions.least_tolls();
+    /// ```
+
+    #[must_use]
+    pub fn least_tolls(&self) -> Option<&Route> {
+        let mentions_toll = |text: &str| text.to_lowercase().contains("toll");
+
+        self.best_by(|route| {
+            let has_toll_warning = route.warnings.iter().any(|warning| mentions_toll(warning));
+            let toll_steps = route
+                .all_steps()
+                .filter(|step| step.html_instructions.as_deref().is_some_and(mentions_toll))
+                .count();
+            (has_toll_warning, toll_steps)
+        }) // best_by
+    } // fn
+} // impl
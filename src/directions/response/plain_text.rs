@@ -0,0 +1,88 @@
+//! Converts the HTML markup that Google includes in some Directions API fields,
+//! such as a step's `html_instructions`, into plain text.
+
+// -----------------------------------------------------------------------------
+//
+/// Strips the tags from an HTML fragment and decodes its character entities.
+///
+/// Block-level tags (`<div>`, `<p>`, `<br>`, and `<li>`) start a new line so
+/// that the sentences they contain are not run together. Runs of whitespace
+/// are collapsed into a single space, and empty lines are removed.
+
+pub fn html_to_plain_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(character) = rest.chars().next() {
+        // Tags are dropped. Block-level tags are replaced with a line break. A
+        // `<` without a closing `>` is not a tag, and is kept as-is:
+        if character == '<' {
+            if let Some(end) = rest.find('>') {
+                let name = rest[1..end]
+                    .trim_start_matches('/')
+                    .split(|c: char| c.is_whitespace() || c == '/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_ascii_lowercase();
+                if matches!(name.as_str(), "div" | "p" | "br" | "li") {
+                    text.push('\n');
+                } // if
+                rest = &rest[end + 1..];
+                continue;
+            } // if
+        } // if
+
+        // Character entities are decoded. An unrecognized entity is kept
+        // as-is:
+        if character == '&' {
+            if let Some((decoded, length)) = decode_entity(rest) {
+                text.push(decoded);
+                rest = &rest[length..];
+                continue;
+            } // if
+        } // if
+
+        text.push(character);
+        rest = &rest[character.len_utf8()..];
+    } // while
+
+    text.lines()
+        .map(|line| line.split_whitespace().collect::<Vec<&str>>().join(" "))
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<String>>()
+        .join("\n")
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes the character entity at the start of `input`, for example `&amp;`,
+/// `&#39;` or `&#x27;`. Returns the decoded character and the length of the
+/// entity in bytes, or `None` if the input does not start with a recognized
+/// entity.
+
+fn decode_entity(input: &str) -> Option<(char, usize)> {
+    // Entities are short. Give up if the terminating `;` is not found soon:
+    let (end, _semicolon) = input
+        .char_indices()
+        .take(12)
+        .find(|(_index, character)| *character == ';')?;
+
+    let decoded = match &input[1..end] {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        name => {
+            let number = name.strip_prefix('#')?;
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hexadecimal) => u32::from_str_radix(hexadecimal, 16).ok()?,
+                None => number.parse().ok()?,
+            }; // match
+            char::from_u32(code)?
+        } // name
+    }; // match
+
+    Some((decoded, end + 1))
+} // fn
@@ -1,11 +1,12 @@
 //! A single route containing a set of legs.
 
 use crate::directions::response::{
-    leg::Leg,
+    leg::{steps_to_string, Leg},
     overview_polyline::OverviewPolyline,
+    step::Step,
     transit_fare::TransitFare
 };
-use crate::types::Bounds;
+use crate::types::{Bounds, LatLng, Money};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -20,7 +21,10 @@ use serde::{Deserialize, Serialize};
 pub struct Route {
     /// The bounds for this route.
     pub bounds: Bounds,
-    /// Copyrights text to be displayed for this route.
+    /// Copyrights text to be displayed for this route. Google's terms of
+    /// service require this text to be shown to your users along with the
+    /// route. It is empty if Google did not return copyrights for the route.
+    #[serde(default)]
     pub copyrights: String,
     /// If present, contains the total fare (that is, the total ticket costs) on
     /// this route. This property is only returned for transit requests and only
@@ -46,7 +50,8 @@ pub struct Route {
     /// and disambiguating the route from alternatives.
     pub summary: String,
     /// Contains an array of warnings to be displayed when showing these
-    /// directions. You must handle and display these warnings yourself.
+    /// directions. You must handle and display these warnings yourself. It is
+    /// empty if Google did not return warnings for the route.
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub warnings: Vec<String>,
@@ -94,6 +99,22 @@ impl Route {
         } // match
     } // fn
 
+    /// A helper function for destructuring (or serializing) the `copyrights`
+    /// field. If the _copyrights_ text is populated, this function will return
+    /// the _copyrights_ text in the `String` format. If the _copyrights_ text
+    /// is empty, this function will return `None`.
+    /// ```rust
+    /// let copyrights = route.get_copyrights();
+    /// ```
+
+    #[must_use]
+    pub fn get_copyrights(&self) -> Option<&String> {
+        match &*self.copyrights {
+            "" => None,
+            _ => Some(&self.copyrights),
+        } // match
+    } // fn
+
     /// A helper function for destructuring (or serializing) the optional `fare`
     /// field. If the _fare_ struct is populated, this function will return the
     /// _currency_ code in the `String` format. If the _fare_ struct is empty,
@@ -120,6 +141,19 @@ impl Route {
         self.fare.as_ref().map(|fare| fare.value)
     } // fn
 
+    /// A helper function for destructuring the optional `fare` field. If the
+    /// _fare_ struct is populated, this function will return the fare as an
+    /// amount of `Money`. If the _fare_ struct is empty, this function will
+    /// return `None`.
+    /// ```rust
+    /// let fare = route.get_fare_money();
+    /// ```
+
+    #[must_use]
+    pub fn get_fare_money(&self) -> Option<Money> {
+        self.fare.as_ref().map(TransitFare::money)
+    } // fn
+
     /// A helper function for destructuring (or serializing) the optional `fare`
     /// field. If the _fare_ struct is populated, this function will return the
     /// _text_ `String`. If the _fare_ struct is empty, this function will
@@ -172,6 +206,147 @@ impl Route {
             )
         } // if
     } // fn
+
+    /// Returns the total distance of the route in metres, which is the sum of
+    /// the distances of its legs.
+    /// ```rust
+    /// let meters = route.total_distance_meters();
+    /// ```
+
+    #[must_use]
+    pub fn total_distance_meters(&self) -> u64 {
+        self.legs.iter().map(|leg| u64::from(leg.distance.value)).sum()
+    } // fn
+
+    /// Returns the total duration of the route in seconds, which is the sum of
+    /// the durations of its legs. Traffic conditions are not taken into
+    /// account, see `total_duration_in_traffic_seconds()`.
+    /// ```rust
+    /// let seconds = route.total_duration_seconds();
+    /// ```
+
+    #[must_use]
+    pub fn total_duration_seconds(&self) -> u64 {
+        self.legs
+            .iter()
+            .map(|leg| u64::try_from(leg.duration.value.num_seconds()).unwrap_or_default())
+            .sum()
+    } // fn
+
+    /// Returns the total duration of the route in seconds, taking traffic
+    /// conditions into account. Google only provides this for driving
+    /// directions that have a departure time. If any of the route's legs has
+    /// no `duration_in_traffic`, this function will return `None`.
+    /// ```rust
+    /// let seconds = route.total_duration_in_traffic_seconds();
+    /// ```
+
+    #[must_use]
+    pub fn total_duration_in_traffic_seconds(&self) -> Option<u64> {
+        if self.legs.is_empty() {
+            return None;
+        } // if
+
+        self.legs
+            .iter()
+            .map(|leg| {
+                leg.duration_in_traffic
+                    .as_ref()
+                    .map(|duration| u64::try_from(duration.value.num_seconds()).unwrap_or_default())
+            }) // map
+            .sum()
+    } // fn
+
+    /// Returns an iterator over every step of the route, in order: the steps
+    /// of the first leg, then the steps of the second leg, and so on. The
+    /// substeps of transit steps are not included, they remain in each step's
+    /// `steps` field.
+    /// ```rust
+    /// for step in route.all_steps() {
+    ///     println!("{}", step.plain_instructions().unwrap_or_default());
+    /// } // for
+    /// ```
+
+    pub fn all_steps(&self) -> impl Iterator<Item = &Step> {
+        self.legs.iter().flat_map(|leg| leg.steps.iter())
+    } // fn
+
+    /// Returns an iterator over every step of the route, in the same order as
+    /// `all_steps()`, with the index of the leg in `legs` that each step
+    /// belongs to. A change of index marks the start of a new leg, for example
+    /// at a waypoint.
+    /// ```rust
+    /// for (leg_index, step) in route.all_steps_with_leg_index() { ... }
+    /// ```
+
+    pub fn all_steps_with_leg_index(&self) -> impl Iterator<Item = (usize, &Step)> {
+        self.legs
+            .iter()
+            .enumerate()
+            .flat_map(|(leg_index, leg)| leg.steps.iter().map(move |step| (leg_index, step)))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Route {
+    /// Summarizes the route on a single line: the origin and the destination,
+    /// the route's description (if Google provided one), and the total
+    /// distance, duration, and number of steps over all of its legs. Totals
+    /// are always shown in metric units. This is intended for logging and
+    /// debugging, use `Leg::summary()` for each leg.
+    ///
+    /// For example, `Toronto, ON, Canada → Montreal, QC, Canada via ON-401 E:
+    /// 541.2 km, 5 h 22 min, 23 steps`.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let origin = self.legs.first().map_or("", |leg| leg.start_address.as_str());
+        let destination = self.legs.last().map_or("", |leg| leg.end_address.as_str());
+        write!(f, "{origin} → {destination}")?;
+
+        if !self.summary.is_empty() {
+            write!(f, " via {summary}", summary = self.summary)?;
+        } // if
+
+        let meters = self.total_distance_meters();
+        let seconds = self.total_duration_seconds();
+        let steps: usize = self.legs.iter().map(|leg| leg.steps.len()).sum();
+
+        // Distances are shown in metres, or in kilometres to one decimal
+        // place, and durations are rounded to the nearest minute:
+        let distance = if meters < 1_000 {
+            format!("{meters} m")
+        } else {
+            format!("{km}.{tenths} km", km = meters / 1_000, tenths = meters % 1_000 / 100)
+        }; // if
+        let minutes = (seconds + 30) / 60;
+        let duration = if minutes < 60 {
+            format!("{minutes} min")
+        } else {
+            format!("{hours} h {minutes} min", hours = minutes / 60, minutes = minutes % 60)
+        }; // if
+
+        write!(f, ": {distance}, {duration}, {steps}", steps = steps_to_string(steps))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Route {
+    /// Decodes the route's `overview_polyline` into a list of coordinates,
+    /// using Google's [polyline
+    /// algorithm](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+    /// with a precision of 5 decimal places. The overview is a smoothed
+    /// approximation of the whole route, suitable for plotting on a map.
+    ///
+    /// An empty list is returned if the polyline could not be decoded.
+    /// ```rust
+    /// let path = route.decoded_overview();
+    /// ```
+
+    #[must_use]
+    pub fn decoded_overview(&self) -> Vec<LatLng> {
+        crate::polyline::decode(&self.overview_polyline.points).unwrap_or_default()
+    } // fn
 } // impl
 
 // -----------------------------------------------------------------------------
@@ -5,6 +5,7 @@ use crate::directions::response::{
     directions_distance::DirectionsDistance,
     directions_duration::DirectionsDuration,
     driving_maneuver::DrivingManeuver,
+    plain_text::html_to_plain_text,
     polyline::Polyline,
     transit_details::TransitDetails
 };
@@ -64,7 +65,7 @@ pub struct Step {
     /// "transit". The inner steps array is of the same type as steps.
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub steps: Vec<Step>,
+    pub steps: Vec<Self>,
     /// Transit-specific details about this step. This property will be
     /// undefined unless the travel mode of this step is `TravelMode::Transit`.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -87,6 +88,40 @@ impl Step {
     pub fn get_maneuver(&self) -> Option<String> {
         self.maneuver.as_ref().map(String::from)
     } // fn
+
+    /// Returns the step's `html_instructions` as plain text, with the HTML
+    /// tags removed and character entities such as `&amp;` decoded. This is
+    /// useful for displaying instructions in a terminal or a mobile UI.
+    ///
+    /// Each `<div>` block, which Google uses for notes such as "Destination
+    /// will be on the right", is placed on its own line. The original markup
+    /// remains available in the `html_instructions` field. If the step has no
+    /// instructions, this function will return `None`.
+    /// ```rust
+    /// let instructions = step.plain_instructions();
+    /// ```
+
+    #[must_use]
+    pub fn plain_instructions(&self) -> Option<String> {
+        self.html_instructions
+            .as_deref()
+            .map(html_to_plain_text)
+    } // fn
+
+    /// Decodes the step's `polyline` into a list of coordinates, using
+    /// Google's [polyline
+    /// algorithm](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+    /// with a precision of 5 decimal places.
+    ///
+    /// An empty list is returned if the polyline could not be decoded.
+    /// ```rust
+    /// let path = step.decoded_polyline();
+    /// ```
+
+    #[must_use]
+    pub fn decoded_polyline(&self) -> Vec<LatLng> {
+        crate::polyline::decode(&self.polyline.points).unwrap_or_default()
+    } // fn
 } // impl
 
 // -----------------------------------------------------------------------------
@@ -11,6 +11,8 @@ pub struct TransitAgency {
     /// Contains the phone number of the transit agency.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub phone: Option<String>,
-    /// Contains the URL for the transit agency.
+    /// Contains the URL for the transit agency. Empty if the agency does not
+    /// have a website.
+    #[serde(default)]
     pub url: String,
 } // struct
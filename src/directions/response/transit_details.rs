@@ -24,7 +24,8 @@ pub struct TransitDetails {
     pub departure_time: TransitTime,
     /// Specifies the direction in which to travel on this line, as it is marked
     /// on the vehicle or at the departure stop. This will often be the terminus
-    /// station.
+    /// station. Empty if Google did not return a headsign for this line.
+    #[serde(default)]
     pub headsign: String,
     /// Specifies the expected number of seconds between departures from the
     /// same stop at this time. For example, with a headway value of 600, you
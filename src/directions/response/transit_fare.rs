@@ -1,6 +1,7 @@
 //! Contains the total fare (that is, the total ticket costs) on this route.
 
-use crate::directions::response::transit_currency::TransitCurrency;
+use crate::serde::number_or_string_to_decimal::number_or_string_to_optional_decimal;
+use crate::types::{Currency, Money};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -9,12 +10,73 @@ use serde::{Deserialize, Serialize};
 /// routes where fare information is available for all transit legs.
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "GoogleTransitFare")]
 pub struct TransitFare {
     /// An [ISO 4217 currency code](https://en.wikipedia.org/wiki/ISO_4217)
     /// indicating the currency that the amount is expressed in.
-    pub currency: TransitCurrency,
+    pub currency: Currency,
     /// The total fare amount, formatted in the requested language.
     pub text: String,
-    /// The total fare amount, in the currency specified above.
+    /// The total fare amount, in the currency specified above. Google may
+    /// return this amount as either a number or a string, both are accepted.
+    /// If Google omits the amount, it is parsed from the `text` field.
     pub value: Decimal,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+impl TransitFare {
+    /// Returns the total fare as an amount of money.
+    ///
+    /// ```rust
+    /// println!("Fare: {}", fare.money()); // Fare: 6.00 USD
+    /// ```
+
+    #[must_use]
+    pub fn money(&self) -> Money {
+        Money::new(self.currency.clone(), self.value)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&TransitFare> for Money {
+    /// Converts a `TransitFare` into an amount of money.
+    fn from(transit_fare: &TransitFare) -> Self {
+        transit_fare.money()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// A transit fare, as it is returned by Google. Either the `value` or the
+/// `text` may be used to determine the amount.
+
+#[derive(Deserialize)]
+struct GoogleTransitFare {
+    currency: Currency,
+    #[serde(default)]
+    text: String,
+    #[serde(default, deserialize_with = "number_or_string_to_optional_decimal")]
+    value: Option<Decimal>,
+} // struct
+
+impl std::convert::TryFrom<GoogleTransitFare> for TransitFare {
+    type Error = String;
+    /// Converts a fare that was returned by Google into a `TransitFare`,
+    /// falling back to the formatted `text` when no numeric `value` is given.
+    fn try_from(fare: GoogleTransitFare) -> Result<Self, Self::Error> {
+        let value = match fare.value {
+            Some(value) => value,
+            None => Money::from_text(fare.currency.clone(), &fare.text)
+                .map(|money| money.amount)
+                .ok_or_else(|| format!("could not determine the fare amount from `{}`", fare.text))?,
+        }; // match
+
+        Ok(Self {
+            currency: fare.currency,
+            text: fare.text,
+            value,
+        }) // TransitFare
+    } // fn
+} // impl
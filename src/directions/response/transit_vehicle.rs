@@ -7,13 +7,17 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct TransitVehicle {
-    /// Contains the URL for an icon associated with this vehicle type.
+    /// Contains the URL for an icon associated with this vehicle type. Empty
+    /// if Google did not return an icon.
+    #[serde(default)]
     pub icon: String,
     /// Contains the URL for the icon associated with this vehicle type, based
     /// on the local transport signage.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub local_icon: Option<String>,
-    /// Contains the name of the vehicle on this line. eg. "Subway."
+    /// Contains the name of the vehicle on this line. eg. "Subway." Empty if
+    /// Google did not return a name.
+    #[serde(default)]
     pub name: String,
     /// Contains the type of vehicle that runs on this line. See the [Vehicle
     /// Type](https://developers.google.com/maps/documentation/directions/intro#VehicleType)
@@ -36,6 +36,16 @@ pub enum TravelMode {
     /// to the current time). You can also optionally include a `transit_mode`
     /// and/or a `transit_routing_preference`.
     Transit = 3,
+
+    /// If the travel mode is not recognized by
+    /// [serde](https://crates.io/crates/serde) when reading data from
+    /// Google it will be assigned to this `Other` variant.
+    ///
+    /// As new travel modes are added to Google Maps, they must also be added to
+    /// this crate. However, in the meantime, the `Other` catch-all variant
+    /// allows `serde` to read data from Google without producing an error
+    /// until the new variant added to this `enum`.
+    Other(String) = 4,
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -43,12 +53,12 @@ pub enum TravelMode {
 impl<'de> Deserialize<'de> for TravelMode {
     /// Manual implementation of `Deserialize` for `serde`. This will take
     /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    /// Unrecognized values are deserialized into the `Other` variant.
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let string = String::deserialize(deserializer)?;
-        match Self::try_from(string.as_str()) {
-            Ok(variant) => Ok(variant),
-            Err(error) => Err(serde::de::Error::custom(error.to_string())),
-        } // match
+        // A value that this crate does not recognize yet is kept in the
+        // `Other` variant, rather than failing the entire response:
+        Ok(Self::try_from(string.as_str()).unwrap_or(Self::Other(string)))
     } // fn
 } // impl
 
@@ -66,16 +76,17 @@ impl Serialize for TravelMode {
 
 // -----------------------------------------------------------------------------
 
-impl std::convert::From<&TravelMode> for &str {
+impl<'a> std::convert::From<&'a TravelMode> for &'a str {
     /// Converts a `TravelMode` enum to a `String` that contains a [travel
     /// mode](https://developers.google.com/maps/documentation/directions/intro#TravelModes)
     /// code.
-    fn from(travel_mode: &TravelMode) -> Self {
+    fn from(travel_mode: &'a TravelMode) -> Self {
         match travel_mode {
             TravelMode::Bicycling => "BICYCLING",
             TravelMode::Driving => "DRIVING",
             TravelMode::Transit => "TRANSIT",
             TravelMode::Walking => "WALKING",
+            TravelMode::Other(string) => string,
         } // match
     } // fn
 } // impl
@@ -151,12 +162,13 @@ impl TravelMode {
     /// Formats a `TravelMode` enum into a string that is presentable to the
     /// end user.
     #[must_use]
-    pub const fn display(&self) -> &str {
+    pub fn display(&self) -> &str {
         match self {
             Self::Bicycling => "Bicycling",
             Self::Driving => "Driving",
             Self::Transit => "Transit",
             Self::Walking => "Walking",
+            Self::Other(string) => string,
         } // match
     } // fn
 } // impl
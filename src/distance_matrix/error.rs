@@ -3,6 +3,7 @@
 // -----------------------------------------------------------------------------
 
 use crate::distance_matrix::response::status::Status;
+#[cfg(feature = "diagnostics")]
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -10,11 +11,20 @@ use thiserror::Error;
 //
 /// Errors that may be produced by the Google Maps Distance Matrix API client.
 
-#[derive(Debug, Diagnostic, Error)]
-#[diagnostic(code(google_maps::distance_matrix::error), url(docsrs))]
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+#[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::distance_matrix::error), url(docsrs)))]
 pub enum Error {
     /// An arrival time may only be specified in Transit travel mode.
     ArrivalTimeIsForTransitOnly(String, String),
+    /// A block of a matrix that was split across several requests failed.
+    /// Contains the indices of the origins and destinations in the failed
+    /// block, and the error that the block failed with.
+    BlockFailed {
+        origins: std::ops::Range<usize>,
+        destinations: std::ops::Range<usize>,
+        error: Box<crate::error::Error>,
+    },
     /// Alternatives may not be requested when waypoints are specified.
     EitherAlternativesOrWaypoints(usize),
     /// An departure time may not be specified when an arrival time is
@@ -29,6 +39,15 @@ pub enum Error {
     GoogleMapsService(Status, Option<String>),
     /// The HTTP request was unsuccessful.
     HttpUnsuccessful(String),
+    /// The HTTP request was unsuccessful. Contains the HTTP status code, the
+    /// headers that help explain the failure (such as `Retry-After` and
+    /// Google's `X-Goog-*` headers), and the truncated response body.
+    #[cfg(feature = "reqwest")]
+    HttpUnsuccessfulResponse {
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String,
+    },
     /// API client library attempted to parse a string that contained an invalid
     /// avoid/restrictions code. See
     /// `google_maps\src\directions\request\avoid.rs` for more information.
@@ -89,6 +108,19 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SimdJson(simd_json::Error),
+    /// The dependency library Serde JSON could not parse the response from the
+    /// server. The response body is included to help with debugging. It is
+    /// truncated to the length set by `GoogleMapsClient::with_error_body_length`.
+    SimdJsonWithBody(simd_json::Error, String),
+    /// The service responded successfully, but not with JSON, for example
+    /// with an HTML error page from a proxy or a sign-in page. Contains the
+    /// response's `Content-Type` header, and the response body truncated to the
+    /// length set by `GoogleMapsClient::with_error_body_length`.
+    #[cfg(feature = "reqwest")]
+    UnexpectedContentType {
+        content_type: String,
+        body_snippet: String,
+    },
     /// Too many waypoints specified.
     TooManyWaypoints(usize),
     /// Transit mode may only be specified in Transit travel mode.
@@ -109,6 +141,10 @@ impl std::fmt::Display for Error {
                 The with_arrival_time() method may only be used when with_travel_mode() is set to `TravelMode::Transit`. \
                 The travel mode is set to `{travel_mode}` and the arrival time is set to `{arrival_time}`. \
                 Try again either with a travel mode of `TravelMode::Transit` or no arrival time."),
+            Self::BlockFailed { origins, destinations, error } => write!(f,
+                "Google Maps Distance Matrix API client: \
+                The matrix was split across several requests, and the request for origins `{origins:?}` \
+                and destinations `{destinations:?}` failed: {error}"),
             Self::EitherAlternativesOrWaypoints(waypoint_count) => write!(f,
                 "Google Maps Distance Matrix API client: \
                 The with_alternatives() method cannot be set to `true` if with_waypoints() has been set. \
@@ -164,12 +200,19 @@ impl std::fmt::Display for Error {
                     Status::UnknownError => write!(f,
                         "Google Maps Distance Matrix API service: \
                         Unknown error."),
+                    Status::Other(status_code) => write!(f, "Google Maps Distance Matrix API service: \
+                        Unrecognized status `{status_code}`."),
                 } // match
             }, // match
             Self::HttpUnsuccessful(status) => write!(f,
                 "Google Maps Distance Matrix API client: \
                 Could not successfully query the Google Cloud Platform service. \
                 The service last responded with a `{status}` status."),
+            #[cfg(feature = "reqwest")]
+            Self::HttpUnsuccessfulResponse { status, body, .. } => write!(f,
+                "Google Maps Distance Matrix API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status: `{body}`"),
             Self::InvalidAvoidCode(avoid_code) => write!(f,
                 "Google Maps Distance Matrix API client: \
                 `{avoid_code}` is not a valid restrictions code. \
@@ -237,6 +280,13 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "reqwest")]
             Self::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Self::SimdJson(error) => write!(f, "Google Maps Distance Matrix API client in the Serde JSON library: {error}"),
+            Self::SimdJsonWithBody(error, body) => write!(f, "Google Maps Distance Matrix API client in the Serde JSON library: {error}. \
+                The response body was: `{body}`"),
+            #[cfg(feature = "reqwest")]
+            Self::UnexpectedContentType { content_type, body_snippet } => write!(f,
+                "Google Maps Distance Matrix API client: \
+                The service responded with `{content_type}` content instead of JSON. \
+                This is usually an error page. The response body was: `{body_snippet}`"),
             Self::TooManyWaypoints(waypoint_count) => write!(f,
                 "Google Maps Distance Matrix API client: \
                 The maximum allowed number of waypoints is 25 plus the origin and destination. \
@@ -282,3 +332,46 @@ impl From<simd_json::Error> for Error {
         Self::SimdJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl crate::client::HttpError for Error {
+    /// Reports the HTTP-level failures of the retry loop as Google Maps
+    /// Distance Matrix API errors.
+    fn unexpected_content_type(content_type: String, body_snippet: String) -> Self {
+        Self::UnexpectedContentType {
+            content_type,
+            body_snippet,
+        } // UnexpectedContentType
+    } // fn
+
+    fn http_unsuccessful_response(
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String
+    ) -> Self {
+        Self::HttpUnsuccessfulResponse {
+            status,
+            headers,
+            body,
+        } // HttpUnsuccessfulResponse
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(message: String) -> Self {
+        Self::ReqwestMessage(message)
+    } // fn
+} // impl
+
+#[cfg(feature = "reqwest")]
+impl crate::client::JsonError for Error {
+    /// Reports a response body that could not be parsed as a Google Maps
+    /// Distance Matrix API error.
+    fn simd_json_with_body(error: simd_json::Error, body: String) -> Self {
+        Self::SimdJsonWithBody(error, body)
+    } // fn
+} // impl
@@ -84,6 +84,19 @@ pub mod response;
 pub const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/distancematrix";
 pub const OUTPUT_FORMAT: &str = "json"; // json or xml
 
+/// The maximum number of origins that Google accepts in a single request.
+#[cfg(feature = "reqwest")]
+const MAX_ORIGINS: usize = 25;
+
+/// The maximum number of destinations that Google accepts in a single request.
+#[cfg(feature = "reqwest")]
+const MAX_DESTINATIONS: usize = 25;
+
+/// The maximum number of elements (origins × destinations) that Google accepts
+/// in a single request.
+#[cfg(feature = "reqwest")]
+const MAX_ELEMENTS: usize = 100;
+
 // -----------------------------------------------------------------------------
 
 pub use crate::directions::{
@@ -0,0 +1,36 @@
+use crate::distance_matrix::{error::Error, request::Request};
+use crate::fingerprint::fingerprint;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a stable fingerprint of the query you've built, for use as a
+    /// cache key or to recognize the request in logs.
+    ///
+    /// ## Description
+    ///
+    /// The fingerprint is a 64-bit hash of the URL returned by `query_url()`.
+    /// The API key is not part of the fingerprint, and query string parameters
+    /// are sorted first, so two requests that ask Google for the same thing
+    /// have the same fingerprint, whatever order their builder methods were
+    /// called in. Restrictions and transit modes may also be added in any
+    /// order, but the order of the origins and destinations is kept, since it
+    /// orders the rows and elements of the response. The hash does not depend
+    /// on the platform or Rust version, so fingerprints may be stored and
+    /// compared between runs.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the query could not be built, as `query_url()`
+    ///   does.
+
+    pub fn fingerprint(&'a mut self) -> Result<u64, Error> {
+        self.query_url().map(|url| fingerprint(&url))
+    } // fn
+} // impl
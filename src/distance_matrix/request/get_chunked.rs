@@ -0,0 +1,145 @@
+use crate::distance_matrix::{
+    error::Error as DistanceMatrixError, request::Request as DistanceMatrixRequest,
+    response::row::Row, response::status::Status as DistanceMatrixStatus,
+    response::Response as DistanceMatrixResponse, MAX_DESTINATIONS, MAX_ELEMENTS, MAX_ORIGINS,
+}; // use crate::distance_matrix
+use crate::client::remaining_deadline;
+use crate::error::Error as GoogleMapsError;
+
+// -----------------------------------------------------------------------------
+
+impl DistanceMatrixRequest<'_> {
+    /// Returns `true` if the matrix exceeds any of Google's limits on the
+    /// number of origins, destinations, or elements in a single request.
+
+    pub(super) fn exceeds_limits(&self) -> bool {
+        self.origins.len() > MAX_ORIGINS
+            || self.destinations.len() > MAX_DESTINATIONS
+            || self.origins.len() * self.destinations.len() > MAX_ELEMENTS
+    } // fn
+
+    /// Splits the matrix into blocks that are within Google's limits, performs
+    /// an HTTP get request for each block, and reassembles the responses into
+    /// a single response.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub(super) async fn get_chunked(&self) -> Result<DistanceMatrixResponse, GoogleMapsError> {
+        // The deadline applies to the request as a whole, so each chunk is
+        // given whatever time remains. A chunk is not sent once none remains:
+        let started = std::time::Instant::now();
+
+        // Use as many destinations per block as possible, then as many origins
+        // as the element limit allows:
+        let destinations_per_block = self.destinations.len().clamp(1, MAX_DESTINATIONS);
+        let origins_per_block = (MAX_ELEMENTS / destinations_per_block).clamp(1, MAX_ORIGINS);
+
+        let mut origin_addresses: Vec<String> = Vec::with_capacity(self.origins.len());
+        let mut destination_addresses: Vec<String> = Vec::with_capacity(self.destinations.len());
+        let mut rows: Vec<Row> = Vec::with_capacity(self.origins.len());
+        let mut copyrights: Option<String> = None;
+        let mut warnings: Vec<String> = Vec::new();
+
+        for (origin_block, origins) in self.origins.chunks(origins_per_block).enumerate() {
+            let origin_start = origin_block * origins_per_block;
+            let origin_range = origin_start..origin_start + origins.len();
+
+            // Each block of origins produces one row per origin. The elements
+            // of each destination block are appended to these rows in order:
+            let mut block_rows: Vec<Row> = vec![Row { elements: Vec::new() }; origins.len()];
+
+            for (destination_block, destinations) in
+                self.destinations.chunks(destinations_per_block).enumerate()
+            {
+                let destination_start = destination_block * destinations_per_block;
+                let destination_range = destination_start..destination_start + destinations.len();
+
+                // Each block is sent as a separate request, using the same
+                // settings as this request:
+                let mut request = Self {
+                    client: self.client,
+                    destinations: destinations.to_vec(),
+                    origins: origins.to_vec(),
+                    arrival_time: self.arrival_time,
+                    departure_time: self.departure_time.clone(),
+                    language: self.language,
+                    region: self.region,
+                    restrictions: self.restrictions.clone(),
+                    traffic_model: self.traffic_model.clone(),
+                    transit_modes: self.transit_modes.clone(),
+                    transit_route_preference: self.transit_route_preference.clone(),
+                    travel_mode: self.travel_mode.clone(),
+                    unit_system: self.unit_system.clone(),
+                    chunking: false,
+                    backoff: self.backoff.clone(),
+                    max_retries: self.max_retries,
+                    timeout: self.timeout,
+                    deadline: remaining_deadline(self.deadline, started)?,
+                    query: None,
+                    validated: self.validated,
+                }; // DistanceMatrixRequest
+
+                let response = match request.build()?.get_single().await {
+                    Ok(response) => response,
+                    // Report the deadline of the request as a whole, rather than what
+                    // remained of it for this chunk:
+                    Err(GoogleMapsError::DeadlineExceeded(remaining)) => {
+                        let deadline = self.deadline.unwrap_or(remaining);
+                        return Err(GoogleMapsError::DeadlineExceeded(deadline));
+                    } // Err
+                    Err(error) => {
+                        tracing::error!(
+                            "block for origins {origin_range:?} and destinations \
+                            {destination_range:?} failed: {error}"
+                        );
+                        return Err(DistanceMatrixError::BlockFailed {
+                            origins: origin_range,
+                            destinations: destination_range,
+                            error: Box::new(error),
+                        })?;
+                    } // Err
+                }; // match
+
+                // The origin addresses are the same for every destination
+                // block, and the destination addresses are the same for every
+                // origin block, so each is only collected once:
+                if destination_block == 0 {
+                    origin_addresses.extend(response.origin_addresses);
+                } // if
+                if origin_block == 0 {
+                    destination_addresses.extend(response.destination_addresses);
+                } // if
+
+                // Every block is expected to carry the same copyrights and
+                // warnings, so they are not repeated in the combined response:
+                if copyrights.is_none() {
+                    copyrights = response.copyrights;
+                } // if
+                for warning in response.warnings {
+                    if !warnings.contains(&warning) {
+                        warnings.push(warning);
+                    } // if
+                } // for
+
+                for (block_row, row) in block_rows.iter_mut().zip(response.rows) {
+                    block_row.elements.extend(row.elements);
+                } // for
+            } // for
+
+            rows.extend(block_rows);
+        } // for
+
+        // Return reassembled response to caller:
+        Ok(DistanceMatrixResponse {
+            copyrights,
+            destination_addresses,
+            error_message: None,
+            origin_addresses,
+            rows,
+            status: DistanceMatrixStatus::Ok,
+            warnings,
+        }) // DistanceMatrixResponse
+    } // fn
+} // impl
@@ -0,0 +1,76 @@
+use crate::distance_matrix::{
+    error::Error as DistanceMatrixError, request::Request as DistanceMatrixRequest,
+    OUTPUT_FORMAT, SERVICE_URL,
+}; // use crate::distance_matrix
+use crate::client::{Method, RetrySettings, StatusEnvelope};
+use crate::distance_matrix::response::status::Status as DistanceMatrixStatus;
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+
+// -----------------------------------------------------------------------------
+
+impl DistanceMatrixRequest<'_> {
+    /// Performs the HTTP get request and returns the raw response body to the
+    /// caller, without deserializing it.
+    ///
+    /// This is an escape hatch for reading fields that this crate does not
+    /// model yet. The request is checked and retried like `get()`: only
+    /// Google's `status` and `error_message` are parsed, so that an
+    /// unsuccessful status is returned as an error, and `UNKNOWN_ERROR` and
+    /// `OVER_QUERY_LIMIT` are retried. The rest of the body is not
+    /// interpreted.
+    ///
+    /// Requests that exceed Google's limits are not split into several
+    /// requests.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(api = %Api::DistanceMatrix, attempts, duration_ms, status)
+    )]
+    pub async fn get_raw(&self) -> Result<String, GoogleMapsError> {
+        // Build the URL stem for the HTTP get request:
+        let mut url = format!("{SERVICE_URL}/{OUTPUT_FORMAT}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(DistanceMatrixError::QueryNotBuilt)?,
+        } // match
+
+        tracing::info!("making HTTP GET request to Google Maps Distance Matrix API");
+
+        // Observe any rate limiting before executing the request, and retry
+        // it according to its retry settings:
+        let response = self
+            .client
+            .execute_with_retry(
+                Method::Get,
+                &url,
+                RetrySettings {
+                    api: Api::DistanceMatrix,
+                    backoff: self.backoff.as_ref(),
+                    max_retries: self.max_retries,
+                    timeout: self.timeout,
+                    deadline: self.deadline,
+                }, // RetrySettings
+                "application/json",
+                |body| -> Result<String, backoff::Error<DistanceMatrixError>> {
+                    // Google's `status` is checked, but the body is returned to
+                    // the caller without deserializing it:
+                    let envelope: StatusEnvelope<DistanceMatrixStatus> = self.client.parse_json(&body)?;
+                    envelope.status.check(envelope.error_message.as_deref())?;
+                    Ok(body.text())
+                }, // classify
+            )
+            .await?;
+
+        // Return response to caller:
+        Ok(response)
+    } // fn
+} // impl
@@ -20,7 +20,7 @@ impl Request<'_> {
         client: &GoogleMapsClient,
         origins: Vec<Waypoint>,
         destinations: Vec<Waypoint>
-    ) -> Request {
+    ) -> Request<'_> {
         Request {
             // Required parameters:
             client,
@@ -37,6 +37,16 @@ impl Request<'_> {
             transit_route_preference: None,
             travel_mode: None,
             unit_system: None,
+            chunking: true,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
             validated: false,
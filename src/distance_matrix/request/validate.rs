@@ -13,7 +13,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn validate(&mut self) -> Result<&'a mut Request<'_>, Error> {
         if let Some(travel_mode) = &self.travel_mode {
             // If the transit mode is not set to TravelMode::Transit...
             if *travel_mode != TravelMode::Transit {
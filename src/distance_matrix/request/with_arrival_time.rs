@@ -15,6 +15,12 @@ impl<'a> Request<'a> {
     /// use either the `.with_departure_time()` or the `.with_arrival_time()`
     /// method, but not both together.
     ///
+    /// Google expects a Unix timestamp, so the `NaiveDateTime` is interpreted
+    /// as a UTC date & time. It is _not_ interpreted as the local time at the
+    /// origin, or on this machine. Passing a local time will shift the arrival
+    /// time by the local UTC offset, so a warning is logged through `tracing`.
+    /// Use `.with_arrival_time_utc()` to pass an unambiguous point in time.
+    ///
     /// ## Example
     ///
     /// * Arriving by January 1, 2019 at 12:00:00 AM:
@@ -27,6 +33,10 @@ impl<'a> Request<'a> {
         arrival_time: impl Into<NaiveDateTime>
     ) -> &'a mut Self {
         let arrival_time: NaiveDateTime = arrival_time.into();
+        tracing::warn!(
+            "arrival time `{arrival_time}` has no time zone and will be interpreted as UTC; \
+            use `with_arrival_time_utc` to specify the time zone"
+        );
         self.arrival_time = Some(arrival_time);
         self
     } // fn
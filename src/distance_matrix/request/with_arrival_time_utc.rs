@@ -0,0 +1,41 @@
+use crate::distance_matrix::request::Request;
+use chrono::{DateTime, Utc};
+
+impl<'a> Request<'a> {
+    /// Specifies the desired arrival time, as a point in time.
+    ///
+    /// ## Arguments
+    ///
+    /// * `arrival_time` ‧ The time the passenger should arrive at their final
+    ///   destination by. A `DateTime<Utc>`, or a `DateTime<Local>` or
+    ///   `DateTime<FixedOffset>`, which are converted automatically. Other time
+    ///   zones, such as a `chrono_tz::Tz`, may be converted with
+    ///   `.with_timezone(&Utc)`.
+    ///
+    /// ## Description
+    ///
+    /// Specifies the desired time of arrival for _transit_ distances. Unlike
+    /// `.with_arrival_time()`, the time zone is known, so the arrival time is
+    /// always converted to the correct Unix timestamp. You can use either a
+    /// departure time or an arrival time, but not both together.
+    ///
+    /// ## Example
+    ///
+    /// * Arriving by 9:00:00 AM in Toronto on January 1, 2030:
+    /// ```rust
+    /// .with_arrival_time_utc(
+    ///     chrono_tz::America::Toronto
+    ///         .with_ymd_and_hms(2030, 1, 1, 9, 0, 0)
+    ///         .unwrap()
+    ///         .with_timezone(&Utc)
+    /// )
+    /// ```
+
+    pub fn with_arrival_time_utc(
+        &'a mut self,
+        arrival_time: impl Into<DateTime<Utc>>
+    ) -> &'a mut Self {
+        self.arrival_time = Some(arrival_time.into().naive_utc());
+        self
+    } // fn
+} // impl
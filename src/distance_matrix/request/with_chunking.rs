@@ -0,0 +1,39 @@
+use crate::distance_matrix::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies whether a matrix that exceeds Google's limits is split across
+    /// several requests.
+    ///
+    /// ## Arguments
+    ///
+    /// * `chunking` ‧ When `true`, a matrix with more than 25 origins, more
+    ///   than 25 destinations, or more than 100 elements (origins ×
+    ///   destinations) is split into blocks that are within Google's limits.
+    ///   Each block is sent as a separate request, subject to the client's
+    ///   rate limiting, and the responses are reassembled into a single
+    ///   response in the original order of the origins and destinations. When
+    ///   `false`, the matrix is always sent in a single request, and Google
+    ///   will return an error if it is too large. Defaults to `true`.
+    ///
+    /// ## Description
+    ///
+    /// Note that each block is billed by Google as a separate request. If any
+    /// block fails, the error identifies the origins and destinations that
+    /// were in the failed block, and the responses of other blocks are
+    /// discarded.
+    ///
+    /// ## Example
+    ///
+    /// * Always send the matrix in a single request:
+    /// ```rust
+    /// .with_chunking(false)
+    /// ```
+
+    pub fn with_chunking(
+        &'a mut self,
+        chunking: bool
+    ) -> &'a mut Self {
+        self.chunking = chunking;
+        self
+    } // fn
+} // impl
@@ -0,0 +1,45 @@
+use crate::distance_matrix::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the maximum total time that the request may take, including
+    /// every retry and the delays between them.
+    ///
+    /// Unlike `with_timeout()`, which limits each HTTP request attempt, the
+    /// deadline is a hard ceiling on the whole request. Whichever expires
+    /// first wins: an attempt that times out is only retried if the deadline
+    /// has not yet been reached, and an attempt or retry delay that is in
+    /// progress when the deadline is reached is abandoned. The deadline starts
+    /// once the client's rate limit allows the request to be sent.
+    ///
+    /// ## Arguments
+    ///
+    /// * `deadline` ‧ The maximum duration of the request, including its
+    ///   retries. If this method is not called, the request is only limited by
+    ///   its retry policy and maximum number of retries.
+    ///
+    /// # Errors
+    ///
+    /// * The request returns a `GoogleMapsError::DeadlineExceeded` error if it
+    ///   did not complete within the deadline.
+    ///
+    /// ## Example
+    ///
+    /// * Give up on the request, and all of its retries, after 5 seconds:
+    /// ```rust
+    /// .with_deadline(std::time::Duration::from_secs(5))
+    /// ```
+
+    pub fn with_deadline(
+        &'a mut self,
+        deadline: std::time::Duration
+    ) -> &'a mut Self {
+        // Set deadline in Request struct.
+        self.deadline = Some(deadline);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,31 @@
+use crate::directions::request::departure_time::DepartureTime;
+use crate::distance_matrix::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies that the passenger intends to depart now.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Description
+    ///
+    /// Sends the literal `departure_time=now` to Google, which sets the
+    /// departure time to the current time on Google's servers (correct to the
+    /// nearest second). This is the usual way to receive live traffic-aware
+    /// durations in each element's `duration_in_traffic` field, and it avoids
+    /// any clock skew between this machine and Google. It is equivalent to
+    /// `.with_departure_time(DepartureTime::Now)`.
+    ///
+    /// ## Example
+    ///
+    /// * Departing now:
+    /// ```rust
+    /// .with_departure_now()
+    /// ```
+
+    pub fn with_departure_now(&'a mut self) -> &'a mut Self {
+        self.departure_time = Some(DepartureTime::Now);
+        self
+    } // fn
+} // impl
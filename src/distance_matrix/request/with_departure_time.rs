@@ -36,6 +36,13 @@ impl<'a> Request<'a> {
     ///   the distributed nature of the service. Results may also vary between
     ///   nearly-equivalent routes at any time or frequency.
     ///
+    /// Google expects a Unix timestamp, so a `NaiveDateTime` is interpreted as
+    /// a UTC date & time. It is _not_ interpreted as the local time at the
+    /// origin, or on this machine. Passing a local time will shift the
+    /// departure time by the local UTC offset, so a warning is logged through
+    /// `tracing`. Use `.with_departure_time_utc()` to pass an unambiguous point
+    /// in time.
+    ///
     /// ## Examples:
     ///
     /// * Departing now:
@@ -43,6 +50,11 @@ impl<'a> Request<'a> {
     /// .with_departure_time(DepartureTime::Now)
     /// ```
     ///
+    /// * Departing at a `NaiveDateTime`, which is converted automatically:
+    /// ```rust
+    /// .with_departure_time(NaiveDate::from_ymd(2030, 1, 1).and_hms(12, 30, 0))
+    /// ```
+    ///
     /// * Departing on Tuesday February 22, 2022 at 1:00:00 PM:
     /// ```rust
     /// .with_departure_time(DepartureTime::At(
@@ -61,7 +73,14 @@ impl<'a> Request<'a> {
         &'a mut self,
         departure_time: impl Into<DepartureTime>
     ) -> &'a mut Self {
-        self.departure_time = Some(departure_time.into());
+        let departure_time: DepartureTime = departure_time.into();
+        if let DepartureTime::At(naive_departure_time) = &departure_time {
+            tracing::warn!(
+                "departure time `{naive_departure_time}` has no time zone and will be interpreted \
+                as UTC; use `with_departure_time_utc` to specify the time zone"
+            );
+        } // if
+        self.departure_time = Some(departure_time);
         self
     } // fn
 } // impl
@@ -0,0 +1,36 @@
+use crate::directions::request::departure_time::DepartureTime;
+use crate::distance_matrix::request::Request;
+use chrono::{DateTime, Utc};
+
+impl<'a> Request<'a> {
+    /// Specifies the desired departure time, as a point in time.
+    ///
+    /// ## Arguments
+    ///
+    /// * `departure_time` ‧ The soonest time the passenger intends to depart.
+    ///   A `DateTime<Utc>`, or a `DateTime<Local>` or `DateTime<FixedOffset>`,
+    ///   which are converted automatically. Other time zones, such as a
+    ///   `chrono_tz::Tz`, may be converted with `.with_timezone(&Utc)`.
+    ///
+    /// ## Description
+    ///
+    /// Unlike `.with_departure_time()`, the time zone is known, so the
+    /// departure time is always converted to the correct Unix timestamp. See
+    /// `.with_departure_time()` for how the departure time is used. You can
+    /// use either a departure time or an arrival time, but not both together.
+    ///
+    /// ## Example
+    ///
+    /// * Departing in 30 minutes:
+    /// ```rust
+    /// .with_departure_time_utc(Utc::now() + chrono::Duration::minutes(30))
+    /// ```
+
+    pub fn with_departure_time_utc(
+        &'a mut self,
+        departure_time: impl Into<DateTime<Utc>>
+    ) -> &'a mut Self {
+        self.departure_time = Some(DepartureTime::At(departure_time.into().naive_utc()));
+        self
+    } // fn
+} // impl
@@ -62,13 +62,17 @@ impl<'a> Request<'a> {
         &'a mut self,
         restriction: impl Into<Avoid>
     ) -> &'a mut Self {
-        // Add restriction to Request struct.
-        self.restrictions = vec![restriction.into()];
+        // Add restriction to Request struct, unless it has already been added.
+        let restriction = restriction.into();
+        if !self.restrictions.contains(&restriction) {
+            self.restrictions.push(restriction);
+        } // if
         // Return modified Request struct to caller.
         self
     } // fn
 
-    /// Specify features that routes should avoid.
+    /// Specify features that routes should avoid. The restrictions are added
+    /// to any restrictions that have already been set.
     ///
     /// # Example:
     ///
@@ -96,8 +100,14 @@ impl<'a> Request<'a> {
     where
         C: IntoIterator<Item = A>,
         A: Into<Avoid> {
-        // Add restrictions to Request struct.
-        self.restrictions = restrictions.into_iter().map(Into::into).collect();
+        // Add restrictions to Request struct, skipping any that have already
+        // been added.
+        for restriction in restrictions {
+            let restriction = restriction.into();
+            if !self.restrictions.contains(&restriction) {
+                self.restrictions.push(restriction);
+            } // if
+        } // for
         // Return modified Request struct to caller.
         self
     } // fn
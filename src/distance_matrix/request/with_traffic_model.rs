@@ -42,6 +42,11 @@ impl<'a> Request<'a> {
     /// alternatively, _longer_ than `Pessimistic`, due to the way the
     /// `BestGuess` prediction model integrates live traffic information.
     ///
+    /// Google ignores the traffic model when no departure time is set, so it
+    /// is only included in the query when `with_departure_time()` or
+    /// `with_departure_now()` has also been called. Read the result from each
+    /// element's `duration_in_traffic` field.
+    ///
     /// ## Example
     ///
     /// * Set traffic model to pessimistic:
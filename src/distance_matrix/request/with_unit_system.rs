@@ -13,7 +13,7 @@ impl<'a> Request<'a> {
     ///
     /// [Unit Systems](https://developers.google.com/maps/documentation/distance-matrix/intro#unit_systems)
     ///
-    /// Directions results contain `text` within `distance` fields that may be
+    /// Distance Matrix results contain `text` within `distance` fields that may be
     /// displayed to the user to indicate the distance of a particular "step" of
     /// the route. By default, this text uses the unit system of the origin's
     /// country or region.
@@ -34,6 +34,10 @@ impl<'a> Request<'a> {
     /// within `distance` fields. The `distance` fields also contain `values`
     /// which are always expressed in meters.
     ///
+    /// If this method is not called, the `units` parameter is left out of the
+    /// query and Google infers the unit system from the origin's country or
+    /// region. Durations are not affected by this setting.
+    ///
     /// ## Example
     ///
     /// * Force unit system to Metric:
@@ -26,6 +26,16 @@ pub enum ElementStatus {
     Ok = 2,
     /// Indicates no route could be found between the origin and destination.
     ZeroResults = 3,
+
+    /// If the status is not recognized by
+    /// [serde](https://crates.io/crates/serde) when reading data from
+    /// Google it will be assigned to this `Other` variant.
+    ///
+    /// As new statuses are added to Google Maps, they must also be added to
+    /// this crate. However, in the meantime, the `Other` catch-all variant
+    /// allows `serde` to read data from Google without producing an error
+    /// until the new variant added to this `enum`.
+    Other(String) = 4,
 } // struct
 
 // -----------------------------------------------------------------------------
@@ -33,12 +43,12 @@ pub enum ElementStatus {
 impl<'de> Deserialize<'de> for ElementStatus {
     /// Manual implementation of `Deserialize` for `serde`. This will take
     /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    /// Unrecognized values are deserialized into the `Other` variant.
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let string = String::deserialize(deserializer)?;
-        match Self::try_from(string.as_str()) {
-            Ok(variant) => Ok(variant),
-            Err(error) => Err(serde::de::Error::custom(error.to_string())),
-        } // match
+        // A value that this crate does not recognize yet is kept in the
+        // `Other` variant, rather than failing the entire response:
+        Ok(Self::try_from(string.as_str()).unwrap_or(Self::Other(string)))
     } // fn
 } // impl
 
@@ -56,16 +66,17 @@ impl Serialize for ElementStatus {
 
 // -----------------------------------------------------------------------------
 
-impl std::convert::From<&ElementStatus> for &str {
+impl<'a> std::convert::From<&'a ElementStatus> for &'a str {
     /// Converts a `ElementStatus` enum to a `String` that contains a [element
     /// status](https://developers.google.com/maps/documentation/distance-matrix/intro#element-level-status-codes)
     /// code.
-    fn from(element_status: &ElementStatus) -> Self {
+    fn from(element_status: &'a ElementStatus) -> Self {
         match element_status {
             ElementStatus::MaxRouteLengthExceeded => "MAX_ROUTE_LENGTH_EXCEEDED",
             ElementStatus::NotFound => "NOT_FOUND",
             ElementStatus::Ok => "OK",
             ElementStatus::ZeroResults => "ZERO_RESULTS",
+            ElementStatus::Other(string) => string,
         } // match
     } // fn
 } // impl
@@ -143,12 +154,13 @@ impl ElementStatus {
     /// Formats a `ElementStatus` enum into a string that is presentable to the
     /// end user.
     #[must_use]
-    pub const fn display(&self) -> &str {
+    pub fn display(&self) -> &str {
         match self {
             Self::MaxRouteLengthExceeded => "Maximum Route Length Exceeded",
             Self::NotFound => "Not Found",
             Self::Ok => "OK",
             Self::ZeroResults => "Zero Results",
+            Self::Other(string) => string,
         } // match
     } // fn
 } // impl
@@ -17,6 +17,13 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct Response {
+    /// Copyrights text to be displayed with the results, if Google returned
+    /// any. Google's terms of service require this text to be shown to your
+    /// users along with the results.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copyrights: Option<String>,
+
     /// Contains an array of addresses as returned by the API from your original
     /// request. As with `origin_addresses`, these are localized if appropriate.
     #[serde(default)]
@@ -50,6 +57,13 @@ pub struct Response {
 
     /// Contains metadata on the request.
     pub status: Status,
+
+    /// Contains an array of warnings to be displayed with the results, if
+    /// Google returned any. You must handle and display these warnings
+    /// yourself.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 } // struct
 
 impl std::str::FromStr for Response {
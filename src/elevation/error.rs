@@ -3,6 +3,7 @@
 // -----------------------------------------------------------------------------
 
 use crate::elevation::response::status::Status;
+#[cfg(feature = "diagnostics")]
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -10,17 +11,39 @@ use thiserror::Error;
 //
 /// Errors that may be produced by the Google Maps Elevation API client.
 
-#[derive(Debug, Diagnostic, Error)]
-#[diagnostic(code(google_maps::elevation::error), url(docsrs))]
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+#[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::elevation::error), url(docsrs)))]
 pub enum Error {
+    /// A chunk of a positional request that was split across several requests
+    /// failed. Contains the indices of the locations in the failed chunk, and
+    /// the error that the chunk failed with.
+    ChunkFailed {
+        locations: std::ops::Range<usize>,
+        error: Box<crate::error::Error>,
+    },
     /// A `sampled_path_request()` method cannot be used when `postional_request()`
     /// has been set.
     EitherPositionalOrSampledPath,
+    /// A sampled path request must have both a path and a number of samples.
+    IncompleteSampledPath,
+    /// The number of samples for a sampled path request must be between 1 and
+    /// 512.
+    InvalidSamples(u16),
     /// Google Maps Elevation API server generated an error. See the `Status`
     /// enum for more information.
     GoogleMapsService(Status, Option<String>),
     /// The HTTP request was unsuccessful.
     HttpUnsuccessful(String),
+    /// The HTTP request was unsuccessful. Contains the HTTP status code, the
+    /// headers that help explain the failure (such as `Retry-After` and
+    /// Google's `X-Goog-*` headers), and the truncated response body.
+    #[cfg(feature = "reqwest")]
+    HttpUnsuccessfulResponse {
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String,
+    },
     /// API client library attempted to parse a string that contained an invalid
     /// status code. See `google_maps\src\elevation\response\status.rs` for
     /// more information.
@@ -39,6 +62,19 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SimdJson(simd_json::Error),
+    /// The dependency library Serde JSON could not parse the response from the
+    /// server. The response body is included to help with debugging. It is
+    /// truncated to the length set by `GoogleMapsClient::with_error_body_length`.
+    SimdJsonWithBody(simd_json::Error, String),
+    /// The service responded successfully, but not with JSON, for example
+    /// with an HTML error page from a proxy or a sign-in page. Contains the
+    /// response's `Content-Type` header, and the response body truncated to the
+    /// length set by `GoogleMapsClient::with_error_body_length`.
+    #[cfg(feature = "reqwest")]
+    UnexpectedContentType {
+        content_type: String,
+        body_snippet: String,
+    },
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -48,10 +84,22 @@ impl std::fmt::Display for Error {
     /// to the user.
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
+            Self::ChunkFailed { locations, error } => write!(f,
+                "Google Maps Elevation API client: \
+                The locations were split across several requests, and the request for locations \
+                `{locations:?}` failed: {error}"),
             Self::EitherPositionalOrSampledPath => write!(f,
                 "Google Maps Elevation API client: \
                 A for_sampled_path_request() method cannot be used when for_postional_request() has been set. \
                 Try again with only a positional request or only a sampled path request."),
+            Self::IncompleteSampledPath => write!(f,
+                "Google Maps Elevation API client: \
+                A sampled path request requires both a path and a number of samples. \
+                Try again with both with_path() and with_samples(), or use for_sampled_path_request()."),
+            Self::InvalidSamples(samples) => write!(f,
+                "Google Maps Elevation API client: \
+                `{samples}` is not a valid number of samples. \
+                The number of samples must be between 1 and 512."),
             Self::GoogleMapsService(status, error_message) => match error_message {
                 // If the Google Maps Elevation API server generated an error
                 // message, return that:
@@ -77,12 +125,19 @@ impl std::fmt::Display for Error {
                         Service did not complete the request."),
                     Status::UnknownError => write!(f, "Google Maps Elevation API service: \
                         Unknown error."),
+                    Status::Other(status_code) => write!(f, "Google Maps Elevation API service: \
+                        Unrecognized status `{status_code}`."),
                 } // match
             }, // match
             Self::HttpUnsuccessful(status) => write!(f,
                 "Google Maps Elevation API client: \
                 Could not successfully query the Google Cloud Platform service. \
                 The service last responded with a `{status}` status."),
+            #[cfg(feature = "reqwest")]
+            Self::HttpUnsuccessfulResponse { status, body, .. } => write!(f,
+                "Google Maps Elevation API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status: `{body}`"),
             Self::InvalidStatusCode(status_code) => write!(f,
                 "Google Maps Elevation API client: \
                 `{status_code}` is not a valid status code. \
@@ -98,6 +153,13 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "reqwest")]
             Self::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Self::SimdJson(error) => write!(f, "Google Maps Elevation API client in the Serde JSON library: {error}"),
+            Self::SimdJsonWithBody(error, body) => write!(f, "Google Maps Elevation API client in the Serde JSON library: {error}. \
+                The response body was: `{body}`"),
+            #[cfg(feature = "reqwest")]
+            Self::UnexpectedContentType { content_type, body_snippet } => write!(f,
+                "Google Maps Elevation API client: \
+                The service responded with `{content_type}` content instead of JSON. \
+                This is usually an error page. The response body was: `{body_snippet}`"),
             Self::QueryNotBuilt => write!(f,
                 "Google Maps Elevation API client: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
@@ -130,3 +192,46 @@ impl From<simd_json::Error> for Error {
         Self::SimdJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl crate::client::HttpError for Error {
+    /// Reports the HTTP-level failures of the retry loop as Google Maps
+    /// Elevation API errors.
+    fn unexpected_content_type(content_type: String, body_snippet: String) -> Self {
+        Self::UnexpectedContentType {
+            content_type,
+            body_snippet,
+        } // UnexpectedContentType
+    } // fn
+
+    fn http_unsuccessful_response(
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String
+    ) -> Self {
+        Self::HttpUnsuccessfulResponse {
+            status,
+            headers,
+            body,
+        } // HttpUnsuccessfulResponse
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(message: String) -> Self {
+        Self::ReqwestMessage(message)
+    } // fn
+} // impl
+
+#[cfg(feature = "reqwest")]
+impl crate::client::JsonError for Error {
+    /// Reports a response body that could not be parsed as a Google Maps
+    /// Elevation API error.
+    fn simd_json_with_body(error: simd_json::Error, body: String) -> Self {
+        Self::SimdJsonWithBody(error, body)
+    } // fn
+} // impl
@@ -82,6 +82,11 @@ pub mod response;
 const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/elevation";
 const OUTPUT_FORMAT: &str = "json"; // json or xml
 
+/// The maximum number of locations that Google accepts in a single positional
+/// request.
+#[cfg(feature = "reqwest")]
+const MAX_LOCATIONS: usize = 512;
+
 // -----------------------------------------------------------------------------
 
 pub use crate::elevation::{
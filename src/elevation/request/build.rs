@@ -8,7 +8,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn build(&mut self) -> Result<&'a mut Request<'_>, Error> {
         // Ensure request has been validated before building the query string:
 
         if !self.validated {
@@ -27,7 +27,7 @@ impl<'a> Request<'a> {
         // Locations key/value pair:
         if let Some(locations) = &self.locations {
             query.push_str("&locations=");
-            query.push_str(&String::from(locations));
+            query.push_str(&locations.to_query_string());
         } // if
 
         // This section builds the "sampled path request" portion of the query
@@ -36,7 +36,7 @@ impl<'a> Request<'a> {
         // Path key/value pair:
         if let Some(path) = &self.path {
             query.push_str("&path=");
-            query.push_str(&String::from(path));
+            query.push_str(&path.to_query_string());
         } // if
 
         // Samples key/value pair:
@@ -46,7 +46,7 @@ impl<'a> Request<'a> {
         } // if
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         Ok(self)
@@ -18,6 +18,7 @@ impl<'a> Request<'a> {
     /// * `samples` ‧ Specifies the number of sample points along a path for
     ///   which to return elevation data. The samples parameter divides the
     ///   given path into an ordered set of equidistant points along the path.
+    ///   Google accepts between 1 and 512 samples.
     ///
     /// ## Examples:
     ///
@@ -47,10 +48,10 @@ impl<'a> Request<'a> {
     pub fn for_sampled_path_request(
         &'a mut self,
         path: impl Into<Locations>,
-        samples: impl Into<u8>
+        samples: impl Into<u16>
     ) -> &'a mut Self {
         let path: Locations = path.into();
-        let samples: u8 = samples.into();
+        let samples: u16 = samples.into();
         // Set the path in Request struct.
         self.path = Some(path);
         // Set the sample number in Request struct.
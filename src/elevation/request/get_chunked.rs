@@ -0,0 +1,101 @@
+use crate::elevation::{
+    error::Error as ElevationError,
+    request::{locations::Locations, Request as ElevationRequest},
+    response::status::Status as ElevationStatus,
+    response::Response as ElevationResponse,
+    MAX_LOCATIONS,
+}; // crate::elevation
+use crate::client::remaining_deadline;
+use crate::error::Error as GoogleMapsError;
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+
+impl ElevationRequest<'_> {
+    /// Returns the request's locations if they exceed Google's limit of 512
+    /// locations, and can be split. Returns `None` if the request fits in a
+    /// single request.
+
+    pub(super) fn locations_to_chunk(&self) -> Option<Vec<LatLng>> {
+        match &self.locations {
+            Some(Locations::LatLngs(latlngs)) if latlngs.len() > MAX_LOCATIONS => {
+                Some(latlngs.clone())
+            } // LatLngs
+            // An encoded polyline that can not be decoded is sent as-is, and
+            // Google will report the problem:
+            Some(Locations::Polyline(polyline)) => crate::polyline::decode(polyline)
+                .ok()
+                .filter(|latlngs| latlngs.len() > MAX_LOCATIONS),
+            _ => None,
+        } // match
+    } // fn
+
+    /// Splits the locations into chunks that do not exceed Google's limit of
+    /// 512 locations, performs an HTTP get request for each chunk, and
+    /// concatenates the results into a single response.
+    ///
+    /// ## Arguments
+    ///
+    /// * `locations` ‧ The locations of the request, as returned by
+    ///   `locations_to_chunk()`.
+
+    pub(super) async fn get_chunked(
+        &self,
+        locations: &[LatLng]
+    ) -> Result<ElevationResponse, GoogleMapsError> {
+        // The deadline applies to the request as a whole, so each chunk is
+        // given whatever time remains. A chunk is not sent once none remains:
+        let started = std::time::Instant::now();
+
+        let mut results = Vec::with_capacity(locations.len());
+
+        for (chunk_index, chunk) in locations.chunks(MAX_LOCATIONS).enumerate() {
+            let chunk_start = chunk_index * MAX_LOCATIONS;
+            let chunk_range = chunk_start..chunk_start + chunk.len();
+
+            // Each chunk is sent as a separate request, using the same settings
+            // as this request:
+            let mut request = Self {
+                client: self.client,
+                // Each chunk is sent as an encoded polyline when that is
+                // shorter, see `Locations::to_query_string()`:
+                locations: Some(Locations::LatLngs(chunk.to_vec())),
+                path: None,
+                samples: None,
+                chunking: false,
+                backoff: self.backoff.clone(),
+                max_retries: self.max_retries,
+                timeout: self.timeout,
+                deadline: remaining_deadline(self.deadline, started)?,
+                query: None,
+                validated: self.validated,
+            }; // ElevationRequest
+
+            let response = match request.build()?.get_single().await {
+                Ok(response) => response,
+                // Report the deadline of the request as a whole, rather than what
+                // remained of it for this chunk:
+                Err(GoogleMapsError::DeadlineExceeded(remaining)) => {
+                    let deadline = self.deadline.unwrap_or(remaining);
+                    return Err(GoogleMapsError::DeadlineExceeded(deadline));
+                } // Err
+                Err(error) => {
+                    tracing::error!("chunk for locations {chunk_range:?} failed: {error}");
+                    return Err(ElevationError::ChunkFailed {
+                        locations: chunk_range,
+                        error: Box::new(error),
+                    })?;
+                } // Err
+            }; // match
+
+            results.extend(response.results);
+        } // for
+
+        // Return concatenated response to caller:
+        Ok(ElevationResponse {
+            error_message: None,
+            results,
+            status: ElevationStatus::Ok,
+        }) // ElevationResponse
+    } // fn
+} // impl
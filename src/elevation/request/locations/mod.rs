@@ -141,6 +141,15 @@ impl std::default::Default for Locations {
 
 // -----------------------------------------------------------------------------
 
+impl std::convert::From<Vec<LatLng>> for Locations {
+    /// Converts a list of `LatLng` coordinates into a `Locations` enum.
+    fn from(latlngs: Vec<LatLng>) -> Self {
+        Self::LatLngs(latlngs)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl Locations {
     /// An [encoded
     /// polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
@@ -151,3 +160,32 @@ impl Locations {
         Self::Polyline(polyline.into())
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Locations {
+    /// Converts the locations to the value of a `locations` or `path`
+    /// parameter.
+    ///
+    /// A list of `LatLng` coordinates is sent as an encoded polyline whenever
+    /// that is shorter, which is the case for all but the shortest lists. This
+    /// keeps long requests well within Google's URL length limit. An encoded
+    /// polyline rounds coordinates to 5 decimal places, or about a metre,
+    /// which is finer than the resolution of Google's elevation data. The
+    /// order of the coordinates, and so the order of the results, is
+    /// unchanged.
+
+    pub(crate) fn to_query_string(&self) -> String {
+        let string = String::from(self);
+
+        if let Self::LatLngs(latlngs) = self {
+            let polyline = Self::Polyline(crate::polyline::encode(latlngs));
+            let polyline = String::from(&polyline);
+            if polyline.len() < string.len() {
+                return polyline;
+            } // if
+        } // if
+
+        string
+    } // fn
+} // impl
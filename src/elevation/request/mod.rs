@@ -6,18 +6,40 @@
 mod build;
 #[cfg(feature = "reqwest")]
 mod execute;
+mod fingerprint;
 mod for_positional_request;
 mod for_sampled_path_request;
 #[cfg(feature = "reqwest")]
 mod get;
+#[cfg(feature = "reqwest")]
+mod get_chunked;
+#[cfg(feature = "reqwest")]
+mod get_raw;
 pub mod locations;
 mod new;
 mod query_url;
+#[cfg(feature = "reqwest")]
+mod url;
 mod validate;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+mod with_chunking;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
+mod with_encoded_locations;
+mod with_encoded_path;
+mod with_locations;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
+mod with_path;
+mod with_samples;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
 
 // -----------------------------------------------------------------------------
 
 use crate::{client::GoogleMapsClient, elevation::request::locations::Locations};
+use crate::redact::QueryString;
 
 // -----------------------------------------------------------------------------
 //
@@ -53,12 +75,41 @@ pub struct Request<'a> {
     /// Specifies the number of sample points along a path for which to return
     /// elevation data. The samples parameter divides the given path into an
     /// ordered set of equidistant points along the path.
-    samples: Option<u8>,
+    samples: Option<u16>,
+
+    // Optional parameters:
+    // --------------------
+    /// Whether positional requests that exceed Google's limit of 512 locations
+    /// are split across several requests. Defaults to `true`. See method
+    /// `with_chunking()` for more information.
+    chunking: bool,
+
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
 
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<QueryString>,
 
     /// Has the request been validated?
     validated: bool,
@@ -14,7 +14,7 @@ impl Request<'_> {
     /// * `client` ‧ Your application's Google Maps API client struct.
 
     #[must_use]
-    pub const fn new(client: &GoogleMapsClient) -> Request {
+    pub const fn new(client: &GoogleMapsClient) -> Request<'_> {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
@@ -24,6 +24,17 @@ impl Request<'_> {
             // Sampled path requests:
             path: None,
             samples: None,
+            // Optional parameters:
+            chunking: true,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
             validated: false,
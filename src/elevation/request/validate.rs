@@ -12,7 +12,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut Request, Error> {
+    pub fn validate(&mut self) -> Result<&'a mut Request<'_>, Error> {
         // If a "for positional request" has been set...
         if let Some(_locations) = &self.locations {
             // ...a "for sampled path request" cannot be set.
@@ -21,6 +21,19 @@ impl<'a> Request<'a> {
             }
         } // if
 
+        // A "sampled path request" requires both a path and a number of
+        // samples...
+        if self.path.is_some() != self.samples.is_some() {
+            return Err(Error::IncompleteSampledPath);
+        } // if
+
+        // ...and Google will only accept between 1 and 512 samples:
+        if let Some(samples) = self.samples {
+            if !(1..=512).contains(&samples) {
+                return Err(Error::InvalidSamples(samples));
+            } // if
+        } // if
+
         // Indicated that the request passed validation.
         self.validated = true;
 
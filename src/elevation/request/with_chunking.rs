@@ -0,0 +1,44 @@
+use crate::elevation::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies whether a positional request that exceeds Google's limit of
+    /// 512 locations is split across several requests.
+    ///
+    /// ## Arguments
+    ///
+    /// * `chunking` ‧ When `true`, a positional request with more than 512
+    ///   locations is split into chunks of 512 locations. Each chunk is sent as
+    ///   a separate request, subject to the client's rate limiting, and the
+    ///   results are concatenated into a single response in the original order
+    ///   of the locations. When `false`, the locations are always sent in a
+    ///   single request, and Google will return an error if there are too
+    ///   many. Defaults to `true`.
+    ///
+    /// ## Description
+    ///
+    /// Chunking applies to locations given as a list of `LatLng` coordinates
+    /// or as an encoded polyline. An encoded polyline is decoded, split, and
+    /// each chunk is re-encoded. Sampled path requests are never split, as
+    /// Google already limits them to 512 samples.
+    ///
+    /// Note that each chunk is billed by Google as a separate request. If any
+    /// chunk fails, the error identifies the locations that were in the failed
+    /// chunk, and the results of other chunks are discarded.
+    ///
+    /// ## Example
+    ///
+    /// * Always send the locations in a single request:
+    /// ```rust
+    /// .with_chunking(false)
+    /// ```
+
+    pub fn with_chunking(
+        &'a mut self,
+        chunking: bool
+    ) -> &'a mut Self {
+        // Set chunking in Request struct.
+        self.chunking = chunking;
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
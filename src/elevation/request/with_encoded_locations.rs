@@ -0,0 +1,37 @@
+use crate::elevation::request::{locations::Locations, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies the location(s) for a _positional request_ as an encoded
+    /// polyline.
+    ///
+    /// ## Arguments
+    ///
+    /// * `polyline` ‧ Defines the location(s) on the earth from which to
+    ///   return elevation data, as an [encoded
+    ///   polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+    ///   without the `enc:` prefix.
+    ///
+    /// ## Description
+    ///
+    /// This is the same as calling `with_locations()` with a
+    /// `Locations::Polyline`. The response contains one result per vertex of
+    /// the polyline, in the order of the vertices. Polylines with more than
+    /// 512 vertices are decoded and split, as described in the
+    /// `with_chunking()` method.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_encoded_locations("gfo}EtohhUxD@bAxJmGF")
+    /// ```
+
+    pub fn with_encoded_locations(
+        &'a mut self,
+        polyline: impl Into<String>
+    ) -> &'a mut Self {
+        // Set locations in Request struct.
+        self.locations = Some(Locations::Polyline(polyline.into()));
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,38 @@
+use crate::elevation::request::{locations::Locations, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies the path for a _sampled path request_ as an encoded polyline.
+    ///
+    /// ## Arguments
+    ///
+    /// * `polyline` ‧ Defines a path on the earth for which to return
+    ///   elevation data, as an [encoded
+    ///   polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+    ///   without the `enc:` prefix. A polyline may be created from a list of
+    ///   `LatLng` coordinates with `google_maps::polyline::encode()`.
+    ///
+    /// ## Description
+    ///
+    /// This is the same as calling `with_path()` with a `Locations::Polyline`.
+    /// An encoded polyline is much shorter than a list of latitude/longitude
+    /// pairs, so long paths fit well within Google's URL length limit. The
+    /// samples are returned in order along the path, whichever form the path
+    /// was given in.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_encoded_path("gfo}EtohhUxD@bAxJmGF")
+    /// .with_samples(4)
+    /// ```
+
+    pub fn with_encoded_path(
+        &'a mut self,
+        polyline: impl Into<String>
+    ) -> &'a mut Self {
+        // Set path in Request struct.
+        self.path = Some(Locations::Polyline(polyline.into()));
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
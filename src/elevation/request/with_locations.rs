@@ -0,0 +1,41 @@
+use crate::elevation::request::{locations::Locations, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies the location(s) for a _positional request_.
+    ///
+    /// ## Arguments
+    ///
+    /// * `locations` ‧ Defines the location(s) on the earth from which to
+    ///   return elevation data. This parameter takes either a list of
+    ///   latitude/longitude pairs, or an encoded polyline. For more
+    ///   information, see [Specifying
+    ///   Locations](https://developers.google.com/maps/documentation/elevation/intro#Locations).
+    ///
+    /// ## Description
+    ///
+    /// A positional request returns the elevation of each of the given
+    /// locations. It cannot be combined with a _sampled path request_ (see the
+    /// `with_path()` and `with_samples()` methods). Setting both will cause
+    /// the request to fail validation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_locations(vec![
+    ///     // Denver, Colorado, the "Mile High City"
+    ///     LatLng::try_from_dec(dec!(39.7391536), dec!(-104.9847034))?,
+    ///     // Death Valley
+    ///     LatLng::try_from_dec(dec!(36.23998), dec!(-116.83171))?,
+    /// ])
+    /// ```
+
+    pub fn with_locations(
+        &'a mut self,
+        locations: impl Into<Locations>
+    ) -> &'a mut Self {
+        // Set locations in Request struct.
+        self.locations = Some(locations.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,43 @@
+use crate::elevation::request::{locations::Locations, Request};
+
+impl<'a> Request<'a> {
+    /// Specifies the path for a _sampled path request_.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` ‧ Defines a path on the earth for which to return elevation
+    ///   data. This parameter takes a list of two or more ordered
+    ///   latitude/longitude pairs, or an encoded polyline. For more
+    ///   information, see [Specifying
+    ///   Paths](https://developers.google.com/maps/documentation/elevation/intro#Paths).
+    ///
+    /// ## Description
+    ///
+    /// A sampled path request returns elevations at evenly-spaced points along
+    /// the path, which is useful for building an elevation profile. The path
+    /// must be used together with the `with_samples()` method, and cannot be
+    /// combined with a _positional request_ (see the `with_locations()`
+    /// method). Otherwise, the request will fail validation.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_path(vec![
+    ///     // Mount Whitney
+    ///     LatLng::try_from_dec(dec!(36.578581), dec!(-118.291994))?,
+    ///     // Badwater Basin
+    ///     LatLng::try_from_dec(dec!(36.23998), dec!(-116.83171))?,
+    /// ])
+    /// .with_samples(256)
+    /// ```
+
+    pub fn with_path(
+        &'a mut self,
+        path: impl Into<Locations>
+    ) -> &'a mut Self {
+        // Set path in Request struct.
+        self.path = Some(path.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
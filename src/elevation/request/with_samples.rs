@@ -0,0 +1,34 @@
+use crate::elevation::request::Request;
+
+impl<'a> Request<'a> {
+    /// Specifies the number of samples for a _sampled path request_.
+    ///
+    /// ## Arguments
+    ///
+    /// * `samples` ‧ Specifies the number of sample points along the path for
+    ///   which to return elevation data. The path is divided into an ordered
+    ///   set of equidistant points. Google accepts between 1 and 512 samples.
+    ///
+    /// ## Description
+    ///
+    /// The number of samples must be used together with the `with_path()`
+    /// method. Each sample in the response includes its `location` along the
+    /// path, its `elevation`, and the `resolution` that the elevation was
+    /// interpolated from.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_samples(256)
+    /// ```
+
+    pub fn with_samples(
+        &'a mut self,
+        samples: impl Into<u16>
+    ) -> &'a mut Self {
+        // Set samples in Request struct.
+        self.samples = Some(samples.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -2,6 +2,7 @@
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "diagnostics")]
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -10,79 +11,413 @@ use thiserror::Error;
 /// Errors that may be produced by the root part of the Google Maps Platform API
 /// client.
 
-#[derive(Debug, Diagnostic, Error)]
-#[diagnostic(url(docsrs))]
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+#[cfg_attr(feature = "diagnostics", diagnostic(url(docsrs)))]
 pub enum Error {
     /// Error originating from the types and structs in the `google_maps` crate.
     #[error(transparent)]
-    #[diagnostic(code(google_maps::types))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::types)))]
     Type(#[from] crate::types::Error),
 
+    /// Error originating from the `address_validation` module in the
+    /// `google_maps` crate.
+    #[cfg(feature = "address_validation")]
+    #[error(transparent)]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::address_validation)))]
+    AddressValidation(#[from] crate::address_validation::error::Error),
+
     /// Error originating from the `directions` module in the `google_maps`
     /// crate.
     #[cfg(any(feature = "directions", feature = "distance_matrix"))]
     #[error(transparent)]
-    #[diagnostic(code(google_maps::directions))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::directions)))]
     Directions(#[from] crate::directions::error::Error),
 
     /// Error originating from the `distance_matrix` module in the `google_maps`
     /// crate.
     #[cfg(feature = "distance_matrix")]
     #[error(transparent)]
-    #[diagnostic(code(google_maps::distance_matrix))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::distance_matrix)))]
     DistanceMatrix(#[from] crate::distance_matrix::error::Error),
 
     /// Error originating from the `elevation` module in the `google_maps`
     /// crate.
     #[cfg(feature = "elevation")]
     #[error(transparent)]
-    #[diagnostic(code(google_maps::elevation))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::elevation)))]
     Elevation(#[from] crate::elevation::error::Error),
 
     /// Error originating from the `geocoding` module in the `google_maps`
     /// crate.
     #[cfg(feature = "geocoding")]
     #[error(transparent)]
-    #[diagnostic(code(google_maps::geocoding))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::geocoding)))]
     Geocoding(#[from] crate::geocoding::error::Error),
 
     /// Error originating from the `places` module in the `google_maps` crate.
     #[cfg(feature = "places")]
     #[error(transparent)]
-    #[diagnostic(code(google_maps::places))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::places)))]
     Places(#[from] crate::places::error::Error),
 
     /// Error originating from the `place_autocomplete` module in the
     /// `google_maps` crate.
     #[cfg(feature = "autocomplete")]
     #[error(transparent)]
-    #[diagnostic(code(google_maps::place_autocomplete))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::place_autocomplete)))]
     PlaceAutocomplete(#[from] crate::places::place_autocomplete::error::Error),
 
     /// Error originating from the `roads` module in the `google_maps` crate.
     #[cfg(feature = "roads")]
     #[error(transparent)]
-    #[diagnostic(code(google_maps::roads))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::roads)))]
     Roads(#[from] crate::roads::error::Error),
 
+    /// Error originating from the `static_map` module in the `google_maps`
+    /// crate.
+    #[cfg(feature = "static_map")]
+    #[error(transparent)]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::static_map)))]
+    StaticMap(#[from] crate::static_map::error::Error),
+
     /// Error originating from the `time_zone` module in the `google_maps`
     /// crate.
     #[cfg(feature = "time_zone")]
     #[error(transparent)]
-    #[diagnostic(code(google_maps::time_zone))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::time_zone)))]
     TimeZone(#[from] crate::time_zone::error::Error),
 
     /// Error originating from the [reqwest](https://crates.io/crates/reqwest)
     /// crate.
     #[cfg(feature = "reqwest")]
     #[error(transparent)]
-    #[diagnostic(code(google_maps::reqwest))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::reqwest)))]
     Reqwest(#[from] reqwest::Error),
 
+    /// The request, including all of its retries, did not complete within
+    /// its deadline. Contains the deadline. See the `with_deadline()` method
+    /// of the request for more information.
+    #[cfg(feature = "reqwest")]
+    #[error("the request did not complete within its {0:?} deadline")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(google_maps::deadline_exceeded),
+            help("increase the deadline, or reduce the number of retries")
+        )
+    )]
+    DeadlineExceeded(std::time::Duration),
+
+    /// A quota was exceeded. Every API reports an exceeded quota with this
+    /// error, whether Google responded with an `OVER_DAILY_LIMIT` or
+    /// `OVER_QUERY_LIMIT` status, or with an HTTP `429 Too Many Requests` or
+    /// `403 Forbidden` response that gives a quota as the reason. Contains
+    /// the kind of quota, the delay requested by the server's `Retry-After`
+    /// header, if any, and the error that the API reported.
+    #[cfg(feature = "reqwest")]
+    #[error("{kind} exceeded: {error}")]
+    #[cfg_attr(
+        feature = "diagnostics",
+        diagnostic(
+            code(google_maps::quota),
+            help("slow down if a rate limit was exceeded, or check the project's quotas and \
+                billing in the Google Cloud console if a daily quota was exceeded")
+        )
+    )]
+    Quota {
+        kind: crate::quota::QuotaKind,
+        retry_after: Option<std::time::Duration>,
+        error: Box<Self>,
+    },
+
     /// Error originating from the [polyline](https://crates.io/crates/polyline)
     /// crate.
     #[cfg(feature = "polyline")]
     #[error(transparent)]
-    #[diagnostic(code(google_maps::polyline))]
+    #[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::polyline)))]
     Polyline(#[from] polyline::errors::PolylineError),
 } // enum Error
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl Error {
+    /// Returns the HTTP status code of an unsuccessful HTTP response, for any
+    /// API. `None` is returned for every other kind of error, such as a
+    /// connection error or an unsuccessful Google Maps status.
+
+    #[must_use]
+    pub fn http_status(&self) -> Option<reqwest::StatusCode> {
+        match self {
+            Self::Reqwest(error) => error.status(),
+            _ => self.http_response().map(|(status, _headers, _body)| status),
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the HTTP status code, headers, and truncated body of an
+    /// unsuccessful HTTP response, for any API.
+
+    fn http_response(&self) -> Option<(reqwest::StatusCode, &reqwest::header::HeaderMap, &str)> {
+        match self {
+            #[cfg(feature = "address_validation")]
+            Self::AddressValidation(
+                crate::address_validation::error::Error::HttpUnsuccessfulResponse { status, headers, body }
+            ) => Some((*status, headers, body)),
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            Self::Directions(
+                crate::directions::error::Error::HttpUnsuccessfulResponse { status, headers, body }
+            ) => Some((*status, headers, body)),
+            #[cfg(feature = "distance_matrix")]
+            Self::DistanceMatrix(
+                crate::distance_matrix::error::Error::HttpUnsuccessfulResponse { status, headers, body }
+            ) => Some((*status, headers, body)),
+            #[cfg(feature = "elevation")]
+            Self::Elevation(
+                crate::elevation::error::Error::HttpUnsuccessfulResponse { status, headers, body }
+            ) => Some((*status, headers, body)),
+            #[cfg(feature = "geocoding")]
+            Self::Geocoding(
+                crate::geocoding::error::Error::HttpUnsuccessfulResponse { status, headers, body }
+            ) => Some((*status, headers, body)),
+            #[cfg(feature = "places")]
+            Self::Places(
+                crate::places::error::Error::HttpUnsuccessfulResponse { status, headers, body }
+            ) => Some((*status, headers, body)),
+            #[cfg(feature = "autocomplete")]
+            Self::PlaceAutocomplete(
+                crate::places::place_autocomplete::error::Error::HttpUnsuccessfulResponse { status, headers, body }
+            ) => Some((*status, headers, body)),
+            #[cfg(feature = "roads")]
+            Self::Roads(
+                crate::roads::error::Error::HttpUnsuccessfulResponse { status, headers, body }
+            ) => Some((*status, headers, body)),
+            #[cfg(feature = "static_map")]
+            Self::StaticMap(
+                crate::static_map::error::Error::HttpUnsuccessfulResponse { status, headers, body }
+            ) => Some((*status, headers, body)),
+            #[cfg(feature = "time_zone")]
+            Self::TimeZone(
+                crate::time_zone::error::Error::HttpUnsuccessfulResponse { status, headers, body }
+            ) => Some((*status, headers, body)),
+            #[cfg(feature = "distance_matrix")]
+            Self::DistanceMatrix(
+                crate::distance_matrix::error::Error::BlockFailed { error, .. }
+            ) => error.http_response(),
+            #[cfg(feature = "elevation")]
+            Self::Elevation(
+                crate::elevation::error::Error::ChunkFailed { error, .. }
+            ) => error.http_response(),
+            Self::Quota { error, .. } => error.http_response(),
+            _ => None,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the `Content-Type` and the truncated body of a successful HTTP
+    /// response that was not in the expected format, such as an HTML error
+    /// page, for any API. `None` is returned for every other kind of error.
+
+    #[must_use]
+    pub fn unexpected_content_type(&self) -> Option<(&str, &str)> {
+        match self {
+            #[cfg(feature = "address_validation")]
+            Self::AddressValidation(
+                crate::address_validation::error::Error::UnexpectedContentType { content_type, body_snippet }
+            ) => Some((content_type, body_snippet)),
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            Self::Directions(
+                crate::directions::error::Error::UnexpectedContentType { content_type, body_snippet }
+            ) => Some((content_type, body_snippet)),
+            #[cfg(feature = "distance_matrix")]
+            Self::DistanceMatrix(
+                crate::distance_matrix::error::Error::UnexpectedContentType { content_type, body_snippet }
+            ) => Some((content_type, body_snippet)),
+            #[cfg(feature = "elevation")]
+            Self::Elevation(
+                crate::elevation::error::Error::UnexpectedContentType { content_type, body_snippet }
+            ) => Some((content_type, body_snippet)),
+            #[cfg(feature = "geocoding")]
+            Self::Geocoding(
+                crate::geocoding::error::Error::UnexpectedContentType { content_type, body_snippet }
+            ) => Some((content_type, body_snippet)),
+            #[cfg(feature = "places")]
+            Self::Places(
+                crate::places::error::Error::UnexpectedContentType { content_type, body_snippet }
+            ) => Some((content_type, body_snippet)),
+            #[cfg(feature = "autocomplete")]
+            Self::PlaceAutocomplete(
+                crate::places::place_autocomplete::error::Error::UnexpectedContentType { content_type, body_snippet }
+            ) => Some((content_type, body_snippet)),
+            #[cfg(feature = "roads")]
+            Self::Roads(
+                crate::roads::error::Error::UnexpectedContentType { content_type, body_snippet }
+            ) => Some((content_type, body_snippet)),
+            #[cfg(feature = "static_map")]
+            Self::StaticMap(
+                crate::static_map::error::Error::UnexpectedContentType { content_type, body_snippet }
+            ) => Some((content_type, body_snippet)),
+            #[cfg(feature = "time_zone")]
+            Self::TimeZone(
+                crate::time_zone::error::Error::UnexpectedContentType { content_type, body_snippet }
+            ) => Some((content_type, body_snippet)),
+            Self::Quota { error, .. } => error.unexpected_content_type(),
+            _ => None,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the kind of quota that was exceeded, for any API. `None` is
+    /// returned for every other kind of error. Use this, rather than matching
+    /// on `Quota`, to also recognize a quota error in a block of a matrix, or a
+    /// chunk of elevation locations, that was split across several requests.
+
+    #[allow(clippy::missing_const_for_fn)] // only without `distance_matrix` and `elevation`
+    #[must_use]
+    pub fn quota_kind(&self) -> Option<crate::quota::QuotaKind> {
+        match self {
+            Self::Quota { kind, .. } => Some(*kind),
+            #[cfg(feature = "distance_matrix")]
+            Self::DistanceMatrix(
+                crate::distance_matrix::error::Error::BlockFailed { error, .. }
+            ) => error.quota_kind(),
+            #[cfg(feature = "elevation")]
+            Self::Elevation(
+                crate::elevation::error::Error::ChunkFailed { error, .. }
+            ) => error.quota_kind(),
+            _ => None,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the status code and error message of an unsuccessful Google
+    /// Maps status, such as `OVER_QUERY_LIMIT`, for any API.
+
+    #[allow(clippy::missing_const_for_fn)] // only without any of these APIs
+    fn google_maps_status(&self) -> Option<(String, Option<&str>)> {
+        match self {
+            #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+            Self::Directions(
+                crate::directions::error::Error::GoogleMapsService(status, error_message)
+            ) => Some((String::from(status), error_message.as_deref())),
+            #[cfg(feature = "distance_matrix")]
+            Self::DistanceMatrix(
+                crate::distance_matrix::error::Error::GoogleMapsService(status, error_message)
+            ) => Some((String::from(status), error_message.as_deref())),
+            #[cfg(feature = "elevation")]
+            Self::Elevation(
+                crate::elevation::error::Error::GoogleMapsService(status, error_message)
+            ) => Some((String::from(status), error_message.as_deref())),
+            #[cfg(feature = "geocoding")]
+            Self::Geocoding(
+                crate::geocoding::error::Error::GoogleMapsService(status, error_message)
+            ) => Some((String::from(status), error_message.as_deref())),
+            #[cfg(feature = "places")]
+            Self::Places(
+                crate::places::error::Error::GoogleMapsService(status, error_message)
+            ) => Some((String::from(status), error_message.as_deref())),
+            #[cfg(feature = "autocomplete")]
+            Self::PlaceAutocomplete(
+                crate::places::place_autocomplete::error::Error::GoogleMapsService(status, error_message)
+            ) => Some((String::from(status), error_message.as_deref())),
+            #[cfg(feature = "roads")]
+            Self::Roads(
+                crate::roads::error::Error::GoogleMapsService(status, error_message)
+            ) => Some((String::from(status), error_message.as_deref())),
+            #[cfg(feature = "time_zone")]
+            Self::TimeZone(
+                crate::time_zone::error::Error::GoogleMapsService(status, error_message)
+            ) => Some((String::from(status), error_message.as_deref())),
+            _ => None,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Wraps the error in a `Quota` error, if it reports an exceeded quota.
+    /// Every other error is returned unchanged.
+    ///
+    /// ## Arguments
+    ///
+    /// * `retry_after` ‧ The delay requested by the server's `Retry-After`
+    ///   header, if it has already been parsed. Otherwise, it is read from the
+    ///   headers of an unsuccessful HTTP response.
+
+    pub(crate) fn into_quota(self, retry_after: Option<std::time::Duration>) -> Self {
+        if matches!(self, Self::Quota { .. }) {
+            return self;
+        } // if
+
+        let kind = match (self.google_maps_status(), self.http_response()) {
+            (Some((status, error_message)), _) => {
+                crate::quota::QuotaKind::from_google_status(&status, error_message)
+            } // Some
+            (None, Some((status, _headers, body))) => {
+                crate::quota::QuotaKind::from_http_response(status, body)
+            } // Some
+            (None, None) => None,
+        }; // match
+
+        let Some(kind) = kind else {
+            return self;
+        }; // let
+
+        let retry_after = retry_after.or_else(|| {
+            self.http_response()
+                .and_then(|(_status, headers, _body)| crate::client::retry_after(headers))
+        }); // or_else
+
+        Self::Quota {
+            kind,
+            retry_after,
+            error: Box::new(self),
+        } // Quota
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "reqwest", feature = "elevation"))]
+mod tests {
+    use crate::elevation::error::Error as ElevationError;
+    use crate::error::Error;
+    use crate::quota::QuotaKind;
+
+    /// An unsuccessful HTTP response from the Elevation API.
+    fn http_error(status: reqwest::StatusCode) -> Error {
+        Error::Elevation(ElevationError::HttpUnsuccessfulResponse {
+            status,
+            headers: Box::default(),
+            body: String::from("Too Many Requests"),
+        }) // Error::Elevation
+    } // fn
+
+    /// The error of the second of several chunks of elevation locations.
+    fn chunk_failed(error: Error) -> Error {
+        Error::Elevation(ElevationError::ChunkFailed {
+            locations: 512..1_024,
+            error: Box::new(error),
+        }) // Error::Elevation
+    } // fn
+
+    #[test]
+    fn quota_kind_of_a_failed_chunk() {
+        let quota = Error::Quota {
+            kind: QuotaKind::Rate,
+            retry_after: None,
+            error: Box::new(http_error(reqwest::StatusCode::TOO_MANY_REQUESTS)),
+        }; // Error::Quota
+        assert_eq!(chunk_failed(quota).quota_kind(), Some(QuotaKind::Rate));
+    } // fn
+
+    #[test]
+    fn http_status_of_a_failed_chunk() {
+        let error = chunk_failed(http_error(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert_eq!(error.http_status(), Some(reqwest::StatusCode::TOO_MANY_REQUESTS));
+    } // fn
+} // mod
@@ -0,0 +1,92 @@
+//! Computes request fingerprints: stable hashes of a request's URL that can be
+//! used as cache keys, or to recognize a request in logs.
+
+// -----------------------------------------------------------------------------
+//
+/// Query string parameters that identify the caller rather than the request.
+/// They are left out of the fingerprint, so that the fingerprint may be logged
+/// or shared without exposing credentials, and so that rotating a key does not
+/// change it.
+
+const EXCLUDED_PARAMETERS: [&str; 4] = ["key", "client", "channel", "signature"];
+
+/// Query string parameters whose value is a `|`-separated set, such as the
+/// restrictions of a route. The order of the values does not change the
+/// response, so they are sorted. Lists whose order matters, such as waypoints
+/// or the points of a path, are not in this list.
+
+const SET_PARAMETERS: [&str; 6] = [
+    "avoid",
+    "components",
+    "location_type",
+    "result_type",
+    "transit_mode",
+    "types",
+]; // SET_PARAMETERS
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the fingerprint of a request URL, as produced by a request's
+/// `query_url()` method.
+///
+/// The URL is normalized with `normalize()`, so logically identical requests
+/// have the same fingerprint regardless of the order in which their builder
+/// methods were called, or in which the values of a set, such as the
+/// restrictions of a route, were added.
+///
+/// The hash is the 64-bit [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function)
+/// hash of the normalized URL. Unlike `std::hash::DefaultHasher`, it is the
+/// same on every platform and with every Rust version, so fingerprints may be
+/// stored and compared between runs.
+
+pub fn fingerprint(url: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    normalize(url)
+        .bytes()
+        .fold(OFFSET_BASIS, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(PRIME))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Returns a request URL without the parameters that identify the caller.
+///
+/// The API key, Premium Plan client ID, channel, and URL signature are
+/// removed, and the remaining query string parameters are sorted. The values
+/// of a set-valued parameter, such as `avoid`, are sorted too. The order of
+/// the values within any other parameter, such as the waypoints of a route, is
+/// significant and is kept.
+
+pub fn normalize(url: &str) -> String {
+    let (stem, query) = url.split_once('?').unwrap_or((url, ""));
+
+    let mut parameters: Vec<String> = query
+        .split('&')
+        .filter_map(|parameter| {
+            let (name, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+            if parameter.is_empty() || EXCLUDED_PARAMETERS.contains(&name) {
+                None
+            } else if SET_PARAMETERS.contains(&name) {
+                Some(format!("{name}={value}", value = sort_set(value)))
+            } else {
+                Some(parameter.to_string())
+            } // if
+        }) // filter_map
+        .collect();
+    parameters.sort_unstable();
+
+    format!("{stem}?{parameters}", parameters = parameters.join("&"))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Sorts the values of a `|`-separated set. The separator may be
+/// percent-encoded, as most builders encode it.
+
+fn sort_set(value: &str) -> String {
+    let separator = if value.contains("%7C") { "%7C" } else { "|" };
+    let mut values: Vec<&str> = value.split(separator).collect();
+    values.sort_unstable();
+    values.join(separator)
+} // fn
@@ -0,0 +1,257 @@
+//! Contains the `Cache` struct, an in-memory, least-recently-used cache of
+//! successful Geocoding API responses. It is shared by all clones of a
+//! `GoogleMapsClient` and may be used from many async tasks at once.
+
+use crate::fingerprint::normalize;
+use crate::geocoding::response::Response;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// An in-memory, least-recently-used cache of successful Geocoding API
+/// responses. Error and `ZERO_RESULTS` responses are never cached.
+///
+/// Responses are keyed on the request URL, without the API key, client ID,
+/// channel, and signature, and with its query string parameters sorted. The
+/// same query made by clients with different credentials, or with its builder
+/// methods called in a different order, is therefore only cached once.
+///
+/// The cache holds at most `capacity` responses. When it is full, the response
+/// that was least recently used is evicted to make room for a new one.
+/// Responses older than the time-to-live are treated as missing, and are
+/// replaced the next time that the same query is made.
+///
+/// Enable the cache with the `GoogleMapsClient::with_geocoding_cache()`
+/// method.
+
+#[derive(Debug)]
+pub struct Cache {
+    /// The maximum number of responses held by the cache.
+    capacity: usize,
+
+    /// How long a cached response may be used for.
+    ttl: Duration,
+
+    /// The cached responses, and the order in which they were used. The mutex
+    /// is never held across an `.await`.
+    entries: Mutex<Entries>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+#[derive(Debug, Default)]
+struct Entries {
+    /// Cached responses, by normalized URL.
+    responses: HashMap<String, Entry>,
+
+    /// Normalized URLs of cached responses, by the generation at which each
+    /// was last used. The least recently used response comes first.
+    order: BTreeMap<u64, String>,
+
+    /// Incremented each time that a response is cached or used.
+    generation: u64,
+} // struct
+
+#[derive(Debug)]
+struct Entry {
+    /// The time at which the response was cached.
+    cached_at: Instant,
+
+    /// The generation at which the response was last used, its key in `order`.
+    used: u64,
+
+    response: Response,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Entries {
+    /// Marks the response as the most recently used.
+    fn touch(&mut self, key: &str) {
+        if let Some(entry) = self.responses.get_mut(key) {
+            self.generation += 1;
+            if let Some(key) = self.order.remove(&entry.used) {
+                self.order.insert(self.generation, key);
+            } // if
+            entry.used = self.generation;
+        } // if
+    } // fn
+
+    /// Removes the response from the cache.
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.responses.remove(key) {
+            self.order.remove(&entry.used);
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Cache {
+    /// Creates an empty cache.
+    ///
+    /// ## Arguments
+    ///
+    /// * `capacity` ‧ The maximum number of responses held by the cache. A
+    ///   value of `0` disables caching.
+    ///
+    /// * `ttl` ‧ How long a cached response may be used for, before the query
+    ///   is sent to Google again.
+
+    #[must_use]
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: Mutex::new(Entries::default()),
+        } // Cache
+    } // fn
+
+    /// Returns the cached response for the URL, if there is one and it has not
+    /// expired.
+
+    pub(crate) fn get(&self, url: &str) -> Option<Response> {
+        let key = normalize(url);
+        let mut entries = self.entries.lock().ok()?;
+        match entries.responses.get(&key) {
+            Some(entry) if entry.cached_at.elapsed() < self.ttl => {
+                let response = entry.response.clone();
+                entries.touch(&key);
+                Some(response)
+            } // Some
+            Some(_expired) => {
+                entries.remove(&key);
+                None
+            } // Some
+            None => None,
+        } // match
+    } // fn
+
+    /// Caches a successful response for the URL, evicting the least recently
+    /// used response if the cache is full.
+
+    pub(crate) fn insert(&self, url: &str, response: Response) {
+        if self.capacity == 0 {
+            return;
+        } // if
+        let key = normalize(url);
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        }; // let
+
+        entries.remove(&key);
+        while entries.responses.len() >= self.capacity {
+            match entries.order.pop_first() {
+                Some((_used, least_recently_used)) => {
+                    entries.responses.remove(&least_recently_used);
+                } // Some
+                None => break,
+            } // match
+        } // while
+
+        entries.generation += 1;
+        let used = entries.generation;
+        entries.order.insert(used, key.clone());
+        entries.responses.insert(key, Entry {
+            cached_at: Instant::now(),
+            used,
+            response,
+        }); // insert
+    } // fn
+
+    /// Removes all responses from the cache.
+
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.responses.clear();
+            entries.order.clear();
+        } // if
+    } // fn
+
+    /// Returns the number of responses in the cache, including any that have
+    /// expired but have not yet been removed.
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .map_or(0, |entries| entries.responses.len())
+    } // fn
+
+    /// Returns `true` if the cache contains no responses.
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://maps.googleapis.com/maps/api/geocode/json";
+
+    fn response() -> Response {
+        r#"{ "results": [], "status": "OK" }"#.parse().unwrap()
+    } // fn
+
+    #[test]
+    fn ignores_credentials_and_parameter_order() {
+        let cache = Cache::new(10, Duration::from_secs(60));
+        cache.insert(&format!("{URL}?key=AIzaOne&address=Paris&language=fr"), response());
+
+        assert!(cache.get(&format!("{URL}?key=AIzaTwo&language=fr&address=Paris")).is_some());
+        assert!(cache
+            .get(&format!("{URL}?language=fr&address=Paris&client=gme-id&channel=web&signature=abc"))
+            .is_some());
+        assert!(cache.get(&format!("{URL}?key=AIzaOne&address=Lyon&language=fr")).is_none());
+        assert_eq!(cache.len(), 1);
+    } // fn
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = Cache::new(2, Duration::from_secs(60));
+        cache.insert(&format!("{URL}?address=a"), response());
+        cache.insert(&format!("{URL}?address=b"), response());
+
+        // Using `a` makes `b` the least recently used:
+        assert!(cache.get(&format!("{URL}?address=a")).is_some());
+        cache.insert(&format!("{URL}?address=c"), response());
+
+        assert!(cache.get(&format!("{URL}?address=a")).is_some());
+        assert!(cache.get(&format!("{URL}?address=b")).is_none());
+        assert!(cache.get(&format!("{URL}?address=c")).is_some());
+        assert_eq!(cache.len(), 2);
+    } // fn
+
+    #[test]
+    fn replacing_a_response_does_not_evict() {
+        let cache = Cache::new(2, Duration::from_secs(60));
+        cache.insert(&format!("{URL}?address=a"), response());
+        cache.insert(&format!("{URL}?address=b"), response());
+        cache.insert(&format!("{URL}?address=a"), response());
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&format!("{URL}?address=b")).is_some());
+    } // fn
+
+    #[test]
+    fn expires_responses() {
+        let cache = Cache::new(10, Duration::ZERO);
+        cache.insert(&format!("{URL}?address=a"), response());
+        assert!(cache.get(&format!("{URL}?address=a")).is_none());
+        assert!(cache.is_empty());
+    } // fn
+
+    #[test]
+    fn zero_capacity_disables_caching() {
+        let cache = Cache::new(0, Duration::from_secs(60));
+        cache.insert(&format!("{URL}?address=a"), response());
+        assert!(cache.is_empty());
+    } // fn
+} // mod
@@ -9,7 +9,7 @@ impl<'a> ForwardRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> Result<&'a mut ForwardRequest, Error> {
+    pub fn build(&mut self) -> Result<&'a mut ForwardRequest<'_>, Error> {
         // Ensure request has been validated before building the query string:
 
         if !self.validated {
@@ -65,20 +65,22 @@ impl<'a> ForwardRequest<'a> {
             ); // push_str
         }
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // one has not been set for this request:
+        if let Some(language) = self.language.or(self.client.language) {
             query.push_str("&language=");
-            query.push_str(&String::from(language));
+            query.push_str(&String::from(&language));
         }
 
-        // Region key/value pair:
-        if let Some(region) = &self.region {
+        // Region key/value pair. The client's default region is used if one
+        // has not been set for this request:
+        if let Some(region) = self.region.or(self.client.region) {
             query.push_str("&region=");
-            query.push_str(&String::from(region));
+            query.push_str(&String::from(&region));
         }
 
         // Set query string in ForwardRequest struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified ForwardRequest struct to caller.
         Ok(self)
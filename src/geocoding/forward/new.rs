@@ -13,7 +13,7 @@ impl ForwardRequest<'_> {
     /// * `client` ‧ Your application's Google Maps API client struct.
 
     #[must_use]
-    pub const fn new(client: &GoogleMapsClient) -> ForwardRequest {
+    pub const fn new(client: &GoogleMapsClient) -> ForwardRequest<'_> {
         // Instantiate struct and return it to caller:
         ForwardRequest {
             // Required parameters:
@@ -25,6 +25,15 @@ impl ForwardRequest<'_> {
             components: Vec::new(),
             language: None,
             region: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             validated: false,
             query: None,
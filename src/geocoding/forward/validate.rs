@@ -12,12 +12,12 @@ impl<'a> ForwardRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn validate(&mut self) -> Result<&'a mut ForwardRequest, Error> {
+    pub fn validate(&mut self) -> Result<&'a mut ForwardRequest<'_>, Error> {
         // If a positional request has been set...
         if self.address.is_none() && self.place_id.is_none() && self.components.is_empty() {
             return Err(Error::AddressOrComponentsRequired);
         } // if
-          // Indicate that the request passed validation.
+        // Indicate that the request passed validation.
         self.validated = true;
         // Return modified Request struct to caller.
         Ok(self)
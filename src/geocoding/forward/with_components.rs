@@ -68,7 +68,7 @@ impl<'a> ForwardRequest<'a> {
         component: impl Into<Component>
     ) -> &'a mut Self {
         // Add component to ForwardRequest struct.
-        self.components = vec![component.into()];
+        self.components.push(component.into());
         // Return modified ForwardRequest struct to caller.
         self
     } // fn
@@ -79,8 +79,9 @@ impl<'a> ForwardRequest<'a> {
     /// # Example:
     ///
     /// * Alternatively, multiple component filters may be passed in a single
-    ///   method call by passing a slice. This example restricts results to a
-    ///   street in a city:
+    ///   method call by passing a slice. Calls are additive, so the filters
+    ///   are added to any that have already been set. This example restricts
+    ///   results to a street in a city:
     ///
     /// ```rust
     /// .with_components(&[
@@ -104,7 +105,7 @@ impl<'a> ForwardRequest<'a> {
         C: IntoIterator<Item = O>,
         O: Into<Component> {
         // Add components to ForwardRequest struct.
-        self.components = components.into_iter().map(Into::into).collect();
+        self.components.extend(components.into_iter().map(Into::into));
         // Return modified ForwardRequest struct to caller.
         self
     } // fn
@@ -0,0 +1,36 @@
+use crate::geocoding::forward::ForwardRequest;
+
+// =============================================================================
+
+impl<'a> ForwardRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the maximum number of times a failed HTTP request will be
+    /// retried.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_retries` ‧ The number of retries that will be attempted after the
+    ///   initial request fails with an error that is eligible for retries. Once
+    ///   this number has been reached, the last error is returned to the
+    ///   caller. A value of `0` disables retries. The retry policy's
+    ///   `max_elapsed_time` continues to apply, see `with_backoff()` for more
+    ///   information.
+    ///
+    /// ## Example
+    ///
+    /// * Give up after 3 retries:
+    /// ```rust
+    /// .with_max_retries(3)
+    /// ```
+
+    pub fn with_max_retries(
+        &'a mut self,
+        max_retries: u32
+    ) -> &'a mut Self {
+        // Set maximum retries in Request struct.
+        self.max_retries = Some(max_retries);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -80,8 +80,11 @@
 //! mobile developers who want to use geocoding data within maps provided by one
 //! of the Google Maps Platform APIs.
 
+#[cfg(feature = "reqwest")]
+pub mod cache;
 pub mod error;
 pub mod forward;
+pub mod plus_codes;
 pub mod response;
 pub mod reverse;
 
@@ -99,7 +102,11 @@ pub use crate::geocoding::{
         geocoding::Geocoding, plus_code::PlusCode, status::Status as GeocodingStatus,
         Response as GeocodingResponse,
     }, // response
+    plus_codes::PlusCodeRequest as GeocodingPlusCodeRequest,
     reverse::ReverseRequest as GeocodingReverseRequest,
 }; // geocoding
 
+#[cfg(feature = "reqwest")]
+pub use crate::geocoding::cache::Cache as GeocodingCache;
+
 pub use crate::types::{AddressComponent, Geometry, LocationType};
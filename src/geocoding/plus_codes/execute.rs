@@ -0,0 +1,26 @@
+use crate::error::Error as GoogleMapsError;
+use crate::geocoding::{
+    plus_codes::PlusCodeRequest, response::Response as GeocodingResponse,
+}; // crate::geocoding
+
+// =============================================================================
+
+impl<'a> PlusCodeRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// A Plus Code query has no separate validation or build steps, so this
+    /// method is the same as `get()`. It is offered for consistency with the
+    /// other requests in this crate.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<GeocodingResponse, GoogleMapsError> {
+        self.get().await
+    } // fn
+} // impl
@@ -0,0 +1,24 @@
+use crate::fingerprint::fingerprint;
+use crate::geocoding::{error::Error, plus_codes::PlusCodeRequest};
+
+// =============================================================================
+
+impl PlusCodeRequest<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a stable fingerprint of the query you've built, for use as a
+    /// cache key or to recognize the request in logs. See
+    /// `ForwardRequest::fingerprint()` for more information.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if a Plus Code has not been set.
+
+    pub fn fingerprint(&self) -> Result<u64, Error> {
+        self.query_url().map(|url| fingerprint(&url))
+    } // fn
+} // impl
@@ -0,0 +1,44 @@
+use crate::error::Error as GoogleMapsError;
+use crate::geocoding::{
+    forward::ForwardRequest as GeocodingForwardRequest, plus_codes::PlusCodeRequest,
+    response::Response as GeocodingResponse,
+}; // crate::geocoding
+
+// -----------------------------------------------------------------------------
+
+impl PlusCodeRequest<'_> {
+    /// Resolves the Plus Code by sending it to Google as a forward geocoding
+    /// query, and returns the response to the caller. The query observes the
+    /// client's Geocoding API rate limit, and is retried like any other
+    /// geocoding request.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(level = "info", skip(self))]
+    pub async fn get(&mut self) -> Result<GeocodingResponse, GoogleMapsError> {
+        let address = self.address()?;
+
+        let mut request = GeocodingForwardRequest::new(self.client);
+        let mut request = request.with_address(address);
+
+        if let Some(backoff) = &self.backoff {
+            request = request.with_backoff(backoff.clone());
+        } // if
+
+        if let Some(max_retries) = self.max_retries {
+            request = request.with_max_retries(max_retries);
+        } // if
+
+        if let Some(timeout) = self.timeout {
+            request = request.with_timeout(timeout);
+        } // if
+
+        if let Some(deadline) = self.deadline {
+            request = request.with_deadline(deadline);
+        } // if
+
+        request.validate()?.build()?.get().await
+    } // fn
+} // impl
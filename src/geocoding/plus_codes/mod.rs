@@ -0,0 +1,85 @@
+//! **Look in this module for documentation on building your _Geocoding API_
+//! Plus Code query**. In particular, look at the _`PlusCodeRequest`_ struct for
+//! examples of the builder pattern. A [Plus Code](https://maps.google.com/pluscodes/)
+//! is a short code that represents a location, and can be used in place of a
+//! street address. This module resolves a Plus Code to latitude & longitude
+//! coordinates and the nearest address.
+//!
+//! Plus Codes are sent to Google as forward geocoding queries, so this request
+//! shares the `Response` structures, rate limiting, and retry behaviour of the
+//! forward geocoding request.
+
+#[cfg(feature = "reqwest")]
+mod execute;
+mod fingerprint;
+#[cfg(feature = "reqwest")]
+mod get;
+#[cfg(feature = "reqwest")]
+mod get_raw;
+mod new;
+mod query_url;
+#[cfg(feature = "reqwest")]
+mod url;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+mod with_code;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
+mod with_locality;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+
+// -----------------------------------------------------------------------------
+
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Plus Code_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+
+#[derive(Debug)]
+pub struct PlusCodeRequest<'a> {
+    // Required parameters:
+    // --------------------
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The global code (for example `849VCWC8+R9`) or compound code (for
+    /// example `CWC8+R9`) to be resolved. See method `with_code()` for more
+    /// information.
+    code: Option<String>,
+
+    // Optional parameters:
+    // --------------------
+    /// The locality that a compound code is relative to, for example
+    /// `Mountain View, CA, USA`. See method `with_locality()` for more
+    /// information.
+    locality: Option<String>,
+
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+} // struct
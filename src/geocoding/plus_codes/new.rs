@@ -0,0 +1,35 @@
+use crate::client::GoogleMapsClient;
+use crate::geocoding::plus_codes::PlusCodeRequest;
+
+// -----------------------------------------------------------------------------
+
+impl PlusCodeRequest<'_> {
+    /// Initializes the builder pattern for a Plus Code query. The Plus Code
+    /// must be set using the `with_code()` method before the query is
+    /// executed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+
+    #[must_use]
+    pub const fn new(client: &GoogleMapsClient) -> PlusCodeRequest<'_> {
+        // Instantiate struct and return it to caller:
+        PlusCodeRequest {
+            // Required parameters:
+            client,
+            code: None,
+            // Optional parameters:
+            locality: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
+        } // struct
+    } // fn
+} // impl
@@ -0,0 +1,47 @@
+use crate::geocoding::{
+    error::Error, forward::ForwardRequest as GeocodingForwardRequest, plus_codes::PlusCodeRequest,
+}; // crate::geocoding
+
+// =============================================================================
+
+impl PlusCodeRequest<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the URL query string that represents the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// Returns the query string that will be sent to the Google Maps API. It
+    /// is the result of the builder pattern. This method could be useful for
+    /// records or logging. It could also be used for passing to your HTTP
+    /// client of choice and executing the HTTP GET request yourself.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn query_url(&self) -> Result<String, Error> {
+        GeocodingForwardRequest::new(self.client)
+            .with_address(self.address()?)
+            .query_url()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the address that is sent to Google as a forward geocoding query:
+    /// the Plus Code, followed by its locality if one was set.
+
+    pub(super) fn address(&self) -> Result<String, Error> {
+        // Ensure that a Plus Code has been set:
+        let Some(code) = &self.code else {
+            return Err(Error::PlusCodeRequired);
+        }; // let
+
+        // Google expects a compound code to be followed by its locality,
+        // separated by a space:
+        Ok(self.locality.as_ref().map_or_else(
+            || code.clone(),
+            |locality| format!("{code} {locality}"),
+        )) // map_or_else
+    } // fn
+} // impl
@@ -0,0 +1,33 @@
+use crate::error::Error as GoogleMapsError;
+use crate::geocoding::{forward::ForwardRequest as GeocodingForwardRequest, plus_codes::PlusCodeRequest};
+
+// =============================================================================
+
+impl PlusCodeRequest<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the URL that the `get()` method will send the request to,
+    /// without sending it.
+    ///
+    /// ## Description
+    ///
+    /// A Plus Code request is sent as a forward geocoding query. This method
+    /// builds that query, and returns its URL in the same way as `get()`
+    /// builds it, including the client's base URL if one has been set.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if a Plus Code has not been set.
+
+    pub fn url(&self) -> Result<String, GoogleMapsError> {
+        GeocodingForwardRequest::new(self.client)
+            .with_address(self.address()?)
+            .validate()?
+            .build()?
+            .url()
+    } // fn
+} // impl
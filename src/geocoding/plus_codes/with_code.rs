@@ -0,0 +1,36 @@
+use crate::geocoding::plus_codes::PlusCodeRequest;
+
+impl<'a> PlusCodeRequest<'a> {
+    /// Specifies the Plus Code to be resolved.
+    ///
+    /// ## Arguments
+    ///
+    /// * `code` ‧ A global code, which is a 4 character area code and 6
+    ///   character or longer local code (for example `849VCWC8+R9`), or a
+    ///   compound code, which is a 6 character or longer local code with an
+    ///   explicit location (for example `CWC8+R9 Mountain View, CA, USA`). A
+    ///   compound code's location may instead be set with the
+    ///   `with_locality()` method.
+    ///
+    /// ## Examples:
+    ///
+    /// * Resolve a global code:
+    /// ```rust
+    /// .with_code("849VCWC8+R9")
+    /// ```
+    ///
+    /// * Resolve a compound code:
+    /// ```rust
+    /// .with_code("CWC8+R9 Mountain View, CA, USA")
+    /// ```
+
+    pub fn with_code(
+        &'a mut self,
+        code: impl Into<String>
+    ) -> &'a mut Self {
+        // Set Plus Code in PlusCodeRequest struct.
+        self.code = Some(code.into());
+        // Return modified PlusCodeRequest struct to caller.
+        self
+    } // fn
+} // impl
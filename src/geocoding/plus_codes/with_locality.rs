@@ -0,0 +1,29 @@
+use crate::geocoding::plus_codes::PlusCodeRequest;
+
+impl<'a> PlusCodeRequest<'a> {
+    /// Specifies the locality that a compound code is relative to.
+    ///
+    /// ## Arguments
+    ///
+    /// * `locality` ‧ A town, city, or other locality near the Plus Code, for
+    ///   example `Mountain View, CA, USA`. A compound code such as `CWC8+R9`
+    ///   only identifies a location when it is combined with a locality. The
+    ///   locality is ignored for global codes.
+    ///
+    /// ## Example:
+    ///
+    /// ```rust
+    /// .with_code("CWC8+R9")
+    /// .with_locality("Mountain View, CA, USA")
+    /// ```
+
+    pub fn with_locality(
+        &'a mut self,
+        locality: impl Into<String>
+    ) -> &'a mut Self {
+        // Set locality in PlusCodeRequest struct.
+        self.locality = Some(locality.into());
+        // Return modified PlusCodeRequest struct to caller.
+        self
+    } // fn
+} // impl
@@ -91,7 +91,7 @@ impl Geocoding {
     pub fn get_global_plus_code(&self) -> Option<String> {
         self.plus_code
             .as_ref()
-            .map(|plus_code| plus_code.global_code.to_string())
+            .map(|plus_code| plus_code.global_code.clone())
     } // fn
 
     /// A helper function for destructuring the optional `compound_code`
@@ -110,4 +110,16 @@ impl Geocoding {
                 .map(std::string::ToString::to_string)
         }) // and_then
     } // fn
+
+    /// A helper function for destructuring the optional `partial_match` field.
+    /// Returns `true` if Google flagged this result as a partial match for the
+    /// original request, which may indicate a low-confidence result. If the
+    /// _`partial_match`_ field is empty, this function will return `false`.
+    /// ```rust
+    /// let low_confidence = geocoding.is_partial_match();
+    /// ```
+    #[must_use]
+    pub fn is_partial_match(&self) -> bool {
+        self.partial_match.unwrap_or(false)
+    } // fn
 } // impl
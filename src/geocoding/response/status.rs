@@ -56,6 +56,17 @@ pub enum Status {
     /// also occur if the geocoder was passed a `latlng` in a remote location.
     #[serde(alias = "ZeroResults")]
     ZeroResults,
+
+    /// If the status is not recognized by
+    /// [serde](https://crates.io/crates/serde) when reading data from
+    /// Google it will be assigned to this `Other` variant.
+    ///
+    /// As new statuses are added to Google Maps, they must also be added to
+    /// this crate. However, in the meantime, the `Other` catch-all variant
+    /// allows `serde` to read data from Google without producing an error
+    /// until the new variant added to this `enum`.
+    #[serde(untagged)]
+    Other(String),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -63,12 +74,12 @@ pub enum Status {
 impl<'de> Deserialize<'de> for Status {
     /// Manual implementation of `Deserialize` for `serde`. This will take
     /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    /// Unrecognized values are deserialized into the `Other` variant.
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let string = String::deserialize(deserializer)?;
-        match Self::try_from(string.as_str()) {
-            Ok(variant) => Ok(variant),
-            Err(error) => Err(serde::de::Error::custom(error.to_string())),
-        } // match
+        // A value that this crate does not recognize yet is kept in the
+        // `Other` variant, rather than failing the entire response:
+        Ok(Self::try_from(string.as_str()).unwrap_or(Self::Other(string)))
     } // fn
 } // impl
 
@@ -87,6 +98,7 @@ impl std::convert::From<&Status> for String {
             Status::RequestDenied => Self::from("REQUEST_DENIED"),
             Status::UnknownError => Self::from("UNKNOWN_ERROR"),
             Status::ZeroResults => Self::from("ZERO_RESULTS"),
+            Status::Other(status_code) => status_code.clone(),
         } // match
     } // fn
 } // impl
@@ -156,6 +168,31 @@ impl std::fmt::Display for Status {
             Self::RequestDenied => write!(f, "Request Denied"),
             Self::UnknownError => write!(f, "Unknown Error"),
             Self::ZeroResults => write!(f, "Zero Results"),
+            Self::Other(status_code) => write!(f, "{status_code}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl Status {
+    /// Checks Google's status for a response. `OK` and `ZERO_RESULTS` are
+    /// successful, since a search that found nothing is not an error. Any
+    /// other status is returned as a `GoogleMapsService` error, which is only
+    /// retried for `UNKNOWN_ERROR` and `OVER_QUERY_LIMIT`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `error_message` ‧ Google's error message for the status, if any.
+
+    pub(crate) fn check(&self, error_message: Option<&str>) -> Result<(), backoff::Error<Error>> {
+        match self {
+            Self::Ok | Self::ZeroResults => Ok(()),
+            status => Err(crate::client::status_error(
+                Error::GoogleMapsService(status.clone(), error_message.map(str::to_string)),
+                matches!(status, Self::UnknownError | Self::OverQueryLimit),
+            )), // status
         } // match
     } // fn
 } // impl
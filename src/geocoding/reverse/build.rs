@@ -9,7 +9,7 @@ impl<'a> ReverseRequest<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut ReverseRequest {
+    pub fn build(&mut self) -> &'a mut ReverseRequest<'_> {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -22,10 +22,11 @@ impl<'a> ReverseRequest<'a> {
         // This section builds the "optional parameters" portion of the query
         // string:
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // one has not been set for this request:
+        if let Some(language) = self.language.or(self.client.language) {
             query.push_str("&language=");
-            query.push_str(&String::from(language));
+            query.push_str(&String::from(&language));
         } // if
 
         // Location type(s) key/value pair:
@@ -63,7 +64,7 @@ impl<'a> ReverseRequest<'a> {
         } // if
 
         // Set query string in ReverseRequest struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified ReverseRequest struct to caller.
         self
@@ -1,24 +1,27 @@
+use crate::client::{Method, RetrySettings};
 use crate::error::Error as GoogleMapsError;
 use crate::geocoding::{
-    error::Error as GeocodingError, response::status::Status as GeocodingStatus,
-    response::Response as GeocodingResponse, reverse::ReverseRequest as ReverseGeocodingRequest,
+    error::Error as GeocodingError,
+    response::{status::Status as GeocodingStatus, Response as GeocodingResponse},
+    reverse::ReverseRequest as ReverseGeocodingRequest,
     OUTPUT_FORMAT, SERVICE_URL,
 };
 use crate::request_rate::api::Api;
-use backoff::future::retry;
-use backoff::Error::{Permanent, Transient};
-use backoff::ExponentialBackoff;
 
 // -----------------------------------------------------------------------------
 
-impl<'a> ReverseGeocodingRequest<'a> {
+impl ReverseGeocodingRequest<'_> {
     /// Performs the HTTP get request and returns the response to the caller.
     ///
     /// ## Arguments
     ///
     /// This method accepts no arguments.
 
-    #[tracing::instrument(level = "info", skip(self))]
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(api = %Api::Geocoding, attempts, duration_ms, status)
+    )]
     pub async fn get(&mut self) -> Result<GeocodingResponse, GoogleMapsError> {
         // Build the URL stem for the HTTP get request:
         let mut url = format!("{SERVICE_URL}/{OUTPUT_FORMAT}?");
@@ -30,115 +33,50 @@ impl<'a> ReverseGeocodingRequest<'a> {
             None => return Err(GeocodingError::QueryNotBuilt)?,
         } // match
 
-        // Observe any rate limiting before executing request:
-        tracing::info!("making HTTP GET request to Google Maps Geocoding API");
-
-        self.client
-            .rate_limit
-            .limit_apis(vec![&Api::All, &Api::Geocoding])
-            .await;
+        // If the response to this query has been cached, return it without
+        // contacting Google:
+        if let Some(cache) = &self.client.geocoding_cache {
+            if let Some(response) = cache.get(&url) {
+                tracing::debug!("returning cached response");
+                return Ok(response);
+            } // if
+        } // if
 
-        tracing::debug!("{url}");
+        tracing::info!("making HTTP GET request to Google Maps Geocoding API");
 
-        // Retries the get request until successful, an error ineligible for
-        // retries is returned, or we have reached the maximum retries. Note:
-        // errors wrapped in `Transient()` will retried by the `backoff` crate
-        // while errors wrapped in `Permanent()` will exit the retry loop.
-        let response = retry(ExponentialBackoff::default(), || async {
-            // Query the Google Cloud Maps Platform using using an HTTP get
-            // request, and return result to caller:
-            let response = self.client.get_request(&url).await;
+        // Observe any rate limiting before executing the request, and retry
+        // it according to its retry settings:
+        let response = self
+            .client
+            .execute_with_retry(
+                Method::Get,
+                &url,
+                RetrySettings {
+                    api: Api::Geocoding,
+                    backoff: self.backoff.as_ref(),
+                    max_retries: self.max_retries,
+                    timeout: self.timeout,
+                    deadline: self.deadline,
+                }, // RetrySettings
+                "application/json",
+                |body| -> Result<GeocodingResponse, backoff::Error<GeocodingError>> {
+                    // If the response JSON was successfully parsed, check the
+                    // Google API status before returning it to the caller:
+                    let response: GeocodingResponse = self.client.parse_json(&body)?;
+                    response.status.check(response.error_message.as_deref())?;
+                    Ok(response)
+                }, // classify
+            )
+            .await?;
 
-            // Check response from the HTTP client:
-            match response {
-                Ok(response) => {
-                    // HTTP client was successful getting a response from the
-                    // server. Check the HTTP status code:
-                    if response.status().is_success() {
-                        // If the HTTP GET request was successful, get the
-                        // response text:
-                        let bytes = response.text().await.map(String::into_bytes);
-                        match bytes {
-                            Ok(mut bytes) => {
-                                match simd_json::serde::from_slice::<GeocodingResponse>(&mut bytes) {
-                                    Ok(deserialized) => {
-                                        // If the response JSON was successfully
-                                        // parsed, check the Google API status
-                                        // before returning it to the caller:
-                                        if deserialized.status == GeocodingStatus::Ok {
-                                            // If Google's response was "Ok"
-                                            // return the struct deserialized
-                                            // from JSON:
-                                            Ok(deserialized)
-                                        // Google API returned an error. This
-                                        // indicates an issue with the request.
-                                        // In most cases, retrying will not
-                                        // help:
-                                        } else {
-                                            let error = GeocodingError::GoogleMapsService(
-                                                deserialized.status.clone(),
-                                                deserialized.error_message,
-                                            );
-                                            // Check Google API response status
-                                            // for error type:
-                                            if deserialized.status == GeocodingStatus::UnknownError
-                                            {
-                                                // Only Google's "Unknown Error"
-                                                // is eligible for retries:
-                                                tracing::warn!("{}", error);
-                                                Err(Transient {
-                                                    err: error,
-                                                    retry_after: None,
-                                                })
-                                            } else {
-                                                // Not an "Unknown Error." The
-                                                // error is permanent, do not
-                                                // retry:
-                                                tracing::error!("{}", error);
-                                                Err(Permanent(error))
-                                            } // if
-                                        } // if
-                                    } // Ok(deserialized)
-                                    Err(error) => {
-                                        tracing::error!("JSON parsing error: {}", error);
-                                        Err(Permanent(GeocodingError::SimdJson(error)))
-                                    } // Err
-                                } // match
-                            } // Ok(text)
-                            Err(error) => {
-                                tracing::error!("HTTP client returned: {}", error);
-                                Err(Permanent(GeocodingError::ReqwestMessage(error.to_string())))
-                            } // Err
-                        } // match
-                          // We got a response from the server but it was not OK.
-                          // Only HTTP "500 Server Errors", and HTTP "429 Too Many
-                          // Requests" are eligible for retries.
-                    } else if response.status().is_server_error() || response.status() == 429 {
-                        tracing::warn!("HTTP client returned: {}", response.status());
-                        Err(Transient {
-                            err: GeocodingError::HttpUnsuccessful(response.status().to_string()),
-                            retry_after: None,
-                        })
-                    // Not a 500 Server Error or "429 Too Many Requests" error.
-                    // The error is permanent, do not retry:
-                    } else {
-                        tracing::error!("HTTP client returned: {}", response.status());
-                        Err(Permanent(GeocodingError::HttpUnsuccessful(
-                            response.status().to_string(),
-                        )))
-                    } // if
-                } // case
-                // HTTP client did not get a response from the server. Retry:
-                Err(error) => {
-                    tracing::warn!("HTTP client returned: {}", error);
-                    Err(Transient {
-                        err: GeocodingError::Reqwest(error),
-                        retry_after: None,
-                    })
-                } // case
-            } // match
-        })
-        .await?;
+        // Only successful responses reach this point, so they may be cached.
+        // A query that found nothing is not cached, so that it is sent to
+        // Google again, in case the address has since been added:
+        if let Some(cache) = &self.client.geocoding_cache {
+            if response.status == GeocodingStatus::Ok {
+                cache.insert(&url, response.clone());
+            } // if
+        } // if
 
         // Return response to caller:
         Ok(response)
@@ -7,18 +7,32 @@
 mod build;
 #[cfg(feature = "reqwest")]
 mod execute;
+mod fingerprint;
 #[cfg(feature = "reqwest")]
 mod get;
+#[cfg(feature = "reqwest")]
+mod get_raw;
 mod new;
 mod query_url;
+#[cfg(feature = "reqwest")]
+mod url;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
 mod with_language;
 mod with_location_types;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
 mod with_result_types;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
 
 // -----------------------------------------------------------------------------
 
 use crate::client::GoogleMapsClient;
 use crate::types::{Language, LatLng, LocationType, PlaceType};
+use crate::redact::QueryString;
 
 // -----------------------------------------------------------------------------
 //
@@ -62,8 +76,30 @@ pub struct ReverseRequest<'a> {
     /// the specified address type(s).
     result_types: Vec<PlaceType>,
 
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<QueryString>,
 } // impl
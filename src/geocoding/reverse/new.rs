@@ -28,6 +28,15 @@ impl<'a> ReverseRequest<'a> {
             language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         } // struct
@@ -62,6 +71,15 @@ impl<'a> ReverseRequest<'a> {
             language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -96,6 +114,15 @@ impl<'a> ReverseRequest<'a> {
             language: None,
             location_types: Vec::new(),
             result_types: Vec::new(),
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         }) // struct
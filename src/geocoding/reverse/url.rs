@@ -0,0 +1,40 @@
+use crate::error::Error as GoogleMapsError;
+use crate::geocoding::{
+    error::Error as GeocodingError, reverse::ReverseRequest as ReverseGeocodingRequest,
+    OUTPUT_FORMAT, SERVICE_URL,
+};
+
+// =============================================================================
+
+impl ReverseGeocodingRequest<'_> {
+    /// Returns the URL that the `get()` method will send the request to,
+    /// without sending it.
+    ///
+    /// ## Description
+    ///
+    /// The URL is built from the query string in the same way as `get()`
+    /// builds it, including the client's base URL if one has been set. This
+    /// method could be useful for debugging, logging, or comparing a request
+    /// against Google's documentation. Unlike `query_url()`, this method does
+    /// not build the query: `.validate()?.build()?` must be called first.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns a `QueryNotBuilt` error if the query has not been built.
+
+    pub fn url(&self) -> Result<String, GoogleMapsError> {
+        match &self.query {
+            // If query string built, append it to the URL stem:
+            Some(query) => Ok(self
+                .client
+                .request_url(&format!("{SERVICE_URL}/{OUTPUT_FORMAT}?{query}"))
+                .into_owned()),
+            // If query string not built, return an error:
+            None => Err(GeocodingError::QueryNotBuilt)?,
+        } // match
+    } // fn
+} // impl
@@ -13,6 +13,9 @@ impl<'a> ReverseRequest<'a> {
     ///
     /// # Description:
     ///
+    /// This filter is only available for reverse geocoding, so it is not
+    /// offered by the forward geocoding request.
+    ///
     /// A filter of one or more location types. If the parameter contains
     /// multiple location types, the API returns all addresses that match any of
     /// the types. A note about processing: The `location_type` parameter does
@@ -63,7 +66,7 @@ impl<'a> ReverseRequest<'a> {
         &'a mut self,
         location_type: impl Into<LocationType>
     ) -> &'a mut Self {
-        self.location_types = vec![location_type.into()];
+        self.location_types.push(location_type.into());
         // Return modified ReverseRequest struct to caller.
         self
     } // fn
@@ -110,7 +113,7 @@ impl<'a> ReverseRequest<'a> {
         C: IntoIterator<Item = L>,
         L: Into<LocationType> {
         // Add location types to ReverseRequest struct.
-        self.location_types = location_types.into_iter().map(Into::into).collect();
+        self.location_types.extend(location_types.into_iter().map(Into::into));
         // Return modified ReverseRequest struct to caller.
         self
     } // fn
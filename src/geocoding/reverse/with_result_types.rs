@@ -9,6 +9,9 @@ impl<'a> ReverseRequest<'a> {
     ///
     /// # Description:
     ///
+    /// This filter is only available for reverse geocoding, so it is not
+    /// offered by the forward geocoding request.
+    ///
     /// A filter of one or more result types. If the parameter contains multiple
     /// result types, the API returns all addresses that match any of the types.
     /// A note about processing: The `result_type` parameter does not _restrict_
@@ -118,7 +121,7 @@ impl<'a> ReverseRequest<'a> {
         result_type: impl Into<PlaceType>
     ) -> &'a mut Self {
         // Add result type to ReverseRequest struct.
-        self.result_types = vec![result_type.into()];
+        self.result_types.push(result_type.into());
         // Return modified ReverseRequest struct to caller.
         self
     } // fn
@@ -143,7 +146,7 @@ impl<'a> ReverseRequest<'a> {
     ///   neighborhood and a locality:
     ///
     /// ```rust
-    /// .with_components(&[
+    /// .with_result_types(&[
     ///     PlaceType::Neighborhood,
     ///     PlaceType::Locality,
     /// ])
@@ -164,7 +167,7 @@ impl<'a> ReverseRequest<'a> {
         C: IntoIterator<Item = P>,
         P: Into<PlaceType> {
         // Add location types to ReverseRequest struct.
-        self.result_types = result_types.into_iter().map(Into::into).collect();
+        self.result_types.extend(result_types.into_iter().map(Into::into));
         // Return modified ReverseRequest struct to caller.
         self
     } // fn
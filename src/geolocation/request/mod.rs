@@ -5,6 +5,7 @@ mod wifi_access_point;
 use crate::geolocation::request::cell_tower::CellTower;
 use crate::geolocation::request::radio_type::RadioType;
 use crate::geolocation::request::wifi_access_point::WiFiAccessPoint;
+use crate::redact::QueryString;
 
 /// The request body must be formatted as JSON. All fields are optional.
 
@@ -57,7 +58,7 @@ pub struct Request {
     body: Option<String>,
 
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<QueryString>,
 
     /// Has the request been validated?
     validated: bool,
@@ -7,9 +7,9 @@
 //! An unofficial Google Maps Platform client library for the Rust programming
 //! language.
 //!
-//! This client currently implements the Directions API, Distance Matrix API,
-//! Elevation API, Geocoding API, Time Zone API, and parts of the Places and
-//! Roads API.
+//! This client currently implements the Address Validation API, Directions
+//! API, Distance Matrix API, Elevation API, Geocoding API, Maps Static API,
+//! Time Zone API, and parts of the Places and Roads API.
 //!
 //! <img src="https://www.arkiteq.io/crates/google_maps/banner.jpg" alt="Unofficial Google Maps Platform Client for Rust" width="400"/>
 //!
@@ -19,7 +19,7 @@
 //!
 //! ```toml
 //! [dependencies]
-//! google_maps = "3.7"
+//! google_maps = "4.0"
 //! ```
 //!
 //! Optionally, add `rust_decimal = "1"` and `rust_decimal_macros = "1"` for
@@ -37,6 +37,7 @@
 //!
 //! ### Google Maps Client Feature Flags:
 //!
+//! * `address_validation` ‧ includes Google Maps Address Validation API
 //! * `autocomplete` ‧ includes Google Maps Places autocomplete API
 //! * `directions` ‧ includes Google Maps Directions API
 //! * `distance_matrix` ‧ includes Google Maps Distance Matrix API
@@ -44,6 +45,7 @@
 //! * `geocoding` ‧ includes Google Maps Geocoding API
 //! * `places` ‧ includes Google Maps Places API
 //! * `roads` ‧ includes Google Maps Roads API
+//! * `static_map` ‧ includes Google Maps Static API
 //! * `time_zone` ‧ includes Google Maps Time Zone API
 //! * `reqwest` ‧ uses [reqwest](https://crates.io/crates/reqwest) for querying
 //!   the Google Maps API
@@ -52,6 +54,13 @@
 //! * `geo` ‧ support for the rust [geo](https://crates.io/crates/geo) ecosystem
 //! * `polyline` ‧ allows easy type conversions from a `Route` or `Step` to a geo
 //!   [LineString](https://docs.rs/geo-types/0.7.13/geo_types/geometry/struct.LineString.html)
+//! * `unredacted-logs` ‧ includes API keys, client IDs, and URL signatures in
+//!   full in `Debug` output, logged URLs, and HTTP client errors, which are
+//!   otherwise redacted. For local debugging only
+//! * `diagnostics` ‧ implements [miette](https://crates.io/crates/miette)'s
+//!   `Diagnostic` trait for the error types, with error codes and help text.
+//!   Without it, the error types still implement `std::error::Error`, and
+//!   `miette` is not compiled
 //!
 //! Note: the `autocomplete` feature covers the Places API autocomplete-related
 //! services:
@@ -65,16 +74,27 @@
 //!
 //! * `reqwest-native-tls` ‧ Enables TLS functionality provided by `native-tls`.
 //! * `reqwest-rustls` ‧ Enables TLS functionality provided by `rustls`.
+//! * `reqwest-gzip` and `reqwest-brotli` ‧ Request compressed responses, and
+//!   decompress them. Google's JSON responses are verbose and repetitive, and
+//!   compress well: in this crate's tests, gzip shrinks a 10 × 10 distance
+//!   matrix response from 31 kB to under 2 kB. Compression may be turned off
+//!   at runtime with `ClientBuilder::without_compression()`.
+//! * `runtime-agnostic` ‧ Waits out retry delays, request deadlines, and rate
+//!   limits with a timer that works on any async runtime, instead of Tokio's
+//!   timer. Tokio is still a dependency, because `reqwest` is built on it. See
+//!   _Async Runtimes_ below.
 //!
 //! ### Default Feature Flags
 //!
 //! By default, the Google Maps client includes all implemented Google Maps
 //! APIs. Reqwest will secure the connection using the system-native TLS
-//! (`native-tls`), and has gzip compression enabled (`gzip`).
+//! (`native-tls`), and has gzip and brotli compression enabled (`reqwest-gzip`
+//! and `reqwest-brotli`).
 //!
 //! ```toml
 //! default = [
 //!     # google_maps default features:
+//!     "address_validation",
 //!     "directions",
 //!     "distance_matrix",
 //!     "elevation",
@@ -85,11 +105,15 @@
 //!     "autocomplete",
 //!     "roads",
 //!     "places",
+//!     "static_map",
+//!     # Implements `miette::Diagnostic` for the error types:
+//!     "diagnostics",
 //!
 //!     # reqwest default features:
 //!     "reqwest",
 //!     "reqwest-default-tls",
 //!     "reqwest-http2",
+//!     "reqwest-gzip",
 //!     "reqwest-brotli",
 //!
 //!     # rust_decimal default features:
@@ -109,7 +133,7 @@
 //!
 //! ```toml
 //! google_maps = {
-//!     version = "3.7",
+//!     version = "4.0",
 //!     default-features = false,
 //!     features = [
 //!         "directions",
@@ -339,11 +363,47 @@
 //! with an empty body to all requests. This API cannot be implemented until the
 //! server responds as expected.
 //!
+//! ### Long Requests
+//!
+//! Google's web services limit request URLs to 16,384 characters. Apart from
+//! the Address Validation API, which is sent as an HTTP `POST` request with a
+//! JSON body, Google's APIs only accept `GET` requests, so a long request
+//! can't be switched to `POST`. Instead:
+//!
+//! * Elevation requests with more locations than Google allows are split into
+//!   several requests, see `with_chunking()`.
+//!
+//! * Paths and waypoints can be sent as encoded polylines, which are much
+//!   shorter than lists of coordinates. Elevation locations and paths may be
+//!   given as polylines with `with_encoded_locations()` and
+//!   `with_encoded_path()`, and lists of coordinates are encoded automatically
+//!   when that is shorter.
+//!
+//! A warning is logged when a request URL is longer than Google's limit.
+//!
 //! ### Controlling Request Settings
 //!
 //! The Google Maps client settings can be used to change the request rate and
 //! automatic retry parameters.
 //!
+//! Requests are retried with exponential backoff when the HTTP client times out
+//! or cannot connect, when the server responds with a `5xx` or `429 Too Many
+//! Requests` status code, or when Google responds `UNKNOWN_ERROR` or
+//! `OVER_QUERY_LIMIT`. Other statuses, such as `REQUEST_DENIED` or
+//! `INVALID_REQUEST`, are returned to the caller as errors immediately.
+//! `ZERO_RESULTS` is not an error. Use `with_retry_classifier()` to override
+//! which errors are retried, and a request's `with_deadline()` method to limit
+//! the total time that it may take, including its retries. Use the client's
+//! `with_backoff()` method to change the default retry policy, for example to
+//! `no_delay_backoff()`, so that tests of the retry logic against a mock server
+//! run without waiting.
+//!
+//! An exceeded quota is returned as a `GoogleMapsError::Quota` error by every
+//! API, whether Google responded with `OVER_DAILY_LIMIT`, `OVER_QUERY_LIMIT`, or
+//! an HTTP `429` or `403` response that gives a quota as the reason. Its
+//! `QuotaKind` tells a rate limit, which is retried, from a daily quota, which
+//! is not.
+//!
 //! ```rust
 //! use google_maps::prelude::*;
 //!
@@ -356,6 +416,27 @@
 //!     .build();
 //! ```
 //!
+//! ### Async Runtimes
+//!
+//! This crate waits out retry delays, request deadlines, rate limits, and the
+//! delay before a next page of Places results may be requested with Tokio's
+//! timer. Enable the `runtime-agnostic` feature to use a timer that works on any
+//! async runtime, such as `async-std` or `smol`, instead. This feature does not
+//! remove the `tokio` dependency. It only means that this crate's own delays no
+//! longer need a Tokio runtime with its timer enabled.
+//!
+//! `reqwest` is built on Tokio: its connections need a Tokio reactor, which may be
+//! provided on other runtimes with a compatibility layer such as
+//! [async-compat](https://crates.io/crates/async-compat). Its timeouts and idle
+//! connection pool also use Tokio's timer. To avoid Tokio's timer entirely,
+//! provide a `reqwest` client that has neither:
+//!
+//! ```rust
+//! let google_maps_client = GoogleMapsClient::builder("YOUR_GOOGLE_API_KEY_HERE")
+//!     .with_reqwest_client(reqwest::Client::builder().pool_idle_timeout(None).build()?)
+//!     .build()?;
+//! ```
+//!
 //! # Feedback
 //!
 //! I would like for you to be successful with your project! If this crate is
@@ -369,13 +450,13 @@
 //! - [ ] Track both _requests_ and request _elements_ for rate limiting.
 //! - [ ] Make a generic `get()` function for that can be used by all APIs.
 //! - [ ] Convert explicit query validation to session types wherever
-//!     reasonable.
+//!   reasonable.
 //! - [ ] [Places API](https://developers.google.com/places/web-service/intro).
-//!     Only partly implemented. If you would like to have any missing pieces
-//!     implemented, please contact me.
+//!   Only partly implemented. If you would like to have any missing pieces
+//!   implemented, please contact me.
 //! - [ ] [Roads API](https://developers.google.com/maps/documentation/roads/intro).
-//!     Only partly implemented. If you would like to have any missing pieces
-//!     implemented, please contact me.
+//!   Only partly implemented. If you would like to have any missing pieces
+//!   implemented, please contact me.
 //!
 //! # Author's Note
 //!
@@ -410,13 +491,42 @@
 
 mod client;
 pub mod error;
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places",
+    feature = "roads",
+    feature = "static_map",
+    feature = "time_zone"
+))]
+mod fingerprint;
+#[cfg(any(
+    feature = "autocomplete",
+    feature = "directions",
+    feature = "distance_matrix",
+    feature = "elevation",
+    feature = "geocoding",
+    feature = "places",
+    feature = "roads",
+    feature = "static_map",
+    feature = "time_zone"
+))]
+pub mod polyline;
 pub mod prelude;
+#[cfg(feature = "reqwest")]
+mod quota;
+mod redact;
 mod serde;
 pub mod types;
 
 // Optional Google Maps API modules. Their inclusion can be changed with
 // feature flags:
 
+#[cfg(feature = "address_validation")]
+pub mod address_validation;
 #[cfg(any(feature = "directions", feature = "distance_matrix"))]
 pub mod directions;
 #[cfg(feature = "distance_matrix")]
@@ -430,6 +540,8 @@ pub mod places;
 pub mod reqwest_maybe_middleware;
 #[cfg(feature = "roads")]
 pub mod roads;
+#[cfg(feature = "static_map")]
+pub mod static_map;
 #[cfg(feature = "time_zone")]
 pub mod time_zone;
 
@@ -443,6 +555,7 @@ pub use crate::{
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::address_component::AddressComponent;
 #[cfg(any(
+    feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
@@ -455,6 +568,8 @@ pub use crate::types::bounds::Bounds;
     feature = "geocoding"
 ))]
 pub use crate::types::country::Country;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::currency::Currency;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
 #[cfg(any(
@@ -467,6 +582,7 @@ pub use crate::types::geometry::Geometry;
 ))]
 pub use crate::types::language::Language;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
@@ -474,11 +590,14 @@ pub use crate::types::language::Language;
     feature = "geocoding",
     feature = "places",
     feature = "roads",
+    feature = "static_map",
     feature = "time_zone"
 ))]
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::location_type::LocationType;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::money::Money;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -501,6 +620,15 @@ pub use crate::types::region::Region;
 #[cfg(feature = "reqwest")]
 mod request_rate;
 
+#[cfg(feature = "reqwest")]
+pub use crate::client::ClientBuilder;
+
+#[cfg(feature = "reqwest")]
+pub use crate::client::{no_delay_backoff, RetryClassifier, RetryDecision};
+
+#[cfg(feature = "reqwest")]
+pub use crate::quota::QuotaKind;
+
 #[cfg(feature = "reqwest")]
 pub use crate::request_rate::api::Api;
 
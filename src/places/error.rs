@@ -3,6 +3,7 @@
 // -----------------------------------------------------------------------------
 
 use crate::places::status::Status;
+#[cfg(feature = "diagnostics")]
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -10,14 +11,25 @@ use thiserror::Error;
 //
 /// Errors that may be produced by the Google Maps Places API client.
 
-#[derive(Debug, Diagnostic, Error)]
-#[diagnostic(code(google_maps::places::error), url(docsrs))]
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+#[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::places::error), url(docsrs)))]
 pub enum Error {
     /// Google Maps Places API server generated an error. See the `Status`
-    /// enum for more information.
+    /// enum for more information. A `ZERO_RESULTS` status is not an error:
+    /// the response is returned with no results.
     GoogleMapsService(Status, Option<String>),
     /// The HTTP request was unsuccessful.
     HttpUnsuccessful(String),
+    /// The HTTP request was unsuccessful. Contains the HTTP status code, the
+    /// headers that help explain the failure (such as `Retry-After` and
+    /// Google's `X-Goog-*` headers), and the truncated response body.
+    #[cfg(feature = "reqwest")]
+    HttpUnsuccessfulResponse {
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String,
+    },
     /// API client library attempted to parse a string that contained an invalid
     /// status code. See
     /// `google_maps\src\places\place_autocomplete\response\status.rs` for more
@@ -40,12 +52,37 @@ pub enum Error {
     /// `google_maps\src\places\place_details\rank_by.rs` for more information.
     InvalidRankByCode(String),
     /// API client library attempted to parse a string that contained an invalid
+    /// input type code. See
+    /// `google_maps\src\places\place_search\find_place\input_type.rs` for
+    /// more information.
+    InvalidInputTypeCode(String),
+    /// API client library attempted to parse a string that contained an invalid
     /// sort order type code. See
     /// `google_maps\src\places\place_details\sort_order.rs` for more information.
     InvalidSortOrderCode(String),
+    /// The field is not supported by the Places API endpoint. Contains the
+    /// name of the endpoint and the field code.
+    UnsupportedField(String, String),
+    /// A price level must be between 0 (most affordable) and 4 (most
+    /// expensive), inclusive.
+    InvalidPriceLevel(u8),
+    /// A _Place Photo_ request must specify a maximum width, a maximum height,
+    /// or both.
+    MaxWidthOrMaxHeightRequired,
+    /// The minimum price of a search must not be greater than its maximum
+    /// price.
+    MinPriceGreaterThanMaxPrice(u8, u8),
     /// The query string must be built before the request may be sent to the
     /// Google Maps Places API server.
     QueryNotBuilt,
+    /// A _Nearby Search_ that is ranked by prominence must specify a radius.
+    RadiusRequired,
+    /// A _Nearby Search_ that is ranked by distance must not specify a radius.
+    /// Contains the radius that was specified.
+    RankByDistanceWithRadius(u32),
+    /// A _Nearby Search_ that is ranked by distance must specify a keyword or
+    /// a place type.
+    RankByDistanceWithoutKeywordOrType,
     /// The dependency library Reqwest generated an error.
     #[cfg(feature = "reqwest")]
     Reqwest(crate::ReqError),
@@ -55,6 +92,20 @@ pub enum Error {
     ReqwestMessage(String),
     /// The dependency library Serde JSON generated an error.
     SimdJson(simd_json::Error),
+    /// The dependency library Serde JSON could not parse the response from the
+    /// server. The response body is included to help with debugging. It is
+    /// truncated to the length set by `GoogleMapsClient::with_error_body_length`.
+    SimdJsonWithBody(simd_json::Error, String),
+    /// The service responded successfully, but not with JSON (or, for Place
+    /// Photo requests, an image), for example with an HTML error page from a
+    /// proxy or a sign-in page. Contains the response's `Content-Type` header,
+    /// and the response body truncated to the length set by
+    /// `GoogleMapsClient::with_error_body_length`.
+    #[cfg(feature = "reqwest")]
+    UnexpectedContentType {
+        content_type: String,
+        body_snippet: String,
+    },
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -93,16 +144,26 @@ impl std::fmt::Display for Error {
                         Not found. \
                         Referenced location, `place_id`, was not found \
                         in the Places database."),
+                    Status::Other(status_code) => write!(f, "Google Maps Places API service: \
+                        Unrecognized status `{status_code}`."),
                 } // match
             }, // match
             Self::HttpUnsuccessful(status) => write!(f,
                 "Google Maps Places API client: \
                 Could not successfully query the Google Cloud Platform service. \
                 The service last responded with a `{status}` status."),
+            #[cfg(feature = "reqwest")]
+            Self::HttpUnsuccessfulResponse { status, body, .. } => write!(f,
+                "Google Maps Places API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status: `{body}`"),
             Self::InvalidBusinessStatusCode(status_code) => write!(f, "Google Maps Places API client: \
                 `{status_code}` is not a valid business status code. \
                 Valid codes are `OPERATIONAL`, `CLOSED_TEMPORARILY`, and \
                 `CLOSED_PERMANENTLY`."),
+            Self::InvalidInputTypeCode(type_code) => write!(f, "Google Maps Places API client: \
+                `{type_code}` is not a valid input type. \
+                Valid input types are `textquery` and `phonenumber`."),
             Self::InvalidRankByCode(type_code) => write!(f, "Google Maps Places API client: \
                 `{type_code}` is not a valid rankby order type. \
                 Valid codes are `prominence`, and `distance`."),
@@ -123,14 +184,42 @@ impl std::fmt::Display for Error {
             Self::InvalidSortOrderCode(sort_order_code) => write!(f, "Google Maps Places API client: \
                 `{sort_order_code}` is not a valid sort order code. \
                 Valid codes are `most_relevant` and `newest`."),
+            Self::UnsupportedField(endpoint, field) => write!(f, "Google Maps Places API client: \
+                The `{field}` field is not supported by the {endpoint} endpoint. \
+                Remove it from the field mask to avoid an invalid or unexpectedly billed request."),
+            Self::InvalidPriceLevel(price) => write!(f, "Google Maps Places API client: \
+                `{price}` is not a valid price level. \
+                Valid price levels are 0 (most affordable) to 4 (most expensive), inclusive."),
+            Self::MaxWidthOrMaxHeightRequired => write!(f, "Google Maps Places API client: \
+                A Place Photo request must specify a maximum width, a maximum height, or both. \
+                Use the with_max_width() or with_max_height() methods."),
+            Self::MinPriceGreaterThanMaxPrice(minprice, maxprice) => write!(f, "Google Maps Places API client: \
+                The minimum price `{minprice}` is greater than the maximum price `{maxprice}`. \
+                Try again with a minimum price that is less than or equal to the maximum price."),
             Self::QueryNotBuilt => write!(f, "Google Maps Places API client library: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
+            Self::RadiusRequired => write!(f, "Google Maps Places API client: \
+                A Nearby Search that is ranked by prominence must specify a radius. \
+                Use the nearby_search() method, which accepts a radius, to create the request."),
+            Self::RankByDistanceWithRadius(radius) => write!(f, "Google Maps Places API client: \
+                A Nearby Search that is ranked by distance must not specify a radius, but a radius of `{radius}` meters was set. \
+                Use the nearby_search_by_distance() method, which does not accept a radius, to create the request."),
+            Self::RankByDistanceWithoutKeywordOrType => write!(f, "Google Maps Places API client: \
+                A Nearby Search that is ranked by distance must specify a keyword, a place type, or both. \
+                Use the with_keyword() or with_type() methods."),
             #[cfg(feature = "reqwest")]
             Self::Reqwest(error) => write!(f, "Google Maps Places API client in the Reqwest library: {error}"),
             #[cfg(feature = "reqwest")]
             Self::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Self::SimdJson(error) => write!(f, "Google Maps Places API client in the Serde JSON library: {error}"),
+            Self::SimdJsonWithBody(error, body) => write!(f, "Google Maps Places API client in the Serde JSON library: {error}. \
+                The response body was: `{body}`"),
+            #[cfg(feature = "reqwest")]
+            Self::UnexpectedContentType { content_type, body_snippet } => write!(f,
+                "Google Maps Places API client: \
+                The service responded with `{content_type}` content instead of JSON or an image. \
+                This is usually an error page. The response body was: `{body_snippet}`"),
         } // match
     } // fn
 } // impl
@@ -146,3 +235,46 @@ impl From<simd_json::Error> for Error {
         Self::SimdJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl crate::client::HttpError for Error {
+    /// Reports the HTTP-level failures of the retry loop as Google Maps
+    /// Places API errors.
+    fn unexpected_content_type(content_type: String, body_snippet: String) -> Self {
+        Self::UnexpectedContentType {
+            content_type,
+            body_snippet,
+        } // UnexpectedContentType
+    } // fn
+
+    fn http_unsuccessful_response(
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String
+    ) -> Self {
+        Self::HttpUnsuccessfulResponse {
+            status,
+            headers,
+            body,
+        } // HttpUnsuccessfulResponse
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(message: String) -> Self {
+        Self::ReqwestMessage(message)
+    } // fn
+} // impl
+
+#[cfg(feature = "reqwest")]
+impl crate::client::JsonError for Error {
+    /// Reports a response body that could not be parsed as a Google Maps
+    /// Places API error.
+    fn simd_json_with_body(error: simd_json::Error, body: String) -> Self {
+        Self::SimdJsonWithBody(error, body)
+    } // fn
+} // impl
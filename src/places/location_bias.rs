@@ -0,0 +1,126 @@
+//! Contains the `LocationBias` enum and its associated traits. It is used to
+//! prefer results in a particular area, without excluding results outside of
+//! it.
+
+use crate::error::Error as GoogleMapsError;
+use crate::types::{Bounds, Error as TypeError, LatLng};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Prefers results in a specified area, by specifying either a radius plus
+/// latitude/longitude, or two latitude/longitude pairs representing the points
+/// of a rectangle. If this parameter is not specified, the API uses IP address
+/// biasing by default.
+///
+/// Use `LocationBias::try_circle()` and `LocationBias::try_rectangle()` to
+/// create a validated circle or rectangle.
+///
+/// See Google's documentation on the
+/// [locationbias](https://developers.google.com/maps/documentation/places/web-service/autocomplete#locationbias)
+/// parameter.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum LocationBias {
+    /// Instructs the API to use IP address biasing.
+    IpBias,
+    /// A single latitude/longitude coordinate.
+    Point(LatLng),
+    /// A circle, specified by its center and its radius in meters.
+    Circle {
+        /// The center of the circle.
+        center: LatLng,
+        /// The radius of the circle, in meters. It must be greater than zero.
+        radius: u32,
+    },
+    /// A rectangle, specified by its south-west and north-east corners.
+    Rectangle(Bounds),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl LocationBias {
+    /// Creates a circular location bias, after checking that its radius is
+    /// greater than zero.
+    ///
+    /// ## Arguments
+    ///
+    /// * `center` ‧ The center of the circle.
+    ///
+    /// * `radius` ‧ The radius of the circle, in meters.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the radius is zero.
+
+    pub fn try_circle(center: impl Into<LatLng>, radius: u32) -> Result<Self, GoogleMapsError> {
+        let location_bias = Self::Circle { center: center.into(), radius };
+        location_bias.validate()?;
+        Ok(location_bias)
+    } // fn
+
+    /// Creates a rectangular location bias, after checking that the
+    /// south-west corner is not north of the north-east corner.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bounds` ‧ The south-west and north-east corners of the rectangle. A
+    ///   rectangle whose south-west longitude is greater than its north-east
+    ///   longitude crosses the antimeridian.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the corners are in the wrong order.
+
+    pub fn try_rectangle(bounds: impl Into<Bounds>) -> Result<Self, GoogleMapsError> {
+        let location_bias = Self::Rectangle(bounds.into());
+        location_bias.validate()?;
+        Ok(location_bias)
+    } // fn
+
+    /// Checks that a circle's radius is greater than zero, and that a
+    /// rectangle's south-west corner is not north of its north-east corner.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the location bias is not valid.
+
+    pub fn validate(&self) -> Result<(), GoogleMapsError> {
+        let is_valid = match self {
+            Self::IpBias | Self::Point(_) => true,
+            Self::Circle { radius, .. } => *radius > 0,
+            Self::Rectangle(bounds) => bounds.southwest.lat <= bounds.northeast.lat,
+        }; // match
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(TypeError::InvalidLocationBias(self.to_string()))?
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for LocationBias {
+    /// Converts a `LocationBias` enum to a `String` in Google's
+    /// `ipbias`, `point:lat,lng`, `circle:radius@lat,lng`, or
+    /// `rectangle:south,west|north,east` format.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::IpBias => write!(f, "ipbias"),
+            Self::Point(point) => write!(f, "point:{point}"),
+            Self::Circle { center, radius } => write!(f, "circle:{radius}@{center}"),
+            Self::Rectangle(bounds) => write!(f, "rectangle:{bounds}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&LocationBias> for String {
+    /// Converts a `LocationBias` enum to a `String` in Google's format.
+    fn from(location_bias: &LocationBias) -> Self {
+        location_bias.to_string()
+    } // fn
+} // impl
@@ -0,0 +1,118 @@
+//! Contains the `LocationRestriction` enum and its associated traits. It is
+//! used to only return results in a particular area.
+
+use crate::error::Error as GoogleMapsError;
+use crate::places::location_bias::LocationBias;
+use crate::types::{Bounds, LatLng};
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Restricts results to a specified area, by specifying either a radius plus
+/// latitude/longitude, or two latitude/longitude pairs representing the points
+/// of a rectangle. Unlike a `LocationBias`, results outside of the area are not
+/// returned.
+///
+/// Use `LocationRestriction::try_circle()` and
+/// `LocationRestriction::try_rectangle()` to create a validated circle or
+/// rectangle.
+///
+/// See Google's documentation on the
+/// [locationrestriction](https://developers.google.com/maps/documentation/places/web-service/autocomplete#locationrestriction)
+/// parameter.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum LocationRestriction {
+    /// A circle, specified by its center and its radius in meters.
+    Circle {
+        /// The center of the circle.
+        center: LatLng,
+        /// The radius of the circle, in meters. It must be greater than zero.
+        radius: u32,
+    },
+    /// A rectangle, specified by its south-west and north-east corners.
+    Rectangle(Bounds),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl LocationRestriction {
+    /// Creates a circular location restriction, after checking that its
+    /// radius is greater than zero.
+    ///
+    /// ## Arguments
+    ///
+    /// * `center` ‧ The center of the circle.
+    ///
+    /// * `radius` ‧ The radius of the circle, in meters.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the radius is zero.
+
+    pub fn try_circle(center: impl Into<LatLng>, radius: u32) -> Result<Self, GoogleMapsError> {
+        let location_restriction = Self::Circle { center: center.into(), radius };
+        location_restriction.validate()?;
+        Ok(location_restriction)
+    } // fn
+
+    /// Creates a rectangular location restriction, after checking that the
+    /// south-west corner is not north of the north-east corner.
+    ///
+    /// ## Arguments
+    ///
+    /// * `bounds` ‧ The south-west and north-east corners of the rectangle.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the corners are in the wrong order.
+
+    pub fn try_rectangle(bounds: impl Into<Bounds>) -> Result<Self, GoogleMapsError> {
+        let location_restriction = Self::Rectangle(bounds.into());
+        location_restriction.validate()?;
+        Ok(location_restriction)
+    } // fn
+
+    /// Checks that a circle's radius is greater than zero, and that a
+    /// rectangle's south-west corner is not north of its north-east corner.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the location restriction is not valid.
+
+    pub fn validate(&self) -> Result<(), GoogleMapsError> {
+        LocationBias::from(self).validate()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&LocationRestriction> for LocationBias {
+    /// Converts a `LocationRestriction` into the equivalent `LocationBias`,
+    /// which covers the same area but does not exclude results outside of it.
+    fn from(location_restriction: &LocationRestriction) -> Self {
+        match *location_restriction {
+            LocationRestriction::Circle { center, radius } => Self::Circle { center, radius },
+            LocationRestriction::Rectangle(bounds) => Self::Rectangle(bounds),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for LocationRestriction {
+    /// Converts a `LocationRestriction` enum to a `String` in Google's
+    /// `circle:radius@lat,lng` or `rectangle:south,west|north,east` format.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        LocationBias::from(self).fmt(f)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&LocationRestriction> for String {
+    /// Converts a `LocationRestriction` enum to a `String` in Google's format.
+    fn from(location_restriction: &LocationRestriction) -> Self {
+        location_restriction.to_string()
+    } // fn
+} // impl
@@ -88,7 +88,22 @@ pub use crate::places::place_autocomplete::{
 }; // crate::places::place_autocomplete
 
 #[cfg(feature = "autocomplete")]
-pub use crate::places::place_autocomplete::request::Request as QueryAutocompleteRequest; // crate::places::place_autocomplete
+pub use crate::places::query_autocomplete::request::Request as QueryAutocompleteRequest; // crate::places::query_autocomplete
+
+// -----------------------------------------------------------------------------
+
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+pub mod location_bias;
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+pub mod location_restriction;
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+pub mod session_token;
+
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+pub use crate::places::{
+    location_bias::LocationBias, location_restriction::LocationRestriction,
+    session_token::SessionToken,
+}; // crate::places
 
 // -----------------------------------------------------------------------------
 
@@ -132,3 +147,6 @@ pub use crate::places::{
     place_review::PlaceReview, place_special_day::PlaceSpecialDay, rank_by::RankBy,
     secondary_hours_type::SecondaryHoursType, status::Status,
 }; // crate::places
+
+#[cfg(feature = "places")]
+pub use crate::places::place_details::{field::Field as PlaceField, field_mask::FieldMask}; // crate::places::place_details
@@ -11,7 +11,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &'a mut Request<'_> {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -36,10 +36,11 @@ impl<'a> Request<'a> {
             query.push_str(&utf8_percent_encode(&components, NON_ALPHANUMERIC).to_string());
         } // if
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // one has not been set for this request:
+        if let Some(language) = self.language.or(self.client.language) {
             query.push_str("&language=");
-            query.push_str(&String::from(language));
+            query.push_str(&String::from(&language));
         }
 
         // Location key/value pair:
@@ -48,6 +49,20 @@ impl<'a> Request<'a> {
             query.push_str(&String::from(location));
         }
 
+        // Location Bias key/value pair:
+        if let Some(locationbias) = &self.locationbias {
+            query.push_str("&locationbias=");
+            query.push_str(&utf8_percent_encode(&String::from(locationbias), NON_ALPHANUMERIC).to_string());
+        }
+
+        // Location Restriction key/value pair:
+        if let Some(locationrestriction) = &self.locationrestriction {
+            query.push_str("&locationrestriction=");
+            query.push_str(
+                &utf8_percent_encode(&String::from(locationrestriction), NON_ALPHANUMERIC).to_string()
+            );
+        }
+
         // Offset key/value pair:
         if let Some(offset) = &self.offset {
             query.push_str("&offset=");
@@ -66,10 +81,11 @@ impl<'a> Request<'a> {
             query.push_str(&radius.to_string());
         }
 
-        // Region key/value pair:
-        if let Some(region) = &self.region {
+        // Region key/value pair. The client's default region is used if one
+        // has not been set for this request:
+        if let Some(region) = self.region.or(self.client.region) {
             query.push_str("&region=");
-            query.push_str(&String::from(region));
+            query.push_str(&String::from(&region));
         }
 
         // Session Token key/value pair:
@@ -97,7 +113,7 @@ impl<'a> Request<'a> {
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
@@ -7,24 +7,41 @@ pub mod autocomplete_type;
 mod build;
 #[cfg(feature = "reqwest")]
 mod execute;
+mod fingerprint;
 #[cfg(feature = "reqwest")]
 mod get;
+#[cfg(feature = "reqwest")]
+mod get_raw;
 mod new;
 mod query_url;
+#[cfg(feature = "reqwest")]
+mod url;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
 mod with_components;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
 mod with_language;
 mod with_location;
+mod with_location_bias;
+mod with_location_restriction;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
 mod with_offset;
 mod with_origin;
 mod with_region;
 mod with_sessiontoken;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
 mod with_types;
 
 // -----------------------------------------------------------------------------
 
 use crate::client::GoogleMapsClient;
 use crate::places::place_autocomplete::request::autocomplete_type::AutocompleteType;
+use crate::places::{LocationBias, LocationRestriction};
 use crate::types::{Country, Language, LatLng, Region};
+use crate::redact::QueryString;
 
 // -----------------------------------------------------------------------------
 
@@ -94,6 +111,14 @@ pub struct Request<'a> {
     ///   'Market in Barcelona'.
     location: Option<LatLng>,
 
+    /// Prefers results in a specified area. See method
+    /// `with_location_bias()` for more information.
+    locationbias: Option<LocationBias>,
+
+    /// Restricts results to a specified area. See method
+    /// `with_location_restriction()` for more information.
+    locationrestriction: Option<LocationRestriction>,
+
     /// The position, in the input term, of the last character that the service
     /// uses to match predictions. For example, if the input is `Google` and the
     /// offset is 3, the service will match on `Goo`. The string determined by
@@ -183,8 +208,30 @@ pub struct Request<'a> {
     /// specifying no types.
     types: Vec<AutocompleteType>,
 
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<QueryString>,
 } // struct
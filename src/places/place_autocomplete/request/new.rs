@@ -16,7 +16,7 @@ impl Request<'_> {
     /// * `input` ‧ The text string on which to search.
 
     #[must_use]
-    pub fn new(client: &GoogleMapsClient, input: impl Into<String>) -> Request {
+    pub fn new(client: &GoogleMapsClient, input: impl Into<String>) -> Request<'_> {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
@@ -26,6 +26,8 @@ impl Request<'_> {
             components: vec![],
             language: None,
             location: None,
+            locationbias: None,
+            locationrestriction: None,
             offset: None,
             origin: None,
             radius: None,
@@ -33,6 +35,15 @@ impl Request<'_> {
             sessiontoken: None,
             strictbounds: None,
             types: vec![],
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         } // struct
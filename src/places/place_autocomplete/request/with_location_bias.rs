@@ -0,0 +1,48 @@
+use crate::places::{place_autocomplete::request::Request, LocationBias};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds the location bias parameter to the Place API _Place Autocomplete_
+    /// query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `locationbias` ‧ Prefer results in a specified area, by specifying
+    ///   either a radius plus latitude/longitude, or two latitude/longitude
+    ///   pairs representing the points of a rectangle. If this parameter is not
+    ///   specified, the API uses IP address biasing by default.
+    ///
+    /// Results outside of the area may still be returned. Use
+    /// `with_location_restriction()` to exclude them. Google recommends this
+    /// parameter over the older `location` and `radius` parameters, set by
+    /// `with_location_and_radius()`, which should not be used with it.
+    ///
+    /// Use `LocationBias::try_circle()` or `LocationBias::try_rectangle()` to
+    /// check the radius or the rectangle's corners before they are sent.
+    ///
+    /// ## Examples:
+    ///
+    /// * Prefer results within 10 km of downtown Toronto:
+    /// ```rust
+    /// .with_location_bias(LocationBias::try_circle(
+    ///     LatLng::try_new(43.653_2, -79.383_2)?,
+    ///     10_000,
+    /// )?)
+    /// ```
+    ///
+    /// * Prefer results near the user's IP address:
+    /// ```rust
+    /// .with_location_bias(LocationBias::IpBias)
+    /// ```
+
+    pub fn with_location_bias(
+        &'a mut self,
+        locationbias: impl Into<LocationBias>
+    ) -> &'a mut Self {
+        // Set location bias in Request struct.
+        self.locationbias = Some(locationbias.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
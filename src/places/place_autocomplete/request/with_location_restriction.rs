@@ -0,0 +1,41 @@
+use crate::places::{place_autocomplete::request::Request, LocationRestriction};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds the location restriction parameter to the Place API _Place
+    /// Autocomplete_ query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `locationrestriction` ‧ Restrict results to a specified area, by
+    ///   specifying either a radius plus latitude/longitude, or two
+    ///   latitude/longitude pairs representing the points of a rectangle.
+    ///
+    /// Unlike `with_location_bias()`, results outside of the area are not
+    /// returned, even if they match the user input.
+    ///
+    /// Use `LocationRestriction::try_circle()` or
+    /// `LocationRestriction::try_rectangle()` to check the radius or the
+    /// rectangle's corners before they are sent.
+    ///
+    /// ## Example
+    ///
+    /// * Only return results in downtown Toronto:
+    /// ```rust
+    /// .with_location_restriction(LocationRestriction::try_rectangle(Bounds::new(
+    ///     LatLng::try_new(43.639_8, -79.396_6)?,
+    ///     LatLng::try_new(43.656_3, -79.372_3)?,
+    /// ))?)
+    /// ```
+
+    pub fn with_location_restriction(
+        &'a mut self,
+        locationrestriction: impl Into<LocationRestriction>
+    ) -> &'a mut Self {
+        // Set location restriction in Request struct.
+        self.locationrestriction = Some(locationrestriction.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
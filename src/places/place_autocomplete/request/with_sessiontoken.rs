@@ -27,7 +27,9 @@ impl<'a> Request<'a> {
     /// * Use session tokens for all autocomplete sessions.
     ///
     /// * Generate a fresh token for each session. Using a version 4 UUID is
-    ///   recommended.
+    ///   recommended. `SessionToken::new()` generates one, and a borrowed
+    ///   `&SessionToken` can be passed to both the autocomplete and the details
+    ///   requests of a session.
     ///
     /// * Ensure that the API key(s) used for all Place Autocomplete and Place
     ///   Details requests within a session belong to the same Cloud Console
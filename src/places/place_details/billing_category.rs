@@ -0,0 +1,39 @@
+//! Contains the `BillingCategory` enum and its associated traits. It specifies
+//! the billing tier that a place details field belongs to.
+
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// Place details fields are divided into three billing categories. Basic fields
+/// are billed at base rate, and incur no additional charges. Contact and
+/// Atmosphere fields are billed at a higher rate. See the [pricing
+/// sheet](https://cloud.google.com/maps-platform/pricing/sheet/) for more
+/// information.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum BillingCategory {
+    /// Fields that are billed at the base rate of the request, such as the
+    /// place's name, address, and geometry.
+    Basic = 0,
+    /// Fields that describe how to reach the place, such as its phone number,
+    /// website, and opening hours.
+    Contact = 1,
+    /// Fields that describe the place's atmosphere, such as its rating,
+    /// reviews, and whether it serves breakfast.
+    Atmosphere = 2,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for BillingCategory {
+    /// Formats a `BillingCategory` enum into a string that is presentable to
+    /// the end user.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Basic => write!(f, "Basic"),
+            Self::Contact => write!(f, "Contact"),
+            Self::Atmosphere => write!(f, "Atmosphere"),
+        } // match
+    } // fn
+} // impl
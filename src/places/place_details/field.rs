@@ -3,6 +3,7 @@
 //! status, price level, wheelchair accessible, and so on.
 
 use crate::error::Error as GoogleMapsError;
+use crate::places::place_details::BillingCategory;
 use phf::phf_map;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -24,7 +25,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 ///   the same fields. Place Search requests return a subset of the fields that
 ///   are returned by Place Details requests. If the field you want is not
 ///   returned by Place Search, you can use Place Search to get a `place_id`,
-///    then use that Place ID to make a Place Details request. For more
+///   then use that Place ID to make a Place Details request. For more
 ///   information on the fields that are unavailable in a Place Search request,
 ///   see [Places API fields
 ///   support](https://developers.google.com/maps/documentation/places/web-service/place-data-fields#places-api-fields-support).
@@ -347,4 +348,113 @@ impl Field {
             .collect::<Vec<String>>()
             .join(",")
     } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the billing category of the field. Requesting any Contact or
+    /// Atmosphere field causes the request to be billed at a higher rate. This
+    /// can be used to check the cost of a field mask before it is sent.
+    ///
+    /// `None` is returned for fields that are not known to this crate.
+
+    #[must_use]
+    pub const fn billing_category(&self) -> Option<BillingCategory> {
+        match self {
+            Self::AddressComponent
+            | Self::AdrAddress
+            | Self::BusinessStatus
+            | Self::FormattedAddress
+            | Self::Geometry
+            | Self::Icon
+            | Self::IconMaskBaseUri
+            | Self::IconBackgroundColor
+            | Self::Name
+            | Self::Photo
+            | Self::PlaceId
+            | Self::PlusCode
+            | Self::Type
+            | Self::Url
+            | Self::UtcOffset
+            | Self::Vicinity
+            | Self::WheelchairAccessibleEntrance => Some(BillingCategory::Basic),
+            Self::CurrentOpeningHours
+            | Self::FormattedPhoneNumber
+            | Self::InternationalPhoneNumber
+            | Self::OpeningHours
+            | Self::SecondaryOpeningHours
+            | Self::Website => Some(BillingCategory::Contact),
+            Self::CurbsidePickup
+            | Self::Delivery
+            | Self::DineIn
+            | Self::EditorialSummary
+            | Self::PriceLevel
+            | Self::Rating
+            | Self::Reservable
+            | Self::Reviews
+            | Self::ServesBeer
+            | Self::ServesBreakfast
+            | Self::ServesBrunch
+            | Self::ServesLunch
+            | Self::ServesVegetarianFood
+            | Self::ServesWine
+            | Self::Takeout
+            | Self::UserRatingsTotal => Some(BillingCategory::Atmosphere),
+            Self::Other(_) => None,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the field may be requested from the _Place Details_
+    /// endpoint.
+    ///
+    /// Every field that is known to this crate is supported. An `Other` field
+    /// is only supported if it looks like a field code: lowercase letters,
+    /// digits, and underscores, with forward slashes separating compound
+    /// values such as `opening_hours/open_now`. This stops a malformed code,
+    /// for example one containing a comma, from changing the field mask.
+
+    #[must_use]
+    pub fn is_supported_by_place_details(&self) -> bool {
+        match self {
+            Self::Other(field_code) => field_code.split('/').all(|segment| {
+                !segment.is_empty()
+                    && segment.bytes().all(|byte| byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'_')
+            }), // Other
+            _ => true,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the field may be requested from the _Find Place_
+    /// endpoint, which supports fewer fields than _Place Details_. See [Places
+    /// API fields
+    /// support](https://developers.google.com/maps/documentation/places/web-service/place-data-fields#places-api-fields-support)
+    /// for more information.
+
+    #[must_use]
+    pub const fn is_supported_by_find_place(&self) -> bool {
+        matches!(
+            self,
+            // Basic
+            Self::BusinessStatus
+                | Self::FormattedAddress
+                | Self::Geometry
+                | Self::Icon
+                | Self::IconMaskBaseUri
+                | Self::IconBackgroundColor
+                | Self::Name
+                | Self::Photo
+                | Self::PlaceId
+                | Self::PlusCode
+                | Self::Type
+                // Contact
+                | Self::OpeningHours
+                // Atmosphere
+                | Self::PriceLevel
+                | Self::Rating
+                | Self::UserRatingsTotal
+        ) // matches!
+    } // fn
 } // impl
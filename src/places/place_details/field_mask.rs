@@ -0,0 +1,188 @@
+//! Contains the `FieldMask` struct and its associated traits. It holds the set
+//! of fields that a Places API request should return, and is shared by every
+//! Places API request that accepts a `fields` parameter.
+
+use crate::places::{
+    error::Error as PlacesError,
+    place_details::{BillingCategory, Field},
+}; // crate::places
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+// -----------------------------------------------------------------------------
+//
+/// A deduplicated set of `Field`s, also known as a field mask, that controls
+/// which place data types a Places API request returns.
+///
+/// The fields that are requested determine how the request is billed. A
+/// `FieldMask` holds each field only once, and always formats its fields in
+/// the same order, so that two masks with the same fields produce identical
+/// query strings. Use `billing_category()` to check what a mask will cost
+/// before it is sent.
+///
+/// ## Examples:
+///
+/// ```rust
+/// use google_maps::places::{FieldMask, PlaceField};
+///
+/// let field_mask = FieldMask::from_iter([
+///     PlaceField::Name,
+///     PlaceField::Geometry,
+///     PlaceField::Name,
+/// ]);
+///
+/// assert_eq!(field_mask.to_string(), "geometry,name");
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct FieldMask(BTreeSet<Field>);
+
+// -----------------------------------------------------------------------------
+
+impl FieldMask {
+    // -------------------------------------------------------------------------
+    //
+    /// Creates an empty `FieldMask`.
+
+    #[must_use]
+    pub const fn new() -> Self {
+        Self(BTreeSet::new())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Adds a field to the mask. Returns `false` if the field was already in
+    /// the mask.
+
+    pub fn insert(&mut self, field: impl Into<Field>) -> bool {
+        self.0.insert(field.into())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the mask contains the specified field.
+
+    #[must_use]
+    pub fn contains(&self, field: &Field) -> bool {
+        self.0.contains(field)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the mask contains no fields.
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the number of fields in the mask.
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns an iterator over the fields in the mask.
+
+    pub fn iter(&self) -> impl Iterator<Item = &Field> {
+        self.0.iter()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the most expensive billing category of the fields in the mask,
+    /// which is the category that the request will be billed at. Fields that
+    /// are not known to this crate are ignored.
+    ///
+    /// `None` is returned if the mask has no known fields.
+
+    #[must_use]
+    pub fn billing_category(&self) -> Option<BillingCategory> {
+        self.0.iter().filter_map(Field::billing_category).max()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Ensures that every field in the mask is supported by an endpoint.
+    ///
+    /// ## Arguments
+    ///
+    /// * `endpoint` ‧ The name of the endpoint, for example `Place Details`,
+    ///   which is used in the error message.
+    ///
+    /// * `is_supported` ‧ Returns whether the endpoint supports a field.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an `UnsupportedField` error for the first field that is not
+    ///   supported.
+
+    pub(crate) fn validate(
+        &self,
+        endpoint: &str,
+        is_supported: impl Fn(&Field) -> bool
+    ) -> Result<(), PlacesError> {
+        if let Some(field) = self.0.iter().find(|field| !is_supported(field)) {
+            return Err(PlacesError::UnsupportedField(endpoint.to_string(), field.to_string()));
+        } // if
+
+        Ok(())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for FieldMask {
+    /// Formats the `FieldMask` as the comma-delimited list of field codes that
+    /// is sent in the `fields` parameter.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields: Vec<&str> = self.0.iter().map(<&str>::from).collect();
+        write!(f, "{}", fields.join(","))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<F: Into<Field>> FromIterator<F> for FieldMask {
+    /// Collects fields into a `FieldMask`. Duplicate fields are discarded.
+    fn from_iter<I: IntoIterator<Item = F>>(fields: I) -> Self {
+        Self(fields.into_iter().map(Into::into).collect())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<F: Into<Field>> Extend<F> for FieldMask {
+    /// Adds fields to the `FieldMask`. Duplicate fields are discarded.
+    fn extend<I: IntoIterator<Item = F>>(&mut self, fields: I) {
+        self.0.extend(fields.into_iter().map(Into::into));
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl IntoIterator for FieldMask {
+    type Item = Field;
+    type IntoIter = std::collections::btree_set::IntoIter<Field>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> IntoIterator for &'a FieldMask {
+    type Item = &'a Field;
+    type IntoIter = std::collections::btree_set::Iter<'a, Field>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    } // fn
+} // impl
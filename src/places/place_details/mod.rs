@@ -4,7 +4,9 @@
 //! information about the indicated place such as its complete address, phone
 //! number, user rating and reviews.
 
+pub mod billing_category;
 pub mod field;
+pub mod field_mask;
 pub mod request;
 pub mod response;
 pub mod sort_order;
@@ -19,5 +21,5 @@ const OUTPUT_FORMAT: &str = "json"; // json or xml
 pub use crate::places::{error::Error, status::Status}; // place_search
 
 pub use crate::places::place_details::{
-    field::Field, request::Request, response::Response, sort_order::SortOrder,
+    billing_category::BillingCategory, field::Field, field_mask::FieldMask, request::Request, response::Response, sort_order::SortOrder,
 }; // place_details
@@ -1,5 +1,4 @@
-use crate::places::place_details::request::Request;
-use crate::places::place_details::Field;
+use crate::places::{error::Error as PlacesError, place_details::{request::Request, Field}};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // -----------------------------------------------------------------------------
@@ -11,8 +10,15 @@ impl<'a> Request<'a> {
     /// ## Arguments
     ///
     /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an `UnsupportedField` error if a field in the field mask is
+    ///   not supported by the _Place Details_ endpoint.
+
+    pub fn build(&mut self) -> Result<&'a mut Request<'_>, PlacesError> {
+        self.fields.validate("Place Details", Field::is_supported_by_place_details)?;
 
-    pub fn build(&mut self) -> &'a mut Request {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -28,19 +34,21 @@ impl<'a> Request<'a> {
         // Fields key/value pair:
         if !self.fields.is_empty() {
             query.push_str("&fields=");
-            query.push_str(&Field::vec_to_csv(&self.fields));
+            query.push_str(&self.fields.to_string());
         }
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // one has not been set for this request:
+        if let Some(language) = self.language.or(self.client.language) {
             query.push_str("&language=");
-            query.push_str(&String::from(language));
+            query.push_str(&String::from(&language));
         }
 
-        // Region key/value pair:
-        if let Some(region) = &self.region {
+        // Region key/value pair. The client's default region is used if one
+        // has not been set for this request:
+        if let Some(region) = self.region.or(self.client.region) {
             query.push_str("&region=");
-            query.push_str(&String::from(region));
+            query.push_str(&String::from(&region));
         }
 
         // `reviews_no_translations` key/value pair:
@@ -62,9 +70,9 @@ impl<'a> Request<'a> {
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
-        self
+        Ok(self)
     } // fn
 } // impl
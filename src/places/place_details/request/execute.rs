@@ -13,7 +13,7 @@ impl<'a> PlaceDetailsRequest<'a> {
     /// ## Description
     ///
     /// My adventures in Rust became messy so I had to make this method. It
-    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// wraps the `.build()?.get()?` chain needed at the end of the
     /// builder pattern.
     ///
     /// ## Arguments
@@ -21,6 +21,6 @@ impl<'a> PlaceDetailsRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(&'a mut self) -> Result<PlaceDetailsResponse, GoogleMapsError> {
-        self.build().get().await
+        self.build()?.get().await
     } // fn
 } // impl
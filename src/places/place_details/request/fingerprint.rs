@@ -0,0 +1,28 @@
+use crate::places::place_details::request::Request;
+use crate::fingerprint::fingerprint;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns a stable fingerprint of the query you've built, for use as a
+    /// cache key or to recognize the request in logs.
+    ///
+    /// ## Description
+    ///
+    /// The fingerprint is a 64-bit hash of the URL returned by `query_url()`.
+    /// The API key is not part of the fingerprint, and query string parameters
+    /// are sorted first, so two requests that ask Google for the same thing
+    /// have the same fingerprint, whatever order their builder methods were
+    /// called in. The hash does not depend on the platform or Rust version,
+    /// so fingerprints may be stored and compared between runs.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn fingerprint(&'a mut self) -> u64 {
+        fingerprint(&self.query_url())
+    } // fn
+} // impl
@@ -6,21 +6,35 @@
 mod build;
 #[cfg(feature = "reqwest")]
 mod execute;
+mod fingerprint;
 #[cfg(feature = "reqwest")]
 mod get;
+#[cfg(feature = "reqwest")]
+mod get_raw;
 mod new;
 mod query_url;
+#[cfg(feature = "reqwest")]
+mod url;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
 mod with_fields;
 mod with_language;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
 mod with_no_review_translations;
 mod with_region;
 mod with_reviews_sort;
 mod with_sessiontoken;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
 
 // -----------------------------------------------------------------------------
 
-use crate::places::place_details::{Field, SortOrder};
+use crate::places::place_details::{FieldMask, SortOrder};
 use crate::{client::GoogleMapsClient, types::Language, types::Region};
+use crate::redact::QueryString;
 
 // -----------------------------------------------------------------------------
 
@@ -65,7 +79,7 @@ pub struct Request<'a> {
     ///   For more information on the fields that are unavailable in a Place
     ///   Search request, see
     ///   [Places API fields support](https://developers.google.com/maps/documentation/places/web-service/place-data-fields#places-api-fields-support).
-    fields: Vec<Field>,
+    fields: FieldMask,
 
     /// The language in which to return results.
     ///
@@ -157,8 +171,30 @@ pub struct Request<'a> {
     ///   billed individually.
     sessiontoken: Option<String>,
 
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<QueryString>,
 } // struct
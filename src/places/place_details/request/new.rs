@@ -1,5 +1,5 @@
 use crate::client::GoogleMapsClient;
-use crate::places::place_details::request::Request;
+use crate::places::place_details::{request::Request, FieldMask};
 
 // =============================================================================
 
@@ -20,19 +20,28 @@ impl Request<'_> {
     ///   [place ID overview](https://developers.google.com/maps/documentation/places/web-service/place-id).
 
     #[must_use]
-    pub fn new(client: &GoogleMapsClient, place_id: impl Into<String>) -> Request {
+    pub fn new(client: &GoogleMapsClient, place_id: impl Into<String>) -> Request<'_> {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
             client,
             place_id: place_id.into(),
             // Optional parameters:
-            fields: Vec::new(),
+            fields: FieldMask::new(),
             language: None,
             region: None,
             reviews_no_translations: None,
             reviews_sort: None,
             sessiontoken: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         } // struct
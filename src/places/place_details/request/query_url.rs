@@ -22,7 +22,11 @@ impl<'a> Request<'a> {
             // If query string has already been built, return it:
             Some(query_string) => Cow::from(query_string),
             // If it hasn't been built, build it:
-            None => Cow::from(self.build().query.clone().unwrap_or_default()),
+            None => Cow::from(
+                self.build()
+                    .map(|request| request.query.clone().unwrap_or_default())
+                    .unwrap_or_default(),
+            ),
         }; // match
 
         format!("{SERVICE_URL}/{OUTPUT_FORMAT}?{query_string}")
@@ -19,7 +19,8 @@ impl<'a> Request<'a> {
     /// rate. See the [pricing sheet](https://cloud.google.com/maps-platform/pricing/sheet/)
     /// for more information. Attributions, `html_attributions`, are always
     /// returned with every call, regardless of whether the field has been
-    /// requested.
+    /// requested. Use `Field::billing_category()` to find the billing category
+    /// of a field.
     ///
     /// * Caution: Place Search requests and Place Details requests do not
     ///   return the same fields. Place Search requests return a subset of the
@@ -30,6 +31,10 @@ impl<'a> Request<'a> {
     ///   request, see [Places API fields
     ///   support](https://developers.google.com/maps/documentation/places/web-service/place-data-fields#places-api-fields-support).
     ///
+    /// * Fields are collected into a `FieldMask`, so duplicates are removed.
+    ///   Fields that are not supported by the _Place Details_ endpoint are
+    ///   rejected by `build()`.
+    ///
     /// * Warning: If you do not specify at least one field with a request, or
     ///   if you omit the `fields` parameter from a request, ALL possible fields
     ///   will be returned, and you will be billed accordingly. This applies only
@@ -40,6 +45,7 @@ impl<'a> Request<'a> {
     /// This method uses generics to improve ergonomics. The `C` generic is
     /// intended to represent any collection that can be iterated over, and the
     /// `F` generic is for any type that can be converted to the `Field` type.
+    /// A `FieldMask` may also be passed.
 
     pub fn with_fields<C, F>(
         &'a mut self,
@@ -49,7 +55,7 @@ impl<'a> Request<'a> {
         C: IntoIterator<Item = F>,
         F: Into<Field> {
         // Set fields in Request struct.
-        self.fields = fields.into_iter().map(Into::into).collect();
+        self.fields = fields.into_iter().collect();
         // Return modified Request struct to caller.
         self
     } // fn
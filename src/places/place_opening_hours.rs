@@ -1,7 +1,7 @@
 //! An object describing the opening hours of a place.
 
 use crate::places::{PlaceOpeningHoursPeriod, PlaceSpecialDay, SecondaryHoursType};
-use chrono::NaiveDate;
+use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -74,3 +74,55 @@ impl PlaceOpeningHours {
             .collect::<HashSet<NaiveDate>>()
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl PlaceOpeningHours {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the place is open 24 hours a day, seven days a week.
+    ///
+    /// Google represents this as a single period that opens on Sunday at 0000
+    /// and has no `close`.
+
+    #[must_use]
+    pub fn is_always_open(&self) -> bool {
+        matches!(self.periods.as_slice(), [period] if period.is_always_open())
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns whether the place is open at the specified date and time,
+    /// according to its weekly opening periods.
+    ///
+    /// Unlike `open_now`, which Google calculates when the request is sent,
+    /// this may be used to check any time, for example when planning a visit.
+    /// Returns `None` if Google did not provide any periods, in which case it
+    /// is not known when the place is open.
+    ///
+    /// ## Arguments
+    ///
+    /// * `date_time` ‧ The date and time to check, in the place's time zone.
+    ///   Opening hours are always reported in the place's local time.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// if let Some(opening_hours) = &place.opening_hours {
+    ///     let visit = chrono::NaiveDate::from_ymd_opt(2024, 7, 6)
+    ///         .and_then(|date| date.and_hms_opt(21, 30, 0))
+    ///         .unwrap();
+    ///     println!("Open at 9:30pm on Saturday: {:?}", opening_hours.is_open_at(visit));
+    ///     println!("{}", opening_hours.weekday_text.join("\n"));
+    /// }
+    /// ```
+
+    #[must_use]
+    pub fn is_open_at(&self, date_time: NaiveDateTime) -> Option<bool> {
+        if self.periods.is_empty() {
+            return None;
+        } // if
+
+        Some(self.periods.iter().any(|period| period.is_open_at(date_time)))
+    } // fn
+} // impl
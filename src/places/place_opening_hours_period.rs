@@ -1,6 +1,6 @@
 //! An object describing the opening hours of a place.
 
-use chrono::Duration;
+use chrono::{Datelike, Duration, NaiveDateTime, Timelike};
 use crate::places::PlaceOpeningHoursPeriodDetail;
 use serde::{Deserialize, Serialize};
 
@@ -46,6 +46,75 @@ impl PlaceOpeningHoursPeriod {
     } // fn
 } // impl
 
+// -----------------------------------------------------------------------------
+//
+/// The number of minutes in a week, which is the span that the weekly opening
+/// periods repeat over.
+
+const MINUTES_PER_WEEK: i64 = 7 * 24 * 60;
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the number of minutes between the start of Sunday and the specified
+/// day and time of the week.
+
+fn minute_of_week(day: chrono::Weekday, hour: u32, minute: u32) -> i64 {
+    i64::from(day.num_days_from_sunday() * 24 * 60 + hour * 60 + minute)
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl PlaceOpeningHoursPeriod {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if this period describes a place that is always open.
+    ///
+    /// Google represents a place that is open 24 hours a day, seven days a
+    /// week, as a single period that opens on Sunday at 0000 and never closes.
+    /// Any period without a `close` is treated as always open.
+
+    #[must_use]
+    pub const fn is_always_open(&self) -> bool {
+        self.close.is_none()
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns `true` if the place is open at the specified date and time,
+    /// according to this period.
+    ///
+    /// The period is treated as repeating every week, so only the day of the
+    /// week and the time of day are compared. A period that closes on an
+    /// earlier day of the week than it opens, for example one that opens on
+    /// Saturday evening and closes on Sunday morning, wraps around the end of
+    /// the week. The opening time is inclusive and the closing time is
+    /// exclusive.
+    ///
+    /// ## Arguments
+    ///
+    /// * `date_time` ‧ The date and time to check, in the place's time zone.
+
+    #[must_use]
+    pub fn is_open_at(&self, date_time: NaiveDateTime) -> bool {
+        let Some(close) = &self.close else {
+            return true;
+        }; // let
+
+        let open = minute_of_week(self.open.day, self.open.time.hour(), self.open.time.minute());
+        let close = minute_of_week(close.day, close.time.hour(), close.time.minute());
+        let now = minute_of_week(date_time.weekday(), date_time.hour(), date_time.minute());
+
+        // A period that opens and closes at the same time of the week lasts for
+        // the whole week:
+        let length = match (close - open).rem_euclid(MINUTES_PER_WEEK) {
+            0 => MINUTES_PER_WEEK,
+            length => length,
+        }; // match
+
+        (now - open).rem_euclid(MINUTES_PER_WEEK) < length
+    } // fn
+} // impl
+
 // -----------------------------------------------------------------------------
 
 impl std::str::FromStr for PlaceOpeningHoursPeriod {
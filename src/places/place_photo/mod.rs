@@ -3,6 +3,17 @@
 /// using a URL.
 use serde::{Deserialize, Serialize};
 
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/place/photo";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::places::place_photo::{request::Request, response::Response}; // place_photo
+
 // -----------------------------------------------------------------------------
 //
 /// A photo of a Place. The photo can be accesed via the
@@ -0,0 +1,53 @@
+use crate::places::{error::Error as PlacesError, place_photo::request::Request};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string for the Google Maps Place Photo request based
+    /// on the input provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> Result<&'a mut Request<'_>, PlacesError> {
+        // Google requires that at least one of the image's dimensions is
+        // limited:
+        if self.max_width.is_none() && self.max_height.is_none() {
+            return Err(PlacesError::MaxWidthOrMaxHeightRequired);
+        } // if
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!(
+            "key={key}&photo_reference={photo_reference}",
+            key = self.client.key,
+            photo_reference = utf8_percent_encode(&self.photo_reference, NON_ALPHANUMERIC),
+        );
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Maximum height key/value pair:
+        if let Some(max_height) = &self.max_height {
+            query.push_str("&maxheight=");
+            query.push_str(&max_height.to_string());
+        } // if
+
+        // Maximum width key/value pair:
+        if let Some(max_width) = &self.max_width {
+            query.push_str("&maxwidth=");
+            query.push_str(&max_width.to_string());
+        } // if
+
+        // Set query string in Request struct.
+        self.query = Some(query.into());
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
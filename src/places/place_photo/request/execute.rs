@@ -0,0 +1,26 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_photo::{
+    request::Request as PlacePhotoRequest, response::Response as PlacePhotoResponse,
+}; // crate::places::place_photo
+
+// =============================================================================
+
+impl<'a> PlacePhotoRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.build()?.get()?` chain needed at the end of the builder
+    /// pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<PlacePhotoResponse, GoogleMapsError> {
+        self.build()?.get().await
+    } // fn
+} // impl
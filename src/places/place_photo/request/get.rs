@@ -0,0 +1,64 @@
+use crate::client::{Method, RetrySettings};
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_photo::{
+    request::Request as PlacePhotoRequest, response::Response as PlacePhotoResponse, SERVICE_URL,
+}; // crate::places::place_photo
+use crate::places::Error as PlacePhotoError;
+use crate::request_rate::api::Api;
+
+// -----------------------------------------------------------------------------
+
+impl PlacePhotoRequest<'_> {
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(api = %Api::Places, attempts, duration_ms, status)
+    )]
+    pub async fn get(&mut self) -> Result<PlacePhotoResponse, GoogleMapsError> {
+        // Build the URL stem for the HTTP get request:
+        let mut url = format!("{SERVICE_URL}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(PlacePhotoError::QueryNotBuilt)?,
+        } // match
+
+        tracing::info!("making HTTP GET request to Google Maps Place Photo API");
+
+        // Observe any rate limiting before executing the request, and retry
+        // it according to its retry settings:
+        let response = self
+            .client
+            .execute_with_retry(
+                Method::Get,
+                &url,
+                RetrySettings {
+                    api: Api::Places,
+                    backoff: self.backoff.as_ref(),
+                    max_retries: self.max_retries,
+                    timeout: self.timeout,
+                    deadline: self.deadline,
+                }, // RetrySettings
+                "image/",
+                |body| -> Result<PlacePhotoResponse, backoff::Error<PlacePhotoError>> {
+                    Ok(PlacePhotoResponse {
+                        content_type: body.content_type,
+                        bytes: body.bytes,
+                        url: body.url,
+                    }) // Ok
+                }, // classify
+            )
+            .await?;
+
+        // Return response to caller:
+        Ok(response)
+    } // fn
+} // impl
@@ -0,0 +1,88 @@
+//! **Look in this module for documentation on building your _Place Photo_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+// -----------------------------------------------------------------------------
+
+mod build;
+#[cfg(feature = "reqwest")]
+mod execute;
+mod fingerprint;
+#[cfg(feature = "reqwest")]
+mod get;
+mod new;
+mod query_url;
+#[cfg(feature = "reqwest")]
+mod url;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
+mod with_max_height;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
+mod with_max_width;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::redact::QueryString;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Place Photo_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// A string identifier that uniquely identifies a photo. Photo references
+    /// are returned from Place Search, Place Details, and Find Place requests.
+    photo_reference: String,
+
+    // Optional parameters:
+    // --------------------
+    /// The maximum height of the image, in pixels. See method
+    /// `with_max_height()` for more information.
+    max_height: Option<u16>,
+
+    /// The maximum width of the image, in pixels. See method
+    /// `with_max_width()` for more information.
+    max_width: Option<u16>,
+
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+
+    // Internal use only:
+    // ------------------
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<QueryString>,
+} // struct
@@ -0,0 +1,44 @@
+use crate::client::GoogleMapsClient;
+use crate::places::place_photo::request::Request;
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Place Photo query. A maximum
+    /// width or a maximum height must be set using the `with_max_width()` or
+    /// `with_max_height()` methods before the query is executed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `photo_reference` ‧ A string identifier that uniquely identifies a
+    ///   photo. Photo references are returned from Place Search, Place
+    ///   Details, and Find Place requests.
+
+    #[must_use]
+    pub fn new(client: &GoogleMapsClient, photo_reference: impl Into<String>) -> Request<'_> {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            photo_reference: photo_reference.into(),
+            // Optional parameters:
+            max_height: None,
+            max_width: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
+            // Internal use only:
+            query: None,
+        } // struct
+    } // fn
+} // impl
@@ -0,0 +1,34 @@
+use crate::places::place_photo::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the maximum height of the image returned by the Places API
+    /// _Place Photo_ query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_height` ‧ The maximum height of the image, in pixels. Values
+    ///   between 1 and 1600 are accepted. If the image is smaller than the
+    ///   value specified, the original image is returned. If the image is
+    ///   larger in either dimension, it is scaled to match the smaller of the
+    ///   two dimensions, restricted to its original aspect ratio. Either
+    ///   `max_height` or `max_width`, or both, must be specified.
+    ///
+    /// ## Example
+    ///
+    /// * Limit the image to 400 pixels in height:
+    /// ```rust
+    /// .with_max_height(400)
+    /// ```
+
+    pub fn with_max_height(
+        &'a mut self,
+        max_height: u16
+    ) -> &'a mut Self {
+        // Set maximum height in Request struct.
+        self.max_height = Some(max_height);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
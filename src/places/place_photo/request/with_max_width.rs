@@ -0,0 +1,34 @@
+use crate::places::place_photo::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the maximum width of the image returned by the Places API
+    /// _Place Photo_ query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `max_width` ‧ The maximum width of the image, in pixels. Values
+    ///   between 1 and 1600 are accepted. If the image is smaller than the
+    ///   value specified, the original image is returned. If the image is
+    ///   larger in either dimension, it is scaled to match the smaller of the
+    ///   two dimensions, restricted to its original aspect ratio. Either
+    ///   `max_width` or `max_height`, or both, must be specified.
+    ///
+    /// ## Example
+    ///
+    /// * Limit the image to 400 pixels in width:
+    /// ```rust
+    /// .with_max_width(400)
+    /// ```
+
+    pub fn with_max_width(
+        &'a mut self,
+        max_width: u16
+    ) -> &'a mut Self {
+        // Set maximum width in Request struct.
+        self.max_width = Some(max_width);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,27 @@
+//! Resources (structs) for processing the _Places API_ _Place Photo_ response
+//! from the Google Maps Platform. Unlike the other Places API services, Google
+//! responds with the image itself rather than a JSON document.
+
+// -----------------------------------------------------------------------------
+//
+/// The image returned by the Google Maps Places API _Place Photo_ request will
+/// be stored in this structure.
+///
+/// See [Place Photo response](https://developers.google.com/maps/documentation/places/web-service/photos#place-photo-response)
+/// for more information.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Response {
+    /// The MIME type of the image, as reported by the `Content-Type` header of
+    /// the final response, for example `image/jpeg` or `image/png`. It is
+    /// `None` if the server did not send a valid header.
+    pub content_type: Option<String>,
+
+    /// The image data.
+    pub bytes: Vec<u8>,
+
+    /// Google redirects Place Photo requests to the location of the image.
+    /// This is the URL the image was downloaded from, after all redirects were
+    /// followed.
+    pub url: String,
+} // struct
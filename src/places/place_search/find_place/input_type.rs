@@ -0,0 +1,142 @@
+//! Contains the `InputType` enum and its associated traits. It specifies
+//! whether a _Find Place_ input is a text query or a phone number.
+
+use crate::error::Error as GoogleMapsError;
+use crate::places::error::Error as PlacesError;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+//
+/// The type of input of a _Find Place_ request.
+
+#[derive(Clone, Debug, Eq, Default, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum InputType {
+    /// The input is a text query, such as a name or an address. Ambiguous
+    /// queries, like `pizza`, may return a place that is only loosely related.
+    #[default]
+    TextQuery = 0,
+
+    /// The input is a phone number. It must be in international format,
+    /// prefixed by a plus sign ("+"), followed by the country code, then the
+    /// phone number itself. For example, `+16502530000`.
+    PhoneNumber = 1,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for InputType {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Self::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string())),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for InputType {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(std::convert::Into::<&str>::into(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&InputType> for &str {
+    /// Converts an `InputType` enum to a `String` that contains an
+    /// [inputtype](https://developers.google.com/maps/documentation/places/web-service/search-find-place#inputtype)
+    /// code.
+    fn from(input_type: &InputType) -> Self {
+        match input_type {
+            InputType::TextQuery => "textquery",
+            InputType::PhoneNumber => "phonenumber",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for InputType {
+    /// Converts an `InputType` enum to a `String` that contains an
+    /// [inputtype](https://developers.google.com/maps/documentation/places/web-service/search-find-place#inputtype)
+    /// code.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&InputType> for String {
+    /// Converts an `InputType` enum to a `String` that contains an
+    /// [inputtype](https://developers.google.com/maps/documentation/places/web-service/search-find-place#inputtype)
+    /// code.
+    fn from(input_type: &InputType) -> Self {
+        std::convert::Into::<&str>::into(input_type).to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static INPUT_TYPES_BY_CODE: phf::Map<&'static str, InputType> = phf_map! {
+    "textquery" => InputType::TextQuery,
+    "phonenumber" => InputType::PhoneNumber,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<&str> for InputType {
+    // Error definitions are contained in the
+    // `google_maps\src\places\error.rs` module.
+    type Error = GoogleMapsError;
+    /// Gets an `InputType` enum from a `String` that contains a valid
+    /// [inputtype](https://developers.google.com/maps/documentation/places/web-service/search-find-place#inputtype)
+    /// code.
+    fn try_from(input_type: &str) -> Result<Self, Self::Error> {
+        Ok(INPUT_TYPES_BY_CODE
+            .get(input_type)
+            .cloned()
+            .ok_or_else(|| PlacesError::InvalidInputTypeCode(input_type.to_string()))?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for InputType {
+    // Error definitions are contained in the
+    // `google_maps\src\places\error.rs` module.
+    type Err = GoogleMapsError;
+    /// Gets an `InputType` enum from a `String` that contains a valid
+    /// [inputtype](https://developers.google.com/maps/documentation/places/web-service/search-find-place#inputtype)
+    /// code.
+    fn from_str(input_type: &str) -> Result<Self, Self::Err> {
+        Ok(INPUT_TYPES_BY_CODE
+            .get(input_type)
+            .cloned()
+            .ok_or_else(|| PlacesError::InvalidInputTypeCode(input_type.to_string()))?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl InputType {
+    /// Formats an `InputType` enum into a string that is presentable to the
+    /// end user.
+    #[must_use]
+    pub const fn display(&self) -> &str {
+        match self {
+            Self::TextQuery => "Text Query",
+            Self::PhoneNumber => "Phone Number",
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,33 @@
+//! A **Find Place** request takes a text input and returns a place. The input
+//! can be any kind of Places text data, such as a name, address, or phone
+//! number. The request must be a string. A Find Place request using
+//! non-string data such as a lat/lng coordinate or plus code generates an
+//! error.
+//!
+//! Unlike Nearby Search and Text Search, a Find Place request may be
+//! constrained to return only specific fields. To keep from requesting (and
+//! paying for) data that you don't need, specify the fields with
+//! `with_fields()`. Only the `place_id` is returned if no fields are
+//! specified.
+//!
+//! * Caution: Find Place supports fewer fields than Place Details. Fields that
+//!   are not supported, such as `reviews` or `website`, are rejected when the
+//!   request is built. Use the `place_id` of a candidate to make a Place
+//!   Details request for them.
+
+pub mod input_type;
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/place/findplacefromtext";
+const OUTPUT_FORMAT: &str = "json"; // json or xml
+
+// -----------------------------------------------------------------------------
+
+pub use crate::places::{error::Error, status::Status}; // place_search
+
+pub use crate::places::place_search::find_place::{
+    input_type::InputType, request::Request, response::Response,
+}; // find_place
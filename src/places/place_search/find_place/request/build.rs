@@ -0,0 +1,64 @@
+use crate::places::{
+    error::Error as PlacesError,
+    place_details::Field,
+    place_search::find_place::request::Request,
+}; // crate::places
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Builds the query string for the Google Maps Places API _Find Place_
+    /// query based on the input provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an `UnsupportedField` error if a field in the field mask is
+    ///   not supported by the _Find Place_ endpoint.
+
+    pub fn build(&mut self) -> Result<&'a mut Request<'_>, PlacesError> {
+        self.fields.validate("Find Place", Field::is_supported_by_find_place)?;
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!(
+            "key={}&input={}&inputtype={}",
+            self.client.key,
+            utf8_percent_encode(&self.input, NON_ALPHANUMERIC),
+            String::from(&self.inputtype),
+        );
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Fields key/value pair:
+        if !self.fields.is_empty() {
+            query.push_str("&fields=");
+            query.push_str(&self.fields.to_string());
+        }
+
+        // Language key/value pair. The client's default language is used if
+        // one has not been set for this request:
+        if let Some(language) = self.language.or(self.client.language) {
+            query.push_str("&language=");
+            query.push_str(&String::from(&language));
+        }
+
+        // Location Bias key/value pair:
+        if let Some(locationbias) = &self.locationbias {
+            query.push_str("&locationbias=");
+            query.push_str(&utf8_percent_encode(&String::from(locationbias), NON_ALPHANUMERIC).to_string());
+        }
+
+        // Set query string in Request struct.
+        self.query = Some(query.into());
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,26 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_search::find_place::{
+    request::Request as FindPlaceRequest, response::Response as FindPlaceResponse,
+}; // crate::places::place_search::find_place
+
+// =============================================================================
+
+impl<'a> FindPlaceRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<FindPlaceResponse, GoogleMapsError> {
+        self.build()?.get().await
+    } // fn
+} // impl
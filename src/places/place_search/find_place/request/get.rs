@@ -0,0 +1,64 @@
+use crate::client::{Method, RetrySettings};
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_search::find_place::{
+    request::Request as FindPlaceRequest, response::Response as FindPlaceResponse,
+    Error as FindPlaceError, OUTPUT_FORMAT, SERVICE_URL,
+};
+use crate::request_rate::api::Api;
+
+// -----------------------------------------------------------------------------
+
+impl FindPlaceRequest<'_> {
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(api = %Api::Places, attempts, duration_ms, status)
+    )]
+    pub async fn get(&mut self) -> Result<FindPlaceResponse, GoogleMapsError> {
+        // Build the URL stem for the HTTP get request:
+        let mut url = format!("{SERVICE_URL}/{OUTPUT_FORMAT}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(FindPlaceError::QueryNotBuilt)?,
+        } // match
+
+        tracing::info!("making HTTP GET request to Google Maps Find Place API");
+
+        // Observe any rate limiting before executing the request, and retry
+        // it according to its retry settings:
+        let response = self
+            .client
+            .execute_with_retry(
+                Method::Get,
+                &url,
+                RetrySettings {
+                    api: Api::Places,
+                    backoff: self.backoff.as_ref(),
+                    max_retries: self.max_retries,
+                    timeout: self.timeout,
+                    deadline: self.deadline,
+                }, // RetrySettings
+                "application/json",
+                |body| -> Result<FindPlaceResponse, backoff::Error<FindPlaceError>> {
+                    // If the response JSON was successfully parsed, check the
+                    // Google API status before returning it to the caller:
+                    let response: FindPlaceResponse = self.client.parse_json(&body)?;
+                    response.status.check(response.error_message.as_deref())?;
+                    Ok(response)
+                }, // classify
+            )
+            .await?;
+
+        // Return response to caller:
+        Ok(response)
+    } // fn
+} // impl
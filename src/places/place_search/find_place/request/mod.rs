@@ -0,0 +1,120 @@
+//! **Look in this module for documentation on building your _Places API_
+//! _Find Place_ query**. In particular, look at the _Request_ struct for
+//! examples of the builder pattern. This module contains the tools (enums,
+//! structs, methods) for building your Google Maps Platform request.
+
+mod build;
+#[cfg(feature = "reqwest")]
+mod execute;
+mod fingerprint;
+#[cfg(feature = "reqwest")]
+mod get;
+#[cfg(feature = "reqwest")]
+mod get_raw;
+mod new;
+mod query_url;
+#[cfg(feature = "reqwest")]
+mod url;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
+mod with_fields;
+mod with_input;
+mod with_input_type;
+mod with_language;
+mod with_location_bias;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
+
+// -----------------------------------------------------------------------------
+
+use crate::places::{place_details::FieldMask, place_search::find_place::InputType, LocationBias};
+use crate::{client::GoogleMapsClient, types::Language};
+use crate::redact::QueryString;
+
+// -----------------------------------------------------------------------------
+
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Find Place_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The text string on which to search, for example: "restaurant" or "123
+    /// Main Street". This must be a place name, address, or category of
+    /// establishments. Any other types of input can generate errors and are
+    /// not guaranteed to return valid results. The Places API will return
+    /// candidate matches based on this string and order the results based on
+    /// their perceived relevance.
+    input: String,
+
+    /// The type of input. This can be one of either `textquery` or
+    /// `phonenumber`. Phone numbers must be in international format (prefixed
+    /// by a plus sign ("+"), followed by the country code, then the phone
+    /// number itself).
+    inputtype: InputType,
+
+    // Optional parameters:
+    // --------------------
+    /// Use the fields parameter to specify a comma-separated list of place data
+    /// types to return. For example: `fields=formatted_address,name,geometry`.
+    /// Use a forward slash when specifying compound values. For example:
+    /// `opening_hours/open_now`.
+    ///
+    /// Fields are divided into three billing categories: Basic, Contact, and
+    /// Atmosphere. Basic fields are billed at base rate, and incur no
+    /// additional charges. Contact and Atmosphere fields are billed at a higher
+    /// rate. If this parameter is omitted, only the `place_id` is returned.
+    fields: FieldMask,
+
+    /// The language in which to return results.
+    ///
+    /// * See the list of supported languages. Google often updates the
+    ///   supported languages, so this list may not be exhaustive.
+    ///
+    /// * If `language` is not supplied, the API attempts to use the preferred
+    ///   language as specified in the `Accept-Language` header.
+    language: Option<Language>,
+
+    /// Prefer results in a specified area, by specifying either a radius plus
+    /// lat/lng, or two lat/lng pairs representing the points of a rectangle.
+    /// If this parameter is not specified, the API uses IP address biasing by
+    /// default.
+    locationbias: Option<LocationBias>,
+
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+
+    // Internal use only:
+    // ------------------
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<QueryString>,
+} // struct
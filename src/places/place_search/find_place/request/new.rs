@@ -0,0 +1,45 @@
+use crate::client::GoogleMapsClient;
+use crate::places::{place_details::FieldMask, place_search::find_place::{request::Request, InputType}};
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Places API _Find Place_ query
+    /// with the required, non-optional parameters. The input is treated as a
+    /// text query, use `with_input_type()` to search by phone number instead.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `input` ‧ The text string on which to search, for example:
+    ///   "restaurant" or "123 Main Street".
+
+    #[must_use]
+    pub fn new(client: &GoogleMapsClient, input: impl Into<String>) -> Request<'_> {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            input: input.into(),
+            inputtype: InputType::default(),
+            // Optional parameters:
+            fields: FieldMask::new(),
+            language: None,
+            locationbias: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
+            // Internal use only:
+            query: None,
+        } // struct
+    } // fn
+} // impl
@@ -0,0 +1,57 @@
+use crate::places::place_search::find_place::request::Request;
+use crate::places::place_details::Field;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Sets the requested fields to the Places API _Find Place_ query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `fields` ‧ Use the fields parameter to specify a comma-separated list
+    ///   of place data types to return. For example:
+    ///   `fields=formatted_address,name,geometry`. Use a forward slash when
+    ///   specifying compound values. For example: `opening_hours/open_now`.
+    ///
+    /// Fields are divided into three billing categories: Basic, Contact, and
+    /// Atmosphere. Basic fields are billed at base rate, and incur no
+    /// additional charges. Contact and Atmosphere fields are billed at a higher
+    /// rate. See the [pricing sheet](https://cloud.google.com/maps-platform/pricing/sheet/)
+    /// for more information. Attributions, `html_attributions`, are always
+    /// returned with every call, regardless of whether the field has been
+    /// requested. Use `Field::billing_category()` to find the billing category
+    /// of a field.
+    ///
+    /// * Caution: Find Place supports only some of the Place Details fields:
+    ///   `business_status`, `formatted_address`, `geometry`, `icon`,
+    ///   `icon_mask_base_uri`, `icon_background_color`, `name`, `photo`,
+    ///   `place_id`, `plus_code`, `type`, `opening_hours`, `price_level`,
+    ///   `rating`, and `user_ratings_total`. Other fields are rejected by
+    ///   `build()`. Use the `place_id` of a candidate to make a Place Details
+    ///   request for them. See [Places API fields
+    ///   support](https://developers.google.com/maps/documentation/places/web-service/place-data-fields#places-api-fields-support).
+    ///
+    /// * Fields are collected into a `FieldMask`, so duplicates are removed.
+    ///
+    /// * If no fields are specified, only the `place_id` is returned.
+    ///
+    /// # Generics
+    ///
+    /// This method uses generics to improve ergonomics. The `C` generic is
+    /// intended to represent any collection that can be iterated over, and the
+    /// `F` generic is for any type that can be converted to the `Field` type.
+    /// A `FieldMask` may also be passed.
+
+    pub fn with_fields<C, F>(
+        &'a mut self,
+        fields: C
+    ) -> &'a mut Self
+    where
+        C: IntoIterator<Item = F>,
+        F: Into<Field> {
+        // Set fields in Request struct.
+        self.fields = fields.into_iter().collect();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,26 @@
+use crate::places::place_search::find_place::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Replaces the input of the Places API _Find Place_ query, which was set
+    /// when the request was created.
+    ///
+    /// ## Arguments
+    ///
+    /// * `input` ‧ The text string on which to search, for example:
+    ///   "restaurant" or "123 Main Street". This must be a place name,
+    ///   address, or category of establishments. If the input type is
+    ///   `InputType::PhoneNumber`, it must be a phone number in international
+    ///   format, for example `+16502530000`.
+
+    pub fn with_input(
+        &'a mut self,
+        input: impl Into<String>
+    ) -> &'a mut Self {
+        // Set input in Request struct.
+        self.input = input.into();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,32 @@
+use crate::places::place_search::find_place::{request::Request, InputType};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Sets the input type of the Places API _Find Place_ query. The input is
+    /// treated as a text query if this method is not called.
+    ///
+    /// ## Arguments
+    ///
+    /// * `inputtype` ‧ The type of input. This can be one of either
+    ///   `textquery` or `phonenumber`. Phone numbers must be in international
+    ///   format (prefixed by a plus sign ("+"), followed by the country code,
+    ///   then the phone number itself).
+    ///
+    /// ## Example
+    ///
+    /// * Find the place with a phone number:
+    /// ```rust
+    /// .with_input_type(InputType::PhoneNumber)
+    /// ```
+
+    pub fn with_input_type(
+        &'a mut self,
+        inputtype: impl Into<InputType>
+    ) -> &'a mut Self {
+        // Set input type in Request struct.
+        self.inputtype = inputtype.into();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,37 @@
+use crate::places::{place_search::find_place::request::Request, LocationBias};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds the location bias parameter to the Places API _Find Place_ query.
+    ///
+    /// ## Arguments
+    ///
+    /// * `locationbias` ‧ Prefer results in a specified area, by specifying
+    ///   either a radius plus latitude/longitude, or two latitude/longitude
+    ///   pairs representing the points of a rectangle. If this parameter is not
+    ///   specified, the API uses IP address biasing by default.
+    ///
+    /// Use `LocationBias::try_circle()` or `LocationBias::try_rectangle()` to
+    /// check the radius or the rectangle's corners before they are sent.
+    ///
+    /// ## Examples:
+    ///
+    /// * Prefer results within 2 km of the Sydney Opera House:
+    /// ```rust
+    /// .with_location_bias(LocationBias::try_circle(
+    ///     LatLng::try_new(-33.856_8, 151.215_3)?,
+    ///     2_000,
+    /// )?)
+    /// ```
+
+    pub fn with_location_bias(
+        &'a mut self,
+        locationbias: impl Into<LocationBias>
+    ) -> &'a mut Self {
+        // Set location bias in Request struct.
+        self.locationbias = Some(locationbias.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,74 @@
+//! Resources (enums, structs) for processing the _Places API_ _Find Place_
+//! response from the Google Maps Platform. Look in here for more information
+//! about the data returned from Google's server and how to parse it with your
+//! program.
+
+// -----------------------------------------------------------------------------
+
+use crate::places::status::Status;
+use crate::places::Place;
+use serde::{Deserialize, Serialize};
+
+/// The response from the Google Maps Places API _Find Place_ request will be
+/// stored in this structure.
+///
+/// See [Find Place responses](https://developers.google.com/maps/documentation/places/web-service/search-find-place#find-place-responses)
+/// for more information
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+    /// Contains an array of places that match the input. Usually only one
+    /// candidate is returned, but an ambiguous query may return several. Each
+    /// candidate only contains the fields that were requested.
+    ///
+    /// See [Place](https://developers.google.com/maps/documentation/places/web-service/search-find-place#Place)
+    /// for more information.
+    #[serde(default)]
+    pub candidates: Vec<Place>,
+
+    /// Contains the status of the request, and may contain debugging
+    /// information to help you track down why the request failed.
+    ///
+    /// See [PlacesSearchStatus](https://developers.google.com/maps/documentation/places/web-service/search-find-place#PlacesSearchStatus)
+    /// for more information.
+    pub status: Status,
+
+    /// When the service returns a status code other than `OK`, there may be
+    /// an additional `error_message` field within the response object. This
+    /// field contains more detailed information about the reasons behind the
+    /// given status code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+
+    /// When the service returns additional information about the request
+    /// specification, there may be an additional `info_messages` field within
+    /// the response object. This field is only returned for successful
+    /// requests. It may not always be returned, and its content is subject to
+    /// change.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub info_messages: Vec<String>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = simd_json::Error;
+    /// Parse a Google Maps Places API _Find Place_ JSON response into a usable
+    /// `Response` struct.
+    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
+        let mut bytes = s.to_string().into_bytes();
+        simd_json::serde::from_slice(&mut bytes)
+    } // fn from_str
+} // impl FromStr
+
+// -----------------------------------------------------------------------------
+
+impl Response {
+    /// Returns the best matching place, which is the first candidate. `None`
+    /// is returned if no place matched the input.
+    #[must_use]
+    pub fn best_candidate(&self) -> Option<&Place> {
+        self.candidates.first()
+    } // fn
+} // impl
@@ -46,5 +46,28 @@
 //! Nearby Search and Text Search allow additional parameters to filter results,
 //! (e.g. `minprice`, `maxprice`, `opennow`, and `type`).
 
+pub mod find_place;
 pub mod nearby_search;
 pub mod text_search;
+
+#[cfg(feature = "reqwest")]
+mod next_page_settings;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+pub(crate) use crate::places::place_search::next_page_settings::NextPageSettings;
+
+// -----------------------------------------------------------------------------
+
+/// Google requires a short delay between when a `next_page_token` is issued,
+/// and when it becomes valid. This is how long to wait before requesting the
+/// next page.
+#[cfg(feature = "reqwest")]
+const NEXT_PAGE_DELAY: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// If the `next_page_token` is not valid yet, Google responds with an
+/// `INVALID_REQUEST` status. This is the number of times the next page will be
+/// requested before that error is returned to the caller.
+#[cfg(feature = "reqwest")]
+const NEXT_PAGE_ATTEMPTS: u32 = 5;
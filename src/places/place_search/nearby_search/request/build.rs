@@ -1,4 +1,6 @@
-use crate::places::place_search::nearby_search::request::Request;
+use crate::places::{
+    error::Error as PlacesError, place_search::nearby_search::request::Request, RankBy,
+}; // crate::places
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // -----------------------------------------------------------------------------
@@ -10,29 +12,70 @@ impl<'a> Request<'a> {
     /// ## Arguments
     ///
     /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the minimum or maximum price is greater than 4,
+    ///   or if the minimum price is greater than the maximum price.
+    ///
+    /// * Returns an error if the search is ranked by distance and a radius is
+    ///   set or neither a keyword nor a place type is set, or if the search is
+    ///   ranked by prominence and no radius is set.
+
+    pub fn build(&mut self) -> Result<&'a mut Request<'_>, PlacesError> {
+        // Price levels range from 0 (most affordable) to 4 (most expensive):
+        for price in [self.minprice, self.maxprice].into_iter().flatten() {
+            if price > 4 {
+                return Err(PlacesError::InvalidPriceLevel(price));
+            } // if
+        } // for
+
+        if let (Some(minprice), Some(maxprice)) = (self.minprice, self.maxprice) {
+            if minprice > maxprice {
+                return Err(PlacesError::MinPriceGreaterThanMaxPrice(minprice, maxprice));
+            } // if
+        } // if
+
+        // Google rejects a search that is ranked by distance if it has a
+        // radius, or if it has nothing to rank:
+        if self.rankby == Some(RankBy::Distance) {
+            if let Some(radius) = self.radius {
+                return Err(PlacesError::RankByDistanceWithRadius(radius));
+            } // if
+            if self.keyword.is_none() && self.place_type.is_none() {
+                return Err(PlacesError::RankByDistanceWithoutKeywordOrType);
+            } // if
+        } else if self.radius.is_none() {
+            return Err(PlacesError::RadiusRequired);
+        } // if
 
-    pub fn build(&mut self) -> &'a mut Request {
         // This section builds the "required parameters" portion of the query
         // string:
 
         let mut query = format!(
-            "key={}&location={}&radius={}",
+            "key={}&location={}",
             self.client.key,
             String::from(&self.location),
-            self.radius,
         );
 
+        if let Some(radius) = &self.radius {
+            query.push_str("&radius=");
+            query.push_str(&radius.to_string());
+        }
+
         // This section builds the "optional parameters" portion of the query
         // string:
 
         if let Some(keyword) = &self.keyword {
-            query.push_str("&keyword={}");
+            query.push_str("&keyword=");
             query.push_str(&utf8_percent_encode(keyword, NON_ALPHANUMERIC).to_string());
         }
 
-        if let Some(language) = &self.language {
+        // The client's default language is used if one has not been set for
+        // this request:
+        if let Some(language) = self.language.or(self.client.language) {
             query.push_str("&language=");
-            query.push_str(&String::from(language));
+            query.push_str(&String::from(&language));
         }
 
         if let Some(maxprice) = &self.maxprice {
@@ -67,9 +110,9 @@ impl<'a> Request<'a> {
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
-        self
+        Ok(self)
     } // fn
 } // impl
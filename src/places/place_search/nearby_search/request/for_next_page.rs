@@ -0,0 +1,34 @@
+use crate::client::GoogleMapsClient;
+use crate::places::place_search::{nearby_search::request::Request, NextPageSettings};
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Returns a request for the page of results identified by `pagetoken`.
+    /// Google ignores every parameter other than the page token, so only the
+    /// retry settings of the request that returned the previous page are set.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that made the original request.
+    ///
+    /// * `pagetoken` ‧ The `next_page_token` of the previous page of results.
+    ///
+    /// * `settings` ‧ The retry settings of the previous page's request.
+
+    pub(crate) fn for_next_page(
+        client: &'a GoogleMapsClient,
+        pagetoken: &str,
+        settings: &NextPageSettings,
+    ) -> Self {
+        Self {
+            pagetoken: Some(pagetoken.to_string()),
+            backoff: settings.backoff.clone(),
+            max_retries: settings.max_retries,
+            timeout: settings.timeout,
+            deadline: settings.deadline,
+            ..Self::new(client, LatLng::default(), 0)
+        } // struct
+    } // fn
+} // impl
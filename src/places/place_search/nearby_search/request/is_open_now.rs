@@ -19,3 +19,21 @@ impl<'a> Request<'a> {
         self
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds "open now" filter to the Places API _Nearby Search_ query. This is
+    /// the same as `is_open_now()`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `opennow` ‧ Returns only those places that are open for business at
+    ///   the time the query is sent. Places that do not specify opening hours
+    ///   in the Google Places database will not be returned if you include this
+    ///   parameter in your query.
+
+    pub fn with_open_now(&'a mut self, opennow: bool) -> &'a mut Self {
+        self.is_open_now(opennow)
+    } // fn
+} // impl
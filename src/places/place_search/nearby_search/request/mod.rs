@@ -6,17 +6,34 @@
 mod build;
 #[cfg(feature = "reqwest")]
 mod execute;
+mod fingerprint;
+#[cfg(feature = "reqwest")]
+mod for_next_page;
 #[cfg(feature = "reqwest")]
 mod get;
+#[cfg(feature = "reqwest")]
+mod get_raw;
 mod is_open_now;
 mod new;
 mod query_url;
+#[cfg(feature = "reqwest")]
+mod stream;
+#[cfg(feature = "reqwest")]
+mod url;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
 mod with_keyword;
 mod with_language;
 mod with_max_price;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
 mod with_min_price;
 mod with_pagetoken;
-mod with_rankby;
+mod with_rank_by;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
 mod with_type;
 
 // -----------------------------------------------------------------------------
@@ -24,6 +41,7 @@ mod with_type;
 use crate::places::RankBy;
 use crate::LatLng;
 use crate::{client::GoogleMapsClient, types::Language, types::PlaceType};
+use crate::redact::QueryString;
 
 // -----------------------------------------------------------------------------
 
@@ -62,7 +80,9 @@ pub struct Request<'a> {
     ///           accepted, and will result in an `INVALID_REQUEST`.
     /// * Query Autocomplete: 50,000 meters
     /// * Nearby Search: 50,000 meters
-    radius: u32,
+    ///
+    /// The radius is `None` for searches that are ranked by distance.
+    radius: Option<u32>,
 
     // Optional parameters:
     // --------------------
@@ -160,8 +180,30 @@ pub struct Request<'a> {
     ///   `ZERO_RESULTS`.
     place_type: Option<PlaceType>,
 
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<QueryString>,
 } // struct
@@ -1,5 +1,6 @@
 use crate::client::GoogleMapsClient;
 use crate::places::place_search::nearby_search::request::Request;
+use crate::places::RankBy;
 use crate::LatLng;
 
 // =============================================================================
@@ -38,13 +39,13 @@ impl Request<'_> {
     /// * Nearby Search: 50,000 meters
 
     #[must_use]
-    pub const fn new(client: &GoogleMapsClient, location: LatLng, radius: u32) -> Request {
+    pub const fn new(client: &GoogleMapsClient, location: LatLng, radius: u32) -> Request<'_> {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
             client,
             location,
-            radius,
+            radius: Some(radius),
             // Optional parameters:
             keyword: None,
             language: None,
@@ -54,8 +55,42 @@ impl Request<'_> {
             pagetoken: None,
             rankby: None,
             place_type: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         } // struct
     } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Places API _Nearby Search_ query
+    /// that is ranked by distance, which does not accept a radius.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `location` ‧ The point around which to retrieve place information.
+    ///   This must be specified as `latitude,longitude`. Results are listed in
+    ///   ascending order of their distance from this point.
+    ///
+    /// A keyword, a place type, or both must be set with the `with_keyword()`
+    /// or `with_type()` methods before the request is built.
+
+    #[must_use]
+    pub fn new_by_distance(client: &GoogleMapsClient, location: LatLng) -> Request<'_> {
+        Request {
+            radius: None,
+            rankby: Some(RankBy::Distance),
+            ..Request::new(client, location, 0)
+        } // struct
+    } // fn
 } // impl
@@ -11,6 +11,9 @@ impl<'a> Request<'a> {
     ///   specified range. Valid values range between 0 (most affordable) to 4
     ///   (most expensive), inclusive. The exact amount indicated by a specific
     ///   value will vary from region to region.
+    ///
+    /// A price greater than 4, or a minimum price that is greater than the
+    /// maximum price, is rejected by `build()`.
 
     pub fn with_max_price(
         &'a mut self,
@@ -23,14 +23,38 @@ impl<'a> Request<'a> {
     ///   their distance from the specified location. When `distance` is
     ///   specified, one or more of `keyword`, `name`, or `type` is required and
     ///   radius is disallowed.
+    ///
+    /// ## Description
+    ///
+    /// These requirements are checked when the request is built, so that a
+    /// request that Google would reject with `INVALID_REQUEST` fails with a
+    /// descriptive error instead. The `nearby_search()` method always sets a
+    /// radius, so searches that are ranked by distance are best created with
+    /// the `nearby_search_by_distance()` method, which sets this parameter.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// .with_rank_by(RankBy::Prominence)
+    /// ```
 
-    pub fn with_rankby(
+    pub fn with_rank_by(
         &'a mut self,
-        rankby: impl Into<RankBy>
+        rank_by: impl Into<RankBy>
     ) -> &'a mut Self {
-        // Set rannk by order in Request struct.
-        self.rankby = Some(rankby.into());
+        // Set rank by order in Request struct.
+        self.rankby = Some(rank_by.into());
         // Return modified Request struct to caller.
         self
     } // fn
+
+    /// Adds the rank-by order parameter to the Places API _Nearby Search_
+    /// query. This is the same as the `with_rank_by()` method.
+
+    pub fn with_rankby(
+        &'a mut self,
+        rankby: impl Into<RankBy>
+    ) -> &'a mut Self {
+        self.with_rank_by(rankby)
+    } // fn
 } // impl
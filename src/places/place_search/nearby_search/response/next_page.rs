@@ -0,0 +1,161 @@
+use crate::client::GoogleMapsClient;
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_search::{
+    nearby_search::{request::Request as NearbySearchRequest, response::Response as NearbySearchResponse},
+    NEXT_PAGE_ATTEMPTS, NEXT_PAGE_DELAY,
+}; // crate::places::place_search
+use crate::places::{error::Error as PlacesError, status::Status as PlacesStatus};
+
+// =============================================================================
+
+impl NearbySearchResponse {
+    // -------------------------------------------------------------------------
+    //
+    /// Requests the next page of results, using this response's
+    /// `next_page_token`. Returns `None` if there are no more results.
+    ///
+    /// Google requires a short delay before a `next_page_token` becomes valid,
+    /// so this method waits before making the request. If Google reports that
+    /// the token is not valid yet, the request is repeated after another
+    /// delay.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that made the original request. Google ignores
+    ///   every parameter other than the page token, so only the retry settings
+    ///   of the request that returned this page are used.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let mut page = google_maps_client.nearby_search(LatLng::try_from_dec(dec!(53.540_418), dec!(-113.493_142))?, 1_000)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// while let Some(next_page) = page.next_page(&google_maps_client).await? {
+    ///     println!("{:#?}", next_page.results);
+    ///     page = next_page;
+    /// }
+    /// ```
+
+    pub async fn next_page(
+        &self,
+        client: &GoogleMapsClient
+    ) -> Result<Option<Self>, GoogleMapsError> {
+        let Some(next_page_token) = &self.next_page_token else {
+            return Ok(None);
+        }; // let
+
+        let mut attempt = 1;
+
+        loop {
+            crate::client::sleep(NEXT_PAGE_DELAY).await;
+
+            let mut request =
+                NearbySearchRequest::for_next_page(client, next_page_token, &self.next_page_settings);
+            let result = request.execute().await;
+
+            match result {
+                // The page token is not valid yet. Wait, and try again:
+                Err(GoogleMapsError::Places(PlacesError::GoogleMapsService(
+                    PlacesStatus::InvalidRequest,
+                    _,
+                ))) if attempt < NEXT_PAGE_ATTEMPTS => {
+                    tracing::warn!("next page token is not valid yet, retrying");
+                    attempt += 1;
+                } // InvalidRequest
+                result => return result.map(Some),
+            } // match
+        } // loop
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Requests the following pages of results, and appends them to this
+    /// response. At most `max_pages` pages, including this one, are returned.
+    /// Google returns at most 20 results per page, and at most 60 results in
+    /// total.
+    ///
+    /// The returned response's `next_page_token` is that of the last page
+    /// retrieved, so that it can be continued later if `max_pages` was reached.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ The client that made the original request.
+    ///
+    /// * `max_pages` ‧ The maximum number of pages to return, including this
+    ///   one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let all_results = google_maps_client.nearby_search(LatLng::try_from_dec(dec!(53.540_418), dec!(-113.493_142))?, 1_000)
+    ///     .execute()
+    ///     .await?
+    ///     .collect_all(&google_maps_client, 3)
+    ///     .await?;
+    /// ```
+
+    pub async fn collect_all(
+        mut self,
+        client: &GoogleMapsClient,
+        max_pages: usize
+    ) -> Result<Self, GoogleMapsError> {
+        let mut pages = 1;
+
+        while pages < max_pages {
+            let Some(next_page) = self.next_page(client).await? else {
+                break;
+            }; // let
+
+            self.results.extend(next_page.results);
+
+            for html_attribution in next_page.html_attributions {
+                if !self.html_attributions.contains(&html_attribution) {
+                    self.html_attributions.push(html_attribution);
+                } // if
+            } // for
+
+            self.next_page_token = next_page.next_page_token;
+            pages += 1;
+        } // while
+
+        Ok(self)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::client::{no_delay_backoff, TestServer};
+    use crate::{GoogleMapsClient, LatLng};
+
+    /// The next page is requested with the retry settings of the request that
+    /// returned the first page: one retry, so two attempts.
+    #[tokio::test]
+    async fn next_page_keeps_retry_settings() {
+        let server = TestServer::start(vec![
+            (200, r#"{ "results": [], "status": "OK", "next_page_token": "TOKEN" }"#),
+            (500, "{}"),
+            (500, "{}"),
+            (500, "{}"),
+        ]); // TestServer
+
+        let client = GoogleMapsClient::builder("AIzaTest")
+            .with_base_url(server.base_url.clone())
+            .with_backoff(no_delay_backoff())
+            .build()
+            .unwrap();
+
+        let page = client
+            .nearby_search(LatLng::try_from_f64(53.54, -113.49).unwrap(), 1_000_u32)
+            .with_max_retries(1)
+            .execute()
+            .await
+            .unwrap();
+
+        assert!(page.next_page(&client).await.is_err());
+        assert_eq!(server.requests(), 3);
+    } // fn
+} // mod
@@ -0,0 +1,37 @@
+use backoff::ExponentialBackoff;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+//
+/// The retry settings of the request that returned a page of results. They are
+/// kept with the response, so that the following pages are requested with the
+/// same settings.
+
+#[derive(Clone, Debug, Default)]
+pub struct NextPageSettings {
+    /// See the request's `with_backoff()` method.
+    pub backoff: Option<ExponentialBackoff>,
+    /// See the request's `with_max_retries()` method.
+    pub max_retries: Option<u32>,
+    /// See the request's `with_timeout()` method.
+    pub timeout: Option<Duration>,
+    /// See the request's `with_deadline()` method.
+    pub deadline: Option<Duration>,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+// The settings are not part of Google's response, so they do not affect
+// whether two responses are equal:
+
+impl PartialEq for NextPageSettings {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    } // fn
+} // impl
+
+impl Eq for NextPageSettings {}
+
+impl std::hash::Hash for NextPageSettings {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+} // impl
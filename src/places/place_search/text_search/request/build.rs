@@ -1,4 +1,4 @@
-use crate::places::place_search::text_search::request::Request;
+use crate::places::{error::Error as PlacesError, place_search::text_search::request::Request};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 // -----------------------------------------------------------------------------
@@ -10,8 +10,26 @@ impl<'a> Request<'a> {
     /// ## Arguments
     ///
     /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the minimum or maximum price is greater than 4,
+    ///   or if the minimum price is greater than the maximum price.
+
+    pub fn build(&mut self) -> Result<&'a mut Request<'_>, PlacesError> {
+        // Price levels range from 0 (most affordable) to 4 (most expensive):
+        for price in [self.minprice, self.maxprice].into_iter().flatten() {
+            if price > 4 {
+                return Err(PlacesError::InvalidPriceLevel(price));
+            } // if
+        } // for
+
+        if let (Some(minprice), Some(maxprice)) = (self.minprice, self.maxprice) {
+            if minprice > maxprice {
+                return Err(PlacesError::MinPriceGreaterThanMaxPrice(minprice, maxprice));
+            } // if
+        } // if
 
-    pub fn build(&mut self) -> &'a mut Request {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -24,10 +42,11 @@ impl<'a> Request<'a> {
         // This section builds the "optional parameters" portion of the query
         // string:
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // one has not been set for this request:
+        if let Some(language) = self.language.or(self.client.language) {
             query.push_str("&language=");
-            query.push_str(&String::from(language));
+            query.push_str(&String::from(&language));
         }
 
         // Location key/value pair:
@@ -58,10 +77,11 @@ impl<'a> Request<'a> {
             query.push_str(pagetoken);
         }
 
-        // Region key/value pair:
-        if let Some(region) = &self.region {
+        // Region key/value pair. The client's default region is used if one
+        // has not been set for this request:
+        if let Some(region) = self.region.or(self.client.region) {
             query.push_str("&region=");
-            query.push_str(&String::from(region));
+            query.push_str(&String::from(&region));
         }
 
         // Place Type key/value pair:
@@ -71,9 +91,9 @@ impl<'a> Request<'a> {
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
-        self
+        Ok(self)
     } // fn
 } // impl
@@ -21,6 +21,6 @@ impl<'a> TextSearchRequest<'a> {
     /// This method accepts no arguments.
 
     pub async fn execute(&'a mut self) -> Result<TextSearchResponse, GoogleMapsError> {
-        self.build().get().await
+        self.build()?.get().await
     } // fn
 } // impl
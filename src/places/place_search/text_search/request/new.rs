@@ -41,7 +41,7 @@ impl Request<'_> {
     /// * Nearby Search: 50,000 meters
 
     #[must_use]
-    pub fn new(client: &GoogleMapsClient, query: impl Into<String>, radius: u32) -> Request {
+    pub fn new(client: &GoogleMapsClient, query: impl Into<String>, radius: u32) -> Request<'_> {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
@@ -57,6 +57,15 @@ impl Request<'_> {
             pagetoken: None,
             region: None,
             place_type: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         } // struct
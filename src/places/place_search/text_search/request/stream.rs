@@ -0,0 +1,78 @@
+use crate::error::Error as GoogleMapsError;
+use crate::places::place_search::text_search::{
+    request::Request as TextSearchRequest, response::Response as TextSearchResponse,
+}; // crate::places::place_search::text_search
+use crate::places::Place;
+use futures::{Stream, StreamExt};
+
+// -----------------------------------------------------------------------------
+
+/// The page that the stream will request next.
+
+enum Page<'a> {
+    /// The first page, which is requested using the caller's request.
+    First(&'a mut TextSearchRequest<'a>),
+    /// A following page, which is requested using the previous page's
+    /// `next_page_token`.
+    Next(Box<TextSearchResponse>),
+} // enum
+
+// =============================================================================
+
+impl<'a> TextSearchRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query, and returns a stream of every place found across
+    /// all pages of results.
+    ///
+    /// Pages are requested lazily: the next page is only requested once every
+    /// place from the previous page has been consumed. Each page request
+    /// observes the client's rate limit, and waits for the `next_page_token` to
+    /// become valid. The stream ends after the last page, or after the first
+    /// error, which is yielded as the final item.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use futures::StreamExt;
+    ///
+    /// let mut request = google_maps_client.text_search("pizza", 5_000);
+    /// let mut places = std::pin::pin!(request.stream());
+    ///
+    /// while let Some(place) = places.next().await {
+    ///     println!("{:#?}", place?.name);
+    /// }
+    /// ```
+
+    pub fn stream(&'a mut self) -> impl Stream<Item = Result<Place, GoogleMapsError>> + 'a {
+        let client = self.client;
+
+        futures::stream::unfold(Some(Page::First(self)), move |page| async move {
+            let response = match page? {
+                Page::First(request) => request.execute().await,
+                Page::Next(response) => response.next_page(client).await.transpose()?,
+            }; // match
+
+            match response {
+                Ok(mut response) => {
+                    let places: Vec<Result<Place, GoogleMapsError>> =
+                        std::mem::take(&mut response.results).into_iter().map(Ok).collect();
+                    // Only request another page if Google indicated that there
+                    // are more results:
+                    let next_page = response
+                        .next_page_token
+                        .is_some()
+                        .then_some(Page::Next(Box::new(response)));
+                    Some((places, next_page))
+                } // Ok
+                // Yield the error, and end the stream:
+                Err(error) => Some((vec![Err(error)], None)),
+            } // match
+        }) // unfold
+        .flat_map(futures::stream::iter)
+    } // fn
+} // impl
@@ -5,7 +5,14 @@
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "reqwest")]
+mod next_page;
+
+// -----------------------------------------------------------------------------
+
 use crate::places::status::Status;
+#[cfg(feature = "reqwest")]
+use crate::places::place_search::NextPageSettings;
 use crate::places::Place;
 use serde::{Deserialize, Serialize};
 
@@ -67,6 +74,12 @@ pub struct Response {
     /// and when it will become valid.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub next_page_token: Option<String>,
+
+    /// The retry settings of the request that returned this page, which are
+    /// used to request the next page.
+    #[cfg(feature = "reqwest")]
+    #[serde(skip)]
+    pub(crate) next_page_settings: NextPageSettings,
 } // struct
 
 // -----------------------------------------------------------------------------
@@ -11,7 +11,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &'a mut Request<'_> {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -24,10 +24,11 @@ impl<'a> Request<'a> {
         // This section builds the "optional parameters" portion of the query
         // string:
 
-        // Language key/value pair:
-        if let Some(language) = &self.language {
+        // Language key/value pair. The client's default language is used if
+        // one has not been set for this request:
+        if let Some(language) = self.language.or(self.client.language) {
             query.push_str("&language=");
-            query.push_str(&String::from(language));
+            query.push_str(&String::from(&language));
         }
 
         // Location key/value pair:
@@ -49,7 +50,7 @@ impl<'a> Request<'a> {
         }
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
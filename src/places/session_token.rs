@@ -0,0 +1,157 @@
+//! Contains the `SessionToken` struct and its associated traits. A session
+//! token groups the _Place Autocomplete_ requests a user makes while typing,
+//! and the _Place Details_ request that follows their selection, into a single
+//! session for billing purposes.
+
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// -----------------------------------------------------------------------------
+//
+/// A random string which identifies an autocomplete
+/// [session](https://developers.google.com/maps/documentation/places/web-service/details#session_tokens)
+/// for billing purposes.
+///
+/// Generate a fresh token with `SessionToken::new()` when the user starts
+/// typing, pass it to every _Place Autocomplete_ request with
+/// `with_sessiontoken(&token)`, and then pass the same token to the _Place
+/// Details_ request for the place the user selected. Once the _Place Details_
+/// request has been made, the session has concluded and the token must not be
+/// used again.
+///
+/// ## Example
+///
+/// ```rust
+/// let session_token = SessionToken::new();
+///
+/// let predictions = google_maps_client.place_autocomplete("Pizza")
+///     .with_sessiontoken(&session_token)
+///     .execute()
+///     .await?;
+///
+/// let details = google_maps_client.place_details(predictions.predictions[0].place_id.clone())
+///     .with_sessiontoken(&session_token)
+///     .execute()
+///     .await?;
+/// ```
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SessionToken(String);
+
+// -----------------------------------------------------------------------------
+
+impl SessionToken {
+    /// Generates a fresh session token. The token is formatted as a version 4
+    /// UUID, as recommended by Google, for example
+    /// `0b1c8a6e-7f3d-4e2a-9c5b-6d4f2e1a3b7c`.
+    ///
+    /// The token is random enough to be unique per session, but it is not
+    /// suitable for cryptographic purposes.
+
+    #[must_use]
+    pub fn new() -> Self {
+        let random = (u128::from(random_u64()) << 64) | u128::from(random_u64());
+
+        // Set the version (4, random) and variant (RFC 4122) bits:
+        let uuid = random & !(0xf_u128 << 76) & !(0x3_u128 << 62);
+        let uuid = uuid | (0x4_u128 << 76) | (0x2_u128 << 62);
+
+        Self(format!(
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            uuid >> 96,
+            (uuid >> 80) & 0xffff,
+            (uuid >> 64) & 0xffff,
+            (uuid >> 48) & 0xffff,
+            uuid & 0xffff_ffff_ffff,
+        )) // SessionToken
+    } // fn
+
+    /// Returns the session token as a string slice.
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+/// Returns 64 random bits. The standard library's `RandomState` is seeded from
+/// the operating system's random number generator, and each instance uses
+/// different keys. A counter and the current time are also hashed, so that
+/// consecutive tokens differ even if the keys repeat.
+
+fn random_u64() -> u64 {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let nanoseconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos();
+
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(nanoseconds);
+    hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+    hasher.finish()
+} // fn
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for SessionToken {
+    /// Returns a freshly generated session token.
+    fn default() -> Self {
+        Self::new()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for SessionToken {
+    /// Formats a `SessionToken` struct into the string that is sent to Google.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<String> for SessionToken {
+    /// Uses an existing string, for example one generated by the `uuid` crate,
+    /// as a session token.
+    fn from(token: String) -> Self {
+        Self(token)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&str> for SessionToken {
+    /// Uses an existing string, for example one generated by the `uuid` crate,
+    /// as a session token.
+    fn from(token: &str) -> Self {
+        Self(token.to_string())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<SessionToken> for String {
+    /// Converts a `SessionToken` struct into the string that is sent to Google.
+    fn from(session_token: SessionToken) -> Self {
+        session_token.0
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&SessionToken> for String {
+    /// Converts a borrowed `SessionToken` struct into the string that is sent
+    /// to Google. This allows the same token to be passed to several requests.
+    fn from(session_token: &SessionToken) -> Self {
+        session_token.0.clone()
+    } // fn
+} // impl
@@ -0,0 +1,402 @@
+//! Contains functions for encoding and decoding Google's [encoded polyline
+//! format](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
+//! Encoded polylines are used by the Directions API to describe the path of
+//! routes and steps, and are a compact way of storing or sending a long list
+//! of coordinates.
+//!
+//! ```rust
+//! use google_maps::{polyline, GoogleMapsError, LatLng};
+//! use rust_decimal_macros::dec;
+//!
+//! let path = vec![
+//!     LatLng::try_from_dec(dec!(38.5), dec!(-120.2))?,
+//!     LatLng::try_from_dec(dec!(40.7), dec!(-120.95))?,
+//!     LatLng::try_from_dec(dec!(43.252), dec!(-126.453))?,
+//! ];
+//!
+//! let encoded = polyline::encode(&path);
+//! assert_eq!(encoded, "_p~iF~ps|U_ulLnnqC_mqNvxq`@");
+//!
+//! let decoded = polyline::decode(&encoded)?;
+//! assert_eq!(decoded, path);
+//! # Ok::<(), GoogleMapsError>(())
+//! ```
+
+use crate::error::Error as GoogleMapsError;
+use crate::types::{Error as TypeError, LatLng};
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+
+// -----------------------------------------------------------------------------
+
+/// The number of decimal places that are preserved by Google's polyline
+/// algorithm, unless a different precision is specified.
+pub const DEFAULT_PRECISION: u32 = 5;
+
+/// The largest supported precision. Coordinates scaled by more than 10¹⁶ no
+/// longer fit in the 64-bit integers that the algorithm works with.
+pub const MAX_PRECISION: u32 = 16;
+
+// -----------------------------------------------------------------------------
+//
+/// Encodes a list of coordinates into an encoded polyline string, using
+/// Google's default precision of 5 decimal places. Coordinates are rounded to
+/// the nearest 0.00001°.
+///
+/// ## Arguments
+///
+/// * `points` ‧ The coordinates to encode, in the order of the path.
+
+#[must_use]
+pub fn encode(points: &[LatLng]) -> String {
+    encode_scaled(points, DEFAULT_PRECISION)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Encodes a list of coordinates into an encoded polyline string.
+///
+/// ## Arguments
+///
+/// * `points` ‧ The coordinates to encode, in the order of the path.
+///
+/// * `precision` ‧ The number of decimal places to preserve. Google uses 5.
+///   Some other services, such as OSRM and Valhalla, use 6.
+///
+/// # Errors
+///
+/// * Returns an error if the precision is greater than `MAX_PRECISION`.
+
+pub fn encode_with_precision(
+    points: &[LatLng],
+    precision: u32
+) -> Result<String, GoogleMapsError> {
+    if precision > MAX_PRECISION {
+        Err(TypeError::InvalidPolylinePrecision(precision))?;
+    } // if
+
+    Ok(encode_scaled(points, precision))
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Encodes a list of coordinates using a precision that is known to be valid.
+
+fn encode_scaled(points: &[LatLng], precision: u32) -> String {
+    let factor = Decimal::from(10_i64.pow(precision));
+    let scale = |value: Decimal| {
+        (value * factor)
+            .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+            .to_i64()
+            .unwrap_or_default()
+    }; // scale
+
+    let mut encoded = String::new();
+
+    // Each coordinate is encoded as the difference from the previous
+    // coordinate:
+    let mut previous_latitude: i64 = 0;
+    let mut previous_longitude: i64 = 0;
+
+    for point in points {
+        let latitude = scale(point.lat);
+        let longitude = scale(point.lng);
+        push_value(&mut encoded, latitude - previous_latitude);
+        push_value(&mut encoded, longitude - previous_longitude);
+        previous_latitude = latitude;
+        previous_longitude = longitude;
+    } // for
+
+    encoded
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Appends a signed value to the encoded polyline string.
+
+fn push_value(encoded: &mut String, value: i64) {
+    // The sign is stored in the lowest bit. Negative values are inverted so
+    // that their leading bits are zero:
+    let mut value = if value < 0 { !(value << 1) } else { value << 1 };
+
+    loop {
+        let mut chunk = value & 0x1f;
+        value >>= 5;
+
+        // The `0x20` bit is set on every chunk except the last one:
+        if value > 0 {
+            chunk |= 0x20;
+        } // if
+
+        // Each chunk is offset by 63 so that it is a printable ASCII
+        // character:
+        encoded.push(char::from(b'?' + u8::try_from(chunk).unwrap_or_default()));
+
+        if value == 0 {
+            break;
+        } // if
+    } // loop
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes an encoded polyline string into a list of coordinates, using
+/// Google's default precision of 5 decimal places.
+///
+/// ## Arguments
+///
+/// * `encoded` ‧ The encoded polyline string. For example, the `points` field
+///   of a route's `overview_polyline`.
+///
+/// # Errors
+///
+/// * Returns an error if the polyline is invalid or if the decoded
+///   coordinates are out of bounds.
+
+pub fn decode(encoded: &str) -> Result<Vec<LatLng>, GoogleMapsError> {
+    decode_with_precision(encoded, DEFAULT_PRECISION)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Decodes an encoded polyline string into a list of coordinates.
+///
+/// ## Arguments
+///
+/// * `encoded` ‧ The encoded polyline string.
+///
+/// * `precision` ‧ The number of decimal places that the coordinates were
+///   encoded with. Google uses 5.
+///
+/// # Errors
+///
+/// * Returns an error if the polyline is invalid, if the decoded coordinates
+///   are out of bounds, or if the precision is greater than `MAX_PRECISION`.
+
+pub fn decode_with_precision(
+    encoded: &str,
+    precision: u32
+) -> Result<Vec<LatLng>, GoogleMapsError> {
+    if precision > MAX_PRECISION {
+        Err(TypeError::InvalidPolylinePrecision(precision))?;
+    } // if
+
+    let mut bytes = encoded.bytes();
+    let mut points = Vec::new();
+
+    // Each coordinate is encoded as the difference from the previous
+    // coordinate:
+    let mut latitude: i64 = 0;
+    let mut longitude: i64 = 0;
+
+    while let Some(latitude_delta) = next_value(&mut bytes, encoded)? {
+        // A latitude must always be followed by a longitude:
+        let longitude_delta = next_value(&mut bytes, encoded)?
+            .ok_or_else(|| TypeError::InvalidPolyline(encoded.to_string()))?;
+
+        latitude = latitude
+            .checked_add(latitude_delta)
+            .ok_or_else(|| TypeError::InvalidPolyline(encoded.to_string()))?;
+        longitude = longitude
+            .checked_add(longitude_delta)
+            .ok_or_else(|| TypeError::InvalidPolyline(encoded.to_string()))?;
+
+        points.push(LatLng::try_from_dec(
+            Decimal::new(latitude, precision),
+            Decimal::new(longitude, precision),
+        )?); // push
+    } // while
+
+    Ok(points)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Reads the next signed value from the encoded polyline's bytes. Returns
+/// `None` if there are no bytes left.
+
+fn next_value(
+    bytes: &mut impl Iterator<Item = u8>,
+    encoded: &str
+) -> Result<Option<i64>, TypeError> {
+    let mut result: i64 = 0;
+    let mut shift = 0;
+
+    loop {
+        let Some(byte) = bytes.next() else {
+            // The polyline may only end between values, not in the middle of
+            // one:
+            return if shift == 0 {
+                Ok(None)
+            } else {
+                Err(TypeError::InvalidPolyline(encoded.to_string()))
+            }; // return
+        }; // let
+
+        // Each byte holds 5 bits of the value, offset by 63 so that it is a
+        // printable ASCII character. A value may not be longer than an `i64`:
+        if !(63..=126).contains(&byte) || shift > 60 {
+            return Err(TypeError::InvalidPolyline(encoded.to_string()));
+        } // if
+
+        let chunk = i64::from(byte - 63);
+        result |= (chunk & 0x1f) << shift;
+        shift += 5;
+
+        // The `0x20` bit is set on every chunk except the last one:
+        if chunk < 0x20 {
+            break;
+        } // if
+    } // loop
+
+    // The sign is stored in the lowest bit:
+    if result & 1 == 1 {
+        Ok(Some(!(result >> 1)))
+    } else {
+        Ok(Some(result >> 1))
+    } // if
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    /// The example from Google's documentation of the polyline algorithm.
+    const GOOGLE_EXAMPLE: &str = "_p~iF~ps|U_ulLnnqC_mqNvxq`@";
+
+    fn google_example_path() -> Vec<LatLng> {
+        vec![
+            LatLng::try_from_dec(dec!(38.5), dec!(-120.2)).unwrap(),
+            LatLng::try_from_dec(dec!(40.7), dec!(-120.95)).unwrap(),
+            LatLng::try_from_dec(dec!(43.252), dec!(-126.453)).unwrap(),
+        ] // vec!
+    } // fn
+
+    fn is_invalid_polyline(result: &Result<Vec<LatLng>, GoogleMapsError>) -> bool {
+        matches!(result, Err(GoogleMapsError::Type(TypeError::InvalidPolyline(_))))
+    } // fn
+
+    #[test]
+    fn encodes_google_example() {
+        assert_eq!(encode(&google_example_path()), GOOGLE_EXAMPLE);
+    } // fn
+
+    #[test]
+    fn decodes_google_example() {
+        assert_eq!(decode(GOOGLE_EXAMPLE).unwrap(), google_example_path());
+    } // fn
+
+    #[test]
+    fn round_trips_at_default_precision() {
+        let path = vec![
+            LatLng::try_from_dec(dec!(51.50722), dec!(-0.1275)).unwrap(),
+            LatLng::try_from_dec(dec!(48.85661), dec!(2.35222)).unwrap(),
+            LatLng::try_from_dec(dec!(-33.86882), dec!(151.20930)).unwrap(),
+        ]; // vec!
+        assert_eq!(decode(&encode(&path)).unwrap(), path);
+    } // fn
+
+    #[test]
+    fn round_trips_at_precision_6() {
+        let path = vec![
+            LatLng::try_from_dec(dec!(51.507222), dec!(-0.127500)).unwrap(),
+            LatLng::try_from_dec(dec!(48.856613), dec!(2.352222)).unwrap(),
+            LatLng::try_from_dec(dec!(-33.868820), dec!(151.209296)).unwrap(),
+        ]; // vec!
+        let encoded = encode_with_precision(&path, 6).unwrap();
+        assert_eq!(decode_with_precision(&encoded, 6).unwrap(), path);
+        // The same polyline does not decode to the same coordinates at another
+        // precision:
+        assert_ne!(decode(&encoded).ok(), Some(path));
+    } // fn
+
+    #[test]
+    fn round_trips_zero_and_negative_deltas() {
+        let path = vec![
+            LatLng::try_from_dec(dec!(0), dec!(0)).unwrap(),
+            // A zero delta:
+            LatLng::try_from_dec(dec!(0), dec!(0)).unwrap(),
+            // Negative deltas:
+            LatLng::try_from_dec(dec!(-0.00001), dec!(-179.99999)).unwrap(),
+            // A zero delta for one coordinate only:
+            LatLng::try_from_dec(dec!(-0.00001), dec!(89.5)).unwrap(),
+        ]; // vec!
+        let encoded = encode(&path);
+        assert!(encoded.starts_with("????"));
+        assert_eq!(decode(&encoded).unwrap(), path);
+    } // fn
+
+    #[test]
+    fn encodes_single_negative_value() {
+        // -1 is stored as `!(-1 << 1)` = 1, which is offset to `@`:
+        let path = [LatLng::try_from_dec(dec!(-0.00001), dec!(0)).unwrap()];
+        assert_eq!(encode(&path), "@?");
+    } // fn
+
+    #[test]
+    fn rounds_to_precision() {
+        let path = [LatLng::try_from_dec(dec!(38.500004), dec!(-120.200006)).unwrap()];
+        assert_eq!(
+            decode(&encode(&path)).unwrap(),
+            [LatLng::try_from_dec(dec!(38.5), dec!(-120.20001)).unwrap()]
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn decodes_empty_polyline() {
+        assert!(decode("").unwrap().is_empty());
+        assert_eq!(encode(&[]), "");
+    } // fn
+
+    #[test]
+    fn rejects_value_truncated_in_the_middle() {
+        // The last byte has its continuation bit set:
+        assert!(is_invalid_polyline(&decode("_p~i")));
+    } // fn
+
+    #[test]
+    fn rejects_latitude_without_longitude() {
+        assert!(is_invalid_polyline(&decode("_p~iF")));
+        assert!(is_invalid_polyline(&decode("_p~iF~ps|U_ulL")));
+    } // fn
+
+    #[test]
+    fn rejects_invalid_bytes() {
+        // Below the printable range used by the algorithm:
+        assert!(is_invalid_polyline(&decode("_p~iF ps|U")));
+        // Above the printable range, and not ASCII:
+        assert!(is_invalid_polyline(&decode("_p~iF\u{7f}ps|U")));
+        assert!(is_invalid_polyline(&decode("_p~iFéps|U")));
+    } // fn
+
+    #[test]
+    fn rejects_values_longer_than_64_bits() {
+        assert!(is_invalid_polyline(&decode("~~~~~~~~~~~~~~?")));
+    } // fn
+
+    #[test]
+    fn rejects_out_of_bounds_coordinates() {
+        // A latitude of 100°:
+        let encoded = {
+            let mut encoded = String::new();
+            push_value(&mut encoded, 10_000_000);
+            push_value(&mut encoded, 0);
+            encoded
+        }; // let
+        assert!(decode(&encoded).is_err());
+    } // fn
+
+    #[test]
+    fn rejects_unsupported_precision() {
+        assert!(matches!(
+            encode_with_precision(&google_example_path(), MAX_PRECISION + 1),
+            Err(GoogleMapsError::Type(TypeError::InvalidPolylinePrecision(17)))
+        )); // assert!
+        assert!(matches!(
+            decode_with_precision(GOOGLE_EXAMPLE, MAX_PRECISION + 1),
+            Err(GoogleMapsError::Type(TypeError::InvalidPolylinePrecision(17)))
+        )); // assert!
+    } // fn
+} // mod
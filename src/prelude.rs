@@ -1,10 +1,17 @@
-//! Put `use google_maps::prelude::*;` in your code will to get more convenient
+//! Put `use google_maps::prelude::*;` in your code to get more convenient
 //! access to everything you need. If you're not concerned with name space
 //! collisions or conflicts, you can glob import all `google_maps` structs and
 //! enums by using this module.
+//!
+//! Types that share a name across APIs, such as each API's `Request`,
+//! `Response`, `Status` and `Error`, are re-exported with the API's name as a
+//! prefix, for example `DirectionsRequest` or `GeocodingStatus`. The granular
+//! paths, such as `google_maps::directions::Request`, remain available.
 
 // Re-export dependencies
 
+#[cfg(feature = "reqwest")]
+pub use backoff::ExponentialBackoff;
 #[cfg(any(
     feature = "directions",
     feature = "distance_matrix",
@@ -28,6 +35,7 @@ pub use crate::{
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::address_component::AddressComponent;
 #[cfg(any(
+    feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
@@ -40,6 +48,8 @@ pub use crate::types::bounds::Bounds;
     feature = "geocoding"
 ))]
 pub use crate::types::country::Country;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::currency::Currency;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
 #[cfg(any(
@@ -52,6 +62,7 @@ pub use crate::types::geometry::Geometry;
 ))]
 pub use crate::types::language::Language;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
@@ -59,11 +70,14 @@ pub use crate::types::language::Language;
     feature = "geocoding",
     feature = "places",
     feature = "roads",
+    feature = "static_map",
     feature = "time_zone"
 ))]
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::location_type::LocationType;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::money::Money;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -87,11 +101,29 @@ pub use crate::types::region::Region;
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "reqwest")]
+pub use crate::client::ClientBuilder;
+
+#[cfg(feature = "reqwest")]
+pub use crate::client::{no_delay_backoff, RetryClassifier, RetryDecision};
+
+#[cfg(feature = "reqwest")]
+pub use crate::quota::QuotaKind;
+
 #[cfg(feature = "reqwest")]
 pub use crate::request_rate::api::Api;
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "address_validation")]
+pub use crate::address_validation::{
+    AddressMetadata, AddressValidationError, AddressValidationRequest, AddressValidationResponse,
+    ComponentName, ConfirmationLevel, Geocode, Granularity, PossibleNextAction, PostalAddress,
+    ValidatedAddress, ValidatedAddressComponent, ValidationResult, Verdict,
+}; // crate::address_validation
+
+// -----------------------------------------------------------------------------
+
 #[cfg(any(feature = "directions", feature = "distance_matrix"))]
 pub use crate::directions::{
     request::{
@@ -118,7 +150,10 @@ pub use crate::directions::{
 pub use crate::directions::{
     error::Error as DirectionsError,
     request::Request as DirectionsRequest,
-    response::{directions_distance::DirectionsDistance, directions_duration::DirectionsDuration}, // response
+    response::{
+        directions_distance::DirectionsDistance, directions_duration::DirectionsDuration,
+        geocoded_waypoint::GeocodedWaypoint, geocoder_status::GeocoderStatus,
+    }, // response
 }; // crate::directions
 
 // -----------------------------------------------------------------------------
@@ -148,9 +183,21 @@ pub use crate::geocoding::{
         geocoding::Geocoding, plus_code::PlusCode, status::Status as GeocodingStatus,
         Response as GeocodingResponse,
     }, // response
+    plus_codes::PlusCodeRequest as GeocodingPlusCodeRequest,
     reverse::ReverseRequest as GeocodingReverseRequest,
 }; // crate::geocoding
 
+#[cfg(all(feature = "geocoding", feature = "reqwest"))]
+pub use crate::geocoding::cache::Cache as GeocodingCache;
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "static_map")]
+pub use crate::static_map::{
+    MapType, MarkerSize, Markers, Path as StaticMapPath, StaticMapError, StaticMapRequest,
+    StaticMapResponse,
+}; // crate::static_map
+
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "time_zone")]
@@ -176,7 +223,13 @@ pub use crate::places::place_autocomplete::{
 // -----------------------------------------------------------------------------
 
 #[cfg(feature = "autocomplete")]
-pub use crate::places::place_autocomplete::request::Request as QueryAutocompleteRequest; // crate::places::place_autocomplete
+pub use crate::places::query_autocomplete::request::Request as QueryAutocompleteRequest; // crate::places::query_autocomplete
+
+#[cfg(any(feature = "autocomplete", feature = "places"))]
+pub use crate::places::{
+    location_bias::LocationBias, location_restriction::LocationRestriction,
+    session_token::SessionToken,
+}; // crate::places
 
 // -----------------------------------------------------------------------------
 
@@ -187,11 +240,19 @@ pub use crate::places::{
     place_opening_hours_period::PlaceOpeningHoursPeriod,
     place_opening_hours_period_detail::PlaceOpeningHoursPeriodDetail, place_photo::PlacePhoto,
     place_review::PlaceReview, place_special_day::PlaceSpecialDay,
-    secondary_hours_type::SecondaryHoursType, status::Status as PlacesStatus,
+    rank_by::RankBy, secondary_hours_type::SecondaryHoursType, status::Status as PlacesStatus,
 }; // crate::places
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "places")]
+pub use crate::places::place_search::find_place::{
+    input_type::InputType, request::Request as FindPlaceRequest,
+    response::Response as FindPlaceResponse,
+}; // find_place
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "places")]
 pub use crate::places::place_search::text_search::{
     request::Request as TextSearchRequest, response::Response as TextSearchResponse,
@@ -199,14 +260,29 @@ pub use crate::places::place_search::text_search::{
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "places")]
+pub use crate::places::place_search::nearby_search::{
+    request::Request as NearbySearchRequest, response::Response as NearbySearchResponse,
+}; // nearby_search
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "places")]
 pub use crate::places::place_details::{
-    field::Field, request::Request as PlaceDetailsRequest,
+    billing_category::BillingCategory, field::Field, field::Field as PlaceField,
+    field_mask::FieldMask, request::Request as PlaceDetailsRequest,
     response::Response as PlaceDetailsResponse, sort_order::SortOrder,
 }; // place_details
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "places")]
+pub use crate::places::place_photo::{
+    request::Request as PlacePhotoRequest, response::Response as PlacePhotoResponse,
+}; // place_photo
+
+// -----------------------------------------------------------------------------
+
 #[cfg(feature = "roads")]
 pub use crate::roads::{
     error::Error as RoadsError, error_response::ErrorResponse as RoadsErrorResponse,
@@ -226,3 +302,11 @@ pub use crate::roads::snap_to_roads::{
 pub use crate::roads::nearest_roads::{
     request::Request as NearestRoadsRequest, response::Response as NearestRoadsResponse,
 }; // crate::roads::nearest_roads
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "roads")]
+pub use crate::roads::speed_limits::{
+    request::Request as SpeedLimitsRequest, response::Response as SpeedLimitsResponse,
+    speed_limit::SpeedLimit, speed_units::SpeedUnits,
+}; // crate::roads::speed_limits
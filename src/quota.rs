@@ -0,0 +1,122 @@
+//! Recognizes quota errors. Google reports an exceeded quota in several ways:
+//! the `OVER_DAILY_LIMIT` and `OVER_QUERY_LIMIT` statuses of the legacy web
+//! services, the Roads API's `RESOURCE_EXHAUSTED` status, and HTTP
+//! `429 Too Many Requests` or `403 Forbidden` responses with a quota reason in
+//! the body. They are all mapped here, so that every API reports them as the
+//! same `GoogleMapsError::Quota` error.
+
+use reqwest::StatusCode;
+
+// -----------------------------------------------------------------------------
+//
+/// Reasons in the body of an HTTP `403 Forbidden` response that indicate that
+/// a quota, rather than a permission, was the problem.
+
+const QUOTA_REASONS: [&str; 8] = [
+    "OVER_DAILY_LIMIT",
+    "OVER_QUERY_LIMIT",
+    "RESOURCE_EXHAUSTED",
+    "RATE_LIMIT_EXCEEDED",
+    "dailyLimitExceeded",
+    "quotaExceeded",
+    "rateLimitExceeded",
+    "userRateLimitExceeded",
+];
+
+// -----------------------------------------------------------------------------
+//
+/// The kind of quota that a request exceeded. Returned by
+/// `GoogleMapsError::quota_kind()`, and in the `GoogleMapsError::Quota` error.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum QuotaKind {
+    /// A daily quota, or a self-imposed usage cap, has been exceeded, or there
+    /// is a problem with the project's billing. Retrying will not help until
+    /// the quota is reset, which may not be until the next day, or until the
+    /// billing problem is fixed.
+    Daily,
+    /// A per-second or per-minute rate limit has been exceeded. The request
+    /// may succeed if it is retried after a short delay.
+    Rate,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl QuotaKind {
+    /// Returns whether a request that exceeded this kind of quota is worth
+    /// retrying soon. Only rate limits are.
+
+    #[must_use]
+    pub const fn is_retryable(self) -> bool {
+        matches!(self, Self::Rate)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Maps an unsuccessful Google Maps status, such as `OVER_QUERY_LIMIT`, to
+    /// the kind of quota that it reports. `None` is returned for statuses that
+    /// are not about quotas.
+    ///
+    /// Google uses `OVER_QUERY_LIMIT` and `RESOURCE_EXHAUSTED` for both daily
+    /// quotas and rate limits, so the error message is used to tell them
+    /// apart.
+
+    #[must_use]
+    pub fn from_google_status(status: &str, error_message: Option<&str>) -> Option<Self> {
+        match status {
+            "OVER_DAILY_LIMIT" => Some(Self::Daily),
+            "OVER_QUERY_LIMIT" | "RESOURCE_EXHAUSTED" => {
+                Some(Self::from_message(error_message.unwrap_or_default()))
+            } // OVER_QUERY_LIMIT
+            _ => None,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Maps an unsuccessful HTTP response to the kind of quota that it reports.
+    /// A `429 Too Many Requests` response is always about a quota. A
+    /// `403 Forbidden` response is only about a quota if its body contains a
+    /// quota reason, such as `RESOURCE_EXHAUSTED` or `dailyLimitExceeded`.
+    /// `None` is returned for every other response.
+
+    #[must_use]
+    pub fn from_http_response(status: StatusCode, body: &str) -> Option<Self> {
+        match status {
+            StatusCode::TOO_MANY_REQUESTS => Some(Self::from_message(body)),
+            StatusCode::FORBIDDEN if QUOTA_REASONS.iter().any(|reason| body.contains(reason)) => {
+                Some(Self::from_message(body))
+            } // FORBIDDEN
+            _ => None,
+        } // match
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Determines whether a quota error message, or response body, is about a
+    /// daily quota or a rate limit. For example, Google's messages include
+    /// _"You have exceeded your daily request quota for this API"_ and
+    /// _"Quota exceeded for quota metric 'Requests' and limit 'Requests per
+    /// day'"_.
+
+    fn from_message(message: &str) -> Self {
+        let message = message.to_lowercase();
+        if message.contains("daily") || message.contains("per day") {
+            Self::Daily
+        } else {
+            Self::Rate
+        } // if
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for QuotaKind {
+    /// Formats the kind of quota for display, for example in error messages.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Daily => write!(f, "daily quota"),
+            Self::Rate => write!(f, "rate limit"),
+        } // match
+    } // fn
+} // impl
@@ -0,0 +1,214 @@
+//! Hides API keys and other credentials from `Debug` output, logged URLs, and
+//! HTTP client errors, so that they do not leak into logs that may be shared,
+//! or shipped to third-party services.
+//!
+//! Enable the `unredacted-logs` feature to log credentials in full. This is intended
+//! for local debugging only.
+
+use std::borrow::Cow;
+
+// -----------------------------------------------------------------------------
+//
+/// Replaces the redacted part of a secret.
+
+#[cfg(not(feature = "unredacted-logs"))]
+const REDACTED: &str = "…redacted";
+
+// -----------------------------------------------------------------------------
+//
+/// Query string parameters whose values are secret: the API key, and the
+/// Premium Plan client ID and URL signature.
+
+#[cfg(not(feature = "unredacted-logs"))]
+const SECRET_PARAMETERS: [&str; 3] = ["key", "signature", "client"];
+
+// -----------------------------------------------------------------------------
+//
+/// Replaces the values of secret query string parameters.
+
+#[cfg(not(feature = "unredacted-logs"))]
+const REDACTED_PARAMETER: &str = "REDACTED";
+
+// -----------------------------------------------------------------------------
+//
+/// Redacts an API key for display. The first four characters, which are `AIza`
+/// for every Google API key, are kept so that it's clear which kind of key was
+/// used, for example `AIza…redacted`.
+
+#[cfg(not(feature = "unredacted-logs"))]
+pub fn redact_key(key: &str) -> Cow<'_, str> {
+    if key.is_empty() {
+        return Cow::Borrowed(key);
+    } // if
+
+    let prefix: String = key.chars().take(4).collect();
+    Cow::Owned(format!("{prefix}{REDACTED}"))
+} // fn
+
+/// Returns the API key unchanged, since the `unredacted-logs` feature is
+/// enabled.
+
+#[cfg(feature = "unredacted-logs")]
+pub const fn redact_key(key: &str) -> Cow<'_, str> {
+    Cow::Borrowed(key)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Redacts the API key, client ID, and signature in a URL, or in a query
+/// string, for logging. Their values are replaced with `REDACTED`, and the rest
+/// of the URL is unchanged.
+
+#[cfg(not(feature = "unredacted-logs"))]
+pub fn redact_url(url: &str) -> Cow<'_, str> {
+    let (stem, query) = match url.split_once('?') {
+        Some((stem, query)) => (Some(stem), query),
+        None => (None, url),
+    }; // match
+
+    let is_secret = |parameter: &str| {
+        parameter
+            .split_once('=')
+            .is_some_and(|(name, value)| !value.is_empty() && SECRET_PARAMETERS.contains(&name))
+    }; // is_secret
+
+    if !query.split('&').any(is_secret) {
+        return Cow::Borrowed(url);
+    } // if
+
+    let query = query
+        .split('&')
+        .map(|parameter| match parameter.split_once('=') {
+            Some((name, _value)) if is_secret(parameter) => {
+                Cow::Owned(format!("{name}={REDACTED_PARAMETER}"))
+            } // Some
+            _ => Cow::Borrowed(parameter),
+        }) // map
+        .collect::<Vec<Cow<str>>>()
+        .join("&");
+
+    match stem {
+        Some(stem) => Cow::Owned(format!("{stem}?{query}")),
+        None => Cow::Owned(query),
+    } // match
+} // fn
+
+/// Returns the URL unchanged, since the `unredacted-logs` feature is enabled.
+
+#[cfg(feature = "unredacted-logs")]
+pub const fn redact_url(url: &str) -> Cow<'_, str> {
+    Cow::Borrowed(url)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Redacts the URL in an HTTP client error. `reqwest` includes the request URL
+/// when an error is displayed, which would otherwise put the API key in every
+/// logged or reported connection error.
+
+#[cfg(all(feature = "reqwest", not(feature = "unredacted-logs")))]
+pub fn redact_error(error: crate::ReqError) -> crate::ReqError {
+    #[cfg(feature = "reqwest-middleware")]
+    let crate::reqwest_maybe_middleware::Error::Reqwest(error) = error else {
+        return error;
+    }; // let
+
+    let url = error
+        .url()
+        .and_then(|url| reqwest::Url::parse(&redact_url(url.as_str())).ok());
+
+    let error = match url {
+        Some(url) => error.with_url(url),
+        None => error,
+    }; // match
+
+    crate::ReqError::from(error)
+} // fn
+
+/// Returns the HTTP client error unchanged, since the `unredacted-logs` feature
+/// is enabled.
+
+#[cfg(all(feature = "reqwest", feature = "unredacted-logs"))]
+pub const fn redact_error(error: crate::ReqError) -> crate::ReqError {
+    error
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// A request's query string. It contains the API key, so its `Debug` output is
+/// redacted. Every other use, such as `Display` and `as_str()`, returns the
+/// query string in full.
+
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct QueryString(String);
+
+// -----------------------------------------------------------------------------
+
+impl QueryString {
+    /// Returns the query string in full.
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Debug for QueryString {
+    /// Formats the query string with its credentials redacted.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", redact_url(&self.0))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for QueryString {
+    /// Formats the query string in full, so that it may be sent to Google.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<String> for QueryString {
+    fn from(query: String) -> Self {
+        Self(query)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::AsRef<str> for QueryString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::ops::Deref for QueryString {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> std::convert::From<&'a QueryString> for Cow<'a, str> {
+    fn from(query: &'a QueryString) -> Self {
+        Cow::Borrowed(&query.0)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<QueryString> for Cow<'_, str> {
+    fn from(query: QueryString) -> Self {
+        Cow::Owned(query.0)
+    } // fn
+} // impl
@@ -14,6 +14,7 @@ pub enum Api {
     /// This variant is used to select settings that are observed for _all_
     /// APIs. These settings are observed in addition to the per-API settings.
     All,
+    AddressValidation,
     Directions,
     DistanceMatrix,
     Elevation,
@@ -21,6 +22,7 @@ pub enum Api {
     TimeZone,
     Places,
     Roads,
+    StaticMap,
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -30,6 +32,7 @@ impl std::convert::From<&Api> for String {
     fn from(api: &Api) -> Self {
         match api {
             Api::All => Self::from("All"),
+            Api::AddressValidation => Self::from("Address Validation"),
             Api::Directions => Self::from("Directions"),
             Api::DistanceMatrix => Self::from("Distance Matrix"),
             Api::Elevation => Self::from("Elevation"),
@@ -37,6 +40,7 @@ impl std::convert::From<&Api> for String {
             Api::TimeZone => Self::from("Time Zone"),
             Api::Places => Self::from("Places"),
             Api::Roads => Self::from("Roads"),
+            Api::StaticMap => Self::from("Maps Static"),
         } // match
     } // fn
 } // impl
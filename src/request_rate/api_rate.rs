@@ -5,7 +5,7 @@
 use crate::request_rate::target_rate::TargetRate;
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use stream_throttle::ThrottlePool;
+use crate::request_rate::throttle_pool::ThrottlePool;
 
 /// Contains the user's specified request rate and the effective current request
 /// rate.
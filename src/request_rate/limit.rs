@@ -17,6 +17,10 @@ impl RequestRate {
     ///
     /// * `apis` ‧ The APIs for which to observe the request rate limit.
     pub async fn limit_apis(&self, apis: Vec<&Api>) {
+        // Rate limiting has been disabled, or no rates have been set:
+        if self.rate_map.is_empty() {
+            return;
+        }
         let mut limit_futures = Vec::new();
         for (key, val) in &self.rate_map {
             if apis.contains(&key) {
@@ -12,13 +12,39 @@ mod duration_unit;
 mod limit;
 mod rate_to_string;
 mod target_rate;
+mod throttle_pool;
 mod with_rate;
+mod with_rate_per_second;
 
 // -----------------------------------------------------------------------------
 
 use crate::request_rate::api::Api;
 use crate::request_rate::api_rate::ApiRate;
 use std::collections::HashMap;
+use std::time::Duration;
+
+// -----------------------------------------------------------------------------
+
+/// The request rates that are applied by default, in requests per second.
+/// These are Google's documented per-minute quotas for each API, spread evenly
+/// over the minute, so that a new client will not be throttled by Google's
+/// servers. For example, the Geocoding API's quota of 3,000 requests per
+/// minute becomes 50 requests per second.
+///
+/// Google does not publish a request quota for the Distance Matrix API, only a
+/// quota of 60,000 _elements_ per minute. Its default assumes 100 elements per
+/// request, the maximum allowed.
+const DEFAULT_RATES_PER_SECOND: [(Api, u16); 9] = [
+    (Api::AddressValidation, 100),
+    (Api::Directions, 50),
+    (Api::DistanceMatrix, 10),
+    (Api::Elevation, 50),
+    (Api::Geocoding, 50),
+    (Api::TimeZone, 50),
+    (Api::Places, 100),
+    (Api::Roads, 500),
+    (Api::StaticMap, 500),
+]; // DEFAULT_RATES_PER_SECOND
 
 // -----------------------------------------------------------------------------
 //
@@ -37,10 +63,18 @@ pub struct RequestRate {
 // -----------------------------------------------------------------------------
 
 impl std::default::Default for RequestRate {
-    /// Returns default values (empty) for the `RequestRate` struct.
+    /// Returns default values for the `RequestRate` struct. Each API is
+    /// limited to Google's documented quota. There is no default `Api::All`
+    /// limit.
     fn default() -> Self {
-        Self {
+        let mut request_rate = Self {
             rate_map: HashMap::new(),
-        } // struct
+        }; // struct
+
+        for (api, requests) in &DEFAULT_RATES_PER_SECOND {
+            request_rate.with_rate(api, *requests, Duration::from_secs(1));
+        } // for
+
+        request_rate
     } // fn
 } // impl
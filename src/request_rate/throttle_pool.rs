@@ -0,0 +1,81 @@
+//! Contains the `ThrottlePool` struct, which limits how many requests may be
+//! sent within a duration.
+
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+// -----------------------------------------------------------------------------
+//
+/// A pool of request slots that is shared by every clone. Each request takes
+/// a slot, which becomes free again once the duration has elapsed. When every
+/// slot is taken, the request waits for the earliest one to become free.
+///
+/// The wait uses this crate's timer, so that with the `runtime-agnostic`
+/// feature rate limiting does not need a Tokio runtime either.
+
+#[derive(Clone, Debug)]
+pub struct ThrottlePool {
+    duration: Duration,
+    /// The instant at which each slot becomes free again. A slot that has not
+    /// been taken yet is `None`.
+    slots: Arc<Mutex<Vec<Option<Instant>>>>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl ThrottlePool {
+    /// Creates a pool that allows `requests` requests per `duration`.
+    pub fn new(requests: usize, duration: Duration) -> Self {
+        Self {
+            duration,
+            slots: Arc::new(Mutex::new(vec![None; requests])),
+        } // ThrottlePool
+    } // fn
+
+    /// Waits until a slot is free, and takes it.
+    pub async fn queue(&self) {
+        while let Some(wait) = self.try_take() {
+            crate::client::sleep(wait).await;
+        } // while
+    } // fn
+
+    /// Takes the slot that became free the earliest, if it is free now.
+    /// Otherwise, returns how long until it will be free.
+    fn try_take(&self) -> Option<Duration> {
+        let now = Instant::now();
+        let mut slots = self.slots.lock().unwrap_or_else(PoisonError::into_inner);
+        let wait = slots.iter_mut().min().and_then(|slot| match *slot {
+            Some(free_at) if free_at > now => Some(free_at - now),
+            _ => {
+                *slot = Some(now + self.duration);
+                None
+            } // case
+        }); // and_then
+        drop(slots);
+        wait
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(all(test, feature = "runtime-agnostic"))]
+mod tests {
+    use crate::request_rate::{api::Api, RequestRate};
+    use futures::executor::block_on;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn throttles_without_a_tokio_runtime() {
+        let mut request_rate = RequestRate::default();
+        request_rate.with_rate(&Api::All, 2, Duration::from_millis(200));
+        let started = Instant::now();
+        block_on(async {
+            request_rate.limit_apis(vec![&Api::All]).await;
+            request_rate.limit_apis(vec![&Api::All]).await;
+            assert!(started.elapsed() < Duration::from_millis(100));
+            // Both slots are taken, so this request waits for the first one:
+            request_rate.limit_apis(vec![&Api::All]).await;
+        }); // block_on
+        assert!(started.elapsed() >= Duration::from_millis(200));
+    } // fn
+} // mod
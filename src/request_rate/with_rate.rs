@@ -1,6 +1,7 @@
-use crate::request_rate::{api::Api, api_rate::ApiRate, target_rate::TargetRate, RequestRate}; // use crate::request_rate
+use crate::request_rate::{
+    api::Api, api_rate::ApiRate, target_rate::TargetRate, throttle_pool::ThrottlePool, RequestRate,
+}; // use crate::request_rate
 use std::time::Duration;
-use stream_throttle::{ThrottlePool, ThrottleRate};
 
 // =============================================================================
 
@@ -52,8 +53,7 @@ impl RequestRate {
         let throttle_pool = if requests == 0 {
             None
         } else {
-            let throttle_rate = ThrottleRate::new(requests as usize, duration);
-            Some(ThrottlePool::new(throttle_rate))
+            Some(ThrottlePool::new(requests as usize, duration))
         };
 
         // Has the ApiRate been set already?
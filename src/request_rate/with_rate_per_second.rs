@@ -0,0 +1,41 @@
+use crate::request_rate::{api::Api, RequestRate};
+use std::time::Duration;
+
+// =============================================================================
+
+impl RequestRate {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the request rate for the selected API, in requests per second.
+    /// _Do not use this method to set request rate limits, use
+    /// `ClientSettings.with_rate_per_second()` instead_.
+    ///
+    /// ## Arguments
+    ///
+    /// * `api` ‧ Which Google Maps API are you setting the rate limit for?
+    ///
+    /// * `requests_per_second` ‧ The sustained number of requests per second.
+    ///   A value of `0` removes the rate limit for the API.
+    ///
+    /// * `burst` ‧ The number of requests that may be sent at once. A value of
+    ///   `0` is treated as `requests_per_second`.
+
+    pub fn with_rate_per_second(
+        &mut self,
+        api: &Api,
+        requests_per_second: u16,
+        burst: u16
+    ) -> &mut Self {
+        let burst = if burst == 0 { requests_per_second } else { burst };
+
+        // A burst of `burst` requests is allowed in every period that it would
+        // take to send `burst` requests at the sustained rate:
+        let period = if requests_per_second == 0 {
+            Duration::from_secs(1)
+        } else {
+            Duration::from_secs(1) * u32::from(burst) / u32::from(requests_per_second)
+        }; // if
+
+        self.with_rate(api, burst, period)
+    } // fn
+} // impl
@@ -3,6 +3,7 @@
 // -----------------------------------------------------------------------------
 
 use crate::roads::status::Status;
+#[cfg(feature = "diagnostics")]
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -10,8 +11,9 @@ use thiserror::Error;
 //
 /// Errors that may be produced by the Google Maps Roads API client.
 
-#[derive(Debug, Diagnostic, Error)]
-#[diagnostic(code(google_maps::roads::error), url(docsrs))]
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+#[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::roads::error), url(docsrs)))]
 pub enum Error {
     /// Google Maps Roads API server generated an error. See the `Status`
     /// enum for more information.
@@ -20,11 +22,26 @@ pub enum Error {
     /// The HTTP request was unsuccessful.
     HttpUnsuccessful(String),
 
+    /// The HTTP request was unsuccessful. Contains the HTTP status code, the
+    /// headers that help explain the failure (such as `Retry-After` and
+    /// Google's `X-Goog-*` headers), and the truncated response body.
+    #[cfg(feature = "reqwest")]
+    HttpUnsuccessfulResponse {
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String,
+    },
+
     /// API client library attempted to parse a string that contained an invalid
     /// status code. See `google_maps\src\time_zone\response\status.rs` for more
     /// information.
     InvalidStatusCode(String),
 
+    /// API client library attempted to parse a string that contained an invalid
+    /// speed units code. See `google_maps\src\roads\speed_limits\speed_units.rs`
+    /// for more information.
+    InvalidSpeedUnitsCode(String),
+
     /// The query string must be built before the request may be sent to the
     /// Google Maps Roads API server.
     QueryNotBuilt,
@@ -40,6 +57,20 @@ pub enum Error {
 
     /// The dependency library Serde JSON generated an error.
     SimdJson(simd_json::Error),
+
+    /// The dependency library Serde JSON could not parse the response from the
+    /// server. The response body is included to help with debugging. It is
+    /// truncated to the length set by `GoogleMapsClient::with_error_body_length`.
+    SimdJsonWithBody(simd_json::Error, String),
+    /// The service responded successfully, but not with JSON, for example
+    /// with an HTML error page from a proxy or a sign-in page. Contains the
+    /// response's `Content-Type` header, and the response body truncated to the
+    /// length set by `GoogleMapsClient::with_error_body_length`.
+    #[cfg(feature = "reqwest")]
+    UnexpectedContentType {
+        content_type: String,
+        body_snippet: String,
+    },
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -76,16 +107,26 @@ impl std::fmt::Display for Error {
                         Not found. \
                         You have exceeded the request limit that you configured \
                         in the Google Cloud Platform Console."),
+                    Status::Other(status_code) => write!(f, "Google Maps Roads API service: \
+                        Unrecognized status `{status_code}`."),
                 } // match
             }, // match
             Self::HttpUnsuccessful(status) => write!(f,
                 "Google Maps Roads API client: \
                 Could not successfully query the Google Cloud Platform service. \
                 The service last responded with a `{status}` status."),
+            #[cfg(feature = "reqwest")]
+            Self::HttpUnsuccessfulResponse { status, body, .. } => write!(f,
+                "Google Maps Roads API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status: `{body}`"),
             Self::InvalidStatusCode(status_code) => write!(f, "Google Maps Roads API client: \
                 `{status_code}` is not a valid status code. \
                 Valid codes are `INVALID_ARGUMENT`, `PERMISSION_DENIED`, \
                 `NOT_FOUND`, and `RESOURCE_EXHAUSTED`."),
+            Self::InvalidSpeedUnitsCode(speed_units_code) => write!(f, "Google Maps Roads API client: \
+                `{speed_units_code}` is not a valid speed units code. \
+                Valid codes are `KPH` and `MPH`."),
             Self::QueryNotBuilt => write!(f, "Google Maps Roads API client library: \
                 The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
                 Ensure the build() method is called before run()."),
@@ -94,6 +135,13 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "reqwest")]
             Self::ReqwestMessage(error) => write!(f, "Google Maps Geocoding API client in the Reqwest library: {error}"),
             Self::SimdJson(error) => write!(f, "Google Maps Roads API client in the Serde JSON library: {error}"),
+            Self::SimdJsonWithBody(error, body) => write!(f, "Google Maps Roads API client in the Serde JSON library: {error}. \
+                The response body was: `{body}`"),
+            #[cfg(feature = "reqwest")]
+            Self::UnexpectedContentType { content_type, body_snippet } => write!(f,
+                "Google Maps Roads API client: \
+                The service responded with `{content_type}` content instead of JSON. \
+                This is usually an error page. The response body was: `{body_snippet}`"),
         } // match
     } // fn
 } // impl
@@ -122,3 +170,46 @@ impl From<simd_json::Error> for Error {
         Self::SimdJson(error)
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl crate::client::HttpError for Error {
+    /// Reports the HTTP-level failures of the retry loop as Google Maps
+    /// Roads API errors.
+    fn unexpected_content_type(content_type: String, body_snippet: String) -> Self {
+        Self::UnexpectedContentType {
+            content_type,
+            body_snippet,
+        } // UnexpectedContentType
+    } // fn
+
+    fn http_unsuccessful_response(
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String
+    ) -> Self {
+        Self::HttpUnsuccessfulResponse {
+            status,
+            headers,
+            body,
+        } // HttpUnsuccessfulResponse
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(message: String) -> Self {
+        Self::ReqwestMessage(message)
+    } // fn
+} // impl
+
+#[cfg(feature = "reqwest")]
+impl crate::client::JsonError for Error {
+    /// Reports a response body that could not be parsed as a Google Maps
+    /// Roads API error.
+    fn simd_json_with_body(error: simd_json::Error, body: String) -> Self {
+        Self::SimdJsonWithBody(error, body)
+    } // fn
+} // impl
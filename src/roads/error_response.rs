@@ -27,3 +27,31 @@ pub struct ErrorResponse {
     /// A status code indicating the nature of the error.
     pub status: Status,
 } // struct
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl ErrorResponse {
+    /// Returns the error reported by Google as a `GoogleMapsService` error.
+    /// It is not retried, since it indicates an issue with the request. An
+    /// exceeded quota is reported as a `Quota` error by the retry loop.
+
+    pub(crate) fn into_error(self) -> backoff::Error<crate::roads::error::Error> {
+        let error = crate::roads::error::Error::GoogleMapsService(self.status, Some(self.message));
+        tracing::error!("{}", error);
+        backoff::Error::Permanent(error)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// The `error` field that is common to the responses of the Roads API.
+/// `get_raw()` only parses this field, so that an error reported by Google is
+/// checked without interpreting the rest of the body.
+
+#[cfg(feature = "reqwest")]
+#[derive(Deserialize)]
+pub struct ErrorEnvelope {
+    /// The error reported by Google, if the request was unsuccessful.
+    pub error: Option<ErrorResponse>,
+} // struct
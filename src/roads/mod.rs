@@ -40,7 +40,6 @@
 //!   part of a continuous path.
 //!
 //! * [Speed limits](https://developers.google.com/maps/documentation/roads/speed-limits)
-//!   **(Not yet implemented in this client.)**
 //!   This service returns the posted speed limit for a road segment. The Speed
 //!   Limit service is available to all customers with an Asset Tracking license.
 //!   For [Google Maps Platform Premium Plan customers](https://developers.google.com/maps/premium)
@@ -59,6 +58,7 @@ pub mod error_response;
 pub mod nearest_roads;
 pub mod snap_to_roads;
 pub mod snapped_point;
+pub mod speed_limits;
 pub mod status;
 
 // -----------------------------------------------------------------------------
@@ -75,3 +75,8 @@ pub use crate::roads::snap_to_roads::{
 pub use crate::roads::nearest_roads::{
     request::Request as NearestRoadsRequest, response::Response as NearestRoadsResponse,
 }; // crate::roads::nearest_roads
+
+pub use crate::roads::speed_limits::{
+    request::Request as SpeedLimitsRequest, response::Response as SpeedLimitsResponse,
+    speed_limit::SpeedLimit, speed_units::SpeedUnits,
+}; // crate::roads::speed_limits
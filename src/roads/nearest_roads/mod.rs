@@ -24,6 +24,11 @@ pub mod response;
 
 const SERVICE_URL: &str = "https://roads.googleapis.com/v1/nearestRoads";
 
+/// The maximum number of points that Google accepts in a single request. Longer
+/// paths are split across several requests, see `with_chunking()`.
+#[cfg(feature = "reqwest")]
+const MAX_POINTS: usize = 100;
+
 // -----------------------------------------------------------------------------
 
 pub use crate::roads::nearest_roads::request::Request as NearestRoadsRequest;
@@ -13,7 +13,7 @@ impl<'a> Request<'a> {
     ///
     /// This method accepts no arguments.
 
-    pub fn build(&mut self) -> &'a mut Request {
+    pub fn build(&mut self) -> &'a mut Request<'_> {
         // This section builds the "required parameters" portion of the query
         // string:
 
@@ -29,10 +29,10 @@ impl<'a> Request<'a> {
         let points: String = utf8_percent_encode(&points, NON_ALPHANUMERIC).to_string();
 
         // Build "required parameters" portion of the query string:
-        let query = format!("key={key}&points={points}", key = self.client.key,);
+        let query = format!("key={key}&points={points}", key = self.client.key);
 
         // Set query string in Request struct.
-        self.query = Some(query);
+        self.query = Some(query.into());
 
         // Return modified Request struct to caller.
         self
@@ -0,0 +1,69 @@
+use crate::error::Error as GoogleMapsError;
+use crate::roads::nearest_roads::{
+    request::Request as NearestRoadsRequest, response::Response as NearestRoadsResponse, MAX_POINTS,
+}; // crate::roads::nearest_roads
+
+// -----------------------------------------------------------------------------
+
+impl NearestRoadsRequest<'_> {
+    /// Splits the points into chunks that do not exceed Google's limit of 100
+    /// points, performs an HTTP get request for each chunk, and combines the
+    /// responses into a single response.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub(super) async fn get_chunked(&self) -> Result<NearestRoadsResponse, GoogleMapsError> {
+        // The deadline applies to the request as a whole, so each chunk is
+        // given whatever time remains:
+        let started = std::time::Instant::now();
+
+        let mut snapped_points = Vec::new();
+        let mut warning_messages: Vec<String> = Vec::new();
+
+        for (chunk_index, chunk) in self.points.chunks(MAX_POINTS).enumerate() {
+            // Each chunk is sent as a separate request, using the same settings
+            // as this request:
+            let mut request = Self {
+                client: self.client,
+                points: chunk.to_vec(),
+                chunking: false,
+                backoff: self.backoff.clone(),
+                max_retries: self.max_retries,
+                timeout: self.timeout,
+                deadline: self
+                    .deadline
+                    .map(|deadline| deadline.saturating_sub(started.elapsed())),
+                query: None,
+            }; // NearestRoadsRequest
+            let response = request.build().get_single().await?;
+
+            // The `originalIndex` returned by Google refers to the points in
+            // the chunk. Offset it so that it refers to the points in the
+            // original request:
+            let offset = chunk_index * MAX_POINTS;
+            snapped_points.extend(response.snapped_points.into_iter().map(|mut snapped_point| {
+                snapped_point.origin_index = snapped_point.origin_index.map(|index| index + offset);
+                snapped_point
+            })); // map
+
+            if let Some(warning_message) = response.warning_message {
+                if !warning_messages.contains(&warning_message) {
+                    warning_messages.push(warning_message);
+                } // if
+            } // if
+        } // for
+
+        // Return combined response to caller:
+        Ok(NearestRoadsResponse {
+            snapped_points,
+            warning_message: if warning_messages.is_empty() {
+                None
+            } else {
+                Some(warning_messages.join(" "))
+            }, // if
+            error: None,
+        }) // NearestRoadsResponse
+    } // fn
+} // impl
@@ -8,15 +8,32 @@
 mod build;
 #[cfg(feature = "reqwest")]
 mod execute;
+mod fingerprint;
 #[cfg(feature = "reqwest")]
 mod get;
+#[cfg(feature = "reqwest")]
+mod get_chunked;
+#[cfg(feature = "reqwest")]
+mod get_raw;
 mod new;
 mod query_url;
+#[cfg(feature = "reqwest")]
+mod url;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+mod with_chunking;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
 
 // -----------------------------------------------------------------------------
 
 use crate::client::GoogleMapsClient;
 use crate::types::LatLng;
+use crate::redact::QueryString;
 
 // -----------------------------------------------------------------------------
 //
@@ -39,8 +56,37 @@ pub struct Request<'a> {
     /// `points=60.170880,24.942795|60.170879,24.942796|60.170877,24.942796`.
     points: Vec<LatLng>,
 
+    // Optional parameters:
+    // --------------------
+    /// Whether point lists that exceed Google's limit of 100 points are split
+    /// across several requests. Defaults to `true`. See method
+    /// `with_chunking()` for more information.
+    chunking: bool,
+
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+
     // Internal use only:
     // ------------------
     /// Query string that is to be submitted to the Google Cloud Maps Platform.
-    query: Option<String>,
+    query: Option<QueryString>,
 } // struct
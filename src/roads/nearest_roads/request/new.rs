@@ -26,12 +26,23 @@ impl Request<'_> {
     ///   list of latitude/longitude pairs.
 
     #[must_use]
-    pub const fn new(client: &GoogleMapsClient, points: Vec<LatLng>) -> Request {
+    pub const fn new(client: &GoogleMapsClient, points: Vec<LatLng>) -> Request<'_> {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
             client,
             points,
+            // Optional parameters:
+            chunking: true,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         } // struct
@@ -0,0 +1,38 @@
+use crate::roads::nearest_roads::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies whether a list of points that exceeds Google's limit of 100 points is
+    /// split across several requests.
+    ///
+    /// ## Arguments
+    ///
+    /// * `chunking` ‧ When `true`, a list of points with more than 100 points is
+    ///   split into chunks of 100 points. Each chunk is sent as a separate
+    ///   request, subject to the client's rate limiting, and the responses
+    ///   are combined into a single response. The `originalIndex` of each
+    ///   snapped point is adjusted so that it refers to the points passed to
+    ///   this request. When `false`, the list of points is always sent in a single
+    ///   request, and Google will return an error if it is too long. Defaults
+    ///   to `true`.
+    ///
+    /// ## Example
+    ///
+    /// * Always send the list of points in a single request:
+    /// ```rust
+    /// .with_chunking(false)
+    /// ```
+
+    pub fn with_chunking(
+        &'a mut self,
+        chunking: bool
+    ) -> &'a mut Self {
+        // Set chunking in Request struct.
+        self.chunking = chunking;
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
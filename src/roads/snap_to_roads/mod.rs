@@ -35,6 +35,17 @@ pub mod response;
 
 const SERVICE_URL: &str = "https://roads.googleapis.com/v1/snapToRoads";
 
+/// The maximum number of points that Google accepts in a single request. Longer
+/// paths are split across several requests, see `with_chunking()`.
+#[cfg(feature = "reqwest")]
+const MAX_POINTS: usize = 100;
+
+/// The number of points that consecutive chunks of a long path share, so that
+/// the points near the end of a chunk are snapped with the context of the
+/// points that follow them. See `with_chunking()`.
+#[cfg(feature = "reqwest")]
+const CHUNK_OVERLAP: usize = 10;
+
 // -----------------------------------------------------------------------------
 
 pub use crate::roads::snap_to_roads::request::Request as SnapToRoadsRequest;
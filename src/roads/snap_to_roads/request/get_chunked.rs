@@ -0,0 +1,237 @@
+use crate::client::remaining_deadline;
+use crate::error::Error as GoogleMapsError;
+use crate::roads::snap_to_roads::{
+    request::Request as SnapToRoadsRequest, response::Response as SnapToRoadsResponse,
+    CHUNK_OVERLAP, MAX_POINTS,
+}; // crate::roads::snap_to_roads
+use crate::roads::SnappedPoint;
+use std::ops::Range;
+
+// -----------------------------------------------------------------------------
+
+impl SnapToRoadsRequest<'_> {
+    /// Splits the path into overlapping chunks that do not exceed Google's
+    /// limit of 100 points, performs an HTTP get request for each chunk, and
+    /// combines the responses into a single response.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub(super) async fn get_chunked(&self) -> Result<SnapToRoadsResponse, GoogleMapsError> {
+        // The deadline applies to the request as a whole, so each chunk is
+        // given whatever time remains. A chunk is not sent once none remains:
+        let started = std::time::Instant::now();
+
+        let mut snapped_points = Vec::new();
+        let mut warning_messages: Vec<String> = Vec::new();
+
+        for chunk in chunk_ranges(self.path.len()) {
+            // Each chunk is sent as a separate request, using the same settings
+            // as this request:
+            let mut request = Self {
+                client: self.client,
+                path: self.path[chunk.clone()].to_vec(),
+                interpolate: self.interpolate,
+                chunking: false,
+                backoff: self.backoff.clone(),
+                max_retries: self.max_retries,
+                timeout: self.timeout,
+                deadline: remaining_deadline(self.deadline, started)?,
+                query: None,
+            }; // SnapToRoadsRequest
+            let response = match request.build().get_single().await {
+                Ok(response) => response,
+                // Report the deadline of the request as a whole, rather than
+                // what remained of it for this chunk:
+                Err(GoogleMapsError::DeadlineExceeded(remaining)) => {
+                    let deadline = self.deadline.unwrap_or(remaining);
+                    return Err(GoogleMapsError::DeadlineExceeded(deadline));
+                } // Err
+                Err(error) => return Err(error),
+            }; // match
+
+            stitch(&mut snapped_points, response.snapped_points, &chunk);
+
+            if let Some(warning_message) = response.warning_message {
+                if !warning_messages.contains(&warning_message) {
+                    warning_messages.push(warning_message);
+                } // if
+            } // if
+        } // for
+
+        // Return combined response to caller:
+        Ok(SnapToRoadsResponse {
+            snapped_points,
+            warning_message: if warning_messages.is_empty() {
+                None
+            } else {
+                Some(warning_messages.join(" "))
+            }, // if
+            error: None,
+        }) // SnapToRoadsResponse
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Returns the ranges of the path's points that are sent in each request. Each
+/// chunk has at most `MAX_POINTS` points, and starts `CHUNK_OVERLAP` points
+/// before the end of the previous chunk.
+
+fn chunk_ranges(len: usize) -> impl Iterator<Item = Range<usize>> {
+    let mut start = 0;
+    std::iter::from_fn(move || {
+        if start >= len {
+            return None;
+        } // if
+        let end = (start + MAX_POINTS).min(len);
+        let chunk = start..end;
+        start = if end == len { len } else { end - CHUNK_OVERLAP };
+        Some(chunk)
+    }) // from_fn
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// Appends the snapped points of a chunk to the snapped points of the chunks
+/// before it.
+///
+/// The `originalIndex` returned by Google refers to the points in the chunk, so
+/// it is offset to refer to the points in the original request. The points
+/// that this chunk shares with the previous chunk were already snapped by the
+/// previous chunk, so they are dropped, along with any points that Google
+/// interpolated between them. Interpolated points have no `originalIndex`, so
+/// they are dropped by their position before the first new point.
+///
+/// ## Arguments
+///
+/// * `snapped_points` ‧ The snapped points of the previous chunks.
+///
+/// * `chunk_points` ‧ The snapped points that Google returned for this chunk.
+///
+/// * `chunk` ‧ The range of the path's points that were sent in this chunk.
+
+fn stitch(
+    snapped_points: &mut Vec<SnappedPoint>,
+    chunk_points: Vec<SnappedPoint>,
+    chunk: &Range<usize>,
+) {
+    // The first points of every chunk but the first were already snapped by
+    // the previous chunk:
+    let skip = if chunk.start == 0 {
+        0
+    } else {
+        // Everything before the first new point lies within the overlap...
+        let first_new = chunk_points
+            .iter()
+            .position(|snapped_point| {
+                snapped_point.origin_index.is_some_and(|index| index >= CHUNK_OVERLAP)
+            }) // position
+            .unwrap_or(chunk_points.len());
+
+        // ...except for the points interpolated between the last overlapping
+        // point and the first new point, which are kept. If none of the
+        // overlapping points could be snapped, the interpolated points cannot
+        // be told apart, and all of them are dropped:
+        chunk_points[..first_new]
+            .iter()
+            .rposition(|snapped_point| snapped_point.origin_index.is_some())
+            .map_or(first_new, |position| position + 1)
+    }; // if
+
+    snapped_points.extend(chunk_points.into_iter().skip(skip).map(|mut snapped_point| {
+        snapped_point.origin_index = snapped_point.origin_index.map(|index| index + chunk.start);
+        snapped_point
+    })); // map
+} // fn
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::LatLng;
+
+    fn snapped_point(origin_index: Option<usize>) -> SnappedPoint {
+        SnappedPoint {
+            location: LatLng::default(),
+            place_id: None,
+            origin_index,
+        } // SnappedPoint
+    } // fn
+
+    #[test]
+    fn chunks_overlap_and_cover_the_path() {
+        assert!(chunk_ranges(100).eq(std::iter::once(0..100)));
+        assert_eq!(chunk_ranges(101).collect::<Vec<_>>(), [0..100, 90..101]);
+        assert_eq!(
+            chunk_ranges(250).collect::<Vec<_>>(),
+            [0..100, 90..190, 180..250]
+        ); // assert_eq!
+        assert_eq!(chunk_ranges(0).count(), 0);
+    } // fn
+
+    #[test]
+    fn stitching_drops_the_overlap() {
+        let mut snapped_points = Vec::new();
+        let chunks: Vec<Range<usize>> = chunk_ranges(150).collect();
+
+        for chunk in &chunks {
+            let chunk_points = (0..chunk.len()).map(Some).map(snapped_point).collect();
+            stitch(&mut snapped_points, chunk_points, chunk);
+        } // for
+
+        let origin_indexes: Vec<Option<usize>> = snapped_points
+            .iter()
+            .map(|snapped_point| snapped_point.origin_index)
+            .collect();
+        assert_eq!(origin_indexes, (0..150).map(Some).collect::<Vec<_>>());
+    } // fn
+
+    #[test]
+    fn stitching_keeps_interpolated_points_after_the_overlap() {
+        let mut snapped_points = vec![snapped_point(Some(99))];
+
+        // Points interpolated within the overlap are dropped, and points
+        // interpolated between the overlap and the first new point are kept:
+        let mut chunk_points = Vec::new();
+        for index in 0..12 {
+            chunk_points.push(snapped_point(Some(index)));
+            chunk_points.push(snapped_point(None));
+        } // for
+        stitch(&mut snapped_points, chunk_points, &(90..102));
+
+        let origin_indexes: Vec<Option<usize>> = snapped_points
+            .iter()
+            .map(|snapped_point| snapped_point.origin_index)
+            .collect();
+        assert_eq!(
+            origin_indexes,
+            [Some(99), None, Some(100), None, Some(101), None]
+        ); // assert_eq!
+    } // fn
+
+    #[test]
+    fn stitching_drops_interpolated_points_within_the_overlap() {
+        let mut snapped_points =
+            vec![snapped_point(Some(98)), snapped_point(None), snapped_point(Some(99))];
+
+        // None of the overlapping points could be snapped, so only the points
+        // that Google interpolated between them are returned for the overlap:
+        let mut chunk_points = vec![snapped_point(None); 5];
+        chunk_points.push(snapped_point(Some(10)));
+        chunk_points.push(snapped_point(None));
+        chunk_points.push(snapped_point(Some(11)));
+        stitch(&mut snapped_points, chunk_points, &(90..102));
+
+        let origin_indexes: Vec<Option<usize>> = snapped_points
+            .iter()
+            .map(|snapped_point| snapped_point.origin_index)
+            .collect();
+        assert_eq!(
+            origin_indexes,
+            [Some(98), None, Some(99), Some(100), None, Some(101)]
+        ); // assert_eq!
+    } // fn
+} // mod
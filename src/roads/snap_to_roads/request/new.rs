@@ -23,7 +23,7 @@ impl Request<'_> {
     ///   between consecutive points caused by GPS signal loss, or noise.
 
     #[must_use]
-    pub const fn new(client: &GoogleMapsClient, path: Vec<LatLng>) -> Request {
+    pub const fn new(client: &GoogleMapsClient, path: Vec<LatLng>) -> Request<'_> {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
@@ -31,6 +31,16 @@ impl Request<'_> {
             path,
             // Optional parameters:
             interpolate: None,
+            chunking: true,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         } // struct
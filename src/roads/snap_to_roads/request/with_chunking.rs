@@ -0,0 +1,41 @@
+use crate::roads::snap_to_roads::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies whether a path that exceeds Google's limit of 100 points is
+    /// split across several requests.
+    ///
+    /// ## Arguments
+    ///
+    /// * `chunking` ‧ When `true`, a path with more than 100 points is
+    ///   split into chunks of 100 points. Each chunk starts with the last 10
+    ///   points of the previous chunk, so that the points at the end of a
+    ///   chunk are snapped knowing where the path goes next. Each chunk is
+    ///   sent as a separate request, subject to the client's rate limiting,
+    ///   and the responses are combined into a single response, without the
+    ///   duplicated points. The `originalIndex` of each
+    ///   snapped point is adjusted so that it refers to the path passed to
+    ///   this request. When `false`, the path is always sent in a single
+    ///   request, and Google will return an error if it is too long. Defaults
+    ///   to `true`.
+    ///
+    /// ## Example
+    ///
+    /// * Always send the path in a single request:
+    /// ```rust
+    /// .with_chunking(false)
+    /// ```
+
+    pub fn with_chunking(
+        &'a mut self,
+        chunking: bool
+    ) -> &'a mut Self {
+        // Set chunking in Request struct.
+        self.chunking = chunking;
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,46 @@
+use crate::error::Error as GoogleMapsError;
+use crate::roads::snap_to_roads::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces the path to be snapped with the vertices of an encoded
+    /// polyline.
+    ///
+    /// ## Arguments
+    ///
+    /// * `polyline` ‧ The path to be snapped, as an [encoded
+    ///   polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm).
+    ///   For example, the `points` field of a route's `overview_polyline`.
+    ///   Any path passed to the constructor is discarded.
+    ///
+    /// ## Description
+    ///
+    /// Unlike the Elevation API, the Roads API does not accept encoded
+    /// polylines, so the polyline is decoded and its vertices are sent as
+    /// latitude/longitude pairs. The `origin_index` of each snapped point
+    /// refers to the position of the vertex in the polyline.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the polyline is invalid.
+    ///
+    /// ## Example
+    ///
+    /// * Snap the overview polyline of a route:
+    /// ```rust
+    /// .with_encoded_path(&route.overview_polyline.points)?
+    /// ```
+
+    pub fn with_encoded_path(
+        &'a mut self,
+        polyline: &str
+    ) -> Result<&'a mut Self, GoogleMapsError> {
+        // Set path in Request struct.
+        self.path = crate::polyline::decode(polyline)?;
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
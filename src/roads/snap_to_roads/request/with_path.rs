@@ -0,0 +1,38 @@
+use crate::roads::snap_to_roads::request::Request;
+use crate::types::LatLng;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Replaces the path to be snapped.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` ‧ The path to be snapped. Any path passed to the constructor
+    ///   is discarded. The points should be in the order they were recorded,
+    ///   and consecutive pairs of points should ideally be within 300m of each
+    ///   other.
+    ///
+    /// ## Example
+    ///
+    /// * Snap a new path:
+    /// ```rust
+    /// .with_path(vec![
+    ///     LatLng::try_from_dec(dec!(-35.27801), dec!(149.12958))?,
+    ///     LatLng::try_from_dec(dec!(-35.28032), dec!(149.12907))?,
+    /// ])
+    /// ```
+
+    pub fn with_path<C, L>(&'a mut self, path: C) -> &'a mut Self
+    where
+        C: IntoIterator<Item = L>,
+        L: Into<LatLng>,
+    {
+        // Set path in Request struct.
+        self.path = path.into_iter().map(Into::into).collect();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
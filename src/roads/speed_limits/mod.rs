@@ -0,0 +1,37 @@
+//! The Roads API **Speed Limits** service returns the posted speed limit for a
+//! road segment. The Speed Limit service is available to all customers with an
+//! Asset Tracking license.
+//!
+//! # [Required parameters](https://developers.google.com/maps/documentation/roads/speed-limits#required-parameters)
+//!
+//! One of the following two parameters is required:
+//!
+//! * `path` - A list of up to 100 latitude/longitude pairs representing a
+//!   path. The points are snapped to roads before their speed limits are
+//!   looked up, and the snapped points are returned along with the speed
+//!   limits.
+//!
+//! * `placeId` - The place ID(s) representing one or more road segments. Make
+//!   sure each place ID refers to a road segment and not a different type of
+//!   place. You can pass up to 100 place IDs with each request.
+//!
+//! # [Optional parameters](https://developers.google.com/maps/documentation/roads/speed-limits#optional-parameters)
+//!
+//! * `units` - Whether to return speed limits in kilometers or miles per hour.
+//!   This can be set to either `KPH` or `MPH`. Defaults to `KPH`.
+
+pub mod request;
+pub mod response;
+pub mod speed_limit;
+pub mod speed_units;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://roads.googleapis.com/v1/speedLimits";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::roads::speed_limits::request::Request as SpeedLimitsRequest;
+pub use crate::roads::speed_limits::response::Response as SpeedLimitsResponse;
+pub use crate::roads::speed_limits::speed_limit::SpeedLimit;
+pub use crate::roads::speed_limits::speed_units::SpeedUnits;
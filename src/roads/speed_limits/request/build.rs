@@ -0,0 +1,61 @@
+use crate::roads::speed_limits::request::Request;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string for the Google Maps Speed Limits request based
+    /// on the input provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub fn build(&mut self) -> &'a mut Request<'_> {
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!("key={key}", key = self.client.key);
+
+        // Path key/value pair:
+        if !self.path.is_empty() {
+            // Convert `Vec<LatLng>` to `String`:
+            let path: String = self
+                .path
+                .iter()
+                .map(String::from)
+                .collect::<Vec<String>>()
+                .join("|");
+
+            // URL encode path `String`:
+            let path: String = utf8_percent_encode(&path, NON_ALPHANUMERIC).to_string();
+
+            query.push_str("&path=");
+            query.push_str(&path);
+        } // if
+
+        // Place ID key/value pairs. The `placeId` key is repeated for each
+        // road segment:
+        for place_id in &self.place_ids {
+            query.push_str("&placeId=");
+            query.push_str(&utf8_percent_encode(place_id, NON_ALPHANUMERIC).to_string());
+        } // for
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Units key/value pair:
+        if let Some(units) = &self.units {
+            query.push_str("&units=");
+            query.push_str(&String::from(units));
+        } // if
+
+        // Set query string in Request struct.
+        self.query = Some(query.into());
+
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,26 @@
+use crate::error::Error as GoogleMapsError;
+use crate::roads::speed_limits::{
+    request::Request as SpeedLimitsRequest, response::Response as SpeedLimitsResponse,
+}; // crate::roads::speed_limits
+
+// =============================================================================
+
+impl<'a> SpeedLimitsRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.validate()?.build()?.get()?` chain needed at the end of the
+    /// builder pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<SpeedLimitsResponse, GoogleMapsError> {
+        self.build().get().await
+    } // fn
+} // impl
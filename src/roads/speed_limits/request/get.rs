@@ -0,0 +1,67 @@
+use crate::client::{Method, RetrySettings};
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+use crate::roads::error::Error as RoadsError;
+use crate::roads::speed_limits::{
+    request::Request as SpeedLimitsRequest, response::Response as SpeedLimitsResponse,
+    SERVICE_URL,
+};
+
+// -----------------------------------------------------------------------------
+
+impl SpeedLimitsRequest<'_> {
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(api = %Api::Roads, attempts, duration_ms, status)
+    )]
+    pub async fn get(&mut self) -> Result<SpeedLimitsResponse, GoogleMapsError> {
+        // Build the URL stem for the HTTP get request:
+        let mut url = format!("{SERVICE_URL}/?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(RoadsError::QueryNotBuilt)?,
+        } // match
+
+        tracing::info!("making HTTP GET request to Google Maps Roads API");
+
+        // Observe any rate limiting before executing the request, and retry
+        // it according to its retry settings:
+        let response = self
+            .client
+            .execute_with_retry(
+                Method::Get,
+                &url,
+                RetrySettings {
+                    api: Api::Roads,
+                    backoff: self.backoff.as_ref(),
+                    max_retries: self.max_retries,
+                    timeout: self.timeout,
+                    deadline: self.deadline,
+                }, // RetrySettings
+                "application/json",
+                |body| -> Result<SpeedLimitsResponse, backoff::Error<RoadsError>> {
+                    // If Google's response contains an `ErrorResponse` struct,
+                    // the request was unsuccessful:
+                    let response: SpeedLimitsResponse = self.client.parse_json(&body)?;
+                    match response.error {
+                        Some(error) => Err(error.into_error()),
+                        None => Ok(response),
+                    } // match
+                }, // classify
+            )
+            .await?;
+
+        // Return response to caller:
+        Ok(response)
+    } // fn
+} // impl
@@ -0,0 +1,70 @@
+use crate::client::{Method, RetrySettings};
+use crate::roads::error_response::ErrorEnvelope;
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+use crate::roads::error::Error as RoadsError;
+use crate::roads::speed_limits::{request::Request as SpeedLimitsRequest, SERVICE_URL};
+
+// -----------------------------------------------------------------------------
+
+impl SpeedLimitsRequest<'_> {
+    /// Performs the HTTP get request and returns the raw response body to the
+    /// caller, without deserializing it.
+    ///
+    /// This is an escape hatch for reading fields that this crate does not
+    /// model yet. The request is checked and retried like `get()`: only
+    /// Google's `error` is parsed, so that an error reported in the body is
+    /// returned as an error. The rest of the body is not interpreted.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(api = %Api::Roads, attempts, duration_ms, status)
+    )]
+    pub async fn get_raw(&self) -> Result<String, GoogleMapsError> {
+        // Build the URL stem for the HTTP get request:
+        let mut url = format!("{SERVICE_URL}/?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(RoadsError::QueryNotBuilt)?,
+        } // match
+
+        tracing::info!("making HTTP GET request to Google Maps Roads API");
+
+        // Observe any rate limiting before executing the request, and retry
+        // it according to its retry settings:
+        let response = self
+            .client
+            .execute_with_retry(
+                Method::Get,
+                &url,
+                RetrySettings {
+                    api: Api::Roads,
+                    backoff: self.backoff.as_ref(),
+                    max_retries: self.max_retries,
+                    timeout: self.timeout,
+                    deadline: self.deadline,
+                }, // RetrySettings
+                "application/json",
+                |body| -> Result<String, backoff::Error<RoadsError>> {
+                    // Google's `error` is checked, but the body is returned to the
+                    // caller without deserializing it:
+                    let envelope: ErrorEnvelope = self.client.parse_json(&body)?;
+                    envelope
+                        .error
+                        .map_or_else(|| Ok(body.text()), |error| Err(error.into_error()))
+                }, // classify
+            )
+            .await?;
+
+        // Return response to caller:
+        Ok(response)
+    } // fn
+} // impl
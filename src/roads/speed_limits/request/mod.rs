@@ -0,0 +1,94 @@
+//! **Look in this module for documentation on building your _Speed Limits_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+// -----------------------------------------------------------------------------
+
+mod build;
+#[cfg(feature = "reqwest")]
+mod execute;
+mod fingerprint;
+#[cfg(feature = "reqwest")]
+mod get;
+#[cfg(feature = "reqwest")]
+mod get_raw;
+mod new;
+mod query_url;
+#[cfg(feature = "reqwest")]
+mod url;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
+mod with_path;
+mod with_place_ids;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
+mod with_units;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::roads::speed_limits::speed_units::SpeedUnits;
+use crate::types::LatLng;
+use crate::redact::QueryString;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Speed Limits_ query**. The methods implemented for this struct are what's
+/// used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// A list of up to 100 latitude/longitude pairs representing a path. The
+    /// points are snapped to roads before their speed limits are looked up.
+    /// Either a `path` or `place_ids` must be specified, but not both.
+    path: Vec<LatLng>,
+
+    /// The place IDs representing one or more road segments. Either a `path`
+    /// or `place_ids` must be specified, but not both.
+    place_ids: Vec<String>,
+
+    // Optional parameters:
+    // --------------------
+    /// Whether to return speed limits in kilometers or miles per hour. Defaults
+    /// to kilometers per hour.
+    units: Option<SpeedUnits>,
+
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+
+    // Internal use only:
+    // ------------------
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<QueryString>,
+} // struct
@@ -0,0 +1,40 @@
+use crate::client::GoogleMapsClient;
+use crate::roads::speed_limits::request::Request;
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Speed Limits query. Either a path
+    /// must be set using the `with_path()` method, or road segments must be set
+    /// using the `with_place_ids()` method, before the query is executed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+
+    #[must_use]
+    pub const fn new(client: &GoogleMapsClient) -> Request<'_> {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            path: Vec::new(),
+            place_ids: Vec::new(),
+            // Optional parameters:
+            units: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
+            // Internal use only:
+            query: None,
+        } // struct
+    } // fn
+} // impl
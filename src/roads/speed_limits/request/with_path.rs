@@ -0,0 +1,41 @@
+use crate::roads::speed_limits::request::Request;
+use crate::types::LatLng;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the path whose speed limits are to be returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` ‧ A list of up to 100 latitude/longitude pairs representing a
+    ///   path. The points are snapped to roads before their speed limits are
+    ///   looked up, and the snapped points are returned in the response. Only
+    ///   one of `path` or `place_ids` may be specified, so setting a path
+    ///   clears any place IDs.
+    ///
+    /// ## Example
+    ///
+    /// * Speed limits along a path:
+    /// ```rust
+    /// .with_path(vec![
+    ///     LatLng::try_from_dec(dec!(38.75807927603043), dec!(-9.03741754643809))?,
+    ///     LatLng::try_from_dec(dec!(38.6896537), dec!(-9.1770515))?,
+    ///     LatLng::try_from_dec(dec!(41.1399289), dec!(-8.6094075))?,
+    /// ])
+    /// ```
+
+    pub fn with_path<C, L>(&'a mut self, path: C) -> &'a mut Self
+    where
+        C: IntoIterator<Item = L>,
+        L: Into<LatLng>,
+    {
+        // Set path in Request struct.
+        self.path = path.into_iter().map(Into::into).collect();
+        self.place_ids.clear();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
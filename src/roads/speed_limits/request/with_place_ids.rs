@@ -0,0 +1,39 @@
+use crate::roads::speed_limits::request::Request;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the road segments whose speed limits are to be returned.
+    ///
+    /// ## Arguments
+    ///
+    /// * `place_ids` ‧ The place IDs representing one or more road segments.
+    ///   Make sure each place ID refers to a road segment and not a different
+    ///   type of place. Up to 100 place IDs may be passed with each request.
+    ///   Only one of `path` or `place_ids` may be specified, so setting place
+    ///   IDs clears any path.
+    ///
+    /// ## Example
+    ///
+    /// * Speed limits for two road segments:
+    /// ```rust
+    /// .with_place_ids(vec![
+    ///     "ChIJX12duJAwGQ0Ra0d4Oi4jOGE",
+    ///     "ChIJLQcticc0GQ0RoiNZJVa5GxU",
+    /// ])
+    /// ```
+
+    pub fn with_place_ids<C, S>(&'a mut self, place_ids: C) -> &'a mut Self
+    where
+        C: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        // Set place IDs in Request struct.
+        self.place_ids = place_ids.into_iter().map(Into::into).collect();
+        self.path.clear();
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,33 @@
+use crate::roads::speed_limits::request::Request;
+use crate::roads::speed_limits::speed_units::SpeedUnits;
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Specifies the units that speed limits are returned in.
+    ///
+    /// ## Arguments
+    ///
+    /// * `units` ‧ Whether to return speed limits in kilometers or miles per
+    ///   hour. Defaults to kilometers per hour. A `UnitSystem` may also be
+    ///   passed when the `directions` or `distance_matrix` feature is enabled.
+    ///
+    /// ## Example
+    ///
+    /// * Return speed limits in miles per hour:
+    /// ```rust
+    /// .with_units(SpeedUnits::MilesPerHour)
+    /// ```
+
+    pub fn with_units(
+        &'a mut self,
+        units: impl Into<SpeedUnits>
+    ) -> &'a mut Self {
+        // Set units in Request struct.
+        self.units = Some(units.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,61 @@
+//! Resources (enums, structs) for processing the _Speed Limits_ response from
+//! the Google Maps Platform. Look in here for more information about the data
+//! returned from Google's server and how to parse it with your program.
+
+// -----------------------------------------------------------------------------
+
+use crate::roads::error_response::ErrorResponse;
+use crate::roads::snapped_point::SnappedPoint;
+use crate::roads::speed_limits::speed_limit::SpeedLimit;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// The response from the Google Maps _Speed Limits_ request will be stored in
+/// this structure.
+///
+/// [Speed Limits Responses](https://developers.google.com/maps/documentation/roads/speed-limits#responses)
+/// ------------------------------------------------------------------------------------------------
+/// For each valid request, the Roads API will return a response in the format
+/// indicated within the request URL such as the following JSON response.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+    /// An array of road metadata. Each element consists of the place ID of a
+    /// road segment, its speed limit, and the units of the speed limit.
+    #[serde(alias = "speedLimits")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub speed_limits: Vec<SpeedLimit>,
+
+    /// An array of snapped points. This array is only present when the request
+    /// contained the `path` parameter. See
+    /// [SnappedPoint](https://developers.google.com/maps/documentation/roads/speed-limits#SnappedPoint)
+    /// for more information.
+    #[serde(alias = "snappedPoints")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub snapped_points: Vec<SnappedPoint>,
+
+    /// A string containing a user-visible warning.
+    #[serde(alias = "warningMessage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning_message: Option<String>,
+
+    /// In the case of an error, a standard format error response body will be
+    /// returned and the HTTP status code will be set to an error status.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorResponse>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Response {
+    type Err = simd_json::Error;
+    /// Parse a Google Maps _Speed Limits_ JSON `String` response into a
+    /// usable `Response` struct.
+    fn from_str(s: &str) -> Result<Self, simd_json::Error> {
+        let mut bytes = s.to_string().into_bytes();
+        simd_json::serde::from_slice(&mut bytes)
+    } // fn
+} // impl
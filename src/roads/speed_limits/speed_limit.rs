@@ -0,0 +1,27 @@
+//! Resources (enums, structs) for processing the _Speed Limits_ response from
+//! the Google Maps Platform. Look in here for more information about the data
+//! returned from Google's server and how to parse it with your program.
+
+// -----------------------------------------------------------------------------
+
+use crate::roads::speed_limits::speed_units::SpeedUnits;
+use serde::{Deserialize, Serialize};
+
+// -----------------------------------------------------------------------------
+//
+/// [SpeedLimit](https://developers.google.com/maps/documentation/roads/speed-limits#SpeedLimit)
+
+#[derive(Clone, Debug, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct SpeedLimit {
+    /// A unique identifier for a place. All place IDs returned by the Roads
+    /// API will correspond to road segments.
+    #[serde(alias = "placeId")]
+    pub place_id: String,
+
+    /// The speed limit for that road segment in the specified units.
+    #[serde(alias = "speedLimit")]
+    pub speed_limit: f64,
+
+    /// Either `KPH` or `MPH`.
+    pub units: SpeedUnits,
+} // struct
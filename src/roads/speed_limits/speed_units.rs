@@ -0,0 +1,151 @@
+//! Contains the `SpeedUnits` enum and its associated traits. It is used to
+//! specify whether speed limits are expressed in kilometers or miles per hour.
+
+use crate::error::Error as GoogleMapsError;
+use crate::roads::error::Error as RoadsError;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+
+/// Specifies the units that
+/// [speed limits](https://developers.google.com/maps/documentation/roads/speed-limits#optional-parameters)
+/// are returned in. Defaults to kilometers per hour.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum SpeedUnits {
+    /// Speed limits are expressed in kilometers per hour.
+    #[default]
+    KilometersPerHour = 0,
+    /// Speed limits are expressed in miles per hour.
+    MilesPerHour = 1,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for SpeedUnits {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Self::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string())),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for SpeedUnits {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(std::convert::Into::<&str>::into(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&SpeedUnits> for &str {
+    /// Converts a `SpeedUnits` enum to a `String` that contains a [speed
+    /// units](https://developers.google.com/maps/documentation/roads/speed-limits#optional-parameters)
+    /// code.
+    fn from(units: &SpeedUnits) -> Self {
+        match units {
+            SpeedUnits::KilometersPerHour => "KPH",
+            SpeedUnits::MilesPerHour => "MPH",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for SpeedUnits {
+    /// Converts a `SpeedUnits` enum to a `String` that contains a [speed
+    /// units](https://developers.google.com/maps/documentation/roads/speed-limits#optional-parameters)
+    /// code.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&SpeedUnits> for String {
+    /// Converts a `SpeedUnits` enum to a `String` that contains a [speed
+    /// units](https://developers.google.com/maps/documentation/roads/speed-limits#optional-parameters)
+    /// code.
+    fn from(units: &SpeedUnits) -> Self {
+        std::convert::Into::<&str>::into(units).to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static SPEED_UNITS_BY_CODE: phf::Map<&'static str, SpeedUnits> = phf_map! {
+    "KPH" => SpeedUnits::KilometersPerHour,
+    "MPH" => SpeedUnits::MilesPerHour,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<&str> for SpeedUnits {
+    // Error definitions are contained in the `google_maps\src\roads\error.rs`
+    // module.
+    type Error = GoogleMapsError;
+    /// Gets a `SpeedUnits` enum from a `String` that contains a valid [speed
+    /// units](https://developers.google.com/maps/documentation/roads/speed-limits#optional-parameters)
+    /// code.
+    fn try_from(speed_units_code: &str) -> Result<Self, Self::Error> {
+        Ok(SPEED_UNITS_BY_CODE
+            .get(speed_units_code)
+            .cloned()
+            .ok_or_else(|| RoadsError::InvalidSpeedUnitsCode(speed_units_code.to_string()))?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for SpeedUnits {
+    // Error definitions are contained in the `google_maps\src\roads\error.rs`
+    // module.
+    type Err = GoogleMapsError;
+    /// Gets a `SpeedUnits` enum from a `String` that contains a valid [speed
+    /// units](https://developers.google.com/maps/documentation/roads/speed-limits#optional-parameters)
+    /// code.
+    fn from_str(speed_units_code: &str) -> Result<Self, Self::Err> {
+        Self::try_from(speed_units_code)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+impl std::convert::From<crate::directions::request::unit_system::UnitSystem> for SpeedUnits {
+    /// Converts a `UnitSystem` enum to the equivalent `SpeedUnits`: metric
+    /// units are kilometers per hour, and imperial units are miles per hour.
+    fn from(unit_system: crate::directions::request::unit_system::UnitSystem) -> Self {
+        match unit_system {
+            crate::directions::request::unit_system::UnitSystem::Metric => Self::KilometersPerHour,
+            crate::directions::request::unit_system::UnitSystem::Imperial => Self::MilesPerHour,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl SpeedUnits {
+    /// Formats a `SpeedUnits` enum into a string that is presentable to the
+    /// end user.
+    #[must_use]
+    pub const fn display(&self) -> &str {
+        match self {
+            Self::KilometersPerHour => "km/h",
+            Self::MilesPerHour => "mph",
+        } // match
+    } // fn
+} // impl
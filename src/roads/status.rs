@@ -64,6 +64,17 @@ pub enum Status {
     /// to all users. See Capping API Usage to configure these limits.
     #[serde(alias = "ResourceExhausted")]
     ResourceExhausted,
+
+    /// If the status is not recognized by
+    /// [serde](https://crates.io/crates/serde) when reading data from
+    /// Google it will be assigned to this `Other` variant.
+    ///
+    /// As new statuses are added to Google Maps, they must also be added to
+    /// this crate. However, in the meantime, the `Other` catch-all variant
+    /// allows `serde` to read data from Google without producing an error
+    /// until the new variant added to this `enum`.
+    #[serde(untagged)]
+    Other(String),
 } // struct
 
 // -----------------------------------------------------------------------------
@@ -71,12 +82,12 @@ pub enum Status {
 impl<'de> Deserialize<'de> for Status {
     /// Manual implementation of `Deserialize` for `serde`. This will take
     /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    /// Unrecognized values are deserialized into the `Other` variant.
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let string = String::deserialize(deserializer)?;
-        match Self::try_from(string.as_str()) {
-            Ok(variant) => Ok(variant),
-            Err(error) => Err(serde::de::Error::custom(error.to_string())),
-        } // match
+        // A value that this crate does not recognize yet is kept in the
+        // `Other` variant, rather than failing the entire response:
+        Ok(Self::try_from(string.as_str()).unwrap_or(Self::Other(string)))
     } // fn
 } // impl
 
@@ -91,6 +102,7 @@ impl std::convert::From<&Status> for String {
             Status::PermissionDenied => Self::from("PERMISSION_DENIED"),
             Status::NotFound => Self::from("NOT_FOUND"),
             Status::ResourceExhausted => Self::from("RESOURCE_EXHAUSTED"),
+            Status::Other(status_code) => status_code.clone(),
         } // match
     } // fn
 } // impl
@@ -152,6 +164,7 @@ impl std::fmt::Display for Status {
             Self::PermissionDenied => write!(f, "Permission Denied"),
             Self::NotFound => write!(f, "Not Found"),
             Self::ResourceExhausted => write!(f, "Resource Exhausted"),
+            Self::Other(status_code) => write!(f, "{status_code}"),
         } // match
     } // fn
 } // impl
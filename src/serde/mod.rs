@@ -3,4 +3,6 @@
 #[cfg(any(feature = "directions", feature = "distance_matrix"))]
 pub mod duration_to_seconds;
 #[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub mod number_or_string_to_decimal;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
 pub mod seconds_to_duration;
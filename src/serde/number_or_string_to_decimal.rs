@@ -0,0 +1,68 @@
+//! Contains Serde deserializer for converting a number, in either JSON number
+//! or `String` format, into a `rust_decimal::Decimal` struct.
+
+use rust_decimal::Decimal;
+use serde::de::{Deserialize, Deserializer, Error, Visitor};
+use std::str::FromStr;
+
+/// This trait converts a number in either JSON number or `String` format into a
+/// `Decimal` struct. The Google Maps Platform usually returns amounts as JSON
+/// numbers but sometimes as strings, and `rust_decimal` only accepts one or the
+/// other depending on which of its `serde` features is enabled.
+
+pub fn number_or_string_to_decimal<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(DecimalVisitor)
+} // fn
+
+// -----------------------------------------------------------------------------
+//
+/// This trait converts an optional number in either JSON number or `String`
+/// format into an `Option<Decimal>`. Use it together with `#[serde(default)]`
+/// so that a missing field becomes `None`.
+
+pub fn number_or_string_to_optional_decimal<'de, D>(
+    deserializer: D
+) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "number_or_string_to_decimal")] Decimal);
+
+    Option::<Wrapper>::deserialize(deserializer).map(|wrapper| wrapper.map(|Wrapper(value)| value))
+} // fn
+
+// -----------------------------------------------------------------------------
+
+struct DecimalVisitor;
+
+impl Visitor<'_> for DecimalVisitor {
+    type Value = Decimal;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a decimal number, or a string containing a decimal number")
+    } // fn
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    } // fn
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Decimal, E> {
+        Ok(Decimal::from(value))
+    } // fn
+
+    fn visit_f64<E: Error>(self, value: f64) -> Result<Decimal, E> {
+        Decimal::try_from(value)
+            .map_err(|_error| E::custom(format!("could not deserialize a decimal from: {value}")))
+    } // fn
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Decimal, E> {
+        let value = value.trim();
+        Decimal::from_str(value)
+            .or_else(|_error| Decimal::from_scientific(value))
+            .map_err(|_error| E::custom(format!("could not deserialize a decimal from: `{value}`")))
+    } // fn
+} // impl
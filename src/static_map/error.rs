@@ -0,0 +1,166 @@
+//! Maps Static API error types and error messages.
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "diagnostics")]
+use miette::Diagnostic;
+use thiserror::Error;
+
+// -----------------------------------------------------------------------------
+//
+/// Errors that may be produced by the Google Maps Static API client.
+
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+#[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::static_map::error), url(docsrs)))]
+pub enum Error {
+    /// The HTTP request was unsuccessful.
+    HttpUnsuccessful(String),
+    /// The HTTP request was unsuccessful. Contains the HTTP status code, the
+    /// headers that help explain the failure (such as `Retry-After` and
+    /// Google's `X-Goog-*` headers), and the truncated response body.
+    #[cfg(feature = "reqwest")]
+    HttpUnsuccessfulResponse {
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String,
+    },
+    /// API client library attempted to parse a string that contained an invalid
+    /// map type. See `google_maps\src\static_map\request\map_type.rs` for more
+    /// information.
+    InvalidMapTypeCode(String),
+    /// API client library attempted to parse a string that contained an invalid
+    /// marker size. See `google_maps\src\static_map\request\markers.rs` for
+    /// more information.
+    InvalidMarkerSizeCode(String),
+    /// The image's scale factor must be 1, 2, or 4. Contains the scale factor.
+    InvalidScale(u8),
+    /// The image's width and height must both be at least one pixel. Contains
+    /// the width and the height.
+    InvalidSize(u16, u16),
+    /// The zoom level must be between 0 and 21. Contains the zoom level.
+    InvalidZoom(u8),
+    /// Google can not position a map that has neither a center, nor any
+    /// markers or paths.
+    LocationRequired,
+    /// The query string must be built before the request may be sent to the
+    /// Google Maps Static API server.
+    QueryNotBuilt,
+    /// The dependency library Reqwest generated an error.
+    #[cfg(feature = "reqwest")]
+    Reqwest(crate::ReqError),
+    /// The dependency library Reqwest generated an error. The error could
+    /// not be passed normally so a `String` representation is passed instead.
+    #[cfg(feature = "reqwest")]
+    ReqwestMessage(String),
+    /// The service responded successfully, but not with an image, for example
+    /// with an HTML error page from a proxy or a sign-in page. Contains the
+    /// response's `Content-Type` header, and the response body truncated to the
+    /// length set by `GoogleMapsClient::with_error_body_length`.
+    #[cfg(feature = "reqwest")]
+    UnexpectedContentType {
+        content_type: String,
+        body_snippet: String,
+    },
+    /// A map that is positioned by its center must also have a zoom level,
+    /// unless it has markers or paths that Google can fit the map to.
+    ZoomRequired,
+} // enum
+
+impl std::fmt::Display for Error {
+    /// This trait converts the error code into a format that may be presented
+    /// to the user.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::HttpUnsuccessful(status) => write!(f,
+                "Google Maps Static API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status."),
+            #[cfg(feature = "reqwest")]
+            Self::HttpUnsuccessfulResponse { status, body, .. } => write!(f,
+                "Google Maps Static API client: \
+                Could not successfully query the Google Cloud Platform service. \
+                The service last responded with a `{status}` status: `{body}`"),
+            Self::InvalidMapTypeCode(map_type_code) => write!(f, "Google Maps Static API client: \
+                `{map_type_code}` is not a valid map type code. \
+                Valid codes are `roadmap`, `satellite`, `terrain`, and `hybrid`."),
+            Self::InvalidMarkerSizeCode(marker_size_code) => write!(f, "Google Maps Static API client: \
+                `{marker_size_code}` is not a valid marker size code. \
+                Valid codes are `tiny`, `mid`, and `small`."),
+            Self::InvalidScale(scale) => write!(f, "Google Maps Static API client: \
+                `{scale}` is not a valid scale. \
+                Valid scales are `1`, `2`, and `4`."),
+            Self::InvalidSize(width, height) => write!(f, "Google Maps Static API client: \
+                `{width}x{height}` is not a valid image size. \
+                The width and the height must both be at least one pixel."),
+            Self::InvalidZoom(zoom) => write!(f, "Google Maps Static API client: \
+                `{zoom}` is not a valid zoom level. \
+                Valid zoom levels are between `0` and `21`."),
+            Self::LocationRequired => write!(f, "Google Maps Static API client: \
+                The map has no center, markers, or paths. \
+                Set a center and a zoom level, or add at least one marker or path."),
+            Self::QueryNotBuilt => write!(f, "Google Maps Static API client library: \
+                The query string must be built before the request may be sent to the Google Cloud Maps Platform. \
+                Ensure the build() method is called before run()."),
+            #[cfg(feature = "reqwest")]
+            Self::Reqwest(error) => write!(f, "Google Maps Static API client in the Reqwest library: {error}"),
+            #[cfg(feature = "reqwest")]
+            Self::ReqwestMessage(error) => write!(f, "Google Maps Static API client in the Reqwest library: {error}"),
+            #[cfg(feature = "reqwest")]
+            Self::UnexpectedContentType { content_type, body_snippet } => write!(f,
+                "Google Maps Static API client: \
+                The service responded with `{content_type}` content instead of an image. \
+                This is usually an error page. The response body was: `{body_snippet}`"),
+            Self::ZoomRequired => write!(f, "Google Maps Static API client: \
+                The map has a center but no zoom level. \
+                Set a zoom level, or add a marker or path for the map to fit."),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl From<reqwest::Error> for Error {
+    /// This trait converts from an Reqwest error type (`reqwest::Error`) into a
+    /// Google Maps Static API error type
+    /// (`google_maps::static_map::error::Error`) by wrapping it inside. This
+    /// function is required to use the `?` operator.
+    fn from(error: reqwest::Error) -> Self {
+        Self::Reqwest(crate::ReqError::from(error))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(feature = "reqwest")]
+impl crate::client::HttpError for Error {
+    /// Reports the HTTP-level failures of the retry loop as Google Maps
+    /// Maps Static API errors.
+    fn unexpected_content_type(content_type: String, body_snippet: String) -> Self {
+        Self::UnexpectedContentType {
+            content_type,
+            body_snippet,
+        } // UnexpectedContentType
+    } // fn
+
+    fn http_unsuccessful_response(
+        status: reqwest::StatusCode,
+        headers: Box<reqwest::header::HeaderMap>,
+        body: String
+    ) -> Self {
+        Self::HttpUnsuccessfulResponse {
+            status,
+            headers,
+            body,
+        } // HttpUnsuccessfulResponse
+    } // fn
+
+    fn reqwest(error: crate::ReqError) -> Self {
+        Self::Reqwest(error)
+    } // fn
+
+    fn reqwest_message(message: String) -> Self {
+        Self::ReqwestMessage(message)
+    } // fn
+} // impl
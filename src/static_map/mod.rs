@@ -0,0 +1,53 @@
+//! The **Maps Static API** returns an image (either GIF, PNG or JPEG) in
+//! response to an HTTP request via a URL. For each request, you can specify
+//! the location of the map, the size of the image, the zoom level, the type of
+//! map, and the placement of optional markers and paths at locations on the
+//! map.
+//!
+//! # [Get Started](https://developers.google.com/maps/documentation/maps-static/start)
+//!
+//! * **New Users**: Before you can start using the Google Maps Platform APIs
+//!   and SDKs, you must sign up and create a billing account. To learn more,
+//!   see [Get Started with Google Maps
+//!   Platform](https://developers.google.com/maps/gmp-get-started).
+//!
+//! # [Authentication, quotas, pricing, and policies](https://developers.google.com/maps/documentation/maps-static/get-api-key)
+//!
+//! To use the Maps Static API, you must first activate the API in the Google
+//! Cloud Maps Platform and obtain the proper authentication credentials. You
+//! need to provide an **API key** in each request. Requests larger than
+//! 25,000 per day must also be [digitally
+//! signed](https://developers.google.com/maps/documentation/maps-static/digital-signature),
+//! see `GoogleMapsClient::with_signing()`.
+//!
+//! Review the [usage and
+//! billing](https://developers.google.com/maps/documentation/maps-static/usage-and-billing)
+//! page for details on the quotas and pricing set for the Maps Static API.
+//!
+//! Use of the Maps Static API must be in accordance with the [API
+//! policies](https://developers.google.com/maps/documentation/maps-static/policies).
+//!
+//! # [Learn more](https://developers.google.com/maps/documentation/maps-static/start#learn-more)
+//!
+//! The [Maps Static API Developer
+//! Guide](https://developers.google.com/maps/documentation/maps-static/start)
+//! describes all of the parameters that may be used to style a map.
+
+pub mod error;
+pub mod request;
+pub mod response;
+
+// -----------------------------------------------------------------------------
+
+const SERVICE_URL: &str = "https://maps.googleapis.com/maps/api/staticmap";
+
+// -----------------------------------------------------------------------------
+
+pub use crate::static_map::{
+    error::Error as StaticMapError,
+    request::{
+        map_type::MapType, markers::MarkerSize, markers::Markers, path::Path, path::Points,
+        Request as StaticMapRequest,
+    }, // request
+    response::Response as StaticMapResponse,
+}; // crate::static_map
@@ -0,0 +1,106 @@
+use crate::static_map::{error::Error as StaticMapError, request::Request};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Builds the query string for the Google Maps Static API based on the
+    /// input provided by the client.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the size, zoom level, or scale is out of range,
+    ///   or if the map can not be positioned because it has no center, or a
+    ///   center but no zoom level, and no markers or paths.
+
+    pub fn build(&mut self) -> Result<&'a mut Request<'_>, StaticMapError> {
+        let (width, height) = self.size;
+        if width == 0 || height == 0 {
+            return Err(StaticMapError::InvalidSize(width, height));
+        } // if
+
+        if let Some(zoom) = self.zoom {
+            if zoom > 21 {
+                return Err(StaticMapError::InvalidZoom(zoom));
+            } // if
+        } // if
+
+        if let Some(scale) = self.scale {
+            if !matches!(scale, 1 | 2 | 4) {
+                return Err(StaticMapError::InvalidScale(scale));
+            } // if
+        } // if
+
+        // Google positions the map using its center and zoom level, or fits
+        // the map to its markers and paths:
+        if self.markers.is_empty() && self.paths.is_empty() {
+            if self.center.is_none() {
+                return Err(StaticMapError::LocationRequired);
+            } // if
+
+            if self.zoom.is_none() {
+                return Err(StaticMapError::ZoomRequired);
+            } // if
+        } // if
+
+        // This section builds the "required parameters" portion of the query
+        // string:
+
+        let mut query = format!(
+            "key={key}&size={width}x{height}",
+            key = self.client.key,
+        );
+
+        // This section builds the "optional parameters" portion of the query
+        // string:
+
+        // Center key/value pair:
+        if let Some(center) = &self.center {
+            query.push_str("&center=");
+            query.push_str(&String::from(center));
+        } // if
+
+        // Zoom key/value pair:
+        if let Some(zoom) = &self.zoom {
+            query.push_str("&zoom=");
+            query.push_str(&zoom.to_string());
+        } // if
+
+        // Scale key/value pair:
+        if let Some(scale) = &self.scale {
+            query.push_str("&scale=");
+            query.push_str(&scale.to_string());
+        } // if
+
+        // Map type key/value pair:
+        if let Some(map_type) = &self.map_type {
+            query.push_str("&maptype=");
+            query.push_str(&String::from(map_type));
+        } // if
+
+        // Markers key/value pairs. Each group of markers is a separate
+        // parameter:
+        for markers in &self.markers {
+            query.push_str("&markers=");
+            query.push_str(&utf8_percent_encode(&String::from(markers), NON_ALPHANUMERIC).to_string());
+        } // for
+
+        // Path key/value pairs. Each path is a separate parameter:
+        for path in &self.paths {
+            query.push_str("&path=");
+            query.push_str(&utf8_percent_encode(&String::from(path), NON_ALPHANUMERIC).to_string());
+        } // for
+
+        // Set query string in Request struct.
+        self.query = Some(query.into());
+
+        // Return modified Request struct to caller.
+        Ok(self)
+    } // fn
+} // impl
@@ -0,0 +1,26 @@
+use crate::error::Error as GoogleMapsError;
+use crate::static_map::{
+    request::Request as StaticMapRequest, response::Response as StaticMapResponse,
+}; // crate::static_map
+
+// =============================================================================
+
+impl<'a> StaticMapRequest<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Executes the query you've built.
+    ///
+    /// ## Description
+    ///
+    /// My adventures in Rust became messy so I had to make this method. It
+    /// wraps the `.build()?.get()?` chain needed at the end of the builder
+    /// pattern.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    pub async fn execute(&'a mut self) -> Result<StaticMapResponse, GoogleMapsError> {
+        self.build()?.get().await
+    } // fn
+} // impl
@@ -0,0 +1,68 @@
+use crate::client::GoogleMapsClient;
+use crate::directions::response::route::Route;
+use crate::static_map::request::{markers::Markers, path::Path, Request};
+
+// =============================================================================
+
+impl<'a> Request<'a> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Maps Static API query that shows
+    /// a route returned by the Directions API.
+    ///
+    /// The route's overview polyline is drawn as a path, and its origin, stops,
+    /// and destination are marked in order with the labels `A`, `B`, `C`, and
+    /// so on, like the Google Maps website does. Google fits the map to the
+    /// route. Other settings, such as the size of the image, may be changed
+    /// before the query is executed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+    ///
+    /// * `route` ‧ The route to show, for example one of the `routes` of a
+    ///   Directions API response.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let image = StaticMapRequest::from_route(&google_maps_client, &directions.routes[0])
+    ///     .with_size(600, 400)
+    ///     .execute()
+    ///     .await?;
+    ///
+    /// std::fs::write("route.png", image.bytes)?;
+    /// ```
+
+    #[must_use]
+    pub fn from_route(client: &'a GoogleMapsClient, route: &Route) -> Self {
+        let mut request = Request::new(client);
+
+        request
+            .paths
+            .push(Path::from_polyline(&route.overview_polyline.points));
+
+        // Each stop is the start of a leg, and the destination is the end of
+        // the last leg:
+        let stops = route
+            .legs
+            .iter()
+            .map(|leg| leg.start_location)
+            .chain(route.legs.last().map(|leg| leg.end_location));
+
+        // Markers with different labels must be sent as separate groups. Only
+        // 26 letters are available, so any further stops are left unlabelled:
+        request.markers = stops
+            .zip((b'A'..=b'Z').map(char::from).map(Some).chain(std::iter::repeat(None)))
+            .map(|(stop, label)| {
+                let markers = Markers::from(stop);
+                match label {
+                    Some(label) => markers.with_label(label),
+                    None => markers,
+                } // match
+            }) // map
+            .collect();
+
+        request
+    } // fn
+} // impl
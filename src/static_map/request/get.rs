@@ -0,0 +1,63 @@
+use crate::client::{Method, RetrySettings};
+use crate::error::Error as GoogleMapsError;
+use crate::request_rate::api::Api;
+use crate::static_map::{
+    error::Error as StaticMapError, request::Request as StaticMapRequest,
+    response::Response as StaticMapResponse, SERVICE_URL,
+}; // crate::static_map
+
+// -----------------------------------------------------------------------------
+
+impl StaticMapRequest<'_> {
+    /// Performs the HTTP get request and returns the response to the caller.
+    ///
+    /// ## Arguments
+    ///
+    /// This method accepts no arguments.
+
+    #[tracing::instrument(
+        level = "info",
+        skip(self),
+        fields(api = %Api::StaticMap, attempts, duration_ms, status)
+    )]
+    pub async fn get(&mut self) -> Result<StaticMapResponse, GoogleMapsError> {
+        // Build the URL stem for the HTTP get request:
+        let mut url = format!("{SERVICE_URL}?");
+
+        match &self.query {
+            // If query string built, append it to the URL stem.
+            Some(query) => url.push_str(query.as_ref()),
+            // If query string not built, return an error.
+            None => return Err(StaticMapError::QueryNotBuilt)?,
+        } // match
+
+        tracing::info!("making HTTP GET request to Google Maps Static API");
+
+        // Observe any rate limiting before executing the request, and retry
+        // it according to its retry settings:
+        let response = self
+            .client
+            .execute_with_retry(
+                Method::Get,
+                &url,
+                RetrySettings {
+                    api: Api::StaticMap,
+                    backoff: self.backoff.as_ref(),
+                    max_retries: self.max_retries,
+                    timeout: self.timeout,
+                    deadline: self.deadline,
+                }, // RetrySettings
+                "image/",
+                |body| -> Result<StaticMapResponse, backoff::Error<StaticMapError>> {
+                    Ok(StaticMapResponse {
+                        content_type: body.content_type,
+                        bytes: body.bytes,
+                    }) // Ok
+                }, // classify
+            )
+            .await?;
+
+        // Return response to caller:
+        Ok(response)
+    } // fn
+} // impl
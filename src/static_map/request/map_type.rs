@@ -0,0 +1,163 @@
+//! Contains the `MapType` enum and its associated traits. It is used to select
+//! the kind of map that the Maps Static API draws.
+
+use crate::error::Error as GoogleMapsError;
+use crate::static_map::error::Error as StaticMapError;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+
+/// Used to specify the [map
+/// type](https://developers.google.com/maps/documentation/maps-static/start#MapTypes)
+/// to construct.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum MapType {
+    /// Specifies a standard roadmap image, as is normally shown on the Google
+    /// Maps website.
+    #[default]
+    Roadmap = 0,
+    /// Specifies a satellite image.
+    Satellite = 1,
+    /// Specifies a physical relief map image, showing terrain and vegetation.
+    Terrain = 2,
+    /// Specifies a hybrid of the satellite and roadmap image, showing a
+    /// transparent layer of major streets and place names on the satellite
+    /// image.
+    Hybrid = 3,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for MapType {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Self::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string())),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for MapType {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(std::convert::Into::<&str>::into(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&MapType> for &str {
+    /// Converts a `MapType` enum to a `String` that contains a [map
+    /// type](https://developers.google.com/maps/documentation/maps-static/start#MapTypes)
+    /// code.
+    fn from(map_type: &MapType) -> Self {
+        match map_type {
+            MapType::Roadmap => "roadmap",
+            MapType::Satellite => "satellite",
+            MapType::Terrain => "terrain",
+            MapType::Hybrid => "hybrid",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for MapType {
+    /// Converts a `MapType` enum to a `String` that contains a [map
+    /// type](https://developers.google.com/maps/documentation/maps-static/start#MapTypes)
+    /// code.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Self> for MapType {
+    /// Converts a borrowed `&MapType` enum into an owned `MapType` enum by
+    /// cloning it.
+    fn from(map_type: &Self) -> Self {
+        map_type.clone()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&MapType> for String {
+    /// Converts a `MapType` enum to a `String` that contains a [map
+    /// type](https://developers.google.com/maps/documentation/maps-static/start#MapTypes)
+    /// code.
+    fn from(map_type: &MapType) -> Self {
+        std::convert::Into::<&str>::into(map_type).to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static MAP_TYPES_BY_CODE: phf::Map<&'static str, MapType> = phf_map! {
+    "roadmap" => MapType::Roadmap,
+    "satellite" => MapType::Satellite,
+    "terrain" => MapType::Terrain,
+    "hybrid" => MapType::Hybrid,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<&str> for MapType {
+    // Error definitions are contained in the
+    // `google_maps\src\static_map\error.rs` module.
+    type Error = GoogleMapsError;
+    /// Gets a `MapType` enum from a `String` that contains a valid [map
+    /// type](https://developers.google.com/maps/documentation/maps-static/start#MapTypes)
+    /// code.
+    fn try_from(map_type_code: &str) -> Result<Self, Self::Error> {
+        Ok(MAP_TYPES_BY_CODE
+            .get(map_type_code)
+            .cloned()
+            .ok_or_else(|| StaticMapError::InvalidMapTypeCode(map_type_code.to_string()))?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for MapType {
+    // Error definitions are contained in the
+    // `google_maps\src\static_map\error.rs` module.
+    type Err = GoogleMapsError;
+    /// Gets a `MapType` enum from a `String` that contains a valid [map
+    /// type](https://developers.google.com/maps/documentation/maps-static/start#MapTypes)
+    /// code.
+    fn from_str(map_type_code: &str) -> Result<Self, Self::Err> {
+        Ok(MAP_TYPES_BY_CODE
+            .get(map_type_code)
+            .cloned()
+            .ok_or_else(|| StaticMapError::InvalidMapTypeCode(map_type_code.to_string()))?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl MapType {
+    /// Formats a `MapType` enum into a string that is presentable to the end
+    /// user.
+    #[must_use]
+    pub const fn display(&self) -> &str {
+        match self {
+            Self::Roadmap => "Roadmap",
+            Self::Satellite => "Satellite",
+            Self::Terrain => "Terrain",
+            Self::Hybrid => "Hybrid",
+        } // match
+    } // fn
+} // impl
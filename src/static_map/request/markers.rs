@@ -0,0 +1,215 @@
+//! Contains the `Markers` struct and the `MarkerSize` enum. They are used to
+//! place markers at locations on a static map.
+
+use crate::error::Error as GoogleMapsError;
+use crate::static_map::error::Error as StaticMapError;
+use crate::types::LatLng;
+use phf::phf_map;
+
+// -----------------------------------------------------------------------------
+//
+/// A group of [markers](https://developers.google.com/maps/documentation/maps-static/start#Markers)
+/// that share the same style. Each group is sent to Google as one `markers`
+/// parameter, and a map may have several groups.
+///
+/// ## Examples:
+///
+/// ```rust
+/// use google_maps::static_map::{MarkerSize, Markers};
+///
+/// let markers = Markers::new([LatLng::try_from_dec(dec!(62.107733), dec!(-145.541936))?])
+///     .with_size(MarkerSize::Mid)
+///     .with_color("blue")
+///     .with_label('S');
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Markers {
+    /// The size of the markers. If `None`, Google's normal size is used.
+    pub size: Option<MarkerSize>,
+    /// The color of the markers, either as a 24-bit hexadecimal color such as
+    /// `0xFFFFCC`, or as one of Google's predefined colors: `black`, `brown`,
+    /// `green`, `purple`, `yellow`, `blue`, `gray`, `orange`, `red`, or
+    /// `white`. If `None`, Google's red is used.
+    pub color: Option<String>,
+    /// A single uppercase alphanumeric character, from `A` to `Z` or from `0`
+    /// to `9`, that is drawn on the markers. Labels are not drawn on `tiny`
+    /// and `small` markers.
+    pub label: Option<char>,
+    /// The locations of the markers.
+    pub locations: Vec<LatLng>,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Markers {
+    /// Creates a group of markers, in Google's default style, at the specified
+    /// locations.
+
+    pub fn new<L: Into<LatLng>>(locations: impl IntoIterator<Item = L>) -> Self {
+        Self {
+            locations: locations.into_iter().map(Into::into).collect(),
+            ..Default::default()
+        } // struct
+    } // fn
+
+    /// Sets the size of the markers.
+
+    #[must_use]
+    pub const fn with_size(mut self, size: MarkerSize) -> Self {
+        self.size = Some(size);
+        self
+    } // fn
+
+    /// Sets the color of the markers, for example `blue` or `0xFFFFCC`.
+
+    #[must_use]
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    } // fn
+
+    /// Sets the label that is drawn on the markers. Google requires an
+    /// uppercase letter or a digit, so lowercase letters are converted to
+    /// uppercase.
+
+    #[must_use]
+    pub const fn with_label(mut self, label: char) -> Self {
+        self.label = Some(label.to_ascii_uppercase());
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Markers> for String {
+    /// Converts a `Markers` struct to the value of a `markers` parameter: the
+    /// style descriptors, followed by the locations, separated by pipes. For
+    /// example, `size:mid|color:blue|label:S|62.107733,-145.541936`.
+    fn from(markers: &Markers) -> Self {
+        let mut parts: Vec<Self> = Vec::with_capacity(markers.locations.len() + 3);
+
+        if let Some(size) = &markers.size {
+            parts.push(format!("size:{size}"));
+        } // if
+
+        if let Some(color) = &markers.color {
+            parts.push(format!("color:{color}"));
+        } // if
+
+        if let Some(label) = &markers.label {
+            parts.push(format!("label:{label}"));
+        } // if
+
+        parts.extend(markers.locations.iter().map(Self::from));
+        parts.join("|")
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Markers {
+    /// Formats a `Markers` struct as the value of a `markers` parameter.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<LatLng> for Markers {
+    /// Creates a single marker, in Google's default style.
+    fn from(location: LatLng) -> Self {
+        Self::new([location])
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<Vec<LatLng>> for Markers {
+    /// Creates a group of markers, in Google's default style.
+    fn from(locations: Vec<LatLng>) -> Self {
+        Self {
+            locations,
+            ..Default::default()
+        } // struct
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+//
+/// Used to specify the size of a group of
+/// [markers](https://developers.google.com/maps/documentation/maps-static/start#MarkerStyles).
+/// If no size is set, Google draws markers in its normal size.
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum MarkerSize {
+    /// The smallest markers, which have no label.
+    Tiny = 0,
+    /// Markers that are smaller than normal, and larger than `Small` markers.
+    Mid = 1,
+    /// Small markers, which have no label.
+    Small = 2,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&MarkerSize> for &str {
+    /// Converts a `MarkerSize` enum to a `String` that contains a marker size
+    /// code.
+    fn from(marker_size: &MarkerSize) -> Self {
+        match marker_size {
+            MarkerSize::Tiny => "tiny",
+            MarkerSize::Mid => "mid",
+            MarkerSize::Small => "small",
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for MarkerSize {
+    /// Converts a `MarkerSize` enum to a `String` that contains a marker size
+    /// code.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static MARKER_SIZES_BY_CODE: phf::Map<&'static str, MarkerSize> = phf_map! {
+    "tiny" => MarkerSize::Tiny,
+    "mid" => MarkerSize::Mid,
+    "small" => MarkerSize::Small,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<&str> for MarkerSize {
+    // Error definitions are contained in the
+    // `google_maps\src\static_map\error.rs` module.
+    type Error = GoogleMapsError;
+    /// Gets a `MarkerSize` enum from a `String` that contains a valid marker
+    /// size code.
+    fn try_from(marker_size_code: &str) -> Result<Self, Self::Error> {
+        Ok(MARKER_SIZES_BY_CODE
+            .get(marker_size_code)
+            .copied()
+            .ok_or_else(|| StaticMapError::InvalidMarkerSizeCode(marker_size_code.to_string()))?)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for MarkerSize {
+    // Error definitions are contained in the
+    // `google_maps\src\static_map\error.rs` module.
+    type Err = GoogleMapsError;
+    /// Gets a `MarkerSize` enum from a `String` that contains a valid marker
+    /// size code.
+    fn from_str(marker_size_code: &str) -> Result<Self, Self::Err> {
+        Self::try_from(marker_size_code)
+    } // fn
+} // impl
@@ -0,0 +1,115 @@
+//! **Look in this module for documentation on building your _Maps Static API_
+//! query**. In particular, look at the _Request_ struct for examples of the
+//! builder pattern. This module contains the tools (enums, structs, methods)
+//! for building your Google Maps Platform request.
+
+pub mod map_type;
+pub mod markers;
+pub mod path;
+
+mod build;
+#[cfg(feature = "reqwest")]
+mod execute;
+mod fingerprint;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+mod from_route;
+#[cfg(feature = "reqwest")]
+mod get;
+mod new;
+mod query_url;
+#[cfg(feature = "reqwest")]
+mod url;
+#[cfg(feature = "reqwest")]
+mod with_backoff;
+mod with_center;
+#[cfg(feature = "reqwest")]
+mod with_deadline;
+mod with_map_type;
+mod with_markers;
+#[cfg(feature = "reqwest")]
+mod with_max_retries;
+mod with_path;
+mod with_scale;
+mod with_size;
+#[cfg(feature = "reqwest")]
+mod with_timeout;
+mod with_zoom;
+
+// -----------------------------------------------------------------------------
+
+use crate::client::GoogleMapsClient;
+use crate::redact::QueryString;
+use crate::static_map::request::{map_type::MapType, markers::Markers, path::Path};
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+//
+/// **Look at this `Request` struct for documentation on how to build your
+/// _Maps Static API_ query**. The methods implemented for this struct are
+/// what's used to build your request.
+
+#[derive(Debug)]
+pub struct Request<'a> {
+    // Required parameters:
+    // --------------------
+    /// This structure contains the application's API key and other
+    /// user-definable settings such as "maximum retries."
+    client: &'a GoogleMapsClient,
+
+    /// The width and height of the image, in pixels. See method `with_size()`
+    /// for more information.
+    size: (u16, u16),
+
+    // Optional parameters:
+    // --------------------
+    /// The center of the map. See method `with_center()` for more
+    /// information.
+    center: Option<LatLng>,
+
+    /// The type of map to construct. See method `with_map_type()` for more
+    /// information.
+    map_type: Option<MapType>,
+
+    /// Groups of markers to place on the map. See method `with_markers()` for
+    /// more information.
+    markers: Vec<Markers>,
+
+    /// Paths to draw on the map. See method `with_path()` for more
+    /// information.
+    paths: Vec<Path>,
+
+    /// The number of pixels that are returned for each pixel of the image's
+    /// size. See method `with_scale()` for more information.
+    scale: Option<u8>,
+
+    /// The zoom level of the map. See method `with_zoom()` for more
+    /// information.
+    zoom: Option<u8>,
+
+    // Retry settings:
+    // ---------------
+    /// Retry policy to use when the HTTP request fails with an error that is
+    /// eligible for retries. See method `with_backoff()` for more information.
+    #[cfg(feature = "reqwest")]
+    backoff: Option<backoff::ExponentialBackoff>,
+
+    /// Maximum number of times a failed HTTP request will be retried. See
+    /// method `with_max_retries()` for more information.
+    #[cfg(feature = "reqwest")]
+    max_retries: Option<u32>,
+
+    /// Maximum duration of each HTTP request attempt. See method
+    /// `with_timeout()` for more information.
+    #[cfg(feature = "reqwest")]
+    timeout: Option<std::time::Duration>,
+
+    /// Maximum total duration of the request, including retries. See method
+    /// `with_deadline()` for more information.
+    #[cfg(feature = "reqwest")]
+    deadline: Option<std::time::Duration>,
+
+    // Internal use only:
+    // ------------------
+    /// Query string that is to be submitted to the Google Cloud Maps Platform.
+    query: Option<QueryString>,
+} // struct
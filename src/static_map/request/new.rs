@@ -0,0 +1,52 @@
+use crate::client::GoogleMapsClient;
+use crate::static_map::request::Request;
+
+// =============================================================================
+
+/// The size of the image if `with_size()` is not called: 640 × 640 pixels,
+/// which is the largest size available to all Maps Static API customers.
+
+const DEFAULT_SIZE: (u16, u16) = (640, 640);
+
+// =============================================================================
+
+impl Request<'_> {
+    // -------------------------------------------------------------------------
+    //
+    /// Initializes the builder pattern for a Maps Static API query. The map
+    /// must be positioned before the query is executed, either by setting a
+    /// center and zoom level with the `with_center()` and `with_zoom()`
+    /// methods, or by adding markers or paths for Google to fit the map to.
+    ///
+    /// ## Arguments
+    ///
+    /// * `client` ‧ Your application's Google Maps API client struct.
+
+    #[must_use]
+    pub const fn new(client: &GoogleMapsClient) -> Request<'_> {
+        // Instantiate struct and return it to caller:
+        Request {
+            // Required parameters:
+            client,
+            size: DEFAULT_SIZE,
+            // Optional parameters:
+            center: None,
+            map_type: None,
+            markers: Vec::new(),
+            paths: Vec::new(),
+            scale: None,
+            zoom: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
+            // Internal use only:
+            query: None,
+        } // struct
+    } // fn
+} // impl
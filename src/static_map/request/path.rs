@@ -0,0 +1,213 @@
+//! Contains the `Path` struct and the `Points` enum. They are used to draw a
+//! line through a set of locations on a static map.
+
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+//
+/// A [path](https://developers.google.com/maps/documentation/maps-static/start#Paths)
+/// that is drawn as a line through two or more connected points. Each path is
+/// sent to Google as one `path` parameter, and a map may have several paths.
+///
+/// A path through a list of coordinates is sent as an [encoded
+/// polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm)
+/// when that is shorter, which it is for all but the shortest paths. This
+/// keeps the URL within Google's length limit for long routes. Encoded
+/// polylines round coordinates to 5 decimal places, or about a metre.
+///
+/// ## Examples:
+///
+/// ```rust
+/// use google_maps::static_map::Path;
+///
+/// let path = Path::new([
+///     LatLng::try_from_dec(dec!(40.737102), dec!(-73.990318))?,
+///     LatLng::try_from_dec(dec!(40.749825), dec!(-73.987963))?,
+/// ])
+/// .with_color("0x0000ff")
+/// .with_fill_color("0xFFFF0033")
+/// .with_weight(5);
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Path {
+    /// The color of the line, either as a 24-bit hexadecimal color such as
+    /// `0xFFFFCC`, a 32-bit hexadecimal color that includes an alpha channel
+    /// such as `0xFFFFCC80`, or one of Google's predefined colors such as
+    /// `blue`. If `None`, Google's semi-transparent blue is used.
+    pub color: Option<String>,
+    /// The color that the area enclosed by the path is filled with, in the
+    /// same formats as `color`. If `None`, the area is not filled. The path
+    /// does not need to be closed, Google closes it when it is filled.
+    pub fill_color: Option<String>,
+    /// The thickness of the line in pixels. If `None`, Google uses a thickness
+    /// of 5 pixels.
+    pub weight: Option<u8>,
+    /// If `true`, the line is drawn as a geodesic that follows the curvature
+    /// of the earth, rather than as a straight line on the map.
+    pub geodesic: bool,
+    /// The points that the path is drawn through.
+    pub points: Points,
+} // struct
+
+// -----------------------------------------------------------------------------
+//
+/// The points that a `Path` is drawn through.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Points {
+    /// A list of coordinates. It is sent as an encoded polyline when that is
+    /// shorter.
+    LatLngs(Vec<LatLng>),
+    /// An [encoded
+    /// polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm),
+    /// such as a route's `overview_polyline`. It is sent as-is.
+    Polyline(String),
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl std::default::Default for Points {
+    /// Returns an empty list of coordinates.
+    fn default() -> Self {
+        Self::LatLngs(Vec::new())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Points> for String {
+    /// Converts the `Points` enum to the points portion of a `path` parameter,
+    /// whichever of a pipe-separated list of coordinates or an `enc:` encoded
+    /// polyline is shorter.
+    fn from(points: &Points) -> Self {
+        match points {
+            Points::LatLngs(latlngs) => {
+                let list = latlngs.iter().map(Self::from).collect::<Vec<Self>>().join("|");
+                let polyline = format!("enc:{}", crate::polyline::encode(latlngs));
+                if polyline.len() < list.len() {
+                    polyline
+                } else {
+                    list
+                } // if
+            } // LatLngs
+            Points::Polyline(polyline) => format!("enc:{polyline}"),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Path {
+    /// Creates a path, in Google's default style, through the specified
+    /// locations.
+
+    pub fn new<L: Into<LatLng>>(locations: impl IntoIterator<Item = L>) -> Self {
+        Self {
+            points: Points::LatLngs(locations.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        } // struct
+    } // fn
+
+    /// Creates a path, in Google's default style, from an [encoded
+    /// polyline](https://developers.google.com/maps/documentation/utilities/polylinealgorithm),
+    /// such as a route's `overview_polyline`.
+
+    pub fn from_polyline(polyline: impl Into<String>) -> Self {
+        Self {
+            points: Points::Polyline(polyline.into()),
+            ..Default::default()
+        } // struct
+    } // fn
+
+    /// Sets the color of the line, for example `blue` or `0x0000ff80`.
+
+    #[must_use]
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    } // fn
+
+    /// Sets the color that the area enclosed by the path is filled with, for
+    /// example `0xFFFF0033`.
+
+    #[must_use]
+    pub fn with_fill_color(mut self, fill_color: impl Into<String>) -> Self {
+        self.fill_color = Some(fill_color.into());
+        self
+    } // fn
+
+    /// Sets the thickness of the line in pixels.
+
+    #[must_use]
+    pub const fn with_weight(mut self, weight: u8) -> Self {
+        self.weight = Some(weight);
+        self
+    } // fn
+
+    /// Sets whether the line is drawn as a geodesic that follows the curvature
+    /// of the earth.
+
+    #[must_use]
+    pub const fn with_geodesic(mut self, geodesic: bool) -> Self {
+        self.geodesic = geodesic;
+        self
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Path> for String {
+    /// Converts a `Path` struct to the value of a `path` parameter: the style
+    /// descriptors, followed by the points, separated by pipes. For example,
+    /// `color:0x0000ff|weight:5|40.737102,-73.990318|40.749825,-73.987963`.
+    fn from(path: &Path) -> Self {
+        let mut parts: Vec<Self> = Vec::with_capacity(5);
+
+        if let Some(color) = &path.color {
+            parts.push(format!("color:{color}"));
+        } // if
+
+        if let Some(fill_color) = &path.fill_color {
+            parts.push(format!("fillcolor:{fill_color}"));
+        } // if
+
+        if let Some(weight) = &path.weight {
+            parts.push(format!("weight:{weight}"));
+        } // if
+
+        if path.geodesic {
+            parts.push(Self::from("geodesic:true"));
+        } // if
+
+        parts.push(Self::from(&path.points));
+        parts.join("|")
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Path {
+    /// Formats a `Path` struct as the value of a `path` parameter.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", String::from(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<Vec<LatLng>> for Path {
+    /// Creates a path, in Google's default style, through the locations.
+    fn from(locations: Vec<LatLng>) -> Self {
+        Self::new(locations)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&[LatLng]> for Path {
+    /// Creates a path, in Google's default style, through the locations.
+    fn from(locations: &[LatLng]) -> Self {
+        Self::new(locations.iter().copied())
+    } // fn
+} // impl
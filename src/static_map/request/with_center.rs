@@ -0,0 +1,32 @@
+use crate::static_map::request::Request;
+use crate::types::LatLng;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the center of the map.
+    ///
+    /// ## Arguments
+    ///
+    /// * `center` ‧ The location at the center of the map, equidistant from
+    ///   all of its edges. A map that is positioned by its center must also
+    ///   have a zoom level, unless it has markers or paths that Google can fit
+    ///   the map to.
+    ///
+    /// ## Example
+    ///
+    /// * Center the map on the Brooklyn Bridge:
+    /// ```rust
+    /// .with_center(LatLng::try_from_dec(dec!(40.706086), dec!(-73.996864))?)
+    /// ```
+
+    pub fn with_center(
+        &'a mut self,
+        center: impl Into<LatLng>
+    ) -> &'a mut Self {
+        // Set center in Request struct.
+        self.center = Some(center.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,30 @@
+use crate::static_map::request::{map_type::MapType, Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the type of map to construct.
+    ///
+    /// ## Arguments
+    ///
+    /// * `map_type` ‧ A roadmap (the default), a satellite image, a terrain
+    ///   map, or a hybrid of a satellite image and a roadmap. See the
+    ///   `MapType` enum for more information.
+    ///
+    /// ## Example
+    ///
+    /// * Request a satellite image:
+    /// ```rust
+    /// .with_map_type(MapType::Satellite)
+    /// ```
+
+    pub fn with_map_type(
+        &'a mut self,
+        map_type: impl Into<MapType>
+    ) -> &'a mut Self {
+        // Set map type in Request struct.
+        self.map_type = Some(map_type.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,49 @@
+use crate::static_map::request::{markers::Markers, Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds a group of markers to the map.
+    ///
+    /// ## Arguments
+    ///
+    /// * `markers` ‧ The locations of the markers, and their size, color, and
+    ///   label. A location, or a `Vec` of locations, may be passed to place
+    ///   markers in Google's default style. See the `Markers` struct for more
+    ///   information.
+    ///
+    /// ## Description
+    ///
+    /// This method may be called more than once to add markers in different
+    /// styles. If the map has no center or zoom level, Google positions the
+    /// map so that all of its markers are visible.
+    ///
+    /// ## Examples:
+    ///
+    /// * Mark a single location:
+    /// ```rust
+    /// .with_markers(LatLng::try_from_dec(dec!(40.702147), dec!(-74.015794))?)
+    /// ```
+    ///
+    /// * Mark two locations with blue markers labelled `S`:
+    /// ```rust
+    /// .with_markers(
+    ///     Markers::new([
+    ///         LatLng::try_from_dec(dec!(40.702147), dec!(-74.015794))?,
+    ///         LatLng::try_from_dec(dec!(40.711614), dec!(-74.012318))?,
+    ///     ])
+    ///     .with_color("blue")
+    ///     .with_label('S')
+    /// )
+    /// ```
+
+    pub fn with_markers(
+        &'a mut self,
+        markers: impl Into<Markers>
+    ) -> &'a mut Self {
+        // Add markers to Request struct.
+        self.markers.push(markers.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
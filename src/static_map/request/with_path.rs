@@ -0,0 +1,45 @@
+use crate::static_map::request::{path::Path, Request};
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Adds a path to the map.
+    ///
+    /// ## Arguments
+    ///
+    /// * `path` ‧ The locations that the path is drawn through, and its color,
+    ///   weight, and shape. A `Vec` of locations may be passed to draw a path
+    ///   in Google's default style. See the `Path` struct for more
+    ///   information.
+    ///
+    /// ## Description
+    ///
+    /// This method may be called more than once to draw several paths. If the
+    /// map has no center or zoom level, Google positions the map so that all
+    /// of its paths are visible.
+    ///
+    /// ## Examples:
+    ///
+    /// * Draw a line between two locations:
+    /// ```rust
+    /// .with_path(vec![
+    ///     LatLng::try_from_dec(dec!(40.737102), dec!(-73.990318))?,
+    ///     LatLng::try_from_dec(dec!(40.749825), dec!(-73.987963))?,
+    /// ])
+    /// ```
+    ///
+    /// * Draw a route's overview polyline in red:
+    /// ```rust
+    /// .with_path(Path::from_polyline(&route.overview_polyline.points).with_color("red"))
+    /// ```
+
+    pub fn with_path(
+        &'a mut self,
+        path: impl Into<Path>
+    ) -> &'a mut Self {
+        // Add path to Request struct.
+        self.paths.push(path.into());
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,33 @@
+use crate::static_map::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the [scale](https://developers.google.com/maps/documentation/maps-static/start#scale_values)
+    /// of the image, which multiplies the number of pixels that are returned
+    /// while keeping the same coverage area and level of detail.
+    ///
+    /// ## Arguments
+    ///
+    /// * `scale` ‧ `1` (the default), `2` for high-resolution displays, or
+    ///   `4` for Premium Plan customers only. For example, a scale of `2`
+    ///   with a size of 640 × 640 pixels returns an image that is 1280 × 1280
+    ///   pixels.
+    ///
+    /// ## Example
+    ///
+    /// * Request a high-resolution image:
+    /// ```rust
+    /// .with_scale(2)
+    /// ```
+
+    pub fn with_scale(
+        &'a mut self,
+        scale: u8
+    ) -> &'a mut Self {
+        // Set scale in Request struct.
+        self.scale = Some(scale);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,36 @@
+use crate::static_map::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the size of the image.
+    ///
+    /// ## Arguments
+    ///
+    /// * `width` ‧ The width of the image, in pixels.
+    ///
+    /// * `height` ‧ The height of the image, in pixels.
+    ///
+    /// Images may be up to 640 × 640 pixels, which is also the default size.
+    /// The image that is returned is larger if a scale is set, see
+    /// `with_scale()` for more information. Google crops the image, rather
+    /// than failing, if a size larger than your account allows is requested.
+    ///
+    /// ## Example
+    ///
+    /// * Request an image that is 600 pixels wide and 300 pixels high:
+    /// ```rust
+    /// .with_size(600, 300)
+    /// ```
+
+    pub fn with_size(
+        &'a mut self,
+        width: u16,
+        height: u16
+    ) -> &'a mut Self {
+        // Set size in Request struct.
+        self.size = (width, height);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
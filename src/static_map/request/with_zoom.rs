@@ -0,0 +1,34 @@
+use crate::static_map::request::Request;
+
+// -----------------------------------------------------------------------------
+
+impl<'a> Request<'a> {
+    /// Specifies the [zoom
+    /// level](https://developers.google.com/maps/documentation/maps-static/start#Zoomlevels)
+    /// of the map.
+    ///
+    /// ## Arguments
+    ///
+    /// * `zoom` ‧ A zoom level between `0`, which shows the whole world, and
+    ///   `21`, which shows individual buildings. Each zoom level doubles the
+    ///   precision of the level before it. For example, `1` shows the world,
+    ///   `5` a continent, `10` a city, `15` streets, and `20` buildings. If no
+    ///   zoom level is set, Google fits the map to its markers and paths.
+    ///
+    /// ## Example
+    ///
+    /// * Show the streets around the center of the map:
+    /// ```rust
+    /// .with_zoom(15)
+    /// ```
+
+    pub fn with_zoom(
+        &'a mut self,
+        zoom: u8
+    ) -> &'a mut Self {
+        // Set zoom in Request struct.
+        self.zoom = Some(zoom);
+        // Return modified Request struct to caller.
+        self
+    } // fn
+} // impl
@@ -0,0 +1,19 @@
+//! Resources (structs) for processing the _Maps Static API_ response from the
+//! Google Maps Platform. Google responds with the map image itself rather than
+//! a JSON document.
+
+// -----------------------------------------------------------------------------
+//
+/// The image returned by the Google Maps Static API will be stored in this
+/// structure.
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Response {
+    /// The MIME type of the image, as reported by the `Content-Type` header of
+    /// the response, for example `image/png`. It is `None` if the server did
+    /// not send a valid header.
+    pub content_type: Option<String>,
+
+    /// The image data.
+    pub bytes: Vec<u8>,
+} // struct
@@ -6,7 +6,7 @@ use chrono::{DateTime, Utc};
 // =============================================================================
 
 // Explict lifetime required for `geo` feature
-#[allow(clippy::needless_lifetimes)]
+#[allow(clippy::needless_lifetimes, clippy::elidable_lifetime_names)]
 impl<'r> Request<'r> {
     // -------------------------------------------------------------------------
     //
@@ -42,7 +42,7 @@ impl<'r> Request<'r> {
         client: &GoogleMapsClient,
         location: LatLng,
         timestamp: DateTime<Utc>
-    ) -> Request {
+    ) -> Request<'_> {
         // Instantiate struct and return it to caller:
         Request {
             // Required parameters:
@@ -51,6 +51,15 @@ impl<'r> Request<'r> {
             timestamp,
             // Optional parameters:
             language: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         } // struct
@@ -86,6 +95,15 @@ impl<'r> Request<'r> {
             timestamp,
             // Optional parameters:
             language: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         }) // struct
@@ -121,6 +139,15 @@ impl<'r> Request<'r> {
             timestamp,
             // Optional parameters:
             language: None,
+            // Retry settings:
+            #[cfg(feature = "reqwest")]
+            backoff: None,
+            #[cfg(feature = "reqwest")]
+            max_retries: None,
+            #[cfg(feature = "reqwest")]
+            timeout: None,
+            #[cfg(feature = "reqwest")]
+            deadline: None,
             // Internal use only:
             query: None,
         }) // struct
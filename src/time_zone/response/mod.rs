@@ -5,6 +5,7 @@
 pub mod status;
 
 use crate::time_zone::response::status::Status;
+use chrono::{DateTime, FixedOffset, Offset, Utc};
 use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +21,10 @@ use serde::{Deserialize, Serialize};
 ///
 /// The local time of a given location is the sum of the timestamp parameter,
 /// and the dstOffset and rawOffset fields from the result.
+///
+/// The offsets are only valid for the requested instant, which is kept in the
+/// `timestamp` field. Use `utc_offset_at()` to get the offset at any other
+/// instant, for example on the other side of a daylight savings transition.
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct Response {
@@ -28,7 +33,7 @@ pub struct Response {
     /// `time`.
     #[serde(alias = "dstOffset")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub dst_offset: Option<i16>,
+    pub dst_offset: Option<i32>,
 
     /// More detailed information about the reasons behind the given status
     /// code, if other than `OK`.
@@ -43,7 +48,7 @@ pub struct Response {
     /// take into effect daylight savings.
     #[serde(alias = "rawOffset")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub raw_offset: Option<i16>,
+    pub raw_offset: Option<i32>,
 
     /// The status of the response.
     pub status: Status,
@@ -66,8 +71,89 @@ pub struct Response {
     #[serde(alias = "timeZoneName")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub time_zone_name: Option<String>,
+
+    /// The instant that the `dst_offset`, `raw_offset`, and `time_zone_name`
+    /// are for, which is the `timestamp` of the request. It is not part of
+    /// Google's response: it is set by `get()` and `execute()`, and is `None`
+    /// if the response was parsed from JSON.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime<Utc>>,
 } // struct
 
+// -----------------------------------------------------------------------------
+
+impl Response {
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the time zone of the location, which can be used to convert
+    /// timestamps to the location's local time with `chrono`.
+    ///
+    /// Returns `None` if Google did not return a time zone, for example when
+    /// the status is `ZERO_RESULTS` because the location is in the ocean.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// if let Some(time_zone) = response.time_zone() {
+    ///     let local_time = chrono::Utc::now().with_timezone(&time_zone);
+    /// }
+    /// ```
+
+    #[must_use]
+    pub const fn time_zone(&self) -> Option<Tz> {
+        self.time_zone_id
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the total offset from UTC at the requested time, `timestamp`:
+    /// the sum of the `raw_offset` and the `dst_offset`.
+    ///
+    /// Returns `None` if Google did not return the offsets, for example when
+    /// the status is `ZERO_RESULTS`.
+
+    #[must_use]
+    pub fn utc_offset(&self) -> Option<FixedOffset> {
+        let seconds = self.raw_offset?.checked_add(self.dst_offset.unwrap_or_default())?;
+        FixedOffset::east_opt(seconds)
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the total offset from UTC of the location at any instant,
+    /// without sending another request to Google.
+    ///
+    /// Google's offsets are only valid at the requested `timestamp`. Instead,
+    /// this looks the instant up in the IANA time zone database that is built
+    /// into `chrono-tz`, using the time zone returned by Google. The result
+    /// therefore accounts for daylight savings transitions, but a very recent
+    /// change to a country's time zone rules may not be known to `chrono-tz`
+    /// yet. Send another request for the instant if that matters.
+    ///
+    /// Returns `None` if Google did not return a time zone, for example when
+    /// the status is `ZERO_RESULTS`.
+    ///
+    /// ## Arguments
+    ///
+    /// * `instant` ‧ The instant to get the location's offset at.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// let new_year = chrono::Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+    /// let offset = response.utc_offset_at(new_year);
+    /// ```
+
+    #[must_use]
+    pub fn utc_offset_at(&self, instant: DateTime<Utc>) -> Option<FixedOffset> {
+        let time_zone = self.time_zone_id?;
+        Some(instant.with_timezone(&time_zone).offset().fix())
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::str::FromStr for Response {
     type Err = simd_json::Error;
     /// Parse a Google Maps Time Zone API JSON `String` response into a
@@ -10,6 +10,8 @@ mod geo;
 use crate::types::error::Error as GoogleMapsError;
 use crate::types::error::Error as TypeError;
 use crate::types::latlng::LatLng;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 
 // -----------------------------------------------------------------------------
@@ -86,3 +88,132 @@ impl std::str::FromStr for Bounds {
         } // if
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+impl Bounds {
+    /// Creates a bounding box from its south-west and north-east corners.
+    ///
+    /// A bounding box whose south-west longitude is greater than its
+    /// north-east longitude crosses the antimeridian (the ±180° line of
+    /// longitude). For example, a box from `-10,170` to `10,-170` covers the
+    /// 20° of longitude either side of the antimeridian.
+    ///
+    /// ```rust
+    /// let downtown_toronto = Bounds::new(
+    ///     LatLng::try_new(43.639_8, -79.396_6)?,
+    ///     LatLng::try_new(43.656_3, -79.372_3)?,
+    /// );
+    /// ```
+
+    #[must_use]
+    pub const fn new(southwest: LatLng, northeast: LatLng) -> Self {
+        Self { southwest, northeast }
+    } // fn
+
+    /// Creates the smallest bounding box that contains all of the positions,
+    /// for example to fit a map's viewport to a set of geocoding results or
+    /// waypoints. `None` is returned if there are no positions.
+    ///
+    /// The antimeridian (the ±180° line of longitude) is handled: positions on
+    /// either side of it, such as in Fiji, produce a narrow box that crosses
+    /// it, rather than a box that spans the rest of the globe. Use
+    /// `crosses_antimeridian()` to check for this. Boxes never cross the poles.
+    ///
+    /// ```rust
+    /// let locations: Vec<LatLng> = geocoding_response
+    ///     .results
+    ///     .iter()
+    ///     .map(|result| result.geometry.location)
+    ///     .collect();
+    ///
+    /// let viewport = Bounds::from_points(&locations);
+    /// ```
+
+    #[must_use]
+    pub fn from_points(positions: &[LatLng]) -> Option<Self> {
+        let south = positions.iter().map(|position| position.lat).min()?;
+        let north = positions.iter().map(|position| position.lat).max()?;
+
+        let mut longitudes: Vec<Decimal> =
+            positions.iter().map(|position| position.lng).collect();
+        longitudes.sort_unstable();
+        longitudes.dedup();
+
+        // The box covers every longitude except the widest gap between the
+        // positions. Unless a wider gap is found, that is the gap that goes
+        // around the back of the globe from the most easterly position to the
+        // most westerly one, and the box does not cross the antimeridian:
+        let mut west = *longitudes.first()?;
+        let mut east = *longitudes.last()?;
+        let mut widest_gap = west + dec!(360) - east;
+
+        for pair in longitudes.windows(2) {
+            let gap = pair[1] - pair[0];
+            if gap > widest_gap {
+                widest_gap = gap;
+                west = pair[1];
+                east = pair[0];
+            } // if
+        } // for
+
+        Some(Self::new(
+            LatLng { lat: south, lng: west },
+            LatLng { lat: north, lng: east },
+        ))
+    } // fn
+
+    /// Returns whether the bounding box crosses the antimeridian (the ±180°
+    /// line of longitude).
+
+    #[must_use]
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.southwest.lng > self.northeast.lng
+    } // fn
+
+    /// Returns whether a position is inside the bounding box. Positions on the
+    /// edges of the box are inside it.
+    ///
+    /// ```rust
+    /// if geocoding.geometry.viewport.contains(&my_location) { ... }
+    /// ```
+
+    #[must_use]
+    pub fn contains(&self, position: &LatLng) -> bool {
+        let contains_lat =
+            position.lat >= self.southwest.lat && position.lat <= self.northeast.lat;
+
+        let contains_lng = if self.crosses_antimeridian() {
+            position.lng >= self.southwest.lng || position.lng <= self.northeast.lng
+        } else {
+            position.lng >= self.southwest.lng && position.lng <= self.northeast.lng
+        }; // if
+
+        contains_lat && contains_lng
+    } // fn
+
+    /// Returns the centre of the bounding box, halfway between its corners in
+    /// both latitude and longitude.
+    ///
+    /// ```rust
+    /// let center = geocoding.geometry.viewport.center();
+    /// ```
+
+    #[must_use]
+    pub fn center(&self) -> LatLng {
+        let lat = (self.southwest.lat + self.northeast.lat) / dec!(2);
+
+        // A box that crosses the antimeridian is centred on the far side of
+        // the Earth from the average of its corners' longitudes:
+        let mut lng = if self.crosses_antimeridian() {
+            (self.southwest.lng + self.northeast.lng + dec!(360)) / dec!(2)
+        } else {
+            (self.southwest.lng + self.northeast.lng) / dec!(2)
+        }; // if
+        if lng > dec!(180) {
+            lng -= dec!(360);
+        } // if
+
+        LatLng { lat, lng }
+    } // fn
+} // impl
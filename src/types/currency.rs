@@ -0,0 +1,913 @@
+//! Contains the `Currency` enum and its associated traits. It is used to
+//! specify a currency, for example the currency of the transit fares returned
+//! by the Google Maps Directions API.
+
+use crate::error::Error as GoogleMapsError;
+use phf::phf_map;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// -----------------------------------------------------------------------------
+
+/// A comprehensive list of [ISO 4217](https://en.wikipedia.org/wiki/ISO_4217)
+/// currencies, shared by every API that returns an amount of money. The intent behind having _Serde_ convert
+/// the currency code `String` to an `enum` is for efficient currency
+/// conversions, information lookups, and manipulation in the future.
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u16)]
+#[non_exhaustive]
+pub enum Currency {
+    UnitedArabEmiratesDirham = 0,
+    AfghanAfghani = 1,
+    AlbanianLek = 2,
+    ArmenianDram = 3,
+    NetherlandsAntilleanGuilder = 4,
+    AngolanKwanza = 5,
+    ArgentinePeso = 6,
+    AustralianDollar = 7,
+    ArubanFlorin = 8,
+    AzerbaijaniManat = 9,
+    BosniaAndHerzegovinaConvertibleMark = 10,
+    BarbadosDollar = 11,
+    BangladeshiTaka = 12,
+    BulgarianLev = 13,
+    BahrainiDinar = 14,
+    BurundianFranc = 15,
+    BermudianDollar = 16,
+    BruneiDollar = 17,
+    Boliviano = 18,
+    BolivianMvdol = 19,
+    BrazilianReal = 20,
+    BahamianDollar = 21,
+    BhutaneseNgultrum = 22,
+    BotswanaPula = 23,
+    BelarusianRuble = 24,
+    BelizeDollar = 25,
+    CanadianDollar = 26,
+    CongoleseFranc = 27,
+    WirEuro = 28,
+    SwissFranc = 29,
+    WirFranc = 30,
+    UnidadDeFomento = 31,
+    ChileanPeso = 32,
+    RenminbiYuan = 33,
+    ColombianPeso = 34,
+    UnidadDeValorReal = 35,
+    CostaRicanColon = 36,
+    CubanConvertiblePeso = 37,
+    CubanPeso = 38,
+    CapeVerdeanEscudo = 39,
+    CzechKoruna = 40,
+    DjiboutianFranc = 41,
+    DanishKrone = 42,
+    DominicanPeso = 43,
+    AlgerianDinar = 44,
+    EgyptianPound = 45,
+    EritreanNakfa = 46,
+    EthiopianBirr = 47,
+    Euro = 48,
+    FijiDollar = 49,
+    FalklandIslandsPound = 50,
+    PoundSterling = 51,
+    GeorgianLari = 52,
+    GhanaianCedi = 53,
+    GibraltarPound = 54,
+    GambianDalasi = 55,
+    GuineanFranc = 56,
+    GuatemalanQuetzal = 57,
+    GuyaneseDollar = 58,
+    HongKongDollar = 59,
+    HonduranLempira = 60,
+    CroatianKuna = 61,
+    HaitianGourde = 62,
+    HungarianForint = 63,
+    IndonesianRupiah = 64,
+    IsraeliNewShekel = 65,
+    IndianRupee = 66,
+    IraqiDinar = 67,
+    IranianRial = 68,
+    IcelandicKrona = 69,
+    JamaicanDollar = 70,
+    JordanianDinar = 71,
+    JapaneseYen = 72,
+    KenyanShilling = 73,
+    KyrgyzstaniSom = 74,
+    CambodianRiel = 75,
+    ComoroFranc = 76,
+    NorthKoreanWon = 77,
+    SouthKoreanWon = 78,
+    KuwaitiDinar = 79,
+    CaymanIslandsDollar = 80,
+    KazakhstaniTenge = 81,
+    LaoKip = 82,
+    LebanesePound = 83,
+    SriLankanRupee = 84,
+    LiberianDollar = 85,
+    LesothoLoti = 86,
+    LibyanDinar = 87,
+    MoroccanDirham = 88,
+    MoldovanLeu = 89,
+    MalagasyAriary = 90,
+    MacedonianDenar = 91,
+    MyanmarKyat = 92,
+    MongolianTogrog = 93,
+    MacanesePataca = 94,
+    MauritanianOuguiya = 95,
+    MauritianRupee = 96,
+    MaldivianRufiyaa = 97,
+    MalawianKwacha = 98,
+    MexicanPeso = 99,
+    MexicanUnidadDeInversion = 100,
+    MalaysianRinggit = 101,
+    MozambicanMetical = 102,
+    NamibianDollar = 103,
+    NigerianNaira = 104,
+    NicaraguanCordoba = 105,
+    NorwegianKrone = 106,
+    NepaleseRupee = 107,
+    NewZealandDollar = 108,
+    OmaniRial = 109,
+    PanamanianBalboa = 110,
+    PeruvianSol = 111,
+    PapuaNewGuineanKina = 112,
+    PhilippinePeso = 113,
+    PakistaniRupee = 114,
+    PolishZloty = 115,
+    ParaguayanGuarani = 116,
+    QatariRiyal = 117,
+    RomanianLeu = 118,
+    SerbianDinar = 119,
+    RussianRuble = 120,
+    RwandanFranc = 121,
+    SaudiRiyal = 122,
+    SolomonIslandsDollar = 123,
+    SeychellesRupee = 124,
+    SudanesePound = 125,
+    SwedishKrona = 126,
+    SingaporeDollar = 127,
+    SaintHelenaPound = 128,
+    SierraLeoneanLeone = 129,
+    SomaliShilling = 130,
+    SurinameseDollar = 131,
+    SouthSudanesePound = 132,
+    SaoTomeAndPrincipeDobra = 133,
+    SalvadoranColon = 134,
+    SyrianPound = 135,
+    SwaziLilangeni = 136,
+    ThaiBaht = 137,
+    TajikistaniSomoni = 138,
+    TurkmenistanManat = 139,
+    TunisianDinar = 140,
+    TonganPaanga = 141,
+    TurkishLira = 142,
+    TrinidadAndTobagoDollar = 143,
+    NewTaiwanDollar = 144,
+    TanzanianShilling = 145,
+    UkrainianHryvnia = 146,
+    UgandanShilling = 147,
+    #[default]
+    UnitedStatesDollar = 148,
+    UnitedStatesDollarNextDay = 149,
+    UruguayPesoEnUnidadesIndexadas = 150,
+    UruguayanPeso = 151,
+    UnidadPrevisional = 152,
+    UzbekistanSom = 153,
+    VenezuelanBolivarSoberano = 154,
+    VietnameseDong = 155,
+    VanuatuVatu = 156,
+    SamoanTala = 157,
+    CfaFrancBeac = 158,
+    Silver = 159,
+    Gold = 160,
+    EuropeanCompositeUnit = 161,
+    EuropeanMonetaryUnit = 162,
+    EuropeanUnitOfAccount9 = 163,
+    EuropeanUnitOfAccount17 = 164,
+    EastCaribbeanDollar = 165,
+    SpecialDrawingRights = 166,
+    CfaFrancBceao = 167,
+    Palladium = 168,
+    CfpFranc = 169,
+    Platinum = 170,
+    Sucre = 171,
+    CodeReservedForTesting = 172,
+    AdbUnitOfAccount = 173,
+    NoCurrency = 174,
+    YemeniRial = 175,
+    SouthAfricanRand = 176,
+    ZambianKwacha = 177,
+    ZimbabweanDollar = 178,
+    /// If the currency is not recognized by
+    /// [serde](https://crates.io/crates/serde) when reading data from
+    /// Google it will be assigned to this `Other` variant.
+    ///
+    /// As new types are added to Google Maps, they must also be added to this
+    /// crate. However, in the meantime, the `Other` catch-all variant allows
+    /// `serde` to read data from Google without producing an error until the
+    /// new variant added to this `enum`.
+    Other(String) = 179,
+} // enum
+
+// -----------------------------------------------------------------------------
+
+impl<'de> Deserialize<'de> for Currency {
+    /// Manual implementation of `Deserialize` for `serde`. This will take
+    /// advantage of the `phf`-powered `TryFrom` implementation for this type.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        match Self::try_from(string.as_str()) {
+            Ok(variant) => Ok(variant),
+            Err(error) => Err(serde::de::Error::custom(error.to_string())),
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Serialize for Currency {
+    /// Manual implementation of `Serialize` for `serde`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(std::convert::Into::<&str>::into(self))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl<'a> std::convert::From<&'a Currency> for &'a str {
+    /// Converts a `Currency` enum to a `String` that contains an [ISO
+    /// 4217 currency code](https://en.wikipedia.org/wiki/ISO_4217).
+    fn from(currency: &'a Currency) -> Self {
+        match currency {
+            Currency::UnitedArabEmiratesDirham => "AED",
+            Currency::AfghanAfghani => "AFN",
+            Currency::AlbanianLek => "ALL",
+            Currency::ArmenianDram => "AMD",
+            Currency::NetherlandsAntilleanGuilder => "ANG",
+            Currency::AngolanKwanza => "AOA",
+            Currency::ArgentinePeso => "ARS",
+            Currency::AustralianDollar => "AUD",
+            Currency::ArubanFlorin => "AWG",
+            Currency::AzerbaijaniManat => "AZN",
+            Currency::BosniaAndHerzegovinaConvertibleMark => "BAM",
+            Currency::BarbadosDollar => "BBD",
+            Currency::BangladeshiTaka => "BDT",
+            Currency::BulgarianLev => "BGN",
+            Currency::BahrainiDinar => "BHD",
+            Currency::BurundianFranc => "BIF",
+            Currency::BermudianDollar => "BMD",
+            Currency::BruneiDollar => "BND",
+            Currency::Boliviano => "BOB",
+            Currency::BolivianMvdol => "BOV",
+            Currency::BrazilianReal => "BRL",
+            Currency::BahamianDollar => "BSD",
+            Currency::BhutaneseNgultrum => "BTN",
+            Currency::BotswanaPula => "BWP",
+            Currency::BelarusianRuble => "BYN",
+            Currency::BelizeDollar => "BZD",
+            Currency::CanadianDollar => "CAD",
+            Currency::CongoleseFranc => "CDF",
+            Currency::WirEuro => "CHE",
+            Currency::SwissFranc => "CHF",
+            Currency::WirFranc => "CHW",
+            Currency::UnidadDeFomento => "CLF",
+            Currency::ChileanPeso => "CLP",
+            Currency::RenminbiYuan => "CNY",
+            Currency::ColombianPeso => "COP",
+            Currency::UnidadDeValorReal => "COU",
+            Currency::CostaRicanColon => "CRC",
+            Currency::CubanConvertiblePeso => "CUC",
+            Currency::CubanPeso => "CUP",
+            Currency::CapeVerdeanEscudo => "CVE",
+            Currency::CzechKoruna => "CZK",
+            Currency::DjiboutianFranc => "DJF",
+            Currency::DanishKrone => "DKK",
+            Currency::DominicanPeso => "DOP",
+            Currency::AlgerianDinar => "DZD",
+            Currency::EgyptianPound => "EGP",
+            Currency::EritreanNakfa => "ERN",
+            Currency::EthiopianBirr => "ETB",
+            Currency::Euro => "EUR",
+            Currency::FijiDollar => "FJD",
+            Currency::FalklandIslandsPound => "FKP",
+            Currency::PoundSterling => "GBP",
+            Currency::GeorgianLari => "GEL",
+            Currency::GhanaianCedi => "GHS",
+            Currency::GibraltarPound => "GIP",
+            Currency::GambianDalasi => "GMD",
+            Currency::GuineanFranc => "GNF",
+            Currency::GuatemalanQuetzal => "GTQ",
+            Currency::GuyaneseDollar => "GYD",
+            Currency::HongKongDollar => "HKD",
+            Currency::HonduranLempira => "HNL",
+            Currency::CroatianKuna => "HRK",
+            Currency::HaitianGourde => "HTG",
+            Currency::HungarianForint => "HUF",
+            Currency::IndonesianRupiah => "IDR",
+            Currency::IsraeliNewShekel => "ILS",
+            Currency::IndianRupee => "INR",
+            Currency::IraqiDinar => "IQD",
+            Currency::IranianRial => "IRR",
+            Currency::IcelandicKrona => "ISK",
+            Currency::JamaicanDollar => "JMD",
+            Currency::JordanianDinar => "JOD",
+            Currency::JapaneseYen => "JPY",
+            Currency::KenyanShilling => "KES",
+            Currency::KyrgyzstaniSom => "KGS",
+            Currency::CambodianRiel => "KHR",
+            Currency::ComoroFranc => "KMF",
+            Currency::NorthKoreanWon => "KPW",
+            Currency::SouthKoreanWon => "KRW",
+            Currency::KuwaitiDinar => "KWD",
+            Currency::CaymanIslandsDollar => "KYD",
+            Currency::KazakhstaniTenge => "KZT",
+            Currency::LaoKip => "LAK",
+            Currency::LebanesePound => "LBP",
+            Currency::SriLankanRupee => "LKR",
+            Currency::LiberianDollar => "LRD",
+            Currency::LesothoLoti => "LSL",
+            Currency::LibyanDinar => "LYD",
+            Currency::MoroccanDirham => "MAD",
+            Currency::MoldovanLeu => "MDL",
+            Currency::MalagasyAriary => "MGA",
+            Currency::MacedonianDenar => "MKD",
+            Currency::MyanmarKyat => "MMK",
+            Currency::MongolianTogrog => "MNT",
+            Currency::MacanesePataca => "MOP",
+            Currency::MauritanianOuguiya => "MRU",
+            Currency::MauritianRupee => "MUR",
+            Currency::MaldivianRufiyaa => "MVR",
+            Currency::MalawianKwacha => "MWK",
+            Currency::MexicanPeso => "MXN",
+            Currency::MexicanUnidadDeInversion => "MXV",
+            Currency::MalaysianRinggit => "MYR",
+            Currency::MozambicanMetical => "MZN",
+            Currency::NamibianDollar => "NAD",
+            Currency::NigerianNaira => "NGN",
+            Currency::NicaraguanCordoba => "NIO",
+            Currency::NorwegianKrone => "NOK",
+            Currency::NepaleseRupee => "NPR",
+            Currency::NewZealandDollar => "NZD",
+            Currency::OmaniRial => "OMR",
+            Currency::PanamanianBalboa => "PAB",
+            Currency::PeruvianSol => "PEN",
+            Currency::PapuaNewGuineanKina => "PGK",
+            Currency::PhilippinePeso => "PHP",
+            Currency::PakistaniRupee => "PKR",
+            Currency::PolishZloty => "PLN",
+            Currency::ParaguayanGuarani => "PYG",
+            Currency::QatariRiyal => "QAR",
+            Currency::RomanianLeu => "RON",
+            Currency::SerbianDinar => "RSD",
+            Currency::RussianRuble => "RUB",
+            Currency::RwandanFranc => "RWF",
+            Currency::SaudiRiyal => "SAR",
+            Currency::SolomonIslandsDollar => "SBD",
+            Currency::SeychellesRupee => "SCR",
+            Currency::SudanesePound => "SDG",
+            Currency::SwedishKrona => "SEK",
+            Currency::SingaporeDollar => "SGD",
+            Currency::SaintHelenaPound => "SHP",
+            Currency::SierraLeoneanLeone => "SLL",
+            Currency::SomaliShilling => "SOS",
+            Currency::SurinameseDollar => "SRD",
+            Currency::SouthSudanesePound => "SSP",
+            Currency::SaoTomeAndPrincipeDobra => "STN",
+            Currency::SalvadoranColon => "SVC",
+            Currency::SyrianPound => "SYP",
+            Currency::SwaziLilangeni => "SZL",
+            Currency::ThaiBaht => "THB",
+            Currency::TajikistaniSomoni => "TJS",
+            Currency::TurkmenistanManat => "TMT",
+            Currency::TunisianDinar => "TND",
+            Currency::TonganPaanga => "TOP",
+            Currency::TurkishLira => "TRY",
+            Currency::TrinidadAndTobagoDollar => "TTD",
+            Currency::NewTaiwanDollar => "TWD",
+            Currency::TanzanianShilling => "TZS",
+            Currency::UkrainianHryvnia => "UAH",
+            Currency::UgandanShilling => "UGX",
+            Currency::UnitedStatesDollar => "USD",
+            Currency::UnitedStatesDollarNextDay => "USN",
+            Currency::UruguayPesoEnUnidadesIndexadas => "UYI",
+            Currency::UruguayanPeso => "UYU",
+            Currency::UnidadPrevisional => "UYW",
+            Currency::UzbekistanSom => "UZS",
+            Currency::VenezuelanBolivarSoberano => "VES",
+            Currency::VietnameseDong => "VND",
+            Currency::VanuatuVatu => "VUV",
+            Currency::SamoanTala => "WST",
+            Currency::CfaFrancBeac => "XAF",
+            Currency::Silver => "XAG",
+            Currency::Gold => "XAU",
+            Currency::EuropeanCompositeUnit => "XBA",
+            Currency::EuropeanMonetaryUnit => "XBB",
+            Currency::EuropeanUnitOfAccount9 => "XBC",
+            Currency::EuropeanUnitOfAccount17 => "XBD",
+            Currency::EastCaribbeanDollar => "XCD",
+            Currency::SpecialDrawingRights => "XDR",
+            Currency::CfaFrancBceao => "XOF",
+            Currency::Palladium => "XPD",
+            Currency::CfpFranc => "CFP franc",
+            Currency::Platinum => "XPT",
+            Currency::Sucre => "XSU",
+            Currency::CodeReservedForTesting => "XTS",
+            Currency::AdbUnitOfAccount => "XUA",
+            Currency::NoCurrency => "XXX",
+            Currency::YemeniRial => "YER",
+            Currency::SouthAfricanRand => "ZAR",
+            Currency::ZambianKwacha => "ZMW",
+            Currency::ZimbabweanDollar => "ZWL",
+            Currency::Other(string) => string,
+        } // match
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Currency {
+    /// Converts a `Currency` enum to a `String` that contains an [ISO
+    /// 4217 currency code](https://en.wikipedia.org/wiki/ISO_4217).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", std::convert::Into::<&str>::into(self))
+    } // fmt
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<&Currency> for String {
+    /// Converts a `Currency` enum to a `String` that contains an [ISO
+    /// 4217 currency code](https://en.wikipedia.org/wiki/ISO_4217).
+    fn from(currency: &Currency) -> Self {
+        std::convert::Into::<&str>::into(currency).to_string()
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+static CURRENCIES_BY_CODE: phf::Map<&'static str, Currency> = phf_map! {
+    "AED" => Currency::UnitedArabEmiratesDirham,
+    "AFN" => Currency::AfghanAfghani,
+    "ALL" => Currency::AlbanianLek,
+    "AMD" => Currency::ArmenianDram,
+    "ANG" => Currency::NetherlandsAntilleanGuilder,
+    "AOA" => Currency::AngolanKwanza,
+    "ARS" => Currency::ArgentinePeso,
+    "AUD" => Currency::AustralianDollar,
+    "AWG" => Currency::ArubanFlorin,
+    "AZN" => Currency::AzerbaijaniManat,
+    "BAM" => Currency::BosniaAndHerzegovinaConvertibleMark,
+    "BBD" => Currency::BarbadosDollar,
+    "BDT" => Currency::BangladeshiTaka,
+    "BGN" => Currency::BulgarianLev,
+    "BHD" => Currency::BahrainiDinar,
+    "BIF" => Currency::BurundianFranc,
+    "BMD" => Currency::BermudianDollar,
+    "BND" => Currency::BruneiDollar,
+    "BOB" => Currency::Boliviano,
+    "BOV" => Currency::BolivianMvdol,
+    "BRL" => Currency::BrazilianReal,
+    "BSD" => Currency::BahamianDollar,
+    "BTN" => Currency::BhutaneseNgultrum,
+    "BWP" => Currency::BotswanaPula,
+    "BYN" => Currency::BelarusianRuble,
+    "BZD" => Currency::BelizeDollar,
+    "CAD" => Currency::CanadianDollar,
+    "CDF" => Currency::CongoleseFranc,
+    "CHE" => Currency::WirEuro,
+    "CHF" => Currency::SwissFranc,
+    "CHW" => Currency::WirFranc,
+    "CLF" => Currency::UnidadDeFomento,
+    "CLP" => Currency::ChileanPeso,
+    "CNY" => Currency::RenminbiYuan,
+    "COP" => Currency::ColombianPeso,
+    "COU" => Currency::UnidadDeValorReal,
+    "CRC" => Currency::CostaRicanColon,
+    "CUC" => Currency::CubanConvertiblePeso,
+    "CUP" => Currency::CubanPeso,
+    "CVE" => Currency::CapeVerdeanEscudo,
+    "CZK" => Currency::CzechKoruna,
+    "DJF" => Currency::DjiboutianFranc,
+    "DKK" => Currency::DanishKrone,
+    "DOP" => Currency::DominicanPeso,
+    "DZD" => Currency::AlgerianDinar,
+    "EGP" => Currency::EgyptianPound,
+    "ERN" => Currency::EritreanNakfa,
+    "ETB" => Currency::EthiopianBirr,
+    "EUR" => Currency::Euro,
+    "FJD" => Currency::FijiDollar,
+    "FKP" => Currency::FalklandIslandsPound,
+    "GBP" => Currency::PoundSterling,
+    "GEL" => Currency::GeorgianLari,
+    "GHS" => Currency::GhanaianCedi,
+    "GIP" => Currency::GibraltarPound,
+    "GMD" => Currency::GambianDalasi,
+    "GNF" => Currency::GuineanFranc,
+    "GTQ" => Currency::GuatemalanQuetzal,
+    "GYD" => Currency::GuyaneseDollar,
+    "HKD" => Currency::HongKongDollar,
+    "HNL" => Currency::HonduranLempira,
+    "HRK" => Currency::CroatianKuna,
+    "HTG" => Currency::HaitianGourde,
+    "HUF" => Currency::HungarianForint,
+    "IDR" => Currency::IndonesianRupiah,
+    "ILS" => Currency::IsraeliNewShekel,
+    "INR" => Currency::IndianRupee,
+    "IQD" => Currency::IraqiDinar,
+    "IRR" => Currency::IranianRial,
+    "ISK" => Currency::IcelandicKrona,
+    "JMD" => Currency::JamaicanDollar,
+    "JOD" => Currency::JordanianDinar,
+    "JPY" => Currency::JapaneseYen,
+    "KES" => Currency::KenyanShilling,
+    "KGS" => Currency::KyrgyzstaniSom,
+    "KHR" => Currency::CambodianRiel,
+    "KMF" => Currency::ComoroFranc,
+    "KPW" => Currency::NorthKoreanWon,
+    "KRW" => Currency::SouthKoreanWon,
+    "KWD" => Currency::KuwaitiDinar,
+    "KYD" => Currency::CaymanIslandsDollar,
+    "KZT" => Currency::KazakhstaniTenge,
+    "LAK" => Currency::LaoKip,
+    "LBP" => Currency::LebanesePound,
+    "LKR" => Currency::SriLankanRupee,
+    "LRD" => Currency::LiberianDollar,
+    "LSL" => Currency::LesothoLoti,
+    "LYD" => Currency::LibyanDinar,
+    "MAD" => Currency::MoroccanDirham,
+    "MDL" => Currency::MoldovanLeu,
+    "MGA" => Currency::MalagasyAriary,
+    "MKD" => Currency::MacedonianDenar,
+    "MMK" => Currency::MyanmarKyat,
+    "MNT" => Currency::MongolianTogrog,
+    "MOP" => Currency::MacanesePataca,
+    "MRU" => Currency::MauritanianOuguiya,
+    "MUR" => Currency::MauritianRupee,
+    "MVR" => Currency::MaldivianRufiyaa,
+    "MWK" => Currency::MalawianKwacha,
+    "MXN" => Currency::MexicanPeso,
+    "MXV" => Currency::MexicanUnidadDeInversion,
+    "MYR" => Currency::MalaysianRinggit,
+    "MZN" => Currency::MozambicanMetical,
+    "NAD" => Currency::NamibianDollar,
+    "NGN" => Currency::NigerianNaira,
+    "NIO" => Currency::NicaraguanCordoba,
+    "NOK" => Currency::NorwegianKrone,
+    "NPR" => Currency::NepaleseRupee,
+    "NZD" => Currency::NewZealandDollar,
+    "OMR" => Currency::OmaniRial,
+    "PAB" => Currency::PanamanianBalboa,
+    "PEN" => Currency::PeruvianSol,
+    "PGK" => Currency::PapuaNewGuineanKina,
+    "PHP" => Currency::PhilippinePeso,
+    "PKR" => Currency::PakistaniRupee,
+    "PLN" => Currency::PolishZloty,
+    "PYG" => Currency::ParaguayanGuarani,
+    "QAR" => Currency::QatariRiyal,
+    "RON" => Currency::RomanianLeu,
+    "RSD" => Currency::SerbianDinar,
+    "RUB" => Currency::RussianRuble,
+    "RWF" => Currency::RwandanFranc,
+    "SAR" => Currency::SaudiRiyal,
+    "SBD" => Currency::SolomonIslandsDollar,
+    "SCR" => Currency::SeychellesRupee,
+    "SDG" => Currency::SudanesePound,
+    "SEK" => Currency::SwedishKrona,
+    "SGD" => Currency::SingaporeDollar,
+    "SHP" => Currency::SaintHelenaPound,
+    "SLL" => Currency::SierraLeoneanLeone,
+    "SOS" => Currency::SomaliShilling,
+    "SRD" => Currency::SurinameseDollar,
+    "SSP" => Currency::SouthSudanesePound,
+    "STN" => Currency::SaoTomeAndPrincipeDobra,
+    "SVC" => Currency::SalvadoranColon,
+    "SYP" => Currency::SyrianPound,
+    "SZL" => Currency::SwaziLilangeni,
+    "THB" => Currency::ThaiBaht,
+    "TJS" => Currency::TajikistaniSomoni,
+    "TMT" => Currency::TurkmenistanManat,
+    "TND" => Currency::TunisianDinar,
+    "TOP" => Currency::TonganPaanga,
+    "TRY" => Currency::TurkishLira,
+    "TTD" => Currency::TrinidadAndTobagoDollar,
+    "TWD" => Currency::NewTaiwanDollar,
+    "TZS" => Currency::TanzanianShilling,
+    "UAH" => Currency::UkrainianHryvnia,
+    "UGX" => Currency::UgandanShilling,
+    "USD" => Currency::UnitedStatesDollar,
+    "USN" => Currency::UnitedStatesDollarNextDay,
+    "UYI" => Currency::UruguayPesoEnUnidadesIndexadas,
+    "UYU" => Currency::UruguayanPeso,
+    "UYW" => Currency::UnidadPrevisional,
+    "UZS" => Currency::UzbekistanSom,
+    "VES" => Currency::VenezuelanBolivarSoberano,
+    "VND" => Currency::VietnameseDong,
+    "VUV" => Currency::VanuatuVatu,
+    "WST" => Currency::SamoanTala,
+    "XAF" => Currency::CfaFrancBeac,
+    "XAG" => Currency::Silver,
+    "XAU" => Currency::Gold,
+    "XBA" => Currency::EuropeanCompositeUnit,
+    "XBB" => Currency::EuropeanMonetaryUnit,
+    "XBC" => Currency::EuropeanUnitOfAccount9,
+    "XBD" => Currency::EuropeanUnitOfAccount17,
+    "XCD" => Currency::EastCaribbeanDollar,
+    "XDR" => Currency::SpecialDrawingRights,
+    "XOF" => Currency::CfaFrancBceao,
+    "XPD" => Currency::Palladium,
+    "XPF" => Currency::CfpFranc,
+    "XPT" => Currency::Platinum,
+    "XSU" => Currency::Sucre,
+    "XTS" => Currency::CodeReservedForTesting,
+    "XUA" => Currency::AdbUnitOfAccount,
+    "XXX" => Currency::NoCurrency,
+    "YER" => Currency::YemeniRial,
+    "ZAR" => Currency::SouthAfricanRand,
+    "ZMW" => Currency::ZambianKwacha,
+    "ZWL" => Currency::ZimbabweanDollar,
+};
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::TryFrom<&str> for Currency {
+    // Error definitions are contained in the
+    // `google_maps\src\error.rs` module.
+    type Error = GoogleMapsError;
+    /// Gets a `Currency` enum from a `String` that contains a supported
+    /// [ISO 4217 currency code](https://en.wikipedia.org/wiki/ISO_4217).
+    fn try_from(currency_code: &str) -> Result<Self, Self::Error> {
+        Ok(CURRENCIES_BY_CODE
+            .get(currency_code)
+            .cloned()
+            .unwrap_or_else(|| Self::Other(currency_code.to_string())))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::str::FromStr for Currency {
+    // Error definitions are contained in the
+    // `google_maps\src\error.rs` module.
+    type Err = GoogleMapsError;
+    /// Gets a `Currency` enum from a `String` that contains a supported
+    /// [ISO 4217 currency code](https://en.wikipedia.org/wiki/ISO_4217).
+    fn from_str(currency_code: &str) -> Result<Self, Self::Err> {
+        Ok(CURRENCIES_BY_CODE
+            .get(currency_code)
+            .cloned()
+            .unwrap_or_else(|| Self::Other(currency_code.to_string())))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl Currency {
+    /// Formats a `Currency` enum into a string that is presentable to
+    /// the end user.
+    #[must_use]
+    pub fn display(&self) -> &str {
+        match self {
+            Self::UnitedArabEmiratesDirham => "United Arab Emirates dirham",
+            Self::AfghanAfghani => "Afghan afghani",
+            Self::AlbanianLek => "Albanian lek",
+            Self::ArmenianDram => "Armenian dram",
+            Self::NetherlandsAntilleanGuilder => "Netherlands Antillean guilder",
+            Self::AngolanKwanza => "Angolan kwanza",
+            Self::ArgentinePeso => "Argentine peso",
+            Self::AustralianDollar => "Australian dollar",
+            Self::ArubanFlorin => "Aruban florin",
+            Self::AzerbaijaniManat => "Azerbaijani manat",
+            Self::BosniaAndHerzegovinaConvertibleMark => "Bosnia and Herzegovina convertible mark",
+            Self::BarbadosDollar => "Barbados dollar",
+            Self::BangladeshiTaka => "Bangladeshi taka",
+            Self::BulgarianLev => "Bulgarian lev",
+            Self::BahrainiDinar => "Bahraini dinar",
+            Self::BurundianFranc => "Burundian franc",
+            Self::BermudianDollar => "Bermudian dollar",
+            Self::BruneiDollar => "Brunei dollar",
+            Self::Boliviano => "Boliviano",
+            Self::BolivianMvdol => "Bolivian Mvdol",
+            Self::BrazilianReal => "Brazilian real",
+            Self::BahamianDollar => "Bahamian dollar",
+            Self::BhutaneseNgultrum => "Bhutanese ngultrum",
+            Self::BotswanaPula => "Botswana pula",
+            Self::BelarusianRuble => "Belarusian ruble",
+            Self::BelizeDollar => "Belize dollar",
+            Self::CanadianDollar => "Canadian dollar",
+            Self::CongoleseFranc => "Congolese franc",
+            Self::WirEuro => "WIR Euro",
+            Self::SwissFranc => "Swiss franc",
+            Self::WirFranc => "WIR Franc",
+            Self::UnidadDeFomento => "Unidad de Fomento",
+            Self::ChileanPeso => "Chilean peso",
+            Self::RenminbiYuan => "Renminbi yuan",
+            Self::ColombianPeso => "Colombian peso",
+            Self::UnidadDeValorReal => "Unidad de Valor Real",
+            Self::CostaRicanColon => "Costa Rican colon",
+            Self::CubanConvertiblePeso => "Cuban convertible peso",
+            Self::CubanPeso => "Cuban peso",
+            Self::CapeVerdeanEscudo => "Cape Verdean escudo",
+            Self::CzechKoruna => "Czech koruna",
+            Self::DjiboutianFranc => "Djiboutian franc",
+            Self::DanishKrone => "Danish krone",
+            Self::DominicanPeso => "Dominican peso",
+            Self::AlgerianDinar => "Algerian dinar",
+            Self::EgyptianPound => "Egyptian pound",
+            Self::EritreanNakfa => "Eritrean nakfa",
+            Self::EthiopianBirr => "Ethiopian birr",
+            Self::Euro => "Euro",
+            Self::FijiDollar => "Fiji dollar",
+            Self::FalklandIslandsPound => "Falkland Islands pound",
+            Self::PoundSterling => "Pound sterling",
+            Self::GeorgianLari => "Georgian lari",
+            Self::GhanaianCedi => "Ghanaian cedi",
+            Self::GibraltarPound => "Gibraltar pound",
+            Self::GambianDalasi => "Gambian dalasi",
+            Self::GuineanFranc => "Guinean franc",
+            Self::GuatemalanQuetzal => "Guatemalan quetzal",
+            Self::GuyaneseDollar => "Guyanese dollar",
+            Self::HongKongDollar => "Hong Kong dollar",
+            Self::HonduranLempira => "Honduran lempira",
+            Self::CroatianKuna => "Croatian kuna",
+            Self::HaitianGourde => "Haitian gourde",
+            Self::HungarianForint => "Hungarian forint",
+            Self::IndonesianRupiah => "Indonesian rupiah",
+            Self::IsraeliNewShekel => "Israeli new shekel",
+            Self::IndianRupee => "Indian rupee",
+            Self::IraqiDinar => "Iraqi dinar",
+            Self::IranianRial => "Iranian rial",
+            Self::IcelandicKrona => "Icelandic króna",
+            Self::JamaicanDollar => "Jamaican dollar",
+            Self::JordanianDinar => "Jordanian dinar",
+            Self::JapaneseYen => "Japanese yen",
+            Self::KenyanShilling => "Kenyan shilling",
+            Self::KyrgyzstaniSom => "Kyrgyzstani som",
+            Self::CambodianRiel => "Cambodian riel",
+            Self::ComoroFranc => "Comoro franc",
+            Self::NorthKoreanWon => "North Korean won",
+            Self::SouthKoreanWon => "South Korean won",
+            Self::KuwaitiDinar => "Kuwaiti dinar",
+            Self::CaymanIslandsDollar => "Cayman Islands dollar",
+            Self::KazakhstaniTenge => "Kazakhstani tenge",
+            Self::LaoKip => "Lao kip",
+            Self::LebanesePound => "Lebanese pound",
+            Self::SriLankanRupee => "Sri Lankan rupee",
+            Self::LiberianDollar => "Liberian dollar",
+            Self::LesothoLoti => "Lesotho loti",
+            Self::LibyanDinar => "Libyan dinar",
+            Self::MoroccanDirham => "Moroccan dirham",
+            Self::MoldovanLeu => "Moldovan leu",
+            Self::MalagasyAriary => "Malagasy ariary",
+            Self::MacedonianDenar => "Macedonian denar",
+            Self::MyanmarKyat => "Myanmar kyat",
+            Self::MongolianTogrog => "Mongolian tögrög",
+            Self::MacanesePataca => "Macanese pataca",
+            Self::MauritanianOuguiya => "Mauritanian ouguiya",
+            Self::MauritianRupee => "Mauritian rupee",
+            Self::MaldivianRufiyaa => "Maldivian rufiyaa",
+            Self::MalawianKwacha => "Malawian kwacha",
+            Self::MexicanPeso => "Mexican peso",
+            Self::MexicanUnidadDeInversion => "Mexican Unidad de Inversion",
+            Self::MalaysianRinggit => "Malaysian ringgit",
+            Self::MozambicanMetical => "Mozambican metical",
+            Self::NamibianDollar => "Namibian dollar",
+            Self::NigerianNaira => "Nigerian naira",
+            Self::NicaraguanCordoba => "Nicaraguan córdoba",
+            Self::NorwegianKrone => "Norwegian krone",
+            Self::NepaleseRupee => "Nepalese rupee",
+            Self::NewZealandDollar => "New Zealand dollar",
+            Self::OmaniRial => "Omani rial",
+            Self::PanamanianBalboa => "Panamanian balboa",
+            Self::PeruvianSol => "Peruvian sol",
+            Self::PapuaNewGuineanKina => "Papua New Guinean kina",
+            Self::PhilippinePeso => "Philippine peso",
+            Self::PakistaniRupee => "Pakistani rupee",
+            Self::PolishZloty => "Polish złoty",
+            Self::ParaguayanGuarani => "Paraguayan guaraní",
+            Self::QatariRiyal => "Qatari riyal",
+            Self::RomanianLeu => "Romanian leu",
+            Self::SerbianDinar => "Serbian dinar",
+            Self::RussianRuble => "Russian ruble",
+            Self::RwandanFranc => "Rwandan franc",
+            Self::SaudiRiyal => "Saudi riyal",
+            Self::SolomonIslandsDollar => "Solomon Islands dollar",
+            Self::SeychellesRupee => "Seychelles rupee",
+            Self::SudanesePound => "Sudanese pound",
+            Self::SwedishKrona => "Swedish krona",
+            Self::SingaporeDollar => "Singapore dollar",
+            Self::SaintHelenaPound => "Saint Helena pound",
+            Self::SierraLeoneanLeone => "Sierra Leonean leone",
+            Self::SomaliShilling => "Somali shilling",
+            Self::SurinameseDollar => "Surinamese dollar",
+            Self::SouthSudanesePound => "South Sudanese pound",
+            Self::SaoTomeAndPrincipeDobra => "São Tomé and Príncipe dobra",
+            Self::SalvadoranColon => "Salvadoran colón",
+            Self::SyrianPound => "Syrian pound",
+            Self::SwaziLilangeni => "Swazi lilangeni",
+            Self::ThaiBaht => "Thai baht",
+            Self::TajikistaniSomoni => "Tajikistani somoni",
+            Self::TurkmenistanManat => "Turkmenistan manat",
+            Self::TunisianDinar => "Tunisian dinar",
+            Self::TonganPaanga => "Tongan paʻanga",
+            Self::TurkishLira => "Turkish lira",
+            Self::TrinidadAndTobagoDollar => "Trinidad and Tobago dollar",
+            Self::NewTaiwanDollar => "New Taiwan dollar",
+            Self::TanzanianShilling => "Tanzanian shilling",
+            Self::UkrainianHryvnia => "Ukrainian hryvnia",
+            Self::UgandanShilling => "Ugandan shilling",
+            Self::UnitedStatesDollar => "United States dollar",
+            Self::UnitedStatesDollarNextDay => "United States dollar next day",
+            Self::UruguayPesoEnUnidadesIndexadas => "Uruguay Peso en Unidades Indexadas",
+            Self::UruguayanPeso => "Uruguayan peso",
+            Self::UnidadPrevisional => "Unidad previsional",
+            Self::UzbekistanSom => "Uzbekistan som",
+            Self::VenezuelanBolivarSoberano => "Venezuelan bolívar soberano",
+            Self::VietnameseDong => "Vietnamese đồng",
+            Self::VanuatuVatu => "Vanuatu vatu",
+            Self::SamoanTala => "Samoan tala",
+            Self::CfaFrancBeac => "CFA franc BEAC",
+            Self::Silver => "Silver",
+            Self::Gold => "Gold",
+            Self::EuropeanCompositeUnit => "European Composite Unit",
+            Self::EuropeanMonetaryUnit => "European Monetary Unit",
+            Self::EuropeanUnitOfAccount9 => "European Unit of Account 9",
+            Self::EuropeanUnitOfAccount17 => "European Unit of Account 17",
+            Self::EastCaribbeanDollar => "East Caribbean dollar",
+            Self::SpecialDrawingRights => "Special drawing rights",
+            Self::CfaFrancBceao => "CFA franc BCEAO",
+            Self::Palladium => "Palladium",
+            Self::CfpFranc => "CFP franc",
+            Self::Platinum => "Platinum",
+            Self::Sucre => "SUCRE",
+            Self::CodeReservedForTesting => "Code reserved for testing",
+            Self::AdbUnitOfAccount => "ADB Unit of Account",
+            Self::NoCurrency => "No currency",
+            Self::YemeniRial => "Yemeni rial",
+            Self::SouthAfricanRand => "South African rand",
+            Self::ZambianKwacha => "Zambian kwacha",
+            Self::ZimbabweanDollar => "Zimbabwean dollar",
+            Self::Other(string) => string,
+        } // match
+    } // fn
+
+    /// Returns the number of digits after the decimal separator that amounts
+    /// in this currency are expressed with, also known as the [minor
+    /// unit](https://en.wikipedia.org/wiki/ISO_4217#Minor_unit_fractions).
+    /// For example, `2` for the United States dollar and `0` for the Japanese
+    /// yen.
+    ///
+    /// `None` is returned for codes that are not currencies, such as precious
+    /// metals and the code reserved for testing, and for currencies that are
+    /// not recognized by this crate.
+    #[must_use]
+    pub const fn minor_units(&self) -> Option<u32> {
+        match self {
+            Self::BurundianFranc
+            | Self::ChileanPeso
+            | Self::DjiboutianFranc
+            | Self::GuineanFranc
+            | Self::IcelandicKrona
+            | Self::JapaneseYen
+            | Self::ComoroFranc
+            | Self::SouthKoreanWon
+            | Self::ParaguayanGuarani
+            | Self::RwandanFranc
+            | Self::UgandanShilling
+            | Self::UruguayPesoEnUnidadesIndexadas
+            | Self::VietnameseDong
+            | Self::VanuatuVatu
+            | Self::CfaFrancBeac
+            | Self::CfaFrancBceao
+            | Self::CfpFranc => Some(0),
+
+            Self::BahrainiDinar
+            | Self::IraqiDinar
+            | Self::JordanianDinar
+            | Self::KuwaitiDinar
+            | Self::LibyanDinar
+            | Self::OmaniRial
+            | Self::TunisianDinar => Some(3),
+
+            Self::UnidadDeFomento | Self::UnidadPrevisional => Some(4),
+
+            Self::Silver
+            | Self::Gold
+            | Self::EuropeanCompositeUnit
+            | Self::EuropeanMonetaryUnit
+            | Self::EuropeanUnitOfAccount9
+            | Self::EuropeanUnitOfAccount17
+            | Self::SpecialDrawingRights
+            | Self::Palladium
+            | Self::Platinum
+            | Self::Sucre
+            | Self::CodeReservedForTesting
+            | Self::AdbUnitOfAccount
+            | Self::NoCurrency
+            | Self::Other(_) => None,
+
+            _ => Some(2),
+        } // match
+    } // fn
+} // impl
@@ -3,6 +3,7 @@
 
 // -----------------------------------------------------------------------------
 
+#[cfg(feature = "diagnostics")]
 use miette::Diagnostic;
 use rust_decimal::Decimal;
 use thiserror::Error;
@@ -12,8 +13,9 @@ use thiserror::Error;
 /// Errors that may be produced by crate types from implementations and
 /// associated functions. For example, type conversions, instantiations, etc.
 
-#[derive(Debug, Diagnostic, Error)]
-#[diagnostic(code(google_maps::types::error), url(docsrs))]
+#[derive(Debug, Error)]
+#[cfg_attr(feature = "diagnostics", derive(Diagnostic))]
+#[cfg_attr(feature = "diagnostics", diagnostic(code(google_maps::types::error), url(docsrs)))]
 pub enum Error {
     /// API client library attempted to parse a string that contained an invalid
     /// language code.
@@ -57,6 +59,30 @@ pub enum Error {
     /// API client library attempted to parse a string that contained an invalid
     /// location type code.
     InvalidLocationTypeCode(String),
+
+    /// API client library attempted to decode an encoded polyline string that
+    /// is invalid.
+    InvalidPolyline(String),
+
+    /// API client library attempted to encode or decode a polyline with a
+    /// precision that is not supported.
+    InvalidPolylinePrecision(u32),
+
+    /// API client library attempted to use a URL signing private key that is
+    /// not valid base64.
+    InvalidSigningKey(String),
+
+    /// API client library attempted to use a usage reporting channel that
+    /// contains characters that Google does not allow.
+    InvalidChannel(String),
+
+    /// API client library attempted to create a client with an API key that is
+    /// empty or contains only whitespace.
+    InvalidApiKey,
+
+    /// API client library attempted to use a location bias or restriction
+    /// with a radius of zero, or with rectangle corners in the wrong order.
+    InvalidLocationBias(String),
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -131,6 +157,46 @@ impl std::fmt::Display for Error {
                 `{location_type_code}` is not a known location type code. \
                 Valid codes are `APPROXIMATE`, `GEOMETRIC_CENTER`, \
                 `RANGE_INTERPOLATED`, and `ROOFTOP`."),
+            Self::InvalidPolyline(polyline) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{polyline}` is not a valid encoded polyline. \
+                For the encoding algorithm see \
+                https://developers.google.com/maps/documentation/utilities/polylinealgorithm"
+            ),
+            Self::InvalidPolylinePrecision(precision) => write!(
+                f,
+                "Google Maps Platform API client: \
+                a polyline precision of `{precision}` decimal places is not supported. \
+                The precision must be between 0 and 16."
+            ),
+            Self::InvalidSigningKey(_private_key) => write!(
+                f,
+                "Google Maps Platform API client: \
+                the URL signing private key is not valid base64. \
+                Use the private key exactly as it is shown in the Google Cloud Console."
+            ),
+            Self::InvalidChannel(channel) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{channel}` is not a valid channel. \
+                A channel must not be empty, and may only contain ASCII letters, digits, \
+                periods (`.`), underscores (`_`), and hyphens (`-`)."
+            ),
+            Self::InvalidApiKey => write!(
+                f,
+                "Google Maps Platform API client: \
+                the API key is empty or only whitespace. \
+                Learn how to get a key at \
+                https://developers.google.com/maps/documentation/geocoding/get-api-key"
+            ),
+            Self::InvalidLocationBias(location_bias) => write!(
+                f,
+                "Google Maps Platform API client: \
+                `{location_bias}` is not a valid location bias or restriction. \
+                A circle's radius must be greater than zero, \
+                and a rectangle's south-west corner must not be north of its north-east corner."
+            ),
         } // match
     } // fn
 } // impl
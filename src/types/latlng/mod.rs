@@ -9,7 +9,7 @@ mod geo;
 
 use crate::error::Error as GoogleMapsError;
 use crate::types::error::Error as TypeError;
-use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
@@ -22,6 +22,12 @@ use std::cmp::{Ord, Ordering};
 /// longitude values can take any value between -180 and 180. If you specify an
 /// invalid latitude or longitude value, your request will be rejected as a bad
 /// request.
+///
+/// The coordinates are stored as `Decimal`s rather than floating-point
+/// numbers, so `LatLng` implements `Eq` and `Hash` and may be used in a
+/// `HashSet` or as a `HashMap` key. Two positions are equal only if their
+/// coordinates are exactly equal, ignoring trailing zeros. To treat nearby
+/// positions as the same, round them first with `round_dp()`.
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct LatLng {
@@ -40,6 +46,33 @@ pub struct LatLng {
 
 // -----------------------------------------------------------------------------
 
+impl LatLng {
+    /// Takes individual latitude & longitude `f64` coordinates and converts
+    /// them into a `LatLng` structure. If either the latitude (-90.0 to +90.0)
+    /// or longitude (-180.0 to +180.0) are out of range, this function will
+    /// return an error that names the coordinate that is out of range.
+    ///
+    /// This is the same as `LatLng::try_from_f64()`. Use
+    /// `LatLng::try_from_dec()` if the coordinates are already `Decimal`s.
+    ///
+    /// ## Examples:
+    ///
+    /// ```rust
+    /// let cn_tower = LatLng::try_new(43.642_567, -79.387_054)?;
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// * Returns an error if the latitude or longitude is out of range, or is
+    ///   not a finite number.
+
+    pub fn try_new(latitude: f64, longitude: f64) -> Result<Self, GoogleMapsError> {
+        Self::try_from_f64(latitude, longitude)
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl LatLng {
     /// Takes individual latitude & longitude `Decimal` coordinates and
     /// converts them into a `LatLng` structure. If either the latitude
@@ -114,6 +147,27 @@ impl LatLng {
 
         Ok(Self { lat, lng })
     } // fn
+
+    /// Rounds both coordinates to the specified number of decimal places. Six
+    /// decimal places is about 11 cm at the equator.
+    ///
+    /// This is useful to remove duplicates from positions that were returned
+    /// by different responses, which may differ in their last digits:
+    ///
+    /// ```rust
+    /// let unique: HashSet<LatLng> = positions
+    ///     .iter()
+    ///     .map(|position| position.round_dp(6))
+    ///     .collect();
+    /// ```
+
+    #[must_use]
+    pub fn round_dp(&self, decimal_places: u32) -> Self {
+        Self {
+            lat: self.lat.round_dp(decimal_places),
+            lng: self.lng.round_dp(decimal_places),
+        } // struct
+    } // fn
 } // impl
 
 // -----------------------------------------------------------------------------
@@ -189,6 +243,43 @@ impl<V: TryInto<Decimal>> TryFrom<(V, V)> for LatLng {
 
 // -----------------------------------------------------------------------------
 
+impl<V: TryInto<Decimal>> TryFrom<[V; 2]> for LatLng {
+    type Error = GoogleMapsError;
+    /// Attempts to get a `LatLng` struct from a `[lat, lng]` array that
+    /// contains a `0` latitude and a `1` longitude (in that order). As with
+    /// tuples, the coordinates are checked to be within range.
+    fn try_from(coordinates: [V; 2]) -> Result<Self, Self::Error> {
+        let [latitude, longitude] = coordinates;
+        Self::try_from((latitude, longitude))
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<LatLng> for (f64, f64) {
+    /// Converts a `LatLng` struct into a `(lat, lng)` tuple of floating-point
+    /// numbers, for use with other geospatial libraries.
+    fn from(lat_lng: LatLng) -> Self {
+        (
+            lat_lng.lat.to_f64().unwrap_or_default(),
+            lat_lng.lng.to_f64().unwrap_or_default(),
+        )
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::convert::From<LatLng> for [f64; 2] {
+    /// Converts a `LatLng` struct into a `[lat, lng]` array of floating-point
+    /// numbers, for use with other geospatial libraries.
+    fn from(lat_lng: LatLng) -> Self {
+        let (latitude, longitude) = lat_lng.into();
+        [latitude, longitude]
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
 impl std::convert::From<&Self> for LatLng {
     /// Converts a borrowed `&LatLng` enum into an owned `LatLng` enum by
     /// copying it.
@@ -328,3 +419,79 @@ impl LatLng {
         (&self.lat, &self.lng)
     }
 } // impl
+
+// -----------------------------------------------------------------------------
+
+/// The mean radius of the Earth in metres, as defined by the International
+/// Union of Geodesy and Geophysics.
+const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+// -----------------------------------------------------------------------------
+
+impl LatLng {
+    /// Returns the latitude & longitude in radians, for use in spherical
+    /// trigonometry.
+    fn to_radians(self) -> (f64, f64) {
+        (
+            self.lat.to_f64().unwrap_or_default().to_radians(),
+            self.lng.to_f64().unwrap_or_default().to_radians(),
+        )
+    } // fn
+
+    /// Returns the great-circle distance, in metres, between this position and
+    /// another one, using the [haversine
+    /// formula](https://en.wikipedia.org/wiki/Haversine_formula).
+    ///
+    /// The Earth is treated as a sphere with a radius of 6,371 km, so the
+    /// result may differ from the true distance by up to about 0.5%. This is
+    /// the distance "as the crow flies," not the distance by road.
+    ///
+    /// ```rust
+    /// let meters = toronto.haversine_distance(&montreal); // ≈ 504 km
+    /// ```
+
+    #[must_use]
+    pub fn haversine_distance(&self, other: &Self) -> f64 {
+        let (lat_1, lng_1) = self.to_radians();
+        let (lat_2, lng_2) = other.to_radians();
+
+        let sin_lat = ((lat_2 - lat_1) / 2.0).sin();
+        let sin_lng = ((lng_2 - lng_1) / 2.0).sin();
+        let haversine = (lat_1.cos() * lat_2.cos()).mul_add(sin_lng * sin_lng, sin_lat * sin_lat);
+
+        2.0 * EARTH_RADIUS_METERS * haversine.sqrt().min(1.0).asin()
+    } // fn
+
+    /// Returns the position halfway between this position and another one,
+    /// along the great circle that joins them. The coordinates are rounded to
+    /// 7 decimal places, which is about 1 cm.
+    ///
+    /// The midpoint of two positions on opposite sides of the Earth is not
+    /// well defined. In that case, one of the possible midpoints is returned.
+    ///
+    /// ```rust
+    /// let halfway = toronto.midpoint(&montreal);
+    /// ```
+
+    #[must_use]
+    pub fn midpoint(&self, other: &Self) -> Self {
+        let (lat_1, lng_1) = self.to_radians();
+        let (lat_2, lng_2) = other.to_radians();
+
+        let b_x = lat_2.cos() * (lng_2 - lng_1).cos();
+        let b_y = lat_2.cos() * (lng_2 - lng_1).sin();
+
+        let lat = (lat_1.sin() + lat_2.sin())
+            .atan2((lat_1.cos() + b_x).hypot(b_y))
+            .to_degrees();
+        let lng = (lng_1 + b_y.atan2(lat_1.cos() + b_x)).to_degrees();
+
+        // Wrap the longitude back into the -180° to +180° range:
+        let lng = (lng + 540.0).rem_euclid(360.0) - 180.0;
+
+        Self {
+            lat: Decimal::from_f64(lat).unwrap_or_default().round_dp(7),
+            lng: Decimal::from_f64(lng).unwrap_or_default().round_dp(7),
+        } // LatLng
+    } // fn
+} // impl
@@ -3,6 +3,7 @@
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub(super) mod address_component;
 #[cfg(any(
+    feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
@@ -15,6 +16,8 @@ pub(super) mod bounds;
     feature = "geocoding"
 ))]
 pub(super) mod country;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub(super) mod currency;
 pub(super) mod error;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub(super) mod geometry;
@@ -28,6 +31,7 @@ pub(super) mod geometry;
 ))]
 pub(super) mod language;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
@@ -35,11 +39,14 @@ pub(super) mod language;
     feature = "geocoding",
     feature = "places",
     feature = "roads",
+    feature = "static_map",
     feature = "time_zone"
 ))]
 pub(super) mod latlng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub(super) mod location_type;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub(super) mod money;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
@@ -62,6 +69,7 @@ pub(super) mod region;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::address_component::AddressComponent;
 #[cfg(any(
+    feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
     feature = "geocoding",
@@ -74,6 +82,8 @@ pub use crate::types::bounds::Bounds;
     feature = "geocoding"
 ))]
 pub use crate::types::country::Country;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::currency::Currency;
 pub use crate::types::error::Error;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::geometry::Geometry;
@@ -87,6 +97,7 @@ pub use crate::types::geometry::Geometry;
 ))]
 pub use crate::types::language::Language;
 #[cfg(any(
+    feature = "address_validation",
     feature = "autocomplete",
     feature = "directions",
     feature = "distance_matrix",
@@ -94,11 +105,14 @@ pub use crate::types::language::Language;
     feature = "geocoding",
     feature = "places",
     feature = "roads",
+    feature = "static_map",
     feature = "time_zone"
 ))]
 pub use crate::types::latlng::LatLng;
 #[cfg(any(feature = "geocoding", feature = "places"))]
 pub use crate::types::location_type::LocationType;
+#[cfg(any(feature = "directions", feature = "distance_matrix"))]
+pub use crate::types::money::Money;
 #[cfg(any(
     feature = "autocomplete",
     feature = "directions",
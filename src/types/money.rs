@@ -0,0 +1,158 @@
+//! Contains the `Money` struct and its associated traits. It is used to
+//! represent an amount of money in a specific currency, such as a transit
+//! fare.
+
+use crate::types::Currency;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+// -----------------------------------------------------------------------------
+//
+/// An amount of money, in a specific currency.
+///
+/// Google returns amounts of money both as a number, and as text that is
+/// formatted for the user's language, for example `2.50` and `"$2.50"`. Use
+/// `Money` to work with the number, and `Money::from_text` to recover the
+/// number when only the text is available.
+///
+/// ## Examples:
+///
+/// ```rust
+/// use google_maps::{Currency, Money};
+/// use rust_decimal_macros::dec;
+///
+/// let fare = Money::new(Currency::JapaneseYen, dec!(210));
+/// assert_eq!(fare.to_string(), "210 JPY");
+///
+/// let fare = Money::from_text(Currency::Euro, "1,80 €").unwrap();
+/// assert_eq!(fare.amount, dec!(1.80));
+/// ```
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct Money {
+    /// The [ISO 4217 currency](https://en.wikipedia.org/wiki/ISO_4217) that
+    /// the amount is expressed in.
+    pub currency: Currency,
+    /// The amount, in the currency specified above.
+    pub amount: Decimal,
+} // struct
+
+// -----------------------------------------------------------------------------
+
+impl Money {
+    // -------------------------------------------------------------------------
+    //
+    /// Creates an amount of money in the specified currency.
+
+    #[must_use]
+    pub const fn new(currency: Currency, amount: Decimal) -> Self {
+        Self { currency, amount }
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Parses an amount of money from text that has been formatted for
+    /// display, such as the `text` field of a transit fare.
+    ///
+    /// ## Arguments
+    ///
+    /// * `currency` ‧ The currency that the amount is expressed in. The
+    ///   currency symbol or code in the text, if any, is ignored.
+    ///
+    /// * `text` ‧ The formatted amount. For example, `$2.50`, `1,80 €`,
+    ///   `¥210`, or `1.234,50 kr`.
+    ///
+    /// ## Description
+    ///
+    /// Both `.` and `,` are accepted as decimal separators. When a separator
+    /// could also be a thousands separator, as in `1,234`, it is treated as a
+    /// decimal separator only if the currency's minor unit has that many
+    /// digits.
+    ///
+    /// `None` is returned if the text does not contain a number.
+
+    #[must_use]
+    pub fn from_text(currency: Currency, text: &str) -> Option<Self> {
+        let start = text.find(|c: char| c.is_ascii_digit())?;
+        let end = text.rfind(|c: char| c.is_ascii_digit())? + 1;
+
+        // Spaces and apostrophes are only ever used to group thousands:
+        let number: String = text[start..end]
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '\'' && *c != '’')
+            .collect();
+
+        if !number.chars().all(|c| c.is_ascii_digit() || c == '.' || c == ',') {
+            return None;
+        } // if
+
+        // Determine which separator, if any, is the decimal separator:
+        let decimal_separator = match (number.rfind('.'), number.rfind(',')) {
+            (Some(period), Some(comma)) => Some(if period > comma { '.' } else { ',' }),
+            (Some(_), None) => Some('.'),
+            (None, Some(_)) => Some(','),
+            (None, None) => None,
+        }; // match
+
+        let decimal_separator = decimal_separator.filter(|separator| {
+            let groups: Vec<&str> = number.split(*separator).collect();
+            let fraction_digits = groups.last().map_or(0, |group| group.len());
+            // A separator that appears more than once groups thousands:
+            groups.len() == 2
+                // ...as does a single separator followed by three digits,
+                // unless the currency has three minor unit digits, and the
+                // other separator isn't present:
+                && (fraction_digits != 3
+                    || currency.minor_units() == Some(3)
+                    || number.contains(if *separator == '.' { ',' } else { '.' }))
+        }); // filter
+
+        let number: String = number
+            .chars()
+            .filter_map(|c| match c {
+                '0'..='9' => Some(c),
+                c if Some(c) == decimal_separator => Some('.'),
+                _ => None,
+            }) // filter_map
+            .collect();
+
+        Decimal::from_str(&number)
+            .ok()
+            .map(|amount| Self::new(currency, amount))
+    } // fn
+
+    // -------------------------------------------------------------------------
+    //
+    /// Returns the amount rounded to the currency's minor unit, for example to
+    /// whole yen or to cents. The amount is returned unchanged if the currency
+    /// has no minor unit.
+
+    #[must_use]
+    pub fn rounded(&self) -> Decimal {
+        self.currency.minor_units().map_or(self.amount, |minor_units| {
+            self.amount
+                .round_dp_with_strategy(minor_units, RoundingStrategy::MidpointAwayFromZero)
+        }) // map_or
+    } // fn
+} // impl
+
+// -----------------------------------------------------------------------------
+
+impl std::fmt::Display for Money {
+    /// Formats the amount with the number of decimal places used by its
+    /// currency, followed by the currency's ISO 4217 code. For example,
+    /// `2.50 USD`, `210 JPY`, or `1.250 KWD`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.currency.minor_units() {
+            Some(minor_units) => write!(
+                f,
+                "{amount:.precision$} {currency}",
+                amount = self.rounded(),
+                precision = minor_units as usize,
+                currency = self.currency,
+            ), // write!
+            None => write!(f, "{amount} {currency}", amount = self.amount, currency = self.currency),
+        } // match
+    } // fn
+} // impl
@@ -15,7 +15,7 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// sought. See [Place
 /// Types](https://developers.google.com/places/web-service/supported_types)
 /// for more information.
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[repr(u8)]
 #[non_exhaustive]
 pub enum PlaceType {
@@ -227,7 +227,7 @@ pub enum PlaceType {
     /// crate. However, in the meantime, the `Other` catch-all variant allows
     /// `serde` to read data from Google without producing an error until the
     /// new variant added to this `enum`.
-    Other = 143,
+    Other(String) = 143,
 } // enum
 
 // -----------------------------------------------------------------------------
@@ -260,19 +260,19 @@ impl Serialize for PlaceType {
 
 impl std::convert::From<&Self> for PlaceType {
     /// Converts a borrowed `&PlaceType` enum into an owned `PlaceType` enum
-    /// by copying it.
+    /// by cloning it.
     fn from(place_type: &Self) -> Self {
-        *place_type
+        place_type.clone()
     } // fn
 } // impl
 
 // -----------------------------------------------------------------------------
 
-impl std::convert::From<&PlaceType> for &str {
+impl<'a> std::convert::From<&'a PlaceType> for &'a str {
     /// Converts a `PlaceType` enum to a `String` that contains a [place
     /// type](https://developers.google.com/places/web-service/supported_types)
     /// code.
-    fn from(place_type: &PlaceType) -> Self {
+    fn from(place_type: &'a PlaceType) -> Self {
         match place_type {
             PlaceType::Accounting => "accounting",
             PlaceType::Airport => "airport",
@@ -417,7 +417,7 @@ impl std::convert::From<&PlaceType> for &str {
             PlaceType::Regions => "regions",
             PlaceType::Cities => "cities",
             PlaceType::Landmark => "landmark",
-            PlaceType::Other => "other",
+            PlaceType::Other(string) => string,
         } // match
     } // fn
 } // impl
@@ -590,7 +590,6 @@ static PLACE_TYPES_BY_CODE: phf::Map<&'static str, PlaceType> = phf_map! {
     "regions" => PlaceType::Regions,
     "cities" => PlaceType::Cities,
     "landmark" => PlaceType::Landmark,
-    "other" => PlaceType::Other,
 };
 
 // -----------------------------------------------------------------------------
@@ -604,8 +603,8 @@ impl std::convert::TryFrom<&str> for PlaceType {
     fn try_from(place_type_code: &str) -> Result<Self, Self::Error> {
         Ok(PLACE_TYPES_BY_CODE
             .get(place_type_code)
-            .copied()
-            .unwrap_or(Self::Other))
+            .cloned()
+            .unwrap_or_else(|| Self::Other(place_type_code.to_string())))
     } // fn
 } // impl
 
@@ -620,8 +619,8 @@ impl std::str::FromStr for PlaceType {
     fn from_str(place_type_code: &str) -> Result<Self, Self::Err> {
         Ok(PLACE_TYPES_BY_CODE
             .get(place_type_code)
-            .copied()
-            .unwrap_or(Self::Other))
+            .cloned()
+            .unwrap_or_else(|| Self::Other(place_type_code.to_string())))
     } // fn
 } // impl
 
@@ -631,7 +630,7 @@ impl PlaceType {
     /// Formats a `PlaceType` enum into a string that is presentable to the end
     /// user.
     #[must_use]
-    pub const fn display(&self) -> &str {
+    pub fn display(&self) -> &str {
         match self {
             Self::Accounting => "Accounting",
             Self::Airport => "Airport",
@@ -776,7 +775,7 @@ impl PlaceType {
             Self::Regions => "Regions",
             Self::Cities => "Cities",
             Self::Landmark => "Landmark",
-            Self::Other => "Other",
+            Self::Other(string) => string,
         } // match
     } // fn
 } // impl
@@ -797,3 +796,37 @@ impl PlaceType {
             .join(",")
     } // fn
 } // impl
+
+// -----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_json(json: &str) -> PlaceType {
+        let mut bytes = json.as_bytes().to_vec();
+        simd_json::serde::from_slice(&mut bytes).unwrap()
+    } // fn
+
+    #[test]
+    fn parses_known_place_types() {
+        assert_eq!("cafe".parse::<PlaceType>().unwrap(), PlaceType::Cafe);
+        assert_eq!(from_json(r#""street_address""#), PlaceType::StreetAddress);
+    } // fn
+
+    #[test]
+    fn keeps_unknown_place_types() {
+        let place_type: PlaceType = "drone_port".parse().unwrap();
+        assert_eq!(place_type, PlaceType::Other("drone_port".to_string()));
+        assert_eq!(from_json(r#""drone_port""#), place_type);
+        assert_eq!(place_type.to_string(), "drone_port");
+        assert_eq!(simd_json::serde::to_string(&place_type).unwrap(), r#""drone_port""#);
+    } // fn
+
+    #[test]
+    fn round_trips_through_codes() {
+        for place_type in [PlaceType::Airport, PlaceType::Other("other".to_string())] {
+            assert_eq!(place_type.to_string().parse::<PlaceType>().unwrap(), place_type);
+        } // for
+    } // fn
+} // mod
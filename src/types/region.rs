@@ -5,6 +5,7 @@
 //! supports._
 
 use crate::error::Error as GoogleMapsError;
+use crate::types::Error as TypeError;
 use phf::phf_map;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -632,7 +633,7 @@ impl std::convert::From<&Region> for &str {
             Region::Uganda => "ug",
             Region::Ukraine => "ua",
             Region::UnitedArabEmirates => "ae",
-            Region::UnitedKingdom => "co.uk",
+            Region::UnitedKingdom => "uk",
             Region::UnitedStates => "us",
             Region::Uruguay => "uy",
             Region::Uzbekistan => "uz",
@@ -915,6 +916,7 @@ static REGIONS_BY_CODE: phf::Map<&'static str, Region> = phf_map! {
     "ug" => Region::Uganda,
     "ua" => Region::Ukraine,
     "ae" => Region::UnitedArabEmirates,
+    "uk" => Region::UnitedKingdom,
     "co.uk" => Region::UnitedKingdom,
     "us" => Region::UnitedStates,
     "uy" => Region::Uruguay,
@@ -939,9 +941,25 @@ impl std::convert::TryFrom<&str> for Region {
     type Error = GoogleMapsError;
     /// Gets a `Region` enum from a `String` that contains a supported
     /// [region](https://developers.google.com/maps/coverage) code.
+    ///
+    /// The code is case-insensitive, so `UK` and `uk` are equivalent. A code
+    /// that is not exactly two ASCII letters is rejected. A well-formed code
+    /// that is not in the list of supported regions becomes `Region::Other`.
     fn try_from(region_code: &str) -> Result<Self, Self::Error> {
+        let lowercase_code = region_code.to_ascii_lowercase();
+
+        // ccTLDs are always two letters long. `co.uk` is still accepted
+        // because earlier versions of this crate used it for the United
+        // Kingdom:
+        if lowercase_code != "co.uk"
+            && (lowercase_code.len() != 2
+                || !lowercase_code.bytes().all(|byte| byte.is_ascii_alphabetic()))
+        {
+            return Err(TypeError::InvalidRegionCode(region_code.to_string()))?;
+        } // if
+
         Ok(REGIONS_BY_CODE
-            .get(region_code)
+            .get(lowercase_code.as_str())
             .copied()
             .unwrap_or(Self::Other))
     } // fn
@@ -955,10 +973,7 @@ impl std::str::FromStr for Region {
     /// Gets a `Region` enum from a `String` that contains a supported
     /// [region](https://developers.google.com/maps/coverage) code.
     fn from_str(region_code: &str) -> Result<Self, Self::Err> {
-        Ok(REGIONS_BY_CODE
-            .get(region_code)
-            .copied()
-            .unwrap_or(Self::Other))
+        Self::try_from(region_code)
     } // fn
 } // impl
 